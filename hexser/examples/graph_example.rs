@@ -4,8 +4,22 @@
 //! graph using hex. It shows node and edge creation, graph construction,
 //! and basic queries for analyzing the architecture.
 //!
+//! It also shows a custom GraphVisitor that counts nodes per role.
+//!
 //! Run with: `cargo run --example graph_example`
 
+/// Example visitor counting nodes per role in a single graph walk.
+#[derive(Default)]
+struct RoleCounter {
+  counts: std::collections::HashMap<hexser::graph::Role, usize>,
+}
+
+impl hexser::graph::GraphVisitor for RoleCounter {
+  fn visit_node(&mut self, node: &hexser::graph::HexNode) {
+    *self.counts.entry(node.role()).or_insert(0) += 1;
+  }
+}
+
 fn main() -> hexser::HexResult<()> {
   println!("=== Hexagonal Architecture Graph Example ===\n");
 
@@ -174,6 +188,16 @@ fn main() -> hexser::HexResult<()> {
     }
   }
 
+  // Custom analysis via visitor
+  println!("=== Role Counts (visitor) ===");
+  let mut counter = RoleCounter::default();
+  graph.accept(&mut counter);
+  let mut counts: Vec<_> = counter.counts.into_iter().collect();
+  counts.sort_by_key(|(role, _)| role.to_string());
+  for (role, count) in counts {
+    println!("{}: {}", role, count);
+  }
+
   println!("\n✅ Graph analysis complete!");
   println!("\nThis graph can be used for:");
   println!("  - Architectural validation");
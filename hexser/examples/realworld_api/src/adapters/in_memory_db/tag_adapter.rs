@@ -4,6 +4,7 @@
 //! Extracts unique tags from articles in the system.
 //!
 //! Revision History
+//! - 2026-10-18T19:20:00Z @AI: Aggregate tags via QueryRepository::group_count.
 //! - 2025-10-10T10:47:00Z @AI: Add HexAdapter derive macro for automatic registration and graph introspection.
 //! - 2025-10-10T09:17:00Z @AI: Add Clone derive for axum state management compatibility.
 //! - 2025-10-09T23:49:00Z @AI: Initial implementation of in-memory tag adapter.
@@ -26,20 +27,13 @@ impl crate::ports::tag_repository::TagRepository for InMemoryTagRepository {
         })?;
 
         let filter = crate::ports::article_repository::ArticleFilter::All;
-        let articles = hexser::ports::repository::QueryRepository::find(
+        let counts = hexser::ports::repository::QueryRepository::group_count(
             &*repo,
             &filter,
-            hexser::ports::repository::FindOptions::default()
+            |article: &crate::domain::article::Article| article.tags.clone(),
         )?;
 
-        let mut tags = std::collections::HashSet::new();
-        for article in articles {
-            for tag in article.tags {
-                tags.insert(tag);
-            }
-        }
-
-        let mut tag_list: std::vec::Vec<_> = tags.into_iter().collect();
+        let mut tag_list: std::vec::Vec<_> = counts.into_keys().collect();
         tag_list.sort();
         std::result::Result::Ok(tag_list)
     }
@@ -59,6 +59,7 @@ where
             }]),
             limit: query.limit,
             offset: query.offset,
+            include_deleted: false,
         };
 
         let articles = hexser::ports::repository::QueryRepository::find(&*repo, &filter, options)?;
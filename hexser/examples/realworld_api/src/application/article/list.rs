@@ -4,6 +4,8 @@
 //! author, and favorited user. Supports pagination and sorting.
 //!
 //! Revision History
+//! - 2026-10-19T01:25:00Z @AI: Build the listing with the fluent QueryRepository::query builder.
+//! - 2026-10-18T13:30:00Z @AI: Use find_page so articles_count reports the total match count.
 //! - 2025-10-10T00:54:00Z @AI: Initial implementation of article listing query.
 
 #[derive(hexser::HexQuery, std::clone::Clone, std::fmt::Debug)]
@@ -74,18 +76,21 @@ where
             crate::ports::article_repository::ArticleFilter::And(filters)
         };
 
-        let options = hexser::ports::repository::FindOptions {
-            sort: std::option::Option::Some(vec![hexser::ports::repository::Sort {
-                key: crate::ports::article_repository::ArticleSortKey::CreatedAt,
-                direction: hexser::ports::repository::Direction::Desc,
-            }]),
-            limit: query.limit,
-            offset: query.offset,
-        };
+        let mut articles = hexser::ports::repository::QueryRepository::query(&*repo, &filter).sort(
+            crate::ports::article_repository::ArticleSortKey::CreatedAt,
+            hexser::ports::repository::Direction::Desc,
+        );
+        if let std::option::Option::Some(limit) = query.limit {
+            articles = articles.limit(limit);
+        }
+        if let std::option::Option::Some(offset) = query.offset {
+            articles = articles.offset(offset);
+        }
 
-        let articles = hexser::ports::repository::QueryRepository::find(&*repo, &filter, options)?;
+        let page = articles.page()?;
 
-        let article_responses: std::vec::Vec<ArticleResponse> = articles
+        let article_responses: std::vec::Vec<ArticleResponse> = page
+            .items
             .into_iter()
             .map(|article| ArticleResponse {
                 id: article.id,
@@ -102,11 +107,9 @@ where
             })
             .collect();
 
-        let count = article_responses.len();
-
         std::result::Result::Ok(ArticleListResponse {
             articles: article_responses,
-            articles_count: count,
+            articles_count: page.total as usize,
         })
     }
 }
@@ -0,0 +1,490 @@
+//! Read-through cache in front of any QueryRepository.
+//!
+//! CachedQueryRepository answers repeated `find_one` and `find` calls from
+//! memory until their time-to-live expires. Entries are keyed by a hash of
+//! the filter and find options, so `Filter` and `SortKey` must be `Hash`.
+//! Writes made through the wrapper clear the cache; writes made through
+//! another handle can clear it via a `CacheInvalidator`, typically from an
+//! `ObservableRepository` hook. Other reads are delegated uncached.
+//!
+//! Revision History
+//! - 2026-10-19T12:25:00Z @AI: Forward upsert_many_and_return and invalidate after it.
+//! - 2026-10-19T09:05:00Z @AI: Forward find_with_timeout on cache misses.
+//! - 2026-10-19T07:55:00Z @AI: Invalidate on upsert_many, even when it fails partway.
+//! - 2026-10-19T07:05:00Z @AI: Forward count_estimate.
+//! - 2026-10-19T06:15:00Z @AI: Invalidate on upsert.
+//! - 2026-10-19T05:05:00Z @AI: Forward clear and invalidate afterwards.
+//! - 2026-10-19T04:45:00Z @AI: Initial CachedQueryRepository.
+
+/// Hit and miss counters of a cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+  /// Lookups answered from the cache.
+  pub hits: u64,
+  /// Lookups forwarded to the inner repository.
+  pub misses: u64,
+}
+
+impl CacheStats {
+  /// Fraction of lookups answered from the cache, or 0.0 before any lookup.
+  pub fn hit_ratio(&self) -> f64 {
+    let total = self.hits + self.misses;
+    if total == 0 {
+      0.0
+    } else {
+      self.hits as f64 / total as f64
+    }
+  }
+}
+
+#[derive(Clone)]
+enum Cached<T> {
+  One(std::option::Option<T>),
+  Many(std::vec::Vec<T>),
+}
+
+struct Entry<T> {
+  stored_at: std::time::SystemTime,
+  value: Cached<T>,
+}
+
+type Entries<T> = std::sync::Arc<std::sync::Mutex<std::collections::HashMap<u64, Entry<T>>>>;
+
+/// Handle clearing a CachedQueryRepository's entries from elsewhere.
+pub struct CacheInvalidator<T> {
+  entries: Entries<T>,
+}
+
+impl<T> CacheInvalidator<T> {
+  /// Drop every cached entry.
+  pub fn invalidate_all(&self) {
+    lock(&self.entries).clear();
+  }
+}
+
+impl<T> Clone for CacheInvalidator<T> {
+  fn clone(&self) -> Self {
+    Self {
+      entries: std::sync::Arc::clone(&self.entries),
+    }
+  }
+}
+
+/// QueryRepository decorator caching `find_one` and `find` results.
+///
+/// `save`, `save_and_return`, `delete_where`, `take_where`, and `clear` are forwarded
+/// and clear the whole cache once they succeed, since any write can change
+/// the result of any cached query. Expired entries are refetched on the next
+/// lookup. Time is read from a `Clock`, `SystemClock` by default.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::Repository;
+/// use hexser::ports::repository::{FindOptions, QueryRepository};
+///
+/// #[derive(Clone, Debug)]
+/// struct Item {
+///     id: u64,
+/// }
+///
+/// impl hexser::domain::HexEntity for Item {
+///     type Id = u64;
+/// }
+///
+/// let inner = hexser::adapters::InMemoryRepository::new(
+///     |item: &Item| item.id,
+///     |item: &Item, id: &u64| item.id == *id,
+///     |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+/// );
+/// let mut repo = hexser::adapters::CachedQueryRepository::new(
+///     inner,
+///     std::time::Duration::from_secs(30),
+/// );
+///
+/// repo.save(Item { id: 1 }).unwrap();
+/// repo.find_one(&1).unwrap();
+/// repo.find_one(&1).unwrap();
+/// std::assert_eq!(repo.stats().hits, 1);
+/// std::assert_eq!(repo.stats().misses, 1);
+/// ```
+pub struct CachedQueryRepository<R, T> {
+  inner: R,
+  ttl: std::time::Duration,
+  clock: std::sync::Arc<dyn crate::ports::clock::Clock>,
+  entries: Entries<T>,
+  hits: std::sync::atomic::AtomicU64,
+  misses: std::sync::atomic::AtomicU64,
+}
+
+impl<R, T> CachedQueryRepository<R, T> {
+  /// Wraps a repository, keeping results for `ttl`.
+  pub fn new(inner: R, ttl: std::time::Duration) -> Self {
+    Self {
+      inner,
+      ttl,
+      clock: std::sync::Arc::new(crate::adapters::clock::SystemClock),
+      entries: std::sync::Arc::default(),
+      hits: std::sync::atomic::AtomicU64::new(0),
+      misses: std::sync::atomic::AtomicU64::new(0),
+    }
+  }
+
+  /// Reads time from `clock` instead of the system clock (builder pattern).
+  pub fn with_clock(mut self, clock: std::sync::Arc<dyn crate::ports::clock::Clock>) -> Self {
+    self.clock = clock;
+    self
+  }
+
+  /// Returns hit and miss counts since creation.
+  pub fn stats(&self) -> CacheStats {
+    CacheStats {
+      hits: self.hits.load(std::sync::atomic::Ordering::Relaxed),
+      misses: self.misses.load(std::sync::atomic::Ordering::Relaxed),
+    }
+  }
+
+  /// Returns a handle that clears this cache, e.g. from an
+  /// `ObservableRepository` hook on a handle that bypasses this wrapper.
+  pub fn invalidator(&self) -> CacheInvalidator<T> {
+    CacheInvalidator {
+      entries: std::sync::Arc::clone(&self.entries),
+    }
+  }
+
+  /// Drops every cached entry.
+  pub fn invalidate_all(&self) {
+    lock(&self.entries).clear();
+  }
+
+  /// Returns a reference to the wrapped repository.
+  pub fn inner(&self) -> &R {
+    &self.inner
+  }
+
+  /// Consumes the wrapper and returns the wrapped repository.
+  pub fn into_inner(self) -> R {
+    self.inner
+  }
+}
+
+impl<R, T: Clone> CachedQueryRepository<R, T> {
+  /// Returns the cached value for `key`, or stores the one `load` produces.
+  fn read_through(
+    &self,
+    key: u64,
+    load: impl FnOnce() -> crate::result::hex_result::HexResult<Cached<T>>,
+  ) -> crate::result::hex_result::HexResult<Cached<T>> {
+    let now = self.clock.now();
+    let fresh = |entry: &Entry<T>| {
+      now
+        .duration_since(entry.stored_at)
+        .map_or(true, |age| age < self.ttl)
+    };
+    if let std::option::Option::Some(value) = lock(&self.entries)
+      .get(&key)
+      .filter(|entry| fresh(entry))
+      .map(|entry| entry.value.clone())
+    {
+      self.hits.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+      return std::result::Result::Ok(value);
+    }
+
+    self
+      .misses
+      .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let value = load()?;
+    lock(&self.entries).insert(
+      key,
+      Entry {
+        stored_at: now,
+        value: value.clone(),
+      },
+    );
+    std::result::Result::Ok(value)
+  }
+}
+
+impl<R, T> crate::adapters::Adapter for CachedQueryRepository<R, T> {}
+
+impl<R, T> crate::ports::repository::Repository<T> for CachedQueryRepository<R, T>
+where
+  R: crate::ports::repository::Repository<T>,
+  T: crate::domain::entity::HexEntity + Clone,
+{
+  fn save(&mut self, entity: T) -> crate::result::hex_result::HexResult<()> {
+    self.inner.save(entity)?;
+    self.invalidate_all();
+    std::result::Result::Ok(())
+  }
+
+  fn save_and_return(&mut self, entity: T) -> crate::result::hex_result::HexResult<T> {
+    let saved = self.inner.save_and_return(entity)?;
+    self.invalidate_all();
+    std::result::Result::Ok(saved)
+  }
+
+  fn upsert(
+    &mut self,
+    entity: T,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Upserted> {
+    let outcome = self.inner.upsert(entity)?;
+    self.invalidate_all();
+    std::result::Result::Ok(outcome)
+  }
+
+  fn upsert_many(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::UpsertReport> {
+    let result = self.inner.upsert_many(entities, strategy);
+    self.invalidate_all();
+    result
+  }
+
+  fn upsert_many_and_return(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<(
+    crate::ports::repository::UpsertReport,
+    std::vec::Vec<T>,
+  )>
+  where
+    T: Clone,
+  {
+    let result = self.inner.upsert_many_and_return(entities, strategy);
+    self.invalidate_all();
+    result
+  }
+}
+
+impl<R, T> crate::ports::repository::QueryRepository<T> for CachedQueryRepository<R, T>
+where
+  R: crate::ports::repository::QueryRepository<T>,
+  R::Filter: std::hash::Hash,
+  R::SortKey: std::hash::Hash,
+  T: crate::domain::entity::HexEntity + Clone,
+{
+  type Filter = R::Filter;
+  type SortKey = R::SortKey;
+
+  fn find_one(
+    &self,
+    filter: &Self::Filter,
+  ) -> crate::result::hex_result::HexResult<std::option::Option<T>> {
+    let key = cache_key(
+      "find_one",
+      filter,
+      &crate::ports::repository::FindOptions::<R::SortKey>::default(),
+    );
+    let value = self.read_through(key, || self.inner.find_one(filter).map(Cached::One))?;
+    match value {
+      Cached::One(found) => std::result::Result::Ok(found),
+      Cached::Many(_) => unreachable!("find_one entries hold a single result"),
+    }
+  }
+
+  fn find(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    let key = cache_key("find", filter, &options);
+    let value = self.read_through(key, || self.inner.find(filter, options).map(Cached::Many))?;
+    match value {
+      Cached::Many(found) => std::result::Result::Ok(found),
+      Cached::One(_) => unreachable!("find entries hold a result list"),
+    }
+  }
+
+  fn find_with_timeout(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+    timeout: std::time::Duration,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    let key = cache_key("find", filter, &options);
+    let value = self.read_through(key, || {
+      self
+        .inner
+        .find_with_timeout(filter, options, timeout)
+        .map(Cached::Many)
+    })?;
+    match value {
+      Cached::Many(found) => std::result::Result::Ok(found),
+      Cached::One(_) => unreachable!("find entries hold a result list"),
+    }
+  }
+
+  fn find_page(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Page<T>> {
+    self.inner.find_page(filter, options)
+  }
+
+  fn exists(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<bool> {
+    self.inner.exists(filter)
+  }
+
+  fn count(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.count(filter)
+  }
+
+  fn count_estimate(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.count_estimate(filter)
+  }
+
+  fn delete_where(&mut self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    let removed = self.inner.delete_where(filter)?;
+    self.invalidate_all();
+    std::result::Result::Ok(removed)
+  }
+
+  fn clear(&mut self) -> crate::result::hex_result::HexResult<u64> {
+    let removed = self.inner.clear()?;
+    self.invalidate_all();
+    std::result::Result::Ok(removed)
+  }
+
+  fn take_where(
+    &mut self,
+    filter: &Self::Filter,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    let taken = self.inner.take_where(filter)?;
+    self.invalidate_all();
+    std::result::Result::Ok(taken)
+  }
+}
+
+/// Hashes the operation, filter, and every option that affects the result.
+fn cache_key<F: std::hash::Hash, K: std::hash::Hash>(
+  operation: &str,
+  filter: &F,
+  options: &crate::ports::repository::FindOptions<K>,
+) -> u64 {
+  let mut hasher = std::collections::hash_map::DefaultHasher::new();
+  std::hash::Hash::hash(operation, &mut hasher);
+  std::hash::Hash::hash(filter, &mut hasher);
+  for sort in options.sort.iter().flatten() {
+    std::hash::Hash::hash(&sort.key, &mut hasher);
+    std::hash::Hash::hash(
+      &(sort.direction == crate::ports::repository::Direction::Asc),
+      &mut hasher,
+    );
+  }
+  std::hash::Hash::hash(&options.sort.is_some(), &mut hasher);
+  std::hash::Hash::hash(&options.limit, &mut hasher);
+  std::hash::Hash::hash(&options.offset, &mut hasher);
+  std::hash::Hash::hash(&options.include_deleted, &mut hasher);
+  std::hash::Hasher::finish(&hasher)
+}
+
+fn lock<T>(
+  entries: &Entries<T>,
+) -> std::sync::MutexGuard<'_, std::collections::HashMap<u64, Entry<T>>> {
+  entries
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::ports::repository::{FindOptions, QueryRepository, Repository};
+
+  #[derive(Clone, Debug, PartialEq)]
+  struct Item {
+    id: u64,
+  }
+
+  impl crate::domain::entity::HexEntity for Item {
+    type Id = u64;
+  }
+
+  type Inner = crate::adapters::InMemoryRepository<Item, u64, ()>;
+
+  fn in_memory() -> Inner {
+    crate::adapters::InMemoryRepository::new(
+      |item: &Item| item.id,
+      |item: &Item, id: &u64| item.id == *id,
+      |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+    )
+  }
+
+  fn cached(
+    ttl_secs: u64,
+  ) -> (
+    super::CachedQueryRepository<Inner, Item>,
+    std::sync::Arc<crate::adapters::FixedClock>,
+  ) {
+    let clock = std::sync::Arc::new(crate::adapters::FixedClock::at_unix_secs(0));
+    let mut repo =
+      super::CachedQueryRepository::new(in_memory(), std::time::Duration::from_secs(ttl_secs))
+        .with_clock(clock.clone());
+    repo.save(Item { id: 1 }).unwrap();
+    (repo, clock)
+  }
+
+  #[test]
+  fn test_repeated_lookup_hits_cache() {
+    let (repo, _clock) = cached(60);
+    std::assert_eq!(repo.find_one(&1).unwrap(), Some(Item { id: 1 }));
+    std::assert_eq!(repo.find_one(&1).unwrap(), Some(Item { id: 1 }));
+    std::assert_eq!(repo.find(&1, FindOptions::default()).unwrap().len(), 1);
+    std::assert_eq!(repo.find(&1, FindOptions::default()).unwrap().len(), 1);
+
+    std::assert_eq!(repo.stats(), super::CacheStats { hits: 2, misses: 2 });
+    std::assert_eq!(repo.stats().hit_ratio(), 0.5);
+  }
+
+  #[test]
+  fn test_different_options_and_expired_entries_miss() {
+    let (repo, clock) = cached(60);
+    repo.find(&1, FindOptions::default()).unwrap();
+    repo
+      .find(
+        &1,
+        FindOptions {
+          limit: Some(1),
+          ..FindOptions::default()
+        },
+      )
+      .unwrap();
+    clock.advance(std::time::Duration::from_secs(60));
+    repo.find(&1, FindOptions::default()).unwrap();
+
+    std::assert_eq!(repo.stats().hits, 0);
+    std::assert_eq!(repo.stats().misses, 3);
+  }
+
+  #[test]
+  fn test_writes_invalidate_cached_results() {
+    let (mut repo, _clock) = cached(60);
+    std::assert_eq!(repo.find_one(&2).unwrap(), None);
+
+    repo.save(Item { id: 2 }).unwrap();
+    std::assert_eq!(repo.find_one(&2).unwrap(), Some(Item { id: 2 }));
+
+    std::assert_eq!(repo.delete_where(&2).unwrap(), 1);
+    std::assert_eq!(repo.find_one(&2).unwrap(), None);
+    std::assert_eq!(repo.stats().hits, 0);
+  }
+
+  #[test]
+  fn test_invalidator_clears_cache_from_observable_hook() {
+    let (repo, _clock) = cached(60);
+    repo.find_one(&1).unwrap();
+
+    let invalidator = repo.invalidator();
+    let mut writer = crate::adapters::ObservableRepository::<_, Item, u64>::new(in_memory())
+      .on_save(move |_item: &Item| invalidator.invalidate_all());
+    writer.save(Item { id: 3 }).unwrap();
+
+    repo.find_one(&1).unwrap();
+    std::assert_eq!(repo.stats().hits, 0);
+    std::assert_eq!(repo.stats().misses, 2);
+  }
+}
@@ -0,0 +1,101 @@
+//! Clock adapters implementing the Clock port.
+//!
+//! `SystemClock` reads the operating system time and is the production
+//! default. `FixedClock` returns a stored instant that tests can set or
+//! advance explicitly, making time-dependent behaviour deterministic.
+//!
+//! Revision History
+//! - 2026-10-18T23:50:00Z @AI: Initial SystemClock and FixedClock adapters.
+
+/// Clock backed by `std::time::SystemTime::now()`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl crate::adapters::Adapter for SystemClock {}
+
+impl crate::ports::clock::Clock for SystemClock {
+  fn now(&self) -> std::time::SystemTime {
+    std::time::SystemTime::now()
+  }
+}
+
+/// Clock returning a stored instant that only moves when told to.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::ports::Clock;
+///
+/// let clock = hexser::adapters::FixedClock::at_unix_secs(1_760_021_460);
+/// std::assert_eq!(clock.now_rfc3339(), "2025-10-09T14:51:00Z");
+///
+/// clock.advance(std::time::Duration::from_secs(60));
+/// std::assert_eq!(clock.now_rfc3339(), "2025-10-09T14:52:00Z");
+/// ```
+#[derive(Debug)]
+pub struct FixedClock {
+  instant: std::sync::Mutex<std::time::SystemTime>,
+}
+
+impl FixedClock {
+  /// Creates a clock frozen at `instant`.
+  pub fn new(instant: std::time::SystemTime) -> Self {
+    Self {
+      instant: std::sync::Mutex::new(instant),
+    }
+  }
+
+  /// Creates a clock frozen at `secs` seconds after the Unix epoch.
+  pub fn at_unix_secs(secs: u64) -> Self {
+    Self::new(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+  }
+
+  /// Moves the clock to `instant`.
+  pub fn set(&self, instant: std::time::SystemTime) {
+    *self.lock() = instant;
+  }
+
+  /// Moves the clock forward by `by`.
+  pub fn advance(&self, by: std::time::Duration) {
+    let mut instant = self.lock();
+    *instant += by;
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, std::time::SystemTime> {
+    self
+      .instant
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+impl crate::adapters::Adapter for FixedClock {}
+
+impl crate::ports::clock::Clock for FixedClock {
+  fn now(&self) -> std::time::SystemTime {
+    *self.lock()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ports::clock::Clock;
+
+  #[test]
+  fn test_fixed_clock_set_and_advance() {
+    let clock = FixedClock::at_unix_secs(0);
+    std::assert_eq!(clock.now(), std::time::UNIX_EPOCH);
+
+    clock.advance(std::time::Duration::from_secs(86_400));
+    std::assert_eq!(clock.now_rfc3339(), "1970-01-02T00:00:00Z");
+
+    clock.set(std::time::UNIX_EPOCH + std::time::Duration::from_secs(951_782_400));
+    std::assert_eq!(clock.now_rfc3339(), "2000-02-29T00:00:00Z");
+  }
+
+  #[test]
+  fn test_system_clock_is_after_epoch() {
+    std::assert!(SystemClock.now() > std::time::UNIX_EPOCH);
+  }
+}
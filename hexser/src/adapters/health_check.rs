@@ -0,0 +1,191 @@
+//! HealthCheck adapters: a constant check and an aggregator.
+//!
+//! AlwaysHealthy suits components with nothing to probe. HealthAggregator
+//! runs a set of named checks and reduces them to a HealthReport whose
+//! overall status is the worst individual one; it is itself a HealthCheck,
+//! so aggregators can be nested per subsystem. Services resolved from the
+//! container come back as `Arc`s and can be registered with
+//! `with_shared_check`.
+//!
+//! Revision History
+//! - 2026-10-19T06:45:00Z @AI: Initial AlwaysHealthy and HealthAggregator.
+
+/// HealthCheck that always reports `Healthy`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysHealthy;
+
+#[async_trait::async_trait]
+impl crate::ports::health_check::HealthCheck for AlwaysHealthy {
+  async fn check(&self) -> crate::ports::health_check::HealthStatus {
+    crate::ports::health_check::HealthStatus::Healthy
+  }
+}
+
+/// Statuses of every check run by a HealthAggregator, in registration order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+  /// Check name and status pairs
+  pub checks: Vec<(String, crate::ports::health_check::HealthStatus)>,
+}
+
+impl HealthReport {
+  /// Worst status among the checks, `Healthy` when there are none
+  ///
+  /// The reason lists every non-healthy check as `name: reason`, separated
+  /// by "; ".
+  pub fn status(&self) -> crate::ports::health_check::HealthStatus {
+    let worst = self
+      .checks
+      .iter()
+      .map(|(_, status)| status.severity())
+      .max()
+      .unwrap_or(0);
+    let reasons = self
+      .checks
+      .iter()
+      .filter_map(|(name, status)| status.reason().map(|r| format!("{}: {}", name, r)))
+      .collect::<Vec<_>>()
+      .join("; ");
+    match worst {
+      0 => crate::ports::health_check::HealthStatus::Healthy,
+      1 => crate::ports::health_check::HealthStatus::Degraded(reasons),
+      _ => crate::ports::health_check::HealthStatus::Unhealthy(reasons),
+    }
+  }
+
+  /// Whether no check is `Unhealthy`
+  pub fn is_ready(&self) -> bool {
+    self.checks.iter().all(|(_, status)| status.is_ready())
+  }
+}
+
+/// Runs named health checks and summarizes them.
+///
+/// Checks run one after another, in registration order.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::adapters::{AlwaysHealthy, HealthAggregator};
+///
+/// let health = HealthAggregator::new()
+///     .with_check("config", AlwaysHealthy)
+///     .with_check("clock", AlwaysHealthy);
+///
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// let report = runtime.block_on(health.run());
+/// assert!(report.status().is_healthy());
+/// ```
+#[derive(Default)]
+pub struct HealthAggregator {
+  checks: Vec<(
+    String,
+    std::sync::Arc<dyn crate::ports::health_check::HealthCheck>,
+  )>,
+}
+
+impl HealthAggregator {
+  /// Create an aggregator with no checks
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Register a check under `name` (builder pattern)
+  pub fn with_check(
+    self,
+    name: &str,
+    check: impl crate::ports::health_check::HealthCheck + 'static,
+  ) -> Self {
+    self.with_shared_check(name, std::sync::Arc::new(check))
+  }
+
+  /// Register a shared check under `name` (builder pattern)
+  pub fn with_shared_check(
+    mut self,
+    name: &str,
+    check: std::sync::Arc<dyn crate::ports::health_check::HealthCheck>,
+  ) -> Self {
+    self.checks.push((String::from(name), check));
+    self
+  }
+
+  /// Number of registered checks
+  pub fn len(&self) -> usize {
+    self.checks.len()
+  }
+
+  /// Whether no checks are registered
+  pub fn is_empty(&self) -> bool {
+    self.checks.is_empty()
+  }
+
+  /// Run every check and collect the results
+  pub async fn run(&self) -> HealthReport {
+    let mut checks = Vec::with_capacity(self.checks.len());
+    for (name, check) in &self.checks {
+      checks.push((name.clone(), check.check().await));
+    }
+    HealthReport { checks }
+  }
+}
+
+impl std::fmt::Debug for HealthAggregator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let names: Vec<&str> = self.checks.iter().map(|(name, _)| name.as_str()).collect();
+    f.debug_struct("HealthAggregator")
+      .field("checks", &names)
+      .finish()
+  }
+}
+
+#[async_trait::async_trait]
+impl crate::ports::health_check::HealthCheck for HealthAggregator {
+  async fn check(&self) -> crate::ports::health_check::HealthStatus {
+    self.run().await.status()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ports::health_check::{HealthCheck, HealthStatus};
+
+  struct Fixed(HealthStatus);
+
+  #[async_trait::async_trait]
+  impl HealthCheck for Fixed {
+    async fn check(&self) -> HealthStatus {
+      self.0.clone()
+    }
+  }
+
+  #[tokio::test]
+  async fn test_aggregates_mixed_statuses() {
+    let cache = Fixed(HealthStatus::Degraded(String::from("high latency")));
+    let health = HealthAggregator::new()
+      .with_check("config", AlwaysHealthy)
+      .with_check("cache", cache);
+
+    let report = health.run().await;
+    assert!(report.is_ready());
+    assert_eq!(
+      report.status(),
+      HealthStatus::Degraded(String::from("cache: high latency"))
+    );
+
+    let health = health.with_shared_check(
+      "db",
+      std::sync::Arc::new(Fixed(HealthStatus::Unhealthy(String::from("refused")))),
+    );
+    assert_eq!(
+      health.check().await,
+      HealthStatus::Unhealthy(String::from("cache: high latency; db: refused"))
+    );
+    assert!(!health.run().await.is_ready());
+  }
+
+  #[tokio::test]
+  async fn test_empty_aggregator_is_healthy() {
+    assert!(HealthAggregator::new().check().await.is_healthy());
+  }
+}
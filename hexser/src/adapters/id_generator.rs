@@ -0,0 +1,149 @@
+//! IdGenerator adapters implementing the IdGenerator port.
+//!
+//! `SequentialGenerator` hands out increasing integers from an atomic
+//! counter and is meant for tests and single-process prototypes.
+//! `UuidGenerator` (behind the `uuid` feature) produces random v4 UUIDs.
+//!
+//! Revision History
+//! - 2026-10-18T23:55:00Z @AI: Initial SequentialGenerator and UuidGenerator adapters.
+
+/// Generator handing out `1, 2, 3, ...` from an atomic counter.
+///
+/// Yields `u64` ids directly, or `String` ids formed as `prefix + n`.
+/// The counter is shared across threads, so concurrent callers never
+/// receive the same value.
+#[derive(Debug)]
+pub struct SequentialGenerator {
+  next: std::sync::atomic::AtomicU64,
+  prefix: std::string::String,
+}
+
+impl SequentialGenerator {
+  /// Creates a generator starting at 1 with no string prefix.
+  pub fn new() -> Self {
+    Self::starting_at(1)
+  }
+
+  /// Creates a generator whose first id is `first`.
+  pub fn starting_at(first: u64) -> Self {
+    Self {
+      next: std::sync::atomic::AtomicU64::new(first),
+      prefix: std::string::String::new(),
+    }
+  }
+
+  /// Creates a generator starting at 1 whose `String` ids carry `prefix`.
+  pub fn with_prefix(prefix: impl Into<std::string::String>) -> Self {
+    Self {
+      prefix: prefix.into(),
+      ..Self::new()
+    }
+  }
+
+  fn next_value(&self) -> crate::result::hex_result::HexResult<u64> {
+    self
+      .next
+      .fetch_update(
+        std::sync::atomic::Ordering::Relaxed,
+        std::sync::atomic::Ordering::Relaxed,
+        |n| n.checked_add(1),
+      )
+      .map_err(|_| {
+        crate::error::hex_error::Hexserror::adapter(
+          crate::error::codes::validation::OUT_OF_RANGE,
+          "SequentialGenerator exhausted the u64 id space",
+        )
+        .with_next_step("Use a wider id type or a UuidGenerator")
+      })
+  }
+}
+
+impl std::default::Default for SequentialGenerator {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl crate::adapters::Adapter for SequentialGenerator {}
+
+impl crate::ports::id_generator::IdGenerator<u64> for SequentialGenerator {
+  fn next_id(&self) -> crate::result::hex_result::HexResult<u64> {
+    self.next_value()
+  }
+}
+
+impl crate::ports::id_generator::IdGenerator<std::string::String> for SequentialGenerator {
+  fn next_id(&self) -> crate::result::hex_result::HexResult<std::string::String> {
+    std::result::Result::Ok(format!("{}{}", self.prefix, self.next_value()?))
+  }
+}
+
+/// Generator producing random (v4) UUIDs.
+///
+/// Yields `uuid::Uuid` ids directly, or their hyphenated `String` form.
+#[cfg(feature = "uuid")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UuidGenerator;
+
+#[cfg(feature = "uuid")]
+impl crate::adapters::Adapter for UuidGenerator {}
+
+#[cfg(feature = "uuid")]
+impl crate::ports::id_generator::IdGenerator<uuid::Uuid> for UuidGenerator {
+  fn next_id(&self) -> crate::result::hex_result::HexResult<uuid::Uuid> {
+    std::result::Result::Ok(uuid::Uuid::new_v4())
+  }
+}
+
+#[cfg(feature = "uuid")]
+impl crate::ports::id_generator::IdGenerator<std::string::String> for UuidGenerator {
+  fn next_id(&self) -> crate::result::hex_result::HexResult<std::string::String> {
+    std::result::Result::Ok(uuid::Uuid::new_v4().to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ports::id_generator::IdGenerator;
+
+  #[test]
+  fn test_sequential_generator_is_unique_across_threads() {
+    let ids = std::sync::Arc::new(SequentialGenerator::starting_at(10));
+    let handles: std::vec::Vec<_> = (0..4)
+      .map(|_| {
+        let ids = std::sync::Arc::clone(&ids);
+        std::thread::spawn(move || {
+          (0..100)
+            .map(|_| IdGenerator::<u64>::next_id(&*ids).unwrap())
+            .collect::<std::vec::Vec<_>>()
+        })
+      })
+      .collect();
+
+    let mut all: std::vec::Vec<u64> = handles
+      .into_iter()
+      .flat_map(|h| h.join().unwrap())
+      .collect();
+    all.sort_unstable();
+    all.dedup();
+    std::assert_eq!(all.len(), 400);
+    std::assert_eq!(all.first(), std::option::Option::Some(&10));
+  }
+
+  #[test]
+  fn test_sequential_generator_reports_exhaustion() {
+    let ids = SequentialGenerator::starting_at(u64::MAX);
+    std::assert!(IdGenerator::<u64>::next_id(&ids).is_err());
+  }
+
+  #[cfg(feature = "uuid")]
+  #[test]
+  fn test_uuid_generator_produces_distinct_ids() {
+    let ids = UuidGenerator;
+    let a: std::string::String = ids.next_id().unwrap();
+    let b: std::string::String = ids.next_id().unwrap();
+    std::assert_ne!(a, b);
+    std::assert_eq!(a.len(), 36);
+  }
+}
@@ -0,0 +1,61 @@
+//! In-memory DirectiveLog adapter.
+//!
+//! Keeps records in a mutex-guarded vector. Suited to tests and to
+//! capturing a session in-process before persisting it elsewhere.
+//!
+//! Revision History
+//! - 2026-10-19T07:25:00Z @AI: Initial InMemoryDirectiveLog.
+
+/// DirectiveLog holding its records in memory.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::adapters::InMemoryDirectiveLog;
+/// use hexser::ports::DirectiveLog;
+///
+/// let log = InMemoryDirectiveLog::new();
+/// log.append(String::from("{\"id\":1}")).unwrap();
+/// assert_eq!(log.records().unwrap(), vec!["{\"id\":1}"]);
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryDirectiveLog {
+  records: std::sync::Mutex<Vec<String>>,
+}
+
+impl InMemoryDirectiveLog {
+  /// Create an empty log
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Number of records
+  pub fn len(&self) -> usize {
+    self.lock().len()
+  }
+
+  /// Whether the log has no records
+  pub fn is_empty(&self) -> bool {
+    self.lock().is_empty()
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+    self
+      .records
+      .lock()
+      .unwrap_or_else(std::sync::PoisonError::into_inner)
+  }
+}
+
+impl crate::adapters::Adapter for InMemoryDirectiveLog {}
+
+impl crate::ports::directive_log::DirectiveLog for InMemoryDirectiveLog {
+  fn append(&self, record: String) -> crate::result::hex_result::HexResult<()> {
+    self.lock().push(record);
+    Ok(())
+  }
+
+  fn records(&self) -> crate::result::hex_result::HexResult<Vec<String>> {
+    Ok(self.lock().clone())
+  }
+}
@@ -6,6 +6,9 @@
 //! and delivered synchronously without persistence.
 //!
 //! Revision History
+//! - 2026-10-19T09:15:00Z @AI: Best-effort publish_batch with per-event PublishReport.
+//! - 2026-10-19T02:15:00Z @AI: Document and test per-partition-key delivery order.
+//! - 2026-10-18T19:30:00Z @AI: Implement AsyncEventPublisher behind the async feature.
 //! - 2025-10-09T15:08:00Z @AI: Fix doc test to use trait imports for subscribe/publish methods.
 //! - 2025-10-09T14:51:00Z @AI: Initial InMemoryEventBus adapter implementation.
 
@@ -18,6 +21,10 @@
 /// # Characteristics
 ///
 /// - **Synchronous**: Events are delivered immediately on publish
+/// - **Ordered**: Handlers and `poll` see events in publish order, so events
+///   sharing a partition key are never reordered
+/// - **Best-effort batches**: `publish_batch` keeps going past a failed
+///   event; see `publish_batch_report`
 /// - **In-memory**: No persistence, events lost on drop
 /// - **Topic-based**: Events routed by topic to registered handlers
 /// - **Single-threaded**: No concurrency support (uses RefCell)
@@ -158,6 +165,51 @@ impl<T> InMemoryEventBus<T> {
   }
 }
 
+impl<T> InMemoryEventBus<T>
+where
+  T: Clone,
+{
+  /// Publishes every envelope in order, reporting each outcome.
+  ///
+  /// A failed envelope does not stop the batch: its error is recorded at
+  /// its index and later envelopes are still attempted, so successes keep
+  /// their relative order. Delivery is at-least-once. A handler failure
+  /// happens after the envelope was queued, so it may already be visible
+  /// to `poll`, and retrying the failed indices can deliver it twice.
+  /// Consumers should deduplicate by envelope id.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let bus: hexser::adapters::InMemoryEventBus<std::string::String> =
+  ///     hexser::adapters::InMemoryEventBus::new();
+  /// let envelope = |id: &str| {
+  ///     hexser::ports::events::CloudEventsEnvelope::<std::string::String>::new(
+  ///         std::string::String::from(id),
+  ///         std::string::String::from("/test/source"),
+  ///         std::string::String::from("com.test.event"),
+  ///     )
+  /// };
+  ///
+  /// let report = bus.publish_batch_report(&[envelope("evt-1"), envelope(""), envelope("evt-3")]);
+  /// std::assert_eq!(report.delivered(), vec![0, 2]);
+  /// std::assert_eq!(report.failed_indices(), vec![1]);
+  /// std::assert_eq!(bus.queue_size(), 2);
+  /// ```
+  pub fn publish_batch_report(
+    &self,
+    envelopes: &[crate::ports::events::CloudEventsEnvelope<T>],
+  ) -> crate::ports::events::PublishReport {
+    let mut report = crate::ports::events::PublishReport::new();
+    for envelope in envelopes {
+      report.push(crate::ports::events::EventPublisher::publish(
+        self, envelope,
+      ));
+    }
+    report
+  }
+}
+
 impl<T> Default for InMemoryEventBus<T> {
   fn default() -> Self {
     Self::new()
@@ -190,14 +242,34 @@ where
     std::result::Result::Ok(())
   }
 
+  /// Best-effort: attempts every envelope, then aggregates any failures.
+  ///
+  /// Use `publish_batch_report` to see which envelopes were delivered.
   fn publish_batch(
     &self,
     envelopes: &[crate::ports::events::CloudEventsEnvelope<T>],
   ) -> crate::HexResult<()> {
-    for envelope in envelopes {
-      self.publish(envelope)?;
-    }
-    std::result::Result::Ok(())
+    self.publish_batch_report(envelopes).into_result()
+  }
+}
+
+#[cfg(feature = "async")]
+impl<T> crate::ports::events::AsyncEventPublisher<T> for InMemoryEventBus<T>
+where
+  T: Clone,
+{
+  async fn publish(
+    &self,
+    envelope: &crate::ports::events::CloudEventsEnvelope<T>,
+  ) -> crate::HexResult<()> {
+    crate::ports::events::EventPublisher::publish(self, envelope)
+  }
+
+  async fn publish_batch(
+    &self,
+    envelopes: &[crate::ports::events::CloudEventsEnvelope<T>],
+  ) -> crate::HexResult<()> {
+    crate::ports::events::EventPublisher::publish_batch(self, envelopes)
   }
 }
 
@@ -312,6 +384,56 @@ mod tests {
     std::assert_eq!(bus.queue_size(), 2);
   }
 
+  #[test]
+  fn test_publish_batch_continues_past_failed_event() {
+    let mut bus: InMemoryEventBus<TestEvent> = InMemoryEventBus::new();
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(std::vec::Vec::new()));
+    let recorder = std::rc::Rc::clone(&seen);
+    bus
+      .subscribe(
+        "default.events",
+        std::boxed::Box::new(move |envelope| {
+          if envelope.id == "evt-2" {
+            return std::result::Result::Err(crate::error::hex_error::Hexserror::adapter(
+              crate::error::codes::adapter::API_FAILURE,
+              "handler rejected event",
+            ));
+          }
+          recorder.borrow_mut().push(envelope.id.clone());
+          std::result::Result::Ok(())
+        }),
+      )
+      .unwrap();
+    let envelopes: std::vec::Vec<_> = ["evt-1", "evt-2", "evt-3"]
+      .iter()
+      .map(|id| {
+        crate::ports::events::CloudEventsEnvelope::from_domain_event(
+          std::string::String::from(*id),
+          std::string::String::from("/test/source"),
+          TestEvent {
+            id: std::string::String::from("order-1"),
+            value: std::string::String::from("v"),
+          },
+        )
+      })
+      .collect();
+
+    let report = bus.publish_batch_report(&envelopes);
+    std::assert_eq!(report.delivered(), vec![0, 2]);
+    let failures: std::vec::Vec<_> = report.failures().collect();
+    std::assert_eq!(failures.len(), 1);
+    std::assert_eq!(failures[0].0, 1);
+    std::assert_eq!(
+      failures[0].1.code(),
+      crate::error::codes::adapter::API_FAILURE
+    );
+    std::assert_eq!(*seen.borrow(), vec!["evt-1", "evt-3"]);
+
+    let err = bus.publish_batch(&envelopes).unwrap_err();
+    std::assert_eq!(err.sub_errors().len(), 1);
+    std::assert_eq!(*seen.borrow(), vec!["evt-1", "evt-3", "evt-1", "evt-3"]);
+  }
+
   #[test]
   fn test_poll_returns_none_when_empty() {
     let mut bus: InMemoryEventBus<TestEvent> = InMemoryEventBus::new();
@@ -375,6 +497,52 @@ mod tests {
     std::assert!(invoked.load(std::sync::atomic::Ordering::SeqCst));
   }
 
+  #[test]
+  fn test_same_partition_key_delivered_in_publish_order() {
+    let mut bus: InMemoryEventBus<TestEvent> = InMemoryEventBus::new();
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(std::vec::Vec::new()));
+    let recorder = std::rc::Rc::clone(&seen);
+    bus
+      .subscribe(
+        "default.events",
+        std::boxed::Box::new(move |envelope| {
+          recorder.borrow_mut().push(envelope.id.clone());
+          std::result::Result::Ok(())
+        }),
+      )
+      .unwrap();
+
+    let envelope = |id: &str, aggregate: &str| {
+      crate::ports::events::CloudEventsEnvelope::from_domain_event(
+        std::string::String::from(id),
+        std::string::String::from("/test/source"),
+        TestEvent {
+          id: std::string::String::from(aggregate),
+          value: std::string::String::from("v"),
+        },
+      )
+    };
+    bus.publish(&envelope("evt-1", "order-1")).unwrap();
+    bus.publish(&envelope("evt-2", "order-2")).unwrap();
+    bus.publish(&envelope("evt-3", "order-1")).unwrap();
+
+    let order_1: std::vec::Vec<std::string::String> = seen
+      .borrow()
+      .iter()
+      .filter(|id| *id != "evt-2")
+      .cloned()
+      .collect();
+    std::assert_eq!(order_1, vec!["evt-1", "evt-3"]);
+
+    let mut polled = std::vec::Vec::new();
+    while let std::option::Option::Some(next) = bus.poll().unwrap() {
+      if next.partition_key() == std::option::Option::Some("order-1") {
+        polled.push(next.id);
+      }
+    }
+    std::assert_eq!(polled, vec!["evt-1", "evt-3"]);
+  }
+
   #[test]
   fn test_clear_empties_queue() {
     let mut bus: InMemoryEventBus<TestEvent> = InMemoryEventBus::new();
@@ -412,4 +580,41 @@ mod tests {
     std::assert!(result.is_err());
     std::assert_eq!(bus.queue_size(), 0);
   }
+
+  #[cfg(feature = "async")]
+  #[tokio::test]
+  async fn test_async_publish_delivers_to_handler() {
+    // Test: The async port publishes through the same queue and handlers.
+    // Justification: Async application code must be testable with the in-memory bus.
+    let delivered = std::rc::Rc::new(std::cell::Cell::new(0));
+    let counter = std::rc::Rc::clone(&delivered);
+    let mut bus: InMemoryEventBus<TestEvent> = InMemoryEventBus::new();
+    bus
+      .subscribe(
+        "test.events",
+        std::boxed::Box::new(move |_envelope| {
+          counter.set(counter.get() + 1);
+          std::result::Result::Ok(())
+        }),
+      )
+      .unwrap();
+
+    let envelope = crate::ports::events::CloudEventsEnvelope::from_domain_event(
+      std::string::String::from("evt-001"),
+      std::string::String::from("/test/source"),
+      TestEvent {
+        id: std::string::String::from("test-1"),
+        value: std::string::String::from("value-1"),
+      },
+    );
+    crate::ports::events::AsyncEventPublisher::publish(&bus, &envelope)
+      .await
+      .unwrap();
+    crate::ports::events::AsyncEventPublisher::publish_batch(&bus, &[envelope])
+      .await
+      .unwrap();
+
+    std::assert_eq!(bus.queue_size(), 2);
+    std::assert_eq!(delivered.get(), 2);
+  }
 }
@@ -0,0 +1,149 @@
+//! InMemoryEventStore adapter for event-sourced aggregates.
+//!
+//! This module provides an in-memory implementation of the EventStore port.
+//! Streams are kept per aggregate id in append order and are lost on drop.
+//! It is intended for tests, prototypes, and as a reference implementation.
+//!
+//! Revision History
+//! - 2026-10-18T14:00:00Z @AI: Initial InMemoryEventStore adapter.
+
+/// In-memory event store keeping one ordered stream per aggregate.
+///
+/// Events are cloned out of the store on `load`, so `E` must be `Clone`.
+/// Heterogeneous streams can use `std::sync::Arc<dyn DomainEvent>`.
+///
+/// # Type Parameter
+///
+/// - `E`: The domain event type stored in each stream
+#[derive(Debug)]
+pub struct InMemoryEventStore<E> {
+  streams: std::collections::HashMap<std::string::String, std::vec::Vec<E>>,
+}
+
+impl<E> InMemoryEventStore<E> {
+  /// Creates an empty event store.
+  pub fn new() -> Self {
+    Self {
+      streams: std::collections::HashMap::new(),
+    }
+  }
+
+  /// Returns the number of events stored for an aggregate.
+  pub fn stream_len(&self, aggregate_id: &str) -> usize {
+    self.streams.get(aggregate_id).map_or(0, |s| s.len())
+  }
+}
+
+impl<E> std::default::Default for InMemoryEventStore<E> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<E> crate::adapters::Adapter for InMemoryEventStore<E> {}
+
+impl<E> crate::ports::event_store::EventStore<E> for InMemoryEventStore<E>
+where
+  E: crate::domain::domain_event::DomainEvent + Clone,
+{
+  fn append(
+    &mut self,
+    aggregate_id: &str,
+    events: std::vec::Vec<E>,
+  ) -> crate::result::hex_result::HexResult<()> {
+    if let std::option::Option::Some(stray) =
+      events.iter().find(|e| e.aggregate_id() != aggregate_id)
+    {
+      return std::result::Result::Err(
+        crate::error::hex_error::Hexserror::validation_field(
+          &format!(
+            "Event '{}' belongs to aggregate '{}', not '{}'",
+            stray.event_type(),
+            stray.aggregate_id(),
+            aggregate_id
+          ),
+          "aggregate_id",
+        )
+        .with_next_step("Append events to the stream of the aggregate that produced them"),
+      );
+    }
+
+    self
+      .streams
+      .entry(std::string::String::from(aggregate_id))
+      .or_default()
+      .extend(events);
+    std::result::Result::Ok(())
+  }
+
+  fn load(&self, aggregate_id: &str) -> crate::result::hex_result::HexResult<std::vec::Vec<E>> {
+    std::result::Result::Ok(self.streams.get(aggregate_id).cloned().unwrap_or_default())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::ports::event_store::EventStore;
+
+  #[derive(Clone, Debug, PartialEq)]
+  struct Deposited {
+    account_id: std::string::String,
+    amount: u64,
+  }
+
+  impl crate::domain::domain_event::DomainEvent for Deposited {
+    fn event_type(&self) -> &str {
+      "Deposited"
+    }
+
+    fn aggregate_id(&self) -> std::string::String {
+      self.account_id.clone()
+    }
+  }
+
+  fn deposit(account_id: &str, amount: u64) -> Deposited {
+    Deposited {
+      account_id: std::string::String::from(account_id),
+      amount,
+    }
+  }
+
+  #[test]
+  fn test_load_returns_events_in_append_order() {
+    let mut store = super::InMemoryEventStore::new();
+    store
+      .append("a", vec![deposit("a", 1), deposit("a", 2)])
+      .unwrap();
+    store.append("b", vec![deposit("b", 10)]).unwrap();
+    store.append("a", vec![deposit("a", 3)]).unwrap();
+
+    let amounts: std::vec::Vec<u64> = store.load("a").unwrap().iter().map(|e| e.amount).collect();
+    std::assert_eq!(amounts, vec![1, 2, 3]);
+    std::assert_eq!(store.stream_len("b"), 1);
+    std::assert!(store.load("missing").unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_append_rejects_foreign_events_atomically() {
+    // Test: A batch containing another aggregate's event is rejected whole.
+    // Justification: Partial appends would corrupt the stream used to rebuild state.
+    let mut store = super::InMemoryEventStore::new();
+    let result = store.append("a", vec![deposit("a", 1), deposit("b", 2)]);
+
+    std::assert!(result.is_err());
+    std::assert_eq!(store.stream_len("a"), 0);
+  }
+
+  #[test]
+  fn test_heterogeneous_stream_with_shared_trait_objects() {
+    let mut store: super::InMemoryEventStore<
+      std::sync::Arc<dyn crate::domain::domain_event::DomainEvent>,
+    > = super::InMemoryEventStore::new();
+    store
+      .append("a", vec![std::sync::Arc::new(deposit("a", 1))])
+      .unwrap();
+
+    let events = store.load("a").unwrap();
+    std::assert_eq!(events[0].event_type(), "Deposited");
+  }
+}
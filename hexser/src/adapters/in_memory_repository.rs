@@ -0,0 +1,739 @@
+//! Generic in-memory repository adapter driven by closures.
+//!
+//! This module provides a reusable in-memory adapter implementing both
+//! `Repository<T>` and `QueryRepository<T>` for any entity type. Instead of
+//! hand-writing filter matching, sorting, and pagination for every adapter,
+//! callers supply a few closures at construction time and get a complete,
+//! working repository suitable for tests, prototypes, and examples.
+//!
+//! Revision History
+//! - 2026-10-19T12:25:00Z @AI: Implement upsert_many_and_return for every ConflictStrategy.
+//! - 2026-10-19T09:35:00Z @AI: Implement count_with over the deleted flags.
+//! - 2026-10-19T07:55:00Z @AI: Implement upsert_many for every ConflictStrategy.
+//! - 2026-10-19T06:15:00Z @AI: Implement upsert; save delegates to it.
+//! - 2026-10-19T05:05:00Z @AI: Implement clear, including soft-deleted entities.
+//! - 2026-10-18T20:45:00Z @AI: Break sort ties by entity id for deterministic results.
+//! - 2026-10-18T19:00:00Z @AI: Implement take_where by partitioning stored entities.
+//! - 2026-10-18T15:30:00Z @AI: Implement SoftDeleteRepository with a per-entity deleted flag.
+//! - 2026-10-18T09:30:00Z @AI: Initial generic InMemoryRepository adapter.
+
+/// Closure extracting the identity of an entity, used by `save` for upserts.
+type IdFn<T> =
+  std::boxed::Box<dyn Fn(&T) -> <T as crate::domain::entity::HexEntity>::Id + Send + Sync>;
+
+/// Closure deciding whether an entity matches a filter.
+type MatcherFn<T, F> = std::boxed::Box<dyn Fn(&T, &F) -> bool + Send + Sync>;
+
+/// Closure ordering two entities by a sort key (ascending).
+type ComparatorFn<T, S> = std::boxed::Box<dyn Fn(&T, &T, &S) -> std::cmp::Ordering + Send + Sync>;
+
+/// Generic in-memory repository over entity `T`, filter `F`, and sort key `S`.
+///
+/// Entities are stored in insertion order in a `Vec`. `save` replaces an
+/// existing entity with the same id or appends a new one. `find` applies the
+/// matcher, then sorts using the comparator (multiple sort keys are applied
+/// with the first key taking precedence, and entities equal on every key are
+/// ordered by id), then applies offset and limit.
+///
+/// The repository also implements `SoftDeleteRepository`: a deleted flag is
+/// kept alongside each entity, and flagged entities are skipped by queries
+/// unless `FindOptions::include_deleted` is set. Saving an entity keeps its
+/// existing flag.
+///
+/// # Type Parameters
+///
+/// * `T` - The entity type (must implement `HexEntity`; querying requires an `Ord` id)
+/// * `F` - The filter type understood by the matcher
+/// * `S` - The sort key type understood by the comparator
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::repository::{Direction, FindOptions, QueryRepository, Sort};
+/// use hexser::ports::Repository;
+///
+/// #[derive(Clone, Debug)]
+/// struct Item {
+///     id: u64,
+///     name: std::string::String,
+/// }
+///
+/// impl hexser::domain::HexEntity for Item {
+///     type Id = u64;
+/// }
+///
+/// enum ItemFilter {
+///     All,
+///     ById(u64),
+/// }
+///
+/// enum ItemSortKey {
+///     Name,
+/// }
+///
+/// let mut repo = hexser::adapters::InMemoryRepository::new(
+///     |item: &Item| item.id,
+///     |item: &Item, filter: &ItemFilter| match filter {
+///         ItemFilter::All => true,
+///         ItemFilter::ById(id) => item.id == *id,
+///     },
+///     |a: &Item, b: &Item, key: &ItemSortKey| match key {
+///         ItemSortKey::Name => a.name.cmp(&b.name),
+///     },
+/// );
+///
+/// repo.save(Item { id: 1, name: std::string::String::from("B") }).unwrap();
+/// repo.save(Item { id: 2, name: std::string::String::from("A") }).unwrap();
+///
+/// let page = repo
+///     .find(
+///         &ItemFilter::All,
+///         FindOptions {
+///             sort: std::option::Option::Some(vec![Sort {
+///                 key: ItemSortKey::Name,
+///                 direction: Direction::Asc,
+///             }]),
+///             limit: std::option::Option::Some(1),
+///             offset: std::option::Option::None,
+///             include_deleted: false,
+///         },
+///     )
+///     .unwrap();
+/// std::assert_eq!(page[0].id, 2);
+/// ```
+pub struct InMemoryRepository<T, F, S>
+where
+  T: crate::domain::entity::HexEntity,
+{
+  items: std::vec::Vec<T>,
+  deleted: std::vec::Vec<bool>,
+  id_of: IdFn<T>,
+  matcher: MatcherFn<T, F>,
+  comparator: ComparatorFn<T, S>,
+}
+
+impl<T, F, S> InMemoryRepository<T, F, S>
+where
+  T: crate::domain::entity::HexEntity,
+  T::Id: PartialEq,
+{
+  /// Creates an empty repository from identity, matcher, and comparator closures.
+  ///
+  /// # Arguments
+  ///
+  /// * `id_of` - Extracts the entity id; `save` replaces entities with an equal id
+  /// * `matcher` - Returns true when an entity satisfies a filter
+  /// * `comparator` - Orders two entities by a sort key in ascending direction
+  pub fn new(
+    id_of: impl Fn(&T) -> T::Id + Send + Sync + 'static,
+    matcher: impl Fn(&T, &F) -> bool + Send + Sync + 'static,
+    comparator: impl Fn(&T, &T, &S) -> std::cmp::Ordering + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      items: std::vec::Vec::new(),
+      deleted: std::vec::Vec::new(),
+      id_of: std::boxed::Box::new(id_of),
+      matcher: std::boxed::Box::new(matcher),
+      comparator: std::boxed::Box::new(comparator),
+    }
+  }
+
+  /// Seeds the repository with initial entities (builder pattern).
+  pub fn with_items(mut self, items: std::vec::Vec<T>) -> Self {
+    self.deleted = vec![false; items.len()];
+    self.items = items;
+    self
+  }
+
+  /// Returns the number of stored entities.
+  pub fn len(&self) -> usize {
+    self.items.len()
+  }
+
+  /// Returns true when no entities are stored.
+  pub fn is_empty(&self) -> bool {
+    self.items.is_empty()
+  }
+
+  /// Returns all stored entities in insertion order, including soft-deleted ones.
+  pub fn items(&self) -> &[T] {
+    &self.items
+  }
+
+  /// Returns true if the entity at `index` in `items()` is soft-deleted.
+  pub fn is_deleted(&self, index: usize) -> bool {
+    self.deleted.get(index).copied().unwrap_or(false)
+  }
+
+  /// Iterates entities that are not soft-deleted.
+  fn live(&self) -> impl Iterator<Item = &T> {
+    self
+      .items
+      .iter()
+      .zip(&self.deleted)
+      .filter(|(_, deleted)| !**deleted)
+      .map(|(item, _)| item)
+  }
+
+  /// Sets the deleted flag on matching entities currently in the opposite state.
+  fn mark(&mut self, filter: &F, deleted: bool) -> u64 {
+    let mut changed = 0;
+    for (item, flag) in self.items.iter().zip(self.deleted.iter_mut()) {
+      if *flag != deleted && (self.matcher)(item, filter) {
+        *flag = deleted;
+        changed += 1;
+      }
+    }
+    changed
+  }
+  /// Applies one `upsert_many` entity, counting it in `report`.
+  ///
+  /// Returns the index the entity was stored at, or `None` when skipped.
+  fn upsert_one(
+    &mut self,
+    entity: T,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+    report: &mut crate::ports::repository::UpsertReport,
+  ) -> std::option::Option<usize> {
+    let id = (self.id_of)(&entity);
+    let std::option::Option::Some(index) = self.items.iter().position(|e| (self.id_of)(e) == id)
+    else {
+      self.items.push(entity);
+      self.deleted.push(false);
+      report.inserted += 1;
+      return std::option::Option::Some(self.items.len() - 1);
+    };
+    match strategy {
+      crate::ports::repository::ConflictStrategy::Overwrite => self.items[index] = entity,
+      crate::ports::repository::ConflictStrategy::KeepExisting => {
+        report.skipped += 1;
+        return std::option::Option::None;
+      }
+      crate::ports::repository::ConflictStrategy::Merge(merge) => {
+        self.items[index] = merge(&self.items[index], entity);
+      }
+    }
+    report.updated += 1;
+    std::option::Option::Some(index)
+  }
+}
+
+impl<T, F, S> crate::adapters::Adapter for InMemoryRepository<T, F, S> where
+  T: crate::domain::entity::HexEntity
+{
+}
+
+impl<T, F, S> crate::ports::repository::Repository<T> for InMemoryRepository<T, F, S>
+where
+  T: crate::domain::entity::HexEntity,
+  T::Id: PartialEq,
+{
+  fn save(&mut self, entity: T) -> crate::result::hex_result::HexResult<()> {
+    self.upsert(entity).map(|_| ())
+  }
+
+  /// Soft-deleted entities count as existing, so they report `Updated`.
+  fn upsert(
+    &mut self,
+    entity: T,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Upserted> {
+    let id = (self.id_of)(&entity);
+    match self.items.iter().position(|e| (self.id_of)(e) == id) {
+      std::option::Option::Some(index) => {
+        self.items[index] = entity;
+        std::result::Result::Ok(crate::ports::repository::Upserted::Updated)
+      }
+      std::option::Option::None => {
+        self.items.push(entity);
+        self.deleted.push(false);
+        std::result::Result::Ok(crate::ports::repository::Upserted::Inserted)
+      }
+    }
+  }
+
+  /// Supports every strategy. Soft-deleted entities count as existing.
+  fn upsert_many(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::UpsertReport> {
+    let mut report = crate::ports::repository::UpsertReport::default();
+    for entity in entities {
+      self.upsert_one(entity, strategy, &mut report);
+    }
+    std::result::Result::Ok(report)
+  }
+
+  /// Supports every strategy, returning merged entities as stored.
+  fn upsert_many_and_return(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<(
+    crate::ports::repository::UpsertReport,
+    std::vec::Vec<T>,
+  )>
+  where
+    T: Clone,
+  {
+    let mut report = crate::ports::repository::UpsertReport::default();
+    let mut stored = std::vec::Vec::new();
+    for entity in entities {
+      if let std::option::Option::Some(index) = self.upsert_one(entity, strategy, &mut report) {
+        stored.push(self.items[index].clone());
+      }
+    }
+    std::result::Result::Ok((report, stored))
+  }
+}
+
+impl<T, F, S> crate::ports::repository::QueryRepository<T> for InMemoryRepository<T, F, S>
+where
+  T: crate::domain::entity::HexEntity + Clone,
+  T::Id: Ord,
+{
+  type Filter = F;
+  type SortKey = S;
+
+  fn find_one(&self, filter: &F) -> crate::result::hex_result::HexResult<std::option::Option<T>> {
+    std::result::Result::Ok(self.live().find(|e| (self.matcher)(e, filter)).cloned())
+  }
+
+  fn find(
+    &self,
+    filter: &F,
+    options: crate::ports::repository::FindOptions<S>,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    let mut matched: std::vec::Vec<&T> = self
+      .items
+      .iter()
+      .zip(&self.deleted)
+      .filter(|(_, deleted)| options.include_deleted || !**deleted)
+      .map(|(item, _)| item)
+      .filter(|e| (self.matcher)(e, filter))
+      .collect();
+
+    if let std::option::Option::Some(sorts) = options.sort {
+      matched.sort_by(|a, b| {
+        sorts
+          .iter()
+          .map(|s| match s.direction {
+            crate::ports::repository::Direction::Asc => (self.comparator)(a, b, &s.key),
+            crate::ports::repository::Direction::Desc => (self.comparator)(b, a, &s.key),
+          })
+          .find(|ordering| *ordering != std::cmp::Ordering::Equal)
+          .unwrap_or_else(|| (self.id_of)(a).cmp(&(self.id_of)(b)))
+      });
+    }
+
+    let offset = options.offset.unwrap_or(0) as usize;
+    let limit = options.limit.map(|l| l as usize).unwrap_or(usize::MAX);
+    std::result::Result::Ok(
+      matched
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .cloned()
+        .collect(),
+    )
+  }
+
+  fn count(&self, filter: &F) -> crate::result::hex_result::HexResult<u64> {
+    std::result::Result::Ok(self.live().filter(|e| (self.matcher)(e, filter)).count() as u64)
+  }
+
+  fn count_with(
+    &self,
+    filter: &F,
+    include_deleted: bool,
+  ) -> crate::result::hex_result::HexResult<u64> {
+    let count = self
+      .items
+      .iter()
+      .zip(&self.deleted)
+      .filter(|(e, deleted)| (include_deleted || !**deleted) && (self.matcher)(e, filter))
+      .count();
+    std::result::Result::Ok(count as u64)
+  }
+
+  fn delete_where(&mut self, filter: &F) -> crate::result::hex_result::HexResult<u64> {
+    let before = self.items.len();
+    let keep: std::vec::Vec<bool> = self
+      .items
+      .iter()
+      .map(|e| !(self.matcher)(e, filter))
+      .collect();
+    let mut flags = keep.iter();
+    self.items.retain(|_| *flags.next().unwrap());
+    let mut flags = keep.iter();
+    self.deleted.retain(|_| *flags.next().unwrap());
+    std::result::Result::Ok((before - self.items.len()) as u64)
+  }
+
+  fn clear(&mut self) -> crate::result::hex_result::HexResult<u64> {
+    let removed = self.items.len() as u64;
+    self.items.clear();
+    self.deleted.clear();
+    std::result::Result::Ok(removed)
+  }
+
+  fn take_where(&mut self, filter: &F) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    let items = std::mem::take(&mut self.items);
+    let deleted = std::mem::take(&mut self.deleted);
+    let mut taken = std::vec::Vec::new();
+    for (item, flag) in items.into_iter().zip(deleted) {
+      if (self.matcher)(&item, filter) {
+        taken.push(item);
+      } else {
+        self.items.push(item);
+        self.deleted.push(flag);
+      }
+    }
+    std::result::Result::Ok(taken)
+  }
+}
+
+impl<T, F, S> crate::ports::soft_delete_repository::SoftDeleteRepository<T>
+  for InMemoryRepository<T, F, S>
+where
+  T: crate::domain::entity::HexEntity + Clone,
+  T::Id: Ord,
+{
+  fn soft_delete(&mut self, filter: &F) -> crate::result::hex_result::HexResult<u64> {
+    std::result::Result::Ok(self.mark(filter, true))
+  }
+
+  fn restore(&mut self, filter: &F) -> crate::result::hex_result::HexResult<u64> {
+    std::result::Result::Ok(self.mark(filter, false))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::ports::repository::{QueryRepository, Repository};
+
+  #[derive(Clone, Debug, PartialEq)]
+  struct Item {
+    id: u64,
+    name: std::string::String,
+  }
+
+  impl crate::domain::entity::HexEntity for Item {
+    type Id = u64;
+  }
+
+  #[derive(Clone, Debug)]
+  enum ItemFilter {
+    All,
+    ById(u64),
+  }
+
+  #[derive(Clone, Copy, Debug)]
+  enum ItemSortKey {
+    Id,
+    Name,
+  }
+
+  fn item_repository() -> super::InMemoryRepository<Item, ItemFilter, ItemSortKey> {
+    super::InMemoryRepository::new(
+      |item: &Item| item.id,
+      |item: &Item, filter: &ItemFilter| match filter {
+        ItemFilter::All => true,
+        ItemFilter::ById(id) => item.id == *id,
+      },
+      |a: &Item, b: &Item, key: &ItemSortKey| match key {
+        ItemSortKey::Id => a.id.cmp(&b.id),
+        ItemSortKey::Name => a.name.cmp(&b.name),
+      },
+    )
+  }
+
+  fn item(id: u64, name: &str) -> Item {
+    Item {
+      id,
+      name: std::string::String::from(name),
+    }
+  }
+
+  #[test]
+  fn test_crud_flow() {
+    // Test: Mirrors the CRUD potion flow (create, get, delete) on the generic adapter.
+    // Justification: The adapter must be a drop-in replacement for hand-written in-memory repos.
+    let mut repo = item_repository();
+    std::assert!(!repo.exists(&ItemFilter::ById(1)).unwrap());
+
+    repo.save(item(1, "A")).unwrap();
+    let fetched = repo.find_one(&ItemFilter::ById(1)).unwrap();
+    std::assert_eq!(fetched, std::option::Option::Some(item(1, "A")));
+
+    let removed = repo.delete_where(&ItemFilter::ById(1)).unwrap();
+    std::assert_eq!(removed, 1);
+    std::assert!(repo.find_one(&ItemFilter::ById(1)).unwrap().is_none());
+  }
+
+  #[test]
+  fn test_save_replaces_existing_id() {
+    let mut repo = item_repository();
+    repo.save(item(1, "A")).unwrap();
+    repo.save(item(1, "A2")).unwrap();
+
+    std::assert_eq!(repo.len(), 1);
+    std::assert_eq!(repo.items()[0].name, "A2");
+  }
+
+  #[test]
+  fn test_upsert_reports_insert_then_update() {
+    let mut repo = item_repository();
+    std::assert_eq!(
+      repo.upsert(item(1, "A")).unwrap(),
+      crate::ports::repository::Upserted::Inserted
+    );
+    std::assert_eq!(
+      repo.upsert(item(1, "A2")).unwrap(),
+      crate::ports::repository::Upserted::Updated
+    );
+    std::assert_eq!(repo.len(), 1);
+    std::assert_eq!(repo.items()[0].name, "A2");
+  }
+
+  #[test]
+  fn test_upsert_many_overwrite_replaces_conflicts() {
+    let mut repo = item_repository().with_items(vec![item(1, "A"), item(2, "B")]);
+    let report = repo
+      .upsert_many(
+        vec![item(2, "B2"), item(3, "C")],
+        crate::ports::repository::ConflictStrategy::Overwrite,
+      )
+      .unwrap();
+
+    std::assert_eq!(
+      report,
+      crate::ports::repository::UpsertReport {
+        inserted: 1,
+        updated: 1,
+        skipped: 0,
+      }
+    );
+    std::assert_eq!(repo.items()[1].name, "B2");
+    std::assert_eq!(repo.len(), 3);
+  }
+
+  #[test]
+  fn test_upsert_many_keep_existing_skips_conflicts() {
+    let mut repo = item_repository().with_items(vec![item(1, "A")]);
+    let report = repo
+      .upsert_many(
+        vec![item(1, "A2"), item(2, "B"), item(2, "B2")],
+        crate::ports::repository::ConflictStrategy::KeepExisting,
+      )
+      .unwrap();
+
+    std::assert_eq!(report.inserted, 1);
+    std::assert_eq!(report.skipped, 2);
+    std::assert_eq!(report.total(), 3);
+    std::assert_eq!(repo.items()[0].name, "A");
+    std::assert_eq!(repo.items()[1].name, "B");
+  }
+
+  #[test]
+  fn test_upsert_many_merge_receives_existing_then_incoming() {
+    let mut repo = item_repository().with_items(vec![item(1, "A")]);
+    let report = repo
+      .upsert_many(
+        vec![item(1, "B"), item(2, "C")],
+        crate::ports::repository::ConflictStrategy::Merge(|existing: &Item, incoming: Item| Item {
+          id: existing.id,
+          name: std::format!("{}+{}", existing.name, incoming.name),
+        }),
+      )
+      .unwrap();
+
+    std::assert_eq!(report.inserted, 1);
+    std::assert_eq!(report.updated, 1);
+    std::assert_eq!(repo.items()[0].name, "A+B");
+    std::assert_eq!(repo.items()[1].name, "C");
+  }
+
+  #[test]
+  fn test_upsert_many_and_return_returns_stored_entities() {
+    // Test: Merged entities come back as stored and skipped ones are left out.
+    // Justification: Observers of a bulk sync must see persisted state, not the input.
+    let mut repo = item_repository().with_items(vec![item(1, "A"), item(2, "B")]);
+    let (report, stored) = repo
+      .upsert_many_and_return(
+        vec![item(1, "X"), item(3, "C")],
+        crate::ports::repository::ConflictStrategy::Merge(|existing: &Item, incoming: Item| Item {
+          id: existing.id,
+          name: std::format!("{}+{}", existing.name, incoming.name),
+        }),
+      )
+      .unwrap();
+    std::assert_eq!(report.updated, 1);
+    std::assert_eq!(stored, vec![item(1, "A+X"), item(3, "C")]);
+
+    let (report, stored) = repo
+      .upsert_many_and_return(
+        vec![item(2, "Y")],
+        crate::ports::repository::ConflictStrategy::KeepExisting,
+      )
+      .unwrap();
+    std::assert_eq!(report.skipped, 1);
+    std::assert!(stored.is_empty());
+  }
+
+  #[test]
+  fn test_find_sorts_and_paginates() {
+    let repo =
+      item_repository().with_items(vec![item(3, "C"), item(1, "B"), item(2, "A"), item(4, "A")]);
+
+    let options = crate::ports::repository::FindOptions {
+      sort: std::option::Option::Some(vec![
+        crate::ports::repository::Sort {
+          key: ItemSortKey::Name,
+          direction: crate::ports::repository::Direction::Asc,
+        },
+        crate::ports::repository::Sort {
+          key: ItemSortKey::Id,
+          direction: crate::ports::repository::Direction::Desc,
+        },
+      ]),
+      limit: std::option::Option::Some(2),
+      offset: std::option::Option::Some(1),
+      include_deleted: false,
+    };
+    let page = repo.find(&ItemFilter::All, options).unwrap();
+
+    let ids: std::vec::Vec<u64> = page.iter().map(|i| i.id).collect();
+    std::assert_eq!(ids, vec![2, 1]);
+  }
+
+  #[test]
+  fn test_find_breaks_sort_ties_by_id() {
+    // Test: Entities equal on every sort key come back ordered by id, not insertion.
+    // Justification: Multi-key sorts must be fully deterministic for stable paging and tests.
+    let repo = item_repository().with_items(vec![item(3, "A"), item(1, "A"), item(2, "B")]);
+
+    let options = crate::ports::repository::FindOptions::default()
+      .with_sort(ItemSortKey::Name, crate::ports::repository::Direction::Asc);
+    let ids: std::vec::Vec<u64> = repo
+      .find(&ItemFilter::All, options)
+      .unwrap()
+      .iter()
+      .map(|i| i.id)
+      .collect();
+    std::assert_eq!(ids, vec![1, 3, 2]);
+  }
+
+  #[test]
+  fn test_count_and_delete_all() {
+    let mut repo = item_repository().with_items(vec![item(1, "A"), item(2, "B")]);
+    std::assert_eq!(repo.count(&ItemFilter::All).unwrap(), 2);
+
+    let removed = repo.delete_where(&ItemFilter::All).unwrap();
+    std::assert_eq!(removed, 2);
+    std::assert!(repo.is_empty());
+  }
+
+  #[test]
+  fn test_clear_removes_live_and_soft_deleted_items() {
+    use crate::ports::soft_delete_repository::SoftDeleteRepository;
+
+    let mut repo = item_repository().with_items(vec![item(1, "A"), item(2, "B")]);
+    repo.soft_delete(&ItemFilter::ById(1)).unwrap();
+
+    std::assert_eq!(repo.clear().unwrap(), 2);
+    std::assert!(repo.is_empty());
+    std::assert_eq!(repo.clear().unwrap(), 0);
+  }
+
+  #[test]
+  fn test_soft_delete_hides_until_restored() {
+    // Test: Soft-deleted entities are hidden from queries but kept, and restore reverses it.
+    // Justification: Audit-friendly deletion must not lose data or leak into normal reads.
+    use crate::ports::soft_delete_repository::SoftDeleteRepository;
+
+    let mut repo = item_repository().with_items(vec![item(1, "A"), item(2, "B")]);
+    std::assert_eq!(repo.soft_delete(&ItemFilter::ById(1)).unwrap(), 1);
+    std::assert_eq!(repo.soft_delete(&ItemFilter::ById(1)).unwrap(), 0);
+
+    std::assert!(repo.find_one(&ItemFilter::ById(1)).unwrap().is_none());
+    std::assert_eq!(repo.count(&ItemFilter::All).unwrap(), 1);
+    std::assert_eq!(
+      repo
+        .find(
+          &ItemFilter::All,
+          crate::ports::repository::FindOptions::default()
+        )
+        .unwrap()
+        .len(),
+      1
+    );
+
+    let with_deleted = crate::ports::repository::FindOptions {
+      include_deleted: true,
+      ..crate::ports::repository::FindOptions::default()
+    };
+    std::assert_eq!(repo.find(&ItemFilter::All, with_deleted).unwrap().len(), 2);
+    std::assert_eq!(repo.len(), 2);
+    std::assert!(repo.is_deleted(0));
+
+    std::assert_eq!(repo.restore(&ItemFilter::All).unwrap(), 1);
+    std::assert_eq!(repo.count(&ItemFilter::All).unwrap(), 2);
+  }
+
+  #[test]
+  fn test_page_total_includes_soft_deleted_when_requested() {
+    // Test: find_page totals cover the same entities as its items under include_deleted.
+    // Justification: A total counting only live rows makes has_more wrong for admin views.
+    use crate::ports::soft_delete_repository::SoftDeleteRepository;
+
+    let mut repo =
+      item_repository().with_items(vec![item(1, "A"), item(2, "B"), item(3, "C"), item(4, "D")]);
+    repo.soft_delete(&ItemFilter::ById(1)).unwrap();
+    repo.soft_delete(&ItemFilter::ById(3)).unwrap();
+
+    let page = repo
+      .find_page(
+        &ItemFilter::All,
+        crate::ports::repository::FindOptions {
+          limit: std::option::Option::Some(3),
+          include_deleted: true,
+          ..crate::ports::repository::FindOptions::default()
+        },
+      )
+      .unwrap();
+    std::assert_eq!(page.items.len(), 3);
+    std::assert_eq!(page.total, 4);
+    std::assert!(page.has_more());
+
+    std::assert_eq!(
+      repo
+        .query(&ItemFilter::All)
+        .include_deleted()
+        .count()
+        .unwrap(),
+      4
+    );
+    std::assert_eq!(repo.query(&ItemFilter::All).count().unwrap(), 2);
+  }
+
+  #[test]
+  fn test_take_where_returns_removed_in_insertion_order() {
+    // Test: take_where removes and returns matches while keeping deleted flags aligned.
+    // Justification: Callers emit one deletion event per returned entity.
+    use crate::ports::soft_delete_repository::SoftDeleteRepository;
+
+    let mut repo = item_repository().with_items(vec![item(3, "C"), item(1, "A"), item(2, "B")]);
+    repo.soft_delete(&ItemFilter::ById(2)).unwrap();
+
+    let taken = repo.take_where(&ItemFilter::ById(1)).unwrap();
+    std::assert_eq!(taken, vec![item(1, "A")]);
+    std::assert_eq!(repo.len(), 2);
+    std::assert!(!repo.is_deleted(0));
+    std::assert!(repo.is_deleted(1));
+
+    let rest = repo.take_where(&ItemFilter::All).unwrap();
+    std::assert_eq!(rest, vec![item(3, "C"), item(2, "B")]);
+    std::assert!(repo.is_empty());
+  }
+}
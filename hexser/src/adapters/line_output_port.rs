@@ -0,0 +1,155 @@
+//! Line-delimited OutputPort adapters for files and stdout.
+//!
+//! OutputPort is abstract until something implements it. These adapters are
+//! ready to use: each `send` writes the request's `Display` form as one line,
+//! to a file with FileOutputPort or to standard output with
+//! StdoutOutputPort. Serialize structured outputs to a string first (for
+//! example one JSON document per line). Write failures become adapter errors
+//! with the underlying `std::io::Error` chained as their source.
+//!
+//! Revision History
+//! - 2026-10-19T05:45:00Z @AI: Initial FileOutputPort and StdoutOutputPort.
+
+/// OutputPort writing one line per request to a file.
+///
+/// Each line is flushed as it is sent, so readers see it immediately.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::adapters::FileOutputPort;
+/// use hexser::ports::OutputPort;
+///
+/// let path = std::env::temp_dir().join("hexser_file_output_port_doc.log");
+/// let port = FileOutputPort::create(&path).unwrap();
+/// port.send("order 42 shipped").unwrap();
+///
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "order 42 shipped\n");
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct FileOutputPort {
+  path: std::path::PathBuf,
+  file: std::sync::Mutex<std::fs::File>,
+}
+
+impl FileOutputPort {
+  /// Create or truncate the file at `path`
+  pub fn create(path: impl AsRef<std::path::Path>) -> crate::result::hex_result::HexResult<Self> {
+    let path = path.as_ref();
+    let file = std::fs::File::create(path)
+      .map_err(|e| io_error(&format!("Failed to create {}", path.display()), e))?;
+    Ok(Self::from_file(path, file))
+  }
+
+  /// Open the file at `path` for appending, creating it if missing
+  pub fn append(path: impl AsRef<std::path::Path>) -> crate::result::hex_result::HexResult<Self> {
+    let path = path.as_ref();
+    let file = std::fs::OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(path)
+      .map_err(|e| io_error(&format!("Failed to open {}", path.display()), e))?;
+    Ok(Self::from_file(path, file))
+  }
+
+  /// Path of the file being written
+  pub fn path(&self) -> &std::path::Path {
+    &self.path
+  }
+
+  fn from_file(path: &std::path::Path, file: std::fs::File) -> Self {
+    Self {
+      path: path.to_path_buf(),
+      file: std::sync::Mutex::new(file),
+    }
+  }
+}
+
+impl<T: std::fmt::Display> crate::ports::output_port::OutputPort<T, ()> for FileOutputPort {
+  fn send(&self, request: T) -> crate::result::hex_result::HexResult<()> {
+    let mut file = self
+      .file
+      .lock()
+      .unwrap_or_else(std::sync::PoisonError::into_inner);
+    write_line(&mut *file, &request)
+      .map_err(|e| io_error(&format!("Failed to write to {}", self.path.display()), e))
+  }
+}
+
+/// OutputPort writing one line per request to standard output.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::adapters::StdoutOutputPort;
+/// use hexser::ports::OutputPort;
+///
+/// StdoutOutputPort::new().send("hello").unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutOutputPort;
+
+impl StdoutOutputPort {
+  /// Create a port writing to stdout
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl<T: std::fmt::Display> crate::ports::output_port::OutputPort<T, ()> for StdoutOutputPort {
+  fn send(&self, request: T) -> crate::result::hex_result::HexResult<()> {
+    write_line(&mut std::io::stdout().lock(), &request)
+      .map_err(|e| io_error("Failed to write to stdout", e))
+  }
+}
+
+fn write_line(
+  writer: &mut dyn std::io::Write,
+  request: &dyn std::fmt::Display,
+) -> std::io::Result<()> {
+  writeln!(writer, "{}", request)?;
+  writer.flush()
+}
+
+fn io_error(message: &str, source: std::io::Error) -> crate::error::hex_error::Hexserror {
+  crate::error::hex_error::Hexserror::Adapter(crate::error::rich_error::RichError::with_source(
+    crate::error::adapter_error::io_failure(format!("{}: {}", message, source)),
+    source,
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ports::output_port::OutputPort;
+
+  fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("hexser_{}_{}.log", name, std::process::id()))
+  }
+
+  #[test]
+  fn test_file_port_writes_lines_and_append_keeps_them() {
+    let path = temp_path("line_output_port");
+    let port = FileOutputPort::create(&path).unwrap();
+    port.send("first").unwrap();
+    port.send(2).unwrap();
+
+    let appended = FileOutputPort::append(&path).unwrap();
+    appended.send(String::from("third")).unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(contents, "first\n2\nthird\n");
+  }
+
+  #[test]
+  fn test_create_failure_chains_io_source() {
+    let path = temp_path("missing_dir").join("out.log");
+    let err = FileOutputPort::create(&path).unwrap_err();
+
+    assert_eq!(err.code(), crate::error::codes::io::IO_FAILURE);
+    let source = std::error::Error::source(&err).expect("io source");
+    assert!(source.downcast_ref::<std::io::Error>().is_some());
+  }
+}
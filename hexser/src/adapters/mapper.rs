@@ -6,6 +6,7 @@
 //! between layers while maintaining data integrity.
 //!
 //! Revision History
+//! - 2026-10-18T16:00:00Z @AI: Add then/try_then combinators with ComposedMapper and FnMapper.
 //! - 2025-10-01T00:00:00Z @AI: Initial Mapper trait definition for bidirectional transformation.
 
 /// Trait for mapping data between different representations.
@@ -59,6 +60,84 @@ pub trait Mapper<From, To> {
   ///
   /// Returns the mapped value if successful, or an error if the mapping fails.
   fn map(&self, from: From) -> crate::result::hex_result::HexResult<To>;
+
+  /// Chain another mapper after this one, producing a `Mapper<From, Next>`.
+  ///
+  /// The first error from either stage is returned unchanged.
+  ///
+  /// ```rust
+  /// use hexser::adapters::Mapper;
+  ///
+  /// struct ParseRow;
+  /// impl Mapper<&'static str, i64> for ParseRow {
+  ///     fn map(&self, from: &'static str) -> hexser::HexResult<i64> {
+  ///         from.parse().map_err(|_| hexser::Hexserror::validation("not a number"))
+  ///     }
+  /// }
+  ///
+  /// struct Double;
+  /// impl Mapper<i64, i64> for Double {
+  ///     fn map(&self, from: i64) -> hexser::HexResult<i64> {
+  ///         Ok(from * 2)
+  ///     }
+  /// }
+  ///
+  /// let pipeline = ParseRow.then(Double);
+  /// assert_eq!(pipeline.map("21").unwrap(), 42);
+  /// ```
+  fn then<Next, M>(self, next: M) -> ComposedMapper<Self, M, To>
+  where
+    Self: Sized,
+    M: Mapper<To, Next>,
+  {
+    ComposedMapper {
+      first: self,
+      second: next,
+      intermediate: std::marker::PhantomData,
+    }
+  }
+
+  /// Chain a fallible closure after this mapper.
+  ///
+  /// Convenient for one-off stages that do not warrant a named mapper type.
+  fn try_then<Next, F>(self, next: F) -> ComposedMapper<Self, FnMapper<F>, To>
+  where
+    Self: Sized,
+    F: Fn(To) -> crate::result::hex_result::HexResult<Next>,
+  {
+    self.then(FnMapper(next))
+  }
+}
+
+/// Mapper applying two mappers in sequence, created by `Mapper::then`.
+///
+/// `B` is the intermediate representation produced by the first mapper.
+pub struct ComposedMapper<M1, M2, B> {
+  first: M1,
+  second: M2,
+  intermediate: std::marker::PhantomData<fn() -> B>,
+}
+
+impl<A, B, C, M1, M2> Mapper<A, C> for ComposedMapper<M1, M2, B>
+where
+  M1: Mapper<A, B>,
+  M2: Mapper<B, C>,
+{
+  fn map(&self, from: A) -> crate::result::hex_result::HexResult<C> {
+    self.second.map(self.first.map(from)?)
+  }
+}
+
+/// Mapper backed by a fallible closure, created by `Mapper::try_then`.
+pub struct FnMapper<F>(pub F);
+
+impl<A, B, F> Mapper<A, B> for FnMapper<F>
+where
+  F: Fn(A) -> crate::result::hex_result::HexResult<B>,
+{
+  fn map(&self, from: A) -> crate::result::hex_result::HexResult<B> {
+    (self.0)(from)
+  }
 }
 
 #[cfg(test)]
@@ -88,4 +167,35 @@ mod tests {
     let target = mapper.map(source).unwrap();
     assert_eq!(target.data, 42);
   }
+
+  struct RowMapper;
+
+  impl Mapper<(i32, &'static str), SourceType> for RowMapper {
+    fn map(&self, from: (i32, &'static str)) -> crate::result::hex_result::HexResult<SourceType> {
+      Result::Ok(SourceType { value: from.0 })
+    }
+  }
+
+  #[test]
+  fn test_then_composes_mappers() {
+    let pipeline = RowMapper.then(TestMapper);
+    let target = pipeline.map((7, "row")).unwrap();
+    assert_eq!(target.data, 7);
+  }
+
+  #[test]
+  fn test_try_then_propagates_errors() {
+    let pipeline = RowMapper.then(TestMapper).try_then(|t: TargetType| {
+      if t.data >= 0 {
+        Result::Ok(t.data as u32)
+      } else {
+        Result::Err(crate::error::hex_error::Hexserror::validation(
+          "Negative value",
+        ))
+      }
+    });
+
+    assert_eq!(pipeline.map((3, "ok")).unwrap(), 3);
+    assert!(pipeline.map((-1, "bad")).is_err());
+  }
 }
@@ -0,0 +1,173 @@
+//! Runtime registry of mappers keyed by source and target type.
+//!
+//! Generic pipelines that only learn at runtime which representations they
+//! convert between cannot name a concrete Mapper. MapperRegistry stores
+//! mappers type-erased under their `(TypeId, TypeId)` pair and looks them
+//! up when asked to convert, so transformation steps can be wired from
+//! data rather than code.
+//!
+//! Revision History
+//! - 2026-10-19T07:35:00Z @AI: Initial MapperRegistry.
+
+/// Registry resolving mappers by `(source, target)` type at runtime.
+///
+/// At most one mapper is registered per type pair; registering a pair
+/// again replaces the previous mapper. Because lookups borrow the source
+/// value, registered source types must be `Clone`: the value is cloned
+/// before being handed to the mapper.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::adapters::{FnMapper, MapperRegistry};
+///
+/// let mut registry = MapperRegistry::new();
+/// registry.register(FnMapper(|cents: u64| Ok(format!("${}.{:02}", cents / 100, cents % 100))));
+///
+/// assert_eq!(registry.map::<u64, String>(&1999).unwrap(), "$19.99");
+/// assert!(registry.map::<u64, i32>(&1).is_err());
+/// ```
+#[derive(Default)]
+pub struct MapperRegistry {
+  mappers: std::collections::HashMap<
+    (std::any::TypeId, std::any::TypeId),
+    std::boxed::Box<dyn std::any::Any + Send + Sync>,
+  >,
+}
+
+/// Type-erased form of a registered mapper from `A` to `B`
+type BoxedMapFn<A, B> =
+  std::boxed::Box<dyn Fn(&A) -> crate::result::hex_result::HexResult<B> + Send + Sync>;
+
+impl MapperRegistry {
+  /// Create an empty registry
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Register `mapper` for conversions from `A` to `B`
+  pub fn register<A, B, M>(&mut self, mapper: M)
+  where
+    A: Clone + 'static,
+    B: 'static,
+    M: crate::adapters::mapper::Mapper<A, B> + Send + Sync + 'static,
+  {
+    let map_fn: BoxedMapFn<A, B> = std::boxed::Box::new(move |from: &A| mapper.map(from.clone()));
+    self.mappers.insert(
+      (std::any::TypeId::of::<A>(), std::any::TypeId::of::<B>()),
+      std::boxed::Box::new(map_fn),
+    );
+  }
+
+  /// Check whether a mapper from `A` to `B` is registered
+  pub fn contains<A: 'static, B: 'static>(&self) -> bool {
+    self
+      .mappers
+      .contains_key(&(std::any::TypeId::of::<A>(), std::any::TypeId::of::<B>()))
+  }
+
+  /// Number of registered mappers
+  pub fn len(&self) -> usize {
+    self.mappers.len()
+  }
+
+  /// Whether no mappers are registered
+  pub fn is_empty(&self) -> bool {
+    self.mappers.is_empty()
+  }
+
+  /// Convert `from` with the mapper registered for `A` to `B`
+  ///
+  /// # Errors
+  ///
+  /// Returns a not-found error (`E_HEX_400`) naming both types when no
+  /// mapper is registered for the pair, and otherwise whatever the mapper
+  /// returns.
+  pub fn map<A: 'static, B: 'static>(&self, from: &A) -> crate::result::hex_result::HexResult<B> {
+    let map_fn = self
+      .mappers
+      .get(&(std::any::TypeId::of::<A>(), std::any::TypeId::of::<B>()))
+      .and_then(|entry| entry.downcast_ref::<BoxedMapFn<A, B>>())
+      .ok_or_else(|| {
+        crate::error::hex_error::Hexserror::not_found(
+          "Mapper",
+          &format!(
+            "{} -> {}",
+            std::any::type_name::<A>(),
+            std::any::type_name::<B>()
+          ),
+        )
+        .with_next_step("Register a mapper for this pair with MapperRegistry::register")
+      })?;
+    map_fn(from)
+  }
+}
+
+impl std::fmt::Debug for MapperRegistry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("MapperRegistry")
+      .field("mappers", &self.mappers.len())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Clone, Debug)]
+  struct User {
+    name: String,
+    age: u32,
+  }
+
+  #[derive(Clone, Debug, PartialEq)]
+  struct UserDto {
+    display: String,
+  }
+
+  struct UserToDto;
+
+  impl crate::adapters::mapper::Mapper<User, UserDto> for UserToDto {
+    fn map(&self, from: User) -> crate::result::hex_result::HexResult<UserDto> {
+      Ok(UserDto {
+        display: format!("{} ({})", from.name, from.age),
+      })
+    }
+  }
+
+  #[test]
+  fn test_converts_through_two_registered_mappers() {
+    let mut registry = MapperRegistry::new();
+    registry.register(UserToDto);
+    registry.register(crate::adapters::mapper::FnMapper(|dto: UserDto| {
+      Ok(format!("<li>{}</li>", dto.display))
+    }));
+
+    let user = User {
+      name: String::from("Ada"),
+      age: 36,
+    };
+    let dto: UserDto = registry.map(&user).unwrap();
+    let html: String = registry.map(&dto).unwrap();
+
+    assert_eq!(dto.display, "Ada (36)");
+    assert_eq!(html, "<li>Ada (36)</li>");
+    assert_eq!(registry.len(), 2);
+  }
+
+  #[test]
+  fn test_missing_pair_is_not_found() {
+    let mut registry = MapperRegistry::new();
+    registry.register(UserToDto);
+
+    assert!(registry.contains::<User, UserDto>());
+    assert!(!registry.contains::<UserDto, User>());
+    let err = registry
+      .map::<UserDto, User>(&UserDto {
+        display: String::new(),
+      })
+      .unwrap_err();
+    assert_eq!(err.code(), crate::error::codes::resource::NOT_FOUND);
+  }
+}
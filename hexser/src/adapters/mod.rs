@@ -7,18 +7,74 @@
 //! event bus implementations.
 //!
 //! Revision History
+//! - 2026-10-19T08:25:00Z @AI: Add timestamping_repository wrapper touching entities on save.
+//! - 2026-10-19T07:35:00Z @AI: Add mapper_registry for runtime mapper lookup.
+//! - 2026-10-19T07:25:00Z @AI: Add in_memory_directive_log adapter.
+//! - 2026-10-19T06:45:00Z @AI: Add health_check adapters behind the async feature.
+//! - 2026-10-19T05:45:00Z @AI: Add line_output_port adapters for files and stdout.
+//! - 2026-10-19T04:45:00Z @AI: Add cached_query_repository read-through cache.
+//! - 2026-10-19T01:35:00Z @AI: Add shared_repository lock-per-call wrapper.
+//! - 2026-10-19T00:15:00Z @AI: Add sse_event_publisher adapter behind the sse feature.
+//! - 2026-10-18T23:55:00Z @AI: Add id_generator adapters (SequentialGenerator, UuidGenerator behind uuid).
+//! - 2026-10-18T23:50:00Z @AI: Add clock adapters (SystemClock, FixedClock).
+//! - 2026-10-18T19:30:00Z @AI: Add sync_publisher_bridge adapter behind the async feature.
+//! - 2026-10-18T16:00:00Z @AI: Re-export ComposedMapper and FnMapper.
+//! - 2026-10-18T14:00:00Z @AI: Add in_memory_event_store adapter.
+//! - 2026-10-18T11:00:00Z @AI: Add observable_repository wrapper with save/delete hooks.
+//! - 2026-10-18T09:30:00Z @AI: Add generic in_memory_repository adapter.
 //! - 2025-10-09T14:51:00Z @AI: Add in_memory_event_bus adapter for CloudEvents v1.0 support.
 //! - 2025-10-08T23:35:00Z @AI: Add mcp_stdio adapter for Model Context Protocol support.
 //! - 2025-10-08T22:54:00Z @AI: Remove rest_weather_adapter module (moved to examples).
 //! - 2025-10-01T00:00:00Z @AI: Initial Phase 1 adapters module structure.
 
 pub mod adapter;
+pub mod cached_query_repository;
+pub mod clock;
+pub mod id_generator;
+pub mod in_memory_directive_log;
 pub mod in_memory_event_bus;
+pub mod in_memory_event_store;
+pub mod in_memory_repository;
+pub mod line_output_port;
 pub mod mapper;
+pub mod mapper_registry;
+pub mod observable_repository;
+pub mod shared_repository;
+pub mod timestamping_repository;
 
 #[cfg(feature = "mcp")]
 pub mod mcp_stdio;
 
+#[cfg(feature = "sse")]
+pub mod sse_event_publisher;
+
+#[cfg(feature = "async")]
+pub mod health_check;
+#[cfg(feature = "async")]
+pub mod sync_publisher_bridge;
+
 pub use adapter::Adapter;
+pub use cached_query_repository::{CacheInvalidator, CacheStats, CachedQueryRepository};
+pub use clock::{FixedClock, SystemClock};
+pub use id_generator::SequentialGenerator;
+pub use in_memory_directive_log::InMemoryDirectiveLog;
 pub use in_memory_event_bus::InMemoryEventBus;
-pub use mapper::Mapper;
+pub use in_memory_event_store::InMemoryEventStore;
+pub use in_memory_repository::InMemoryRepository;
+pub use line_output_port::{FileOutputPort, StdoutOutputPort};
+pub use mapper::{ComposedMapper, FnMapper, Mapper};
+pub use mapper_registry::MapperRegistry;
+pub use observable_repository::ObservableRepository;
+pub use shared_repository::SharedRepository;
+pub use timestamping_repository::TimestampingRepository;
+
+#[cfg(feature = "uuid")]
+pub use id_generator::UuidGenerator;
+
+#[cfg(feature = "sse")]
+pub use sse_event_publisher::SseEventPublisher;
+
+#[cfg(feature = "async")]
+pub use health_check::{AlwaysHealthy, HealthAggregator, HealthReport};
+#[cfg(feature = "async")]
+pub use sync_publisher_bridge::SyncPublisherBridge;
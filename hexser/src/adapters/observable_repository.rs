@@ -0,0 +1,495 @@
+//! Repository wrapper that notifies observers of successful changes.
+//!
+//! ObservableRepository decorates any repository and invokes caller-supplied
+//! callbacks after saves and deletes complete successfully. This gives a
+//! single place to hook cache invalidation or event emission without
+//! modifying concrete adapters. Failed operations never trigger callbacks.
+//!
+//! Revision History
+//! - 2026-10-19T12:25:00Z @AI: Fire the upsert_many save hook with the stored entities.
+//! - 2026-10-19T09:45:00Z @AI: Delegate upsert_many and fire the save hook per written entity.
+//! - 2026-10-19T09:05:00Z @AI: Forward find_with_timeout.
+//! - 2026-10-19T07:05:00Z @AI: Forward count_estimate.
+//! - 2026-10-19T06:15:00Z @AI: Run the save hook after upsert.
+//! - 2026-10-19T05:05:00Z @AI: Delegate clear and add an on_clear hook.
+//! - 2026-10-18T22:45:00Z @AI: Delegate save_and_return and fire on_save with the persisted entity.
+//! - 2026-10-18T19:00:00Z @AI: Delegate take_where and fire on_delete with the removed count.
+//! - 2026-10-18T13:30:00Z @AI: Delegate find_page to the inner repository.
+//! - 2026-10-18T11:00:00Z @AI: Initial ObservableRepository wrapper.
+
+/// Callback invoked with an entity after it has been saved.
+type SaveHook<T> = std::boxed::Box<dyn Fn(&T) + Send + Sync>;
+
+/// Callback invoked with a filter and removed count after a delete.
+type DeleteHook<F> = std::boxed::Box<dyn Fn(&F, u64) + Send + Sync>;
+
+/// Callback invoked with the removed count after a clear.
+type ClearHook = std::boxed::Box<dyn Fn(u64) + Send + Sync>;
+
+/// Repository decorator firing `on_save`/`on_delete` hooks on success.
+///
+/// All `Repository` and `QueryRepository` operations are delegated to the
+/// inner repository. Hooks run only after the inner call returns `Ok`; an
+/// error is returned unchanged and no hook is invoked.
+///
+/// # Type Parameters
+///
+/// * `R` - The wrapped repository
+/// * `T` - The entity type stored by `R`
+/// * `F` - The filter type passed to the delete hook (`R::Filter`)
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::Repository;
+///
+/// #[derive(Clone, Debug)]
+/// struct Item {
+///     id: u64,
+/// }
+///
+/// impl hexser::domain::HexEntity for Item {
+///     type Id = u64;
+/// }
+///
+/// let saved = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+/// let counter = std::sync::Arc::clone(&saved);
+///
+/// let inner = hexser::adapters::InMemoryRepository::new(
+///     |item: &Item| item.id,
+///     |item: &Item, id: &u64| item.id == *id,
+///     |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+/// );
+/// let mut repo: hexser::adapters::ObservableRepository<_, Item, u64> =
+///     hexser::adapters::ObservableRepository::new(inner).on_save(move |_item: &Item| {
+///         counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+///     });
+///
+/// repo.save(Item { id: 1 }).unwrap();
+/// std::assert_eq!(saved.load(std::sync::atomic::Ordering::SeqCst), 1);
+/// ```
+pub struct ObservableRepository<R, T, F> {
+  inner: R,
+  on_save: std::option::Option<SaveHook<T>>,
+  on_delete: std::option::Option<DeleteHook<F>>,
+  on_clear: std::option::Option<ClearHook>,
+}
+
+impl<R, T, F> ObservableRepository<R, T, F> {
+  /// Wraps a repository with no hooks registered.
+  pub fn new(inner: R) -> Self {
+    Self {
+      inner,
+      on_save: std::option::Option::None,
+      on_delete: std::option::Option::None,
+      on_clear: std::option::Option::None,
+    }
+  }
+
+  /// Registers a callback invoked with each successfully saved entity (builder pattern).
+  pub fn on_save(mut self, hook: impl Fn(&T) + Send + Sync + 'static) -> Self {
+    self.on_save = std::option::Option::Some(std::boxed::Box::new(hook));
+    self
+  }
+
+  /// Registers a callback invoked with the filter and removed count after a
+  /// successful `delete_where` (builder pattern).
+  pub fn on_delete(mut self, hook: impl Fn(&F, u64) + Send + Sync + 'static) -> Self {
+    self.on_delete = std::option::Option::Some(std::boxed::Box::new(hook));
+    self
+  }
+
+  /// Registers a callback invoked with the removed count after a
+  /// successful `clear` (builder pattern).
+  pub fn on_clear(mut self, hook: impl Fn(u64) + Send + Sync + 'static) -> Self {
+    self.on_clear = std::option::Option::Some(std::boxed::Box::new(hook));
+    self
+  }
+
+  /// Returns a reference to the wrapped repository.
+  pub fn inner(&self) -> &R {
+    &self.inner
+  }
+
+  /// Consumes the wrapper and returns the wrapped repository.
+  pub fn into_inner(self) -> R {
+    self.inner
+  }
+}
+
+impl<R, T, F> crate::adapters::Adapter for ObservableRepository<R, T, F> {}
+
+impl<R, T, F> crate::ports::repository::Repository<T> for ObservableRepository<R, T, F>
+where
+  R: crate::ports::repository::Repository<T>,
+  T: crate::domain::entity::HexEntity + Clone,
+{
+  fn save(&mut self, entity: T) -> crate::result::hex_result::HexResult<()> {
+    match &self.on_save {
+      std::option::Option::Some(hook) => {
+        let saved = entity.clone();
+        self.inner.save(entity)?;
+        hook(&saved);
+        std::result::Result::Ok(())
+      }
+      std::option::Option::None => self.inner.save(entity),
+    }
+  }
+
+  fn save_and_return(&mut self, entity: T) -> crate::result::hex_result::HexResult<T> {
+    let saved = self.inner.save_and_return(entity)?;
+    if let std::option::Option::Some(hook) = &self.on_save {
+      hook(&saved);
+    }
+    std::result::Result::Ok(saved)
+  }
+
+  fn upsert(
+    &mut self,
+    entity: T,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Upserted> {
+    match &self.on_save {
+      std::option::Option::Some(hook) => {
+        let saved = entity.clone();
+        let outcome = self.inner.upsert(entity)?;
+        hook(&saved);
+        std::result::Result::Ok(outcome)
+      }
+      std::option::Option::None => self.inner.upsert(entity),
+    }
+  }
+
+  /// With an `on_save` hook, the batch goes through the inner
+  /// `upsert_many_and_return` so the hook sees each written entity as stored;
+  /// see that method for the strategies it supports.
+  fn upsert_many(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::UpsertReport> {
+    if self.on_save.is_none() {
+      return self.inner.upsert_many(entities, strategy);
+    }
+    self
+      .upsert_many_and_return(entities, strategy)
+      .map(|(report, _)| report)
+  }
+
+  /// Fires the save hook for each stored entity, skipped ones excluded.
+  ///
+  /// The hook runs only once the whole batch succeeded. If the inner call
+  /// fails partway, entities it already wrote stay written, as the inner
+  /// repository documents, but no hook fires for them.
+  fn upsert_many_and_return(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<(
+    crate::ports::repository::UpsertReport,
+    std::vec::Vec<T>,
+  )> {
+    let (report, stored) = self.inner.upsert_many_and_return(entities, strategy)?;
+    if let std::option::Option::Some(hook) = &self.on_save {
+      for entity in &stored {
+        hook(entity);
+      }
+    }
+    std::result::Result::Ok((report, stored))
+  }
+}
+
+impl<R, T, F> crate::ports::repository::QueryRepository<T> for ObservableRepository<R, T, F>
+where
+  R: crate::ports::repository::QueryRepository<T, Filter = F>,
+  T: crate::domain::entity::HexEntity + Clone,
+{
+  type Filter = F;
+  type SortKey = R::SortKey;
+
+  fn find_one(&self, filter: &F) -> crate::result::hex_result::HexResult<std::option::Option<T>> {
+    self.inner.find_one(filter)
+  }
+
+  fn find(
+    &self,
+    filter: &F,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.inner.find(filter, options)
+  }
+
+  fn find_with_timeout(
+    &self,
+    filter: &F,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+    timeout: std::time::Duration,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.inner.find_with_timeout(filter, options, timeout)
+  }
+
+  fn find_page(
+    &self,
+    filter: &F,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Page<T>> {
+    self.inner.find_page(filter, options)
+  }
+
+  fn exists(&self, filter: &F) -> crate::result::hex_result::HexResult<bool> {
+    self.inner.exists(filter)
+  }
+
+  fn count(&self, filter: &F) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.count(filter)
+  }
+
+  fn count_estimate(&self, filter: &F) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.count_estimate(filter)
+  }
+
+  fn delete_where(&mut self, filter: &F) -> crate::result::hex_result::HexResult<u64> {
+    let removed = self.inner.delete_where(filter)?;
+    if let std::option::Option::Some(hook) = &self.on_delete {
+      hook(filter, removed);
+    }
+    std::result::Result::Ok(removed)
+  }
+
+  fn clear(&mut self) -> crate::result::hex_result::HexResult<u64> {
+    let removed = self.inner.clear()?;
+    if let std::option::Option::Some(hook) = &self.on_clear {
+      hook(removed);
+    }
+    std::result::Result::Ok(removed)
+  }
+
+  fn take_where(&mut self, filter: &F) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    let taken = self.inner.take_where(filter)?;
+    if let std::option::Option::Some(hook) = &self.on_delete {
+      hook(filter, taken.len() as u64);
+    }
+    std::result::Result::Ok(taken)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::ports::repository::{QueryRepository, Repository};
+
+  #[derive(Clone, Debug, PartialEq)]
+  struct Item {
+    id: u64,
+  }
+
+  impl crate::domain::entity::HexEntity for Item {
+    type Id = u64;
+  }
+
+  /// Repository whose operations always fail.
+  struct FailingRepository;
+
+  impl Repository<Item> for FailingRepository {
+    fn save(&mut self, _entity: Item) -> crate::result::hex_result::HexResult<()> {
+      std::result::Result::Err(crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::DB_CONNECTION_FAILURE,
+        "down",
+      ))
+    }
+  }
+
+  impl QueryRepository<Item> for FailingRepository {
+    type Filter = u64;
+    type SortKey = ();
+
+    fn find_one(&self, _filter: &u64) -> crate::result::hex_result::HexResult<Option<Item>> {
+      std::result::Result::Ok(std::option::Option::None)
+    }
+
+    fn find(
+      &self,
+      _filter: &u64,
+      _options: crate::ports::repository::FindOptions<()>,
+    ) -> crate::result::hex_result::HexResult<std::vec::Vec<Item>> {
+      std::result::Result::Ok(std::vec::Vec::new())
+    }
+
+    fn delete_where(&mut self, _filter: &u64) -> crate::result::hex_result::HexResult<u64> {
+      std::result::Result::Err(crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::DB_CONNECTION_FAILURE,
+        "down",
+      ))
+    }
+  }
+
+  fn in_memory() -> crate::adapters::InMemoryRepository<Item, u64, ()> {
+    crate::adapters::InMemoryRepository::new(
+      |item: &Item| item.id,
+      |item: &Item, id: &u64| item.id == *id,
+      |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+    )
+  }
+
+  type Log = std::sync::Arc<std::sync::Mutex<std::vec::Vec<std::string::String>>>;
+
+  fn observe<R>(inner: R, log: &Log) -> super::ObservableRepository<R, Item, u64> {
+    let saves = std::sync::Arc::clone(log);
+    let deletes = std::sync::Arc::clone(log);
+    super::ObservableRepository::new(inner)
+      .on_save(move |item: &Item| saves.lock().unwrap().push(format!("save {}", item.id)))
+      .on_delete(move |id: &u64, removed: u64| {
+        deletes
+          .lock()
+          .unwrap()
+          .push(format!("delete {} ({})", id, removed))
+      })
+  }
+
+  #[test]
+  fn test_hooks_fire_after_success() {
+    let log = Log::default();
+    let mut repo = observe(in_memory(), &log);
+
+    repo.save(Item { id: 7 }).unwrap();
+    std::assert_eq!(repo.delete_where(&7).unwrap(), 1);
+    std::assert!(repo.find_one(&7).unwrap().is_none());
+
+    std::assert_eq!(
+      *log.lock().unwrap(),
+      vec![
+        std::string::String::from("save 7"),
+        std::string::String::from("delete 7 (1)"),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_upsert_many_keep_existing_reaches_inner_and_skips_hook() {
+    let log = Log::default();
+    let mut repo = observe(in_memory(), &log);
+    repo.save(Item { id: 1 }).unwrap();
+
+    let report = repo
+      .upsert_many(
+        vec![Item { id: 1 }, Item { id: 2 }],
+        crate::ports::repository::ConflictStrategy::KeepExisting,
+      )
+      .unwrap();
+
+    std::assert_eq!(report.inserted, 1);
+    std::assert_eq!(report.skipped, 1);
+    std::assert!(repo.find_one(&2).unwrap().is_some());
+    std::assert_eq!(
+      *log.lock().unwrap(),
+      vec![
+        std::string::String::from("save 1"),
+        std::string::String::from("save 2"),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_on_clear_fires_with_removed_count() {
+    let cleared = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let seen = std::sync::Arc::clone(&cleared);
+    let mut repo = crate::adapters::ObservableRepository::<_, Item, u64>::new(in_memory())
+      .on_clear(move |removed| seen.store(removed, std::sync::atomic::Ordering::SeqCst));
+
+    repo.save(Item { id: 1 }).unwrap();
+    repo.save(Item { id: 2 }).unwrap();
+    std::assert_eq!(repo.clear().unwrap(), 2);
+    std::assert_eq!(cleared.load(std::sync::atomic::Ordering::SeqCst), 2);
+  }
+
+  /// Repository assigning ids on write, as a database sequence would.
+  #[derive(Default)]
+  struct SequenceRepository {
+    next_id: u64,
+  }
+
+  impl Repository<Item> for SequenceRepository {
+    fn save(&mut self, _entity: Item) -> crate::result::hex_result::HexResult<()> {
+      self.next_id += 1;
+      std::result::Result::Ok(())
+    }
+
+    fn save_and_return(&mut self, _entity: Item) -> crate::result::hex_result::HexResult<Item> {
+      self.next_id += 1;
+      std::result::Result::Ok(Item { id: self.next_id })
+    }
+
+    fn upsert_many_and_return(
+      &mut self,
+      entities: std::vec::Vec<Item>,
+      _strategy: crate::ports::repository::ConflictStrategy<Item>,
+    ) -> crate::result::hex_result::HexResult<(
+      crate::ports::repository::UpsertReport,
+      std::vec::Vec<Item>,
+    )> {
+      let mut stored = std::vec::Vec::new();
+      for _entity in entities {
+        stored.push(self.save_and_return(Item { id: 0 })?);
+      }
+      let report = crate::ports::repository::UpsertReport {
+        inserted: stored.len() as u64,
+        ..std::default::Default::default()
+      };
+      std::result::Result::Ok((report, stored))
+    }
+  }
+
+  #[test]
+  fn test_save_and_return_forwards_enriched_entity() {
+    // Test: The wrapper returns and observes the entity produced by the inner adapter.
+    // Justification: Wrapping must not discard ids assigned on write.
+    let log = Log::default();
+    let log_clone = log.clone();
+    let mut repo =
+      crate::adapters::ObservableRepository::<_, Item, ()>::new(SequenceRepository::default())
+        .on_save(move |item: &Item| log_clone.lock().unwrap().push(format!("save {}", item.id)));
+
+    let saved = repo.save_and_return(Item { id: 0 }).unwrap();
+    std::assert_eq!(saved.id, 1);
+    std::assert_eq!(
+      *log.lock().unwrap(),
+      vec![std::string::String::from("save 1")]
+    );
+  }
+
+  #[test]
+  fn test_upsert_many_hook_sees_stored_entities() {
+    // Test: The save hook observes entities as the inner adapter stored them.
+    // Justification: Under Merge or adapter-assigned ids the input is not what was persisted.
+    let log = Log::default();
+    let log_clone = log.clone();
+    let mut repo =
+      crate::adapters::ObservableRepository::<_, Item, ()>::new(SequenceRepository::default())
+        .on_save(move |item: &Item| log_clone.lock().unwrap().push(format!("save {}", item.id)));
+
+    let report = repo
+      .upsert_many(
+        vec![Item { id: 0 }, Item { id: 0 }],
+        crate::ports::repository::ConflictStrategy::Merge(|_, incoming| incoming),
+      )
+      .unwrap();
+    std::assert_eq!(report.inserted, 2);
+    std::assert_eq!(
+      *log.lock().unwrap(),
+      vec![
+        std::string::String::from("save 1"),
+        std::string::String::from("save 2"),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_hooks_not_fired_on_error() {
+    // Test: Callbacks must only observe committed changes.
+    // Justification: Cache invalidation or events on a failed write would be wrong.
+    let log = Log::default();
+    let mut repo = observe(FailingRepository, &log);
+
+    std::assert!(repo.save(Item { id: 1 }).is_err());
+    std::assert!(repo.delete_where(&1).is_err());
+    std::assert!(log.lock().unwrap().is_empty());
+  }
+}
@@ -0,0 +1,284 @@
+//! Thread-safe repository handle shared across handlers.
+//!
+//! SharedRepository owns an `Arc<Mutex<R>>` and implements `Repository` and
+//! `QueryRepository` by locking around each call, so handlers can hold a
+//! cheap clone instead of locking by hand. A poisoned lock is reported as an
+//! adapter error with code `E_LOCK` rather than a panic.
+//!
+//! Revision History
+//! - 2026-10-19T12:25:00Z @AI: Forward upsert_many_and_return.
+//! - 2026-10-19T11:15:00Z @AI: LOCK_ERROR_CODE re-exports codes::lock::POISONED.
+//! - 2026-10-19T09:05:00Z @AI: Forward find_with_timeout.
+//! - 2026-10-19T07:55:00Z @AI: Forward upsert_many.
+//! - 2026-10-19T07:05:00Z @AI: Forward count_estimate.
+//! - 2026-10-19T06:15:00Z @AI: Forward upsert.
+//! - 2026-10-19T05:05:00Z @AI: Delegate clear to the inner repository.
+//! - 2026-10-19T01:35:00Z @AI: Initial SharedRepository wrapper.
+
+/// Error code reported when the inner repository's lock is poisoned.
+pub use crate::error::codes::lock::POISONED as LOCK_ERROR_CODE;
+
+/// Cloneable, lock-per-call handle to a repository.
+///
+/// Every trait method acquires the lock once, delegates to the inner
+/// repository, and releases the lock before returning. Two consecutive calls
+/// are therefore not atomic: another clone may write in between. Use
+/// `with_lock` when several operations must observe one consistent state.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::Repository;
+/// use hexser::ports::repository::QueryRepository;
+///
+/// #[derive(Clone, Debug)]
+/// struct Item {
+///     id: u64,
+/// }
+///
+/// impl hexser::domain::HexEntity for Item {
+///     type Id = u64;
+/// }
+///
+/// let mut repo = hexser::adapters::SharedRepository::new(hexser::adapters::InMemoryRepository::new(
+///     |item: &Item| item.id,
+///     |item: &Item, id: &u64| item.id == *id,
+///     |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+/// ));
+/// let reader = repo.clone();
+///
+/// repo.save(Item { id: 7 }).unwrap();
+/// std::assert!(reader.exists(&7).unwrap());
+/// ```
+pub struct SharedRepository<R> {
+  inner: std::sync::Arc<std::sync::Mutex<R>>,
+}
+
+impl<R> SharedRepository<R> {
+  /// Wraps a repository in a new shared lock.
+  pub fn new(repository: R) -> Self {
+    Self::from_shared(std::sync::Arc::new(std::sync::Mutex::new(repository)))
+  }
+
+  /// Adopts an existing `Arc<Mutex<R>>`, e.g. one already held elsewhere.
+  pub fn from_shared(inner: std::sync::Arc<std::sync::Mutex<R>>) -> Self {
+    Self { inner }
+  }
+
+  /// Returns the underlying shared lock.
+  pub fn shared(&self) -> std::sync::Arc<std::sync::Mutex<R>> {
+    std::sync::Arc::clone(&self.inner)
+  }
+
+  /// Runs `f` with the repository locked for its whole duration.
+  pub fn with_lock<O>(
+    &self,
+    f: impl FnOnce(&mut R) -> crate::result::hex_result::HexResult<O>,
+  ) -> crate::result::hex_result::HexResult<O> {
+    let mut guard = self.inner.lock().map_err(|e| {
+      crate::error::hex_error::Hexserror::adapter(
+        LOCK_ERROR_CODE,
+        &format!("Failed to acquire repository lock: {}", e),
+      )
+      .with_next_step(
+        "A previous operation panicked while holding the lock; recreate the repository",
+      )
+    })?;
+    f(&mut guard)
+  }
+}
+
+impl<R> Clone for SharedRepository<R> {
+  fn clone(&self) -> Self {
+    Self {
+      inner: std::sync::Arc::clone(&self.inner),
+    }
+  }
+}
+
+impl<R> crate::adapters::Adapter for SharedRepository<R> {}
+
+impl<R, T> crate::ports::repository::Repository<T> for SharedRepository<R>
+where
+  R: crate::ports::repository::Repository<T>,
+  T: crate::domain::entity::HexEntity,
+{
+  fn save(&mut self, entity: T) -> crate::result::hex_result::HexResult<()> {
+    self.with_lock(|repo| repo.save(entity))
+  }
+
+  fn save_and_return(&mut self, entity: T) -> crate::result::hex_result::HexResult<T>
+  where
+    T: Clone,
+  {
+    self.with_lock(|repo| repo.save_and_return(entity))
+  }
+
+  fn upsert(
+    &mut self,
+    entity: T,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Upserted> {
+    self.with_lock(|repo| repo.upsert(entity))
+  }
+
+  fn upsert_many(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::UpsertReport> {
+    self.with_lock(|repo| repo.upsert_many(entities, strategy))
+  }
+
+  fn upsert_many_and_return(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<(
+    crate::ports::repository::UpsertReport,
+    std::vec::Vec<T>,
+  )>
+  where
+    T: Clone,
+  {
+    self.with_lock(|repo| repo.upsert_many_and_return(entities, strategy))
+  }
+}
+
+impl<R, T> crate::ports::repository::QueryRepository<T> for SharedRepository<R>
+where
+  R: crate::ports::repository::QueryRepository<T>,
+  T: crate::domain::entity::HexEntity,
+{
+  type Filter = R::Filter;
+  type SortKey = R::SortKey;
+
+  fn find_one(
+    &self,
+    filter: &Self::Filter,
+  ) -> crate::result::hex_result::HexResult<std::option::Option<T>> {
+    self.with_lock(|repo| repo.find_one(filter))
+  }
+
+  fn find(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.with_lock(|repo| repo.find(filter, options))
+  }
+
+  fn find_with_timeout(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+    timeout: std::time::Duration,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.with_lock(|repo| repo.find_with_timeout(filter, options, timeout))
+  }
+
+  fn find_page(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Page<T>> {
+    self.with_lock(|repo| repo.find_page(filter, options))
+  }
+
+  fn exists(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<bool> {
+    self.with_lock(|repo| repo.exists(filter))
+  }
+
+  fn count(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.with_lock(|repo| repo.count(filter))
+  }
+
+  fn count_estimate(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.with_lock(|repo| repo.count_estimate(filter))
+  }
+
+  fn exists_all(&self, filters: &[Self::Filter]) -> crate::result::hex_result::HexResult<bool> {
+    self.with_lock(|repo| repo.exists_all(filters))
+  }
+
+  fn count_by(
+    &self,
+    filters: &[Self::Filter],
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<u64>> {
+    self.with_lock(|repo| repo.count_by(filters))
+  }
+
+  fn delete_where(&mut self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.with_lock(|repo| repo.delete_where(filter))
+  }
+
+  fn clear(&mut self) -> crate::result::hex_result::HexResult<u64> {
+    self.with_lock(|repo| repo.clear())
+  }
+
+  fn take_where(
+    &mut self,
+    filter: &Self::Filter,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.with_lock(|repo| repo.take_where(filter))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::ports::repository::{QueryRepository, Repository};
+
+  #[derive(Clone, Debug, PartialEq)]
+  struct Item {
+    id: u64,
+  }
+
+  impl crate::domain::entity::HexEntity for Item {
+    type Id = u64;
+  }
+
+  fn items() -> crate::adapters::in_memory_repository::InMemoryRepository<Item, u64, ()> {
+    crate::adapters::in_memory_repository::InMemoryRepository::new(
+      |item: &Item| item.id,
+      |item: &Item, id: &u64| item.id == *id,
+      |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+    )
+  }
+
+  #[test]
+  fn test_clones_share_state_across_threads() {
+    let repo = super::SharedRepository::new(items());
+    let writers: std::vec::Vec<_> = (0..4)
+      .map(|id| {
+        let mut handle = repo.clone();
+        std::thread::spawn(move || handle.save(Item { id }).unwrap())
+      })
+      .collect();
+    for writer in writers {
+      writer.join().unwrap();
+    }
+
+    assert_eq!(repo.count(&0).unwrap() + repo.count(&3).unwrap(), 2);
+    assert_eq!(
+      repo
+        .with_lock(|inner| inner.find(&1, std::default::Default::default()))
+        .unwrap(),
+      vec![Item { id: 1 }]
+    );
+  }
+
+  #[test]
+  fn test_poisoned_lock_is_adapter_error() {
+    let repo = super::SharedRepository::new(items());
+    let shared = repo.shared();
+    let _ = std::thread::spawn(move || {
+      let _guard = shared.lock().unwrap();
+      panic!("poison the lock");
+    })
+    .join();
+
+    let err = repo.exists(&1).unwrap_err();
+    assert!(err.is_adapter());
+    assert_eq!(err.code(), super::LOCK_ERROR_CODE);
+    assert!(crate::error::codes::is_registered(err.code()));
+  }
+}
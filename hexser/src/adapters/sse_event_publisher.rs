@@ -0,0 +1,259 @@
+//! SseEventPublisher adapter streaming CloudEvents as server-sent events.
+//!
+//! This module provides a browser-facing transport for CloudEvents envelopes.
+//! Each published envelope is encoded with an EventCodec and written to any
+//! `std::io::Write` (an HTTP response body, a pipe, a channel writer) as one
+//! SSE frame: the envelope `id` becomes the SSE `id:` field, the CloudEvents
+//! `type` becomes the `event:` field, and the encoded envelope is carried in
+//! `data:` lines. Gated behind the `sse` feature.
+//!
+//! Revision History
+//! - 2026-10-19T00:15:00Z @AI: Initial SseEventPublisher adapter.
+
+/// Formats one server-sent events frame.
+///
+/// Multi-line `data` is split into one `data:` line per line, as required by
+/// the SSE format, and the frame is terminated by a blank line.
+///
+/// # Examples
+///
+/// ```rust
+/// let frame = hexser::adapters::sse_event_publisher::format_sse_frame(
+///     "evt-1",
+///     "com.example.user.created",
+///     "{\"a\":1}\n{\"b\":2}",
+/// );
+/// std::assert_eq!(
+///     frame,
+///     "id: evt-1\nevent: com.example.user.created\ndata: {\"a\":1}\ndata: {\"b\":2}\n\n"
+/// );
+/// ```
+pub fn format_sse_frame(id: &str, event: &str, data: &str) -> std::string::String {
+  let mut frame = format!("id: {}\nevent: {}\n", id, event);
+  for line in data.lines() {
+    frame.push_str("data: ");
+    frame.push_str(line);
+    frame.push('\n');
+  }
+  if data.is_empty() {
+    frame.push_str("data: \n");
+  }
+  frame.push('\n');
+  frame
+}
+
+/// Event publisher writing CloudEvents envelopes as SSE frames.
+///
+/// The writer is guarded by a mutex so a single publisher can be shared
+/// between request handlers; each frame is written and flushed atomically.
+///
+/// # Type Parameters
+///
+/// - `W`: Destination implementing `std::io::Write`
+/// - `C`: Codec producing the UTF-8 `data:` payload (typically JSON)
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::events::EventPublisher;
+///
+/// struct TextCodec;
+///
+/// impl hexser::ports::events::EventCodec<std::string::String> for TextCodec {
+///     fn encode(
+///         &self,
+///         envelope: &hexser::ports::events::CloudEventsEnvelope<std::string::String>,
+///     ) -> hexser::HexResult<std::vec::Vec<u8>> {
+///         std::result::Result::Ok(envelope.data.clone().unwrap_or_default().into_bytes())
+///     }
+///
+///     fn decode(
+///         &self,
+///         _bytes: &[u8],
+///     ) -> hexser::HexResult<hexser::ports::events::CloudEventsEnvelope<std::string::String>> {
+///         std::result::Result::Err(hexser::Hexserror::validation("decode not supported"))
+///     }
+/// }
+///
+/// let publisher = hexser::adapters::SseEventPublisher::new(std::vec::Vec::new(), TextCodec);
+/// let mut envelope = hexser::ports::events::CloudEventsEnvelope::new(
+///     std::string::String::from("evt-1"),
+///     std::string::String::from("/users"),
+///     std::string::String::from("com.example.user.created"),
+/// );
+/// envelope.data = std::option::Option::Some(std::string::String::from("hello"));
+///
+/// publisher.publish(&envelope).unwrap();
+/// let written = std::string::String::from_utf8(publisher.into_inner()).unwrap();
+/// std::assert_eq!(written, "id: evt-1\nevent: com.example.user.created\ndata: hello\n\n");
+/// ```
+pub struct SseEventPublisher<W, C> {
+  writer: std::sync::Mutex<W>,
+  codec: C,
+}
+
+impl<W, C> SseEventPublisher<W, C>
+where
+  W: std::io::Write,
+{
+  /// Creates a publisher writing frames to `writer`, encoding with `codec`.
+  pub fn new(writer: W, codec: C) -> Self {
+    Self {
+      writer: std::sync::Mutex::new(writer),
+      codec,
+    }
+  }
+
+  /// Consumes the publisher and returns the underlying writer.
+  pub fn into_inner(self) -> W {
+    self
+      .writer
+      .into_inner()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+
+  fn encode_frame<T>(
+    &self,
+    envelope: &crate::ports::events::CloudEventsEnvelope<T>,
+  ) -> crate::HexResult<std::string::String>
+  where
+    C: crate::ports::events::EventCodec<T>,
+  {
+    envelope.validate()?;
+    let bytes = self.codec.encode(envelope)?;
+    let data = std::string::String::from_utf8(bytes).map_err(|e| {
+      crate::Hexserror::adapter(
+        crate::error::codes::adapter::MAPPING_FAILURE,
+        &format!("SSE data must be UTF-8: {}", e),
+      )
+      .with_next_step("Use a text-based codec such as JSON for SSE transport")
+    })?;
+    std::result::Result::Ok(format_sse_frame(&envelope.id, &envelope.r#type, &data))
+  }
+
+  fn write_frames(&self, frames: &[std::string::String]) -> crate::HexResult<()> {
+    let mut writer = self
+      .writer
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner());
+    frames
+      .iter()
+      .try_for_each(|frame| writer.write_all(frame.as_bytes()))
+      .and_then(|_| writer.flush())
+      .map_err(|e| {
+        crate::Hexserror::adapter(
+          crate::error::codes::io::IO_FAILURE,
+          &format!("Failed to write SSE frame: {}", e),
+        )
+      })
+  }
+}
+
+impl<W, C> crate::adapters::Adapter for SseEventPublisher<W, C> {}
+
+impl<T, W, C> crate::ports::events::EventPublisher<T> for SseEventPublisher<W, C>
+where
+  W: std::io::Write,
+  C: crate::ports::events::EventCodec<T>,
+{
+  fn publish(
+    &self,
+    envelope: &crate::ports::events::CloudEventsEnvelope<T>,
+  ) -> crate::HexResult<()> {
+    let frame = self.encode_frame(envelope)?;
+    self.write_frames(std::slice::from_ref(&frame))
+  }
+
+  /// Encodes every envelope before writing, so a failing envelope leaves
+  /// the stream untouched.
+  fn publish_batch(
+    &self,
+    envelopes: &[crate::ports::events::CloudEventsEnvelope<T>],
+  ) -> crate::HexResult<()> {
+    let frames = envelopes
+      .iter()
+      .map(|envelope| self.encode_frame(envelope))
+      .collect::<crate::HexResult<std::vec::Vec<_>>>()?;
+    self.write_frames(&frames)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ports::events::EventPublisher;
+
+  struct JsonishCodec;
+
+  impl crate::ports::events::EventCodec<std::string::String> for JsonishCodec {
+    fn encode(
+      &self,
+      envelope: &crate::ports::events::CloudEventsEnvelope<std::string::String>,
+    ) -> crate::HexResult<std::vec::Vec<u8>> {
+      std::result::Result::Ok(
+        format!(
+          "{{\"specversion\":\"{}\",\"source\":\"{}\",\"data\":\"{}\"}}",
+          envelope.specversion,
+          envelope.source,
+          envelope.data.as_deref().unwrap_or_default()
+        )
+        .into_bytes(),
+      )
+    }
+
+    fn decode(
+      &self,
+      _bytes: &[u8],
+    ) -> crate::HexResult<crate::ports::events::CloudEventsEnvelope<std::string::String>> {
+      std::result::Result::Err(crate::Hexserror::validation("decode not supported"))
+    }
+  }
+
+  fn envelope(id: &str) -> crate::ports::events::CloudEventsEnvelope<std::string::String> {
+    let mut envelope = crate::ports::events::CloudEventsEnvelope::new(
+      std::string::String::from(id),
+      std::string::String::from("/services/users"),
+      std::string::String::from("com.example.user.created"),
+    );
+    envelope.data = std::option::Option::Some(std::string::String::from("u-1"));
+    envelope
+  }
+
+  #[test]
+  fn test_publish_writes_sse_frame() {
+    let publisher = SseEventPublisher::new(std::vec::Vec::new(), JsonishCodec);
+    publisher.publish(&envelope("evt-1")).unwrap();
+
+    let written = std::string::String::from_utf8(publisher.into_inner()).unwrap();
+    std::assert_eq!(
+      written,
+      "id: evt-1\n\
+       event: com.example.user.created\n\
+       data: {\"specversion\":\"1.0\",\"source\":\"/services/users\",\"data\":\"u-1\"}\n\
+       \n"
+    );
+  }
+
+  #[test]
+  fn test_publish_batch_is_all_or_nothing() {
+    let publisher = SseEventPublisher::new(std::vec::Vec::new(), JsonishCodec);
+    let result = publisher.publish_batch(&[envelope("evt-1"), envelope("")]);
+    std::assert!(result.is_err());
+    std::assert!(publisher.into_inner().is_empty());
+
+    let publisher = SseEventPublisher::new(std::vec::Vec::new(), JsonishCodec);
+    publisher
+      .publish_batch(&[envelope("evt-1"), envelope("evt-2")])
+      .unwrap();
+    let written = std::string::String::from_utf8(publisher.into_inner()).unwrap();
+    std::assert_eq!(written.matches("\n\n").count(), 2);
+  }
+
+  #[test]
+  fn test_format_sse_frame_splits_multiline_data() {
+    std::assert_eq!(
+      format_sse_frame("1", "t", "a\r\nb"),
+      "id: 1\nevent: t\ndata: a\ndata: b\n\n"
+    );
+  }
+}
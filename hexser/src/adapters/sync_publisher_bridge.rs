@@ -0,0 +1,75 @@
+//! Bridge exposing a sync EventPublisher as an AsyncEventPublisher.
+//!
+//! Lets code written against the async port use existing sync publishers
+//! (including test doubles) without a separate implementation.
+//!
+//! Revision History
+//! - 2026-10-18T19:30:00Z @AI: Initial SyncPublisherBridge adapter.
+
+/// Adapter implementing `AsyncEventPublisher` by calling a sync `EventPublisher`.
+///
+/// The sync call runs inline on the awaiting task, so the bridge suits
+/// publishers that return quickly (in-memory buses, buffered clients).
+/// Publishers that block on network I/O should implement
+/// `AsyncEventPublisher` natively instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::events::AsyncEventPublisher;
+///
+/// let bus: hexser::adapters::InMemoryEventBus<std::string::String> =
+///     hexser::adapters::InMemoryEventBus::new();
+/// let bridge = hexser::adapters::SyncPublisherBridge::new(bus);
+///
+/// let envelope = hexser::ports::events::CloudEventsEnvelope::new(
+///     std::string::String::from("evt-001"),
+///     std::string::String::from("/services/test"),
+///     std::string::String::from("com.example.test.event"),
+/// );
+///
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// runtime.block_on(bridge.publish(&envelope)).unwrap();
+/// std::assert_eq!(bridge.inner().queue_size(), 1);
+/// ```
+pub struct SyncPublisherBridge<P> {
+  inner: P,
+}
+
+impl<P> SyncPublisherBridge<P> {
+  /// Wraps a sync publisher.
+  pub fn new(inner: P) -> Self {
+    Self { inner }
+  }
+
+  /// Returns a reference to the wrapped publisher.
+  pub fn inner(&self) -> &P {
+    &self.inner
+  }
+
+  /// Consumes the bridge and returns the wrapped publisher.
+  pub fn into_inner(self) -> P {
+    self.inner
+  }
+}
+
+impl<P> crate::adapters::Adapter for SyncPublisherBridge<P> {}
+
+impl<T, P> crate::ports::events::AsyncEventPublisher<T> for SyncPublisherBridge<P>
+where
+  P: crate::ports::events::EventPublisher<T>,
+{
+  async fn publish(
+    &self,
+    envelope: &crate::ports::events::CloudEventsEnvelope<T>,
+  ) -> crate::HexResult<()> {
+    self.inner.publish(envelope)
+  }
+
+  async fn publish_batch(
+    &self,
+    envelopes: &[crate::ports::events::CloudEventsEnvelope<T>],
+  ) -> crate::HexResult<()> {
+    self.inner.publish_batch(envelopes)
+  }
+}
@@ -0,0 +1,284 @@
+//! Repository wrapper that keeps `updated_at` current.
+//!
+//! TimestampingRepository decorates any repository of `Timestamped`
+//! entities and touches each entity from its clock before handing it to the
+//! inner repository, so adapters and handlers never set `updated_at`
+//! themselves. Queries and deletes pass through unchanged.
+//!
+//! Revision History
+//! - 2026-10-19T12:25:00Z @AI: Stamp and forward upsert_many_and_return.
+//! - 2026-10-19T09:05:00Z @AI: Forward find_with_timeout.
+//! - 2026-10-19T08:25:00Z @AI: Initial TimestampingRepository wrapper.
+
+/// Repository decorator touching entities on every write.
+///
+/// `save`, `save_and_return`, `upsert`, and `upsert_many` set `updated_at`
+/// to the clock's current time before delegating. `created_at` is never
+/// changed; set it when constructing the entity.
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::domain::Timestamped;
+/// use hexser::ports::Repository;
+///
+/// #[derive(Clone, Debug)]
+/// struct Item {
+///     id: u64,
+///     created_at: std::time::SystemTime,
+///     updated_at: std::time::SystemTime,
+/// }
+///
+/// impl hexser::domain::HexEntity for Item {
+///     type Id = u64;
+/// }
+///
+/// impl Timestamped for Item {
+///     fn created_at(&self) -> std::time::SystemTime {
+///         self.created_at
+///     }
+///
+///     fn updated_at(&self) -> std::time::SystemTime {
+///         self.updated_at
+///     }
+///
+///     fn set_updated_at(&mut self, at: std::time::SystemTime) {
+///         self.updated_at = at;
+///     }
+/// }
+///
+/// let clock = std::sync::Arc::new(hexser::adapters::FixedClock::at_unix_secs(60));
+/// let inner = hexser::adapters::InMemoryRepository::new(
+///     |item: &Item| item.id,
+///     |item: &Item, id: &u64| item.id == *id,
+///     |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+/// );
+/// let mut repo = hexser::adapters::TimestampingRepository::new(inner, clock);
+///
+/// let saved = repo
+///     .save_and_return(Item {
+///         id: 1,
+///         created_at: std::time::UNIX_EPOCH,
+///         updated_at: std::time::UNIX_EPOCH,
+///     })
+///     .unwrap();
+/// std::assert_eq!(
+///     saved.updated_at(),
+///     std::time::UNIX_EPOCH + std::time::Duration::from_secs(60)
+/// );
+/// ```
+pub struct TimestampingRepository<R> {
+  inner: R,
+  clock: std::sync::Arc<dyn crate::ports::clock::Clock>,
+}
+
+impl<R> TimestampingRepository<R> {
+  /// Wraps a repository, taking write times from `clock`.
+  pub fn new(inner: R, clock: std::sync::Arc<dyn crate::ports::clock::Clock>) -> Self {
+    Self { inner, clock }
+  }
+
+  /// Returns a reference to the wrapped repository.
+  pub fn inner(&self) -> &R {
+    &self.inner
+  }
+
+  /// Consumes the wrapper and returns the wrapped repository.
+  pub fn into_inner(self) -> R {
+    self.inner
+  }
+
+  fn touched<T: crate::domain::timestamped::Timestamped>(&self, entity: T) -> T {
+    let mut entity = entity;
+    entity.touch(self.clock.as_ref());
+    entity
+  }
+}
+
+impl<R> crate::adapters::Adapter for TimestampingRepository<R> {}
+
+impl<R, T> crate::ports::repository::Repository<T> for TimestampingRepository<R>
+where
+  R: crate::ports::repository::Repository<T>,
+  T: crate::domain::entity::HexEntity + crate::domain::timestamped::Timestamped,
+{
+  fn save(&mut self, entity: T) -> crate::result::hex_result::HexResult<()> {
+    let entity = self.touched(entity);
+    self.inner.save(entity)
+  }
+
+  fn save_and_return(&mut self, entity: T) -> crate::result::hex_result::HexResult<T>
+  where
+    T: Clone,
+  {
+    let entity = self.touched(entity);
+    self.inner.save_and_return(entity)
+  }
+
+  fn upsert(
+    &mut self,
+    entity: T,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Upserted> {
+    let entity = self.touched(entity);
+    self.inner.upsert(entity)
+  }
+
+  fn upsert_many(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::UpsertReport> {
+    let entities = entities.into_iter().map(|e| self.touched(e)).collect();
+    self.inner.upsert_many(entities, strategy)
+  }
+
+  fn upsert_many_and_return(
+    &mut self,
+    entities: std::vec::Vec<T>,
+    strategy: crate::ports::repository::ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<(
+    crate::ports::repository::UpsertReport,
+    std::vec::Vec<T>,
+  )>
+  where
+    T: Clone,
+  {
+    let entities = entities.into_iter().map(|e| self.touched(e)).collect();
+    self.inner.upsert_many_and_return(entities, strategy)
+  }
+}
+
+impl<R, T> crate::ports::repository::QueryRepository<T> for TimestampingRepository<R>
+where
+  R: crate::ports::repository::QueryRepository<T>,
+  T: crate::domain::entity::HexEntity,
+{
+  type Filter = R::Filter;
+  type SortKey = R::SortKey;
+
+  fn find_one(
+    &self,
+    filter: &Self::Filter,
+  ) -> crate::result::hex_result::HexResult<std::option::Option<T>> {
+    self.inner.find_one(filter)
+  }
+
+  fn find(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.inner.find(filter, options)
+  }
+
+  fn find_with_timeout(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+    timeout: std::time::Duration,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.inner.find_with_timeout(filter, options, timeout)
+  }
+
+  fn find_page(
+    &self,
+    filter: &Self::Filter,
+    options: crate::ports::repository::FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<crate::ports::repository::Page<T>> {
+    self.inner.find_page(filter, options)
+  }
+
+  fn exists(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<bool> {
+    self.inner.exists(filter)
+  }
+
+  fn count(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.count(filter)
+  }
+
+  fn count_estimate(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.count_estimate(filter)
+  }
+
+  fn delete_where(&mut self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.delete_where(filter)
+  }
+
+  fn clear(&mut self) -> crate::result::hex_result::HexResult<u64> {
+    self.inner.clear()
+  }
+
+  fn take_where(
+    &mut self,
+    filter: &Self::Filter,
+  ) -> crate::result::hex_result::HexResult<std::vec::Vec<T>> {
+    self.inner.take_where(filter)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::timestamped::Timestamped,
+    ports::repository::{QueryRepository, Repository},
+  };
+
+  #[derive(Clone, Debug, PartialEq)]
+  struct Item {
+    id: u64,
+    created_at: std::time::SystemTime,
+    updated_at: std::time::SystemTime,
+  }
+
+  impl crate::domain::entity::HexEntity for Item {
+    type Id = u64;
+  }
+
+  impl Timestamped for Item {
+    fn created_at(&self) -> std::time::SystemTime {
+      self.created_at
+    }
+
+    fn updated_at(&self) -> std::time::SystemTime {
+      self.updated_at
+    }
+
+    fn set_updated_at(&mut self, at: std::time::SystemTime) {
+      self.updated_at = at;
+    }
+  }
+
+  fn at(secs: u64) -> std::time::SystemTime {
+    std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs)
+  }
+
+  #[test]
+  fn test_save_touches_updated_at_from_clock() {
+    let clock = std::sync::Arc::new(crate::adapters::clock::FixedClock::at_unix_secs(100));
+    let inner = crate::adapters::in_memory_repository::InMemoryRepository::new(
+      |item: &Item| item.id,
+      |item: &Item, id: &u64| item.id == *id,
+      |a: &Item, b: &Item, _key: &()| a.id.cmp(&b.id),
+    );
+    let mut repo = super::TimestampingRepository::new(inner, clock.clone());
+    let item = Item {
+      id: 1,
+      created_at: at(0),
+      updated_at: at(0),
+    };
+
+    repo.save(item.clone()).unwrap();
+    let stored = repo.find_one(&1).unwrap().unwrap();
+    std::assert_eq!(stored.created_at(), at(0));
+    std::assert_eq!(stored.updated_at(), at(100));
+
+    clock.advance(std::time::Duration::from_secs(5));
+    repo
+      .upsert_many(
+        vec![stored],
+        crate::ports::repository::ConflictStrategy::Overwrite,
+      )
+      .unwrap();
+    std::assert_eq!(repo.find_one(&1).unwrap().unwrap().updated_at(), at(105));
+  }
+}
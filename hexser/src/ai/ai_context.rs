@@ -5,6 +5,9 @@
 //! Follows JSON Schema for validation and tooling integration.
 //!
 //! Revision History
+//! - 2026-10-19T04:55:00Z @AI: Add to_markdown rendering for generated architecture docs.
+//! - 2026-10-18T21:45:00Z @AI: Add optional source location to ComponentInfo.
+//! - 2026-10-18T09:00:00Z @AI: Add returns to ComponentInfo describing query result types.
 //! - 2025-10-10T20:28:00Z @AI: Add MethodInfo to ComponentInfo for capturing method signatures and documentation.
 //! - 2025-10-02T18:00:00Z @AI: Initial AI context structure.
 //! - 2025-10-06T17:59:00Z @AI: Add to_json() serializer and tests; ensure ai feature includes serde.
@@ -57,6 +60,14 @@ pub struct ComponentInfo {
 
   /// Public methods and their documentation
   pub methods: Vec<MethodInfo>,
+
+  /// Result type produced by the component (queries; "unknown" if undeclared)
+  #[serde(default)]
+  pub returns: Option<String>,
+
+  /// Declaration site as `file:line`; omitted unless source locations are enabled
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub source: Option<String>,
 }
 
 /// Information about a method within a component
@@ -287,6 +298,8 @@ mod tests {
       purpose: Some(String::from("Represents a user")),
       methods: vec![],
       dependencies: vec![],
+      returns: None,
+      source: None,
     };
 
     let json = serde_json::to_string(&component).unwrap();
@@ -364,6 +377,8 @@ mod tests {
         is_async: false,
       }],
       dependencies: vec![],
+      returns: None,
+      source: None,
     };
 
     let json = serde_json::to_string(&component).unwrap();
@@ -384,6 +399,105 @@ impl AIContext {
       Err(e) => Err(format!("Serialization error: {}", e)),
     }
   }
+
+  /// Render this AIContext as Markdown architecture documentation.
+  ///
+  /// Emits one section per layer (Domain, Port, Adapter, Application,
+  /// Infrastructure, then any others alphabetically) listing components by
+  /// type name with their role, module, purpose, and methods, followed by a
+  /// Dependencies section. Output is sorted and omits `generated_at`, so
+  /// regenerating an unchanged architecture yields an identical file. Node
+  /// ids of components are shown as their type names.
+  pub fn to_markdown(&self) -> String {
+    const LAYER_ORDER: [&str; 5] = ["Domain", "Port", "Adapter", "Application", "Infrastructure"];
+
+    let names: std::collections::HashMap<String, &str> = self
+      .components
+      .iter()
+      .map(|c| {
+        (
+          crate::graph::node_id::NodeId::from_type_name(&c.type_name).to_string(),
+          c.type_name.as_str(),
+        )
+      })
+      .collect();
+    let name_of = |id: &str| names.get(id).copied().unwrap_or(id).to_string();
+
+    let mut layers: Vec<&str> = self.components.iter().map(|c| c.layer.as_str()).collect();
+    layers.sort_by_key(|layer| {
+      let rank = LAYER_ORDER.iter().position(|l| l == layer);
+      (rank.unwrap_or(LAYER_ORDER.len()), *layer)
+    });
+    layers.dedup();
+
+    let mut out = format!("# Architecture: {}\n\n", self.architecture);
+    out.push_str(&format!(
+      "Generated by hexser {} (schema {}). {} components, {} relationships.\n",
+      self.metadata.hex_version,
+      self.metadata.schema_version,
+      self.components.len(),
+      self.relationships.len()
+    ));
+
+    for layer in layers {
+      out.push_str(&format!("\n## {}\n\n", layer));
+      let mut components: Vec<&ComponentInfo> = self
+        .components
+        .iter()
+        .filter(|c| c.layer == layer)
+        .collect();
+      components.sort_by(|a, b| a.type_name.cmp(&b.type_name));
+      for component in components {
+        out.push_str(&format!(
+          "- **{}** ({}) in `{}`",
+          component.type_name, component.role, component.module_path
+        ));
+        if let Some(purpose) = &component.purpose {
+          out.push_str(&format!(": {}", purpose));
+        }
+        out.push('\n');
+        let mut methods: Vec<&MethodInfo> = component.methods.iter().collect();
+        methods.sort_by(|a, b| a.name.cmp(&b.name));
+        for method in methods {
+          out.push_str(&format!("  - `{}`", method.signature));
+          if let Some(doc) = &method.documentation {
+            out.push_str(&format!(": {}", doc));
+          }
+          out.push('\n');
+        }
+      }
+    }
+
+    out.push_str("\n## Dependencies\n\n");
+    let mut edges: Vec<(String, String, &RelationshipInfo)> = self
+      .relationships
+      .iter()
+      .map(|r| (name_of(&r.from), name_of(&r.to), r))
+      .collect();
+    edges.sort_by(|a, b| {
+      (&a.0, &a.1, &a.2.relationship_type).cmp(&(&b.0, &b.1, &b.2.relationship_type))
+    });
+    if edges.is_empty() {
+      out.push_str("None.\n");
+    }
+    for (from, to, relationship) in edges {
+      out.push_str(&format!(
+        "- `{}` {} `{}`",
+        from, relationship.relationship_type, to
+      ));
+      if !relationship.is_valid {
+        out.push_str(&format!(
+          " (invalid: {})",
+          relationship
+            .validation_message
+            .as_deref()
+            .unwrap_or("violates architecture rules")
+        ));
+      }
+      out.push('\n');
+    }
+    out
+  }
 }
 
 #[cfg(test)]
@@ -416,3 +530,81 @@ mod tests_to_json {
     assert!(json.contains("\"hexagonal\""));
   }
 }
+
+#[cfg(test)]
+mod tests_to_markdown {
+  fn component(type_name: &str, layer: &str, role: &str) -> super::ComponentInfo {
+    super::ComponentInfo {
+      type_name: String::from(type_name),
+      layer: String::from(layer),
+      role: String::from(role),
+      module_path: format!("app::{}", layer.to_lowercase()),
+      purpose: None,
+      dependencies: Vec::new(),
+      methods: Vec::new(),
+      returns: None,
+      source: None,
+    }
+  }
+
+  fn relationship(from: &str, to: &str, is_valid: bool) -> super::RelationshipInfo {
+    let id = |name: &str| crate::graph::node_id::NodeId::from_type_name(name).to_string();
+    super::RelationshipInfo {
+      from: id(from),
+      to: id(to),
+      relationship_type: String::from("Depends"),
+      is_valid,
+      validation_message: (!is_valid).then(|| String::from("Violates layer dependency rules")),
+    }
+  }
+
+  #[test]
+  fn test_to_markdown_matches_snapshot() {
+    // Test: Rendered Markdown matches the checked-in snapshot byte for byte.
+    // Justification: The output is committed as documentation and must regenerate stably.
+    let mut repository = component("UserRepository", "Port", "Repository");
+    repository.purpose = Some(String::from("Persists users"));
+    repository.methods.push(super::MethodInfo {
+      name: String::from("save"),
+      signature: String::from("fn save(&mut self, user: User) -> HexResult<()>"),
+      documentation: Some(String::from("Insert or update a user")),
+      parameters: Vec::new(),
+      return_type: None,
+      is_public: true,
+      is_async: false,
+    });
+    let ctx = super::AIContext {
+      architecture: String::from("hexagonal"),
+      version: String::from("0.4.0"),
+      components: vec![
+        component("PgUserRepository", "Adapter", "Adapter"),
+        repository,
+        component("User", "Domain", "Entity"),
+        component("Email", "Domain", "ValueObject"),
+      ],
+      relationships: vec![
+        relationship("User", "PgUserRepository", false),
+        relationship("PgUserRepository", "UserRepository", true),
+      ],
+      constraints: super::ConstraintSet {
+        dependency_rules: Vec::new(),
+        layer_boundaries: Vec::new(),
+        naming_conventions: Vec::new(),
+        required_patterns: Vec::new(),
+      },
+      suggestions: Vec::new(),
+      metadata: super::ContextMetadata {
+        generated_at: String::from("2026-10-19T04:55:00Z"),
+        hex_version: String::from("0.4.0"),
+        total_components: 4,
+        total_relationships: 2,
+        schema_version: String::from("1.0.0"),
+      },
+    };
+
+    assert_eq!(
+      ctx.to_markdown(),
+      include_str!("../../tests/fixtures/ai_context.md")
+    );
+  }
+}
@@ -5,6 +5,8 @@
 //! Primary entry point for AI agent integration.
 //!
 //! Revision History
+//! - 2026-10-18T21:45:00Z @AI: Link components to their declaration site when source locations are enabled.
+//! - 2026-10-18T09:00:00Z @AI: Populate ComponentInfo.returns from node metadata.
 //! - 2025-10-10T20:28:00Z @AI: Add methods field to ComponentInfo with empty placeholder for future method extraction.
 //! - 2025-10-02T19:00:00Z @AI: Fix test add_edge calls to use HexEdge constructor, fix Relationship typo, fix edges iteration.
 //! - 2025-10-02T18:30:00Z @AI: Add comprehensive documentation and tests for all functions.
@@ -95,11 +97,30 @@ impl<'a> ContextBuilder<'a> {
             .map(|edge| edge.target.to_string())
             .collect(),
           methods,
+          returns: node
+            .get_metadata(crate::registry::node_builder::RETURNS_METADATA_KEY)
+            .cloned(),
+          source: Self::source_of(node),
         }
       })
       .collect()
   }
 
+  /// Format a node's declaration site as `file:line`
+  ///
+  /// Follows the same policy as error locations: paths are only exposed when
+  /// `HEXSER_INCLUDE_SOURCE_LOCATION` is enabled.
+  fn source_of(node: &crate::graph::hex_node::HexNode) -> Option<String> {
+    if !crate::error::env_control::should_include_source_location() {
+      return None;
+    }
+    let file = node.get_metadata(crate::registry::node_builder::SOURCE_FILE_METADATA_KEY)?;
+    match node.get_metadata(crate::registry::node_builder::SOURCE_LINE_METADATA_KEY) {
+      Some(line) => Some(format!("{}:{}", file, line)),
+      None => Some(file.clone()),
+    }
+  }
+
   /// Extract relationship information from graph edges
   ///
   /// Maps each edge to RelationshipInfo with validation status.
@@ -305,6 +326,26 @@ mod tests {
     assert_eq!(components[0].type_name, "User");
     assert_eq!(components[0].layer, "Domain");
     assert_eq!(components[0].role, "Entity");
+    assert_eq!(components[0].returns, None);
+  }
+
+  #[test]
+  fn test_build_components_query_returns() {
+    let info = crate::registry::node_info::NodeInfo::new(
+      crate::graph::layer::Layer::Application,
+      crate::graph::role::Role::Query,
+      "ListOrders",
+      "application::orders",
+    )
+    .with_returns("Vec<Order>");
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .add_node(crate::registry::node_builder::build_node_from_info(info))
+      .build();
+
+    let builder = ContextBuilder::new(&graph);
+    let components = builder.build_components();
+
+    assert_eq!(components[0].returns, Some(String::from("Vec<Order>")));
   }
 
   #[test]
@@ -0,0 +1,295 @@
+//! AsyncDirectiveHandler trait for cancellable directive execution.
+//!
+//! Long-running directives (imports, migrations, worker jobs) must stop
+//! promptly on shutdown or when a deadline passes. Handlers receive a
+//! `CancellationToken` alongside the directive and report cancellation as
+//! `Hexserror::adapter("E_CANCELLED", ...)`, so callers can tell an aborted
+//! directive apart from a failed one. `with_timeout` wraps any handler with a
+//! deadline that fails with the same code. Available with the `async` feature.
+//!
+//! Revision History
+//! - 2026-10-19T11:25:00Z @AI: CANCELLED_ERROR_CODE re-exports codes::handler::CANCELLED.
+//! - 2026-10-19T02:35:00Z @AI: Initial AsyncDirectiveHandler with cancellation and timeout.
+
+/// Error code reported when a directive is cancelled or times out.
+pub use crate::error::codes::handler::CANCELLED as CANCELLED_ERROR_CODE;
+
+/// Builds the adapter error returned for a cancelled directive.
+pub fn cancelled_error(reason: &str) -> crate::error::hex_error::Hexserror {
+  crate::error::hex_error::Hexserror::adapter(
+    CANCELLED_ERROR_CODE,
+    &format!("Directive cancelled: {}", reason),
+  )
+  .with_next_step("Retry the directive once the worker is running again")
+}
+
+/// Cloneable signal shared between a worker and the handlers it runs.
+///
+/// All clones observe the same state; once cancelled a token stays
+/// cancelled.
+#[derive(Clone, Debug)]
+pub struct CancellationToken {
+  sender: std::sync::Arc<tokio::sync::watch::Sender<bool>>,
+}
+
+impl CancellationToken {
+  /// Creates a token that has not been cancelled.
+  pub fn new() -> Self {
+    let (sender, _) = tokio::sync::watch::channel(false);
+    Self {
+      sender: std::sync::Arc::new(sender),
+    }
+  }
+
+  /// Cancels the token, waking every task waiting in `cancelled`.
+  pub fn cancel(&self) {
+    self.sender.send_replace(true);
+  }
+
+  /// Returns true once `cancel` has been called on any clone.
+  pub fn is_cancelled(&self) -> bool {
+    *self.sender.borrow()
+  }
+
+  /// Completes when the token is cancelled.
+  pub async fn cancelled(&self) {
+    let mut receiver = self.sender.subscribe();
+    // The sender lives as long as self, so wait_for cannot fail here.
+    let _ = receiver.wait_for(|cancelled| *cancelled).await;
+  }
+
+  /// Checkpoint for handlers: fails with `E_CANCELLED` if cancelled.
+  pub fn check(&self) -> crate::result::hex_result::HexResult<()> {
+    if self.is_cancelled() {
+      return Result::Err(cancelled_error("cancellation requested"));
+    }
+    Result::Ok(())
+  }
+
+  /// Runs `future` until it completes or the token is cancelled.
+  ///
+  /// On cancellation the future is dropped mid-flight and `E_CANCELLED` is
+  /// returned.
+  pub async fn run<O>(
+    &self,
+    future: impl std::future::Future<Output = crate::result::hex_result::HexResult<O>>,
+  ) -> crate::result::hex_result::HexResult<O> {
+    tokio::select! {
+      biased;
+      _ = self.cancelled() => Result::Err(cancelled_error("cancellation requested")),
+      result = future => result,
+    }
+  }
+}
+
+impl Default for CancellationToken {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Async counterpart of `DirectiveHandler` that honours cancellation.
+///
+/// Implementations should call `token.check()` between steps or wrap
+/// awaited work in `token.run(...)`, returning the resulting `E_CANCELLED`
+/// error unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{AsyncDirectiveHandler, CancellationToken, Directive};
+/// use hexser::HexResult;
+///
+/// struct ImportRows {
+///     rows: u32,
+/// }
+///
+/// impl Directive for ImportRows {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// struct ImportHandler;
+///
+/// impl AsyncDirectiveHandler<ImportRows> for ImportHandler {
+///     async fn handle(&self, directive: ImportRows, token: &CancellationToken) -> HexResult<()> {
+///         for _ in 0..directive.rows {
+///             token.check()?;
+///             // import one row
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// let token = CancellationToken::new();
+/// token.cancel();
+/// let err = runtime
+///     .block_on(ImportHandler.handle(ImportRows { rows: 3 }, &token))
+///     .unwrap_err();
+/// assert_eq!(err.code(), "E_CANCELLED");
+/// ```
+pub trait AsyncDirectiveHandler<D>
+where
+  D: crate::application::directive::Directive,
+{
+  /// Handle the execution of a directive, stopping early if `token` is cancelled.
+  fn handle(
+    &self,
+    directive: D,
+    token: &CancellationToken,
+  ) -> impl std::future::Future<Output = crate::result::hex_result::HexResult<()>>;
+
+  /// Wraps this handler so each directive fails with `E_CANCELLED` after `timeout`.
+  fn with_timeout(self, timeout: std::time::Duration) -> TimeoutHandler<Self>
+  where
+    Self: Sized,
+  {
+    TimeoutHandler {
+      inner: self,
+      timeout,
+    }
+  }
+}
+
+/// Handler returned by `AsyncDirectiveHandler::with_timeout`.
+///
+/// The inner handler's future is dropped when the deadline passes, and the
+/// caller's token still cancels it before then.
+#[derive(Debug)]
+pub struct TimeoutHandler<H> {
+  inner: H,
+  timeout: std::time::Duration,
+}
+
+impl<H> TimeoutHandler<H> {
+  /// Returns the configured deadline.
+  pub fn timeout(&self) -> std::time::Duration {
+    self.timeout
+  }
+
+  /// Returns the wrapped handler.
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+}
+
+impl<D, H> AsyncDirectiveHandler<D> for TimeoutHandler<H>
+where
+  D: crate::application::directive::Directive,
+  H: AsyncDirectiveHandler<D>,
+{
+  async fn handle(
+    &self,
+    directive: D,
+    token: &CancellationToken,
+  ) -> crate::result::hex_result::HexResult<()> {
+    match tokio::time::timeout(self.timeout, self.inner.handle(directive, token)).await {
+      Result::Ok(result) => result,
+      Result::Err(_) => Result::Err(
+        cancelled_error(&format!("timed out after {}ms", self.timeout.as_millis()))
+          .with_context("timeout_ms", self.timeout.as_millis()),
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct SlowDirective {
+    steps: u32,
+  }
+
+  impl crate::application::directive::Directive for SlowDirective {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      Result::Ok(())
+    }
+  }
+
+  struct SlowHandler {
+    completed: std::sync::atomic::AtomicU32,
+  }
+
+  impl AsyncDirectiveHandler<SlowDirective> for SlowHandler {
+    async fn handle(
+      &self,
+      directive: SlowDirective,
+      token: &CancellationToken,
+    ) -> crate::result::hex_result::HexResult<()> {
+      for _ in 0..directive.steps {
+        token
+          .run(async {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            Result::Ok(())
+          })
+          .await?;
+        self
+          .completed
+          .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+      }
+      Result::Ok(())
+    }
+  }
+
+  fn slow_handler() -> SlowHandler {
+    SlowHandler {
+      completed: std::sync::atomic::AtomicU32::new(0),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_cancelling_mid_flight_returns_cancelled_error() {
+    let handler = slow_handler();
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+      tokio::time::sleep(std::time::Duration::from_millis(25)).await;
+      canceller.cancel();
+    });
+
+    let err = handler
+      .handle(SlowDirective { steps: 100 }, &token)
+      .await
+      .unwrap_err();
+
+    assert!(err.is_adapter());
+    assert_eq!(err.code(), CANCELLED_ERROR_CODE);
+    let completed = handler.completed.load(std::sync::atomic::Ordering::SeqCst);
+    assert!(completed < 100, "handler ran {} steps", completed);
+  }
+
+  #[tokio::test]
+  async fn test_with_timeout_cancels_slow_handler() {
+    let handler = slow_handler().with_timeout(std::time::Duration::from_millis(25));
+
+    let err = handler
+      .handle(SlowDirective { steps: 100 }, &CancellationToken::new())
+      .await
+      .unwrap_err();
+
+    assert_eq!(err.code(), CANCELLED_ERROR_CODE);
+    assert!(crate::error::codes::is_registered(err.code()));
+    assert!(err.to_string().contains("timed out after 25ms"));
+  }
+
+  #[tokio::test]
+  async fn test_with_timeout_passes_through_fast_handler() {
+    let handler = slow_handler().with_timeout(std::time::Duration::from_secs(5));
+
+    handler
+      .handle(SlowDirective { steps: 2 }, &CancellationToken::new())
+      .await
+      .unwrap();
+
+    assert_eq!(
+      handler
+        .into_inner()
+        .completed
+        .load(std::sync::atomic::Ordering::SeqCst),
+      2
+    );
+  }
+}
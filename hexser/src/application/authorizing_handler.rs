@@ -0,0 +1,181 @@
+//! Authorization hook for directive handlers.
+//!
+//! Sensitive directives should only run for callers allowed to issue them.
+//! A handler states its rule by implementing `Authorize`, and
+//! AuthorizingHandler runs that rule for the calling Principal before
+//! delegating, so the check cannot be forgotten at individual call sites.
+//! Denials are returned as `E_FORBIDDEN` errors.
+//!
+//! Revision History
+//! - 2026-10-19T05:55:00Z @AI: Initial Authorize trait and AuthorizingHandler wrapper.
+
+/// Authorization rule for directives of type `D`.
+///
+/// Return `Ok(())` to allow `principal` to issue `directive`, or an error,
+/// usually `Hexserror::forbidden`, to deny it.
+pub trait Authorize<D> {
+  /// Check whether `principal` may issue `directive`
+  fn authorize(
+    &self,
+    principal: &crate::application::principal::Principal,
+    directive: &D,
+  ) -> crate::result::hex_result::HexResult<()>;
+}
+
+/// Handler wrapper that authorizes each directive before delegating.
+///
+/// The wrapper carries the calling principal, so create one per request or
+/// session around a cheaply cloned or shared handler. Both `handle` and
+/// `preview` are authorized; a denied directive never reaches the inner
+/// handler.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{
+///     Authorize, AuthorizingHandler, Directive, DirectiveHandler, Principal,
+/// };
+/// use hexser::HexResult;
+///
+/// struct RefundOrder {
+///     amount: u32,
+/// }
+///
+/// impl Directive for RefundOrder {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// struct RefundHandler;
+///
+/// impl DirectiveHandler<RefundOrder> for RefundHandler {
+///     fn handle(&self, _directive: RefundOrder) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// impl Authorize<RefundOrder> for RefundHandler {
+///     fn authorize(&self, principal: &Principal, directive: &RefundOrder) -> HexResult<()> {
+///         if directive.amount > 100 {
+///             principal.require_role("supervisor")
+///         } else {
+///             principal.require_role("agent")
+///         }
+///     }
+/// }
+///
+/// let agent = AuthorizingHandler::new(RefundHandler, Principal::new("bo").with_role("agent"));
+/// assert!(agent.handle(RefundOrder { amount: 20 }).is_ok());
+/// assert!(agent.handle(RefundOrder { amount: 500 }).unwrap_err().is_forbidden());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuthorizingHandler<H> {
+  inner: H,
+  principal: crate::application::principal::Principal,
+}
+
+impl<H> AuthorizingHandler<H> {
+  /// Wrap `inner`, authorizing every directive for `principal`
+  pub fn new(inner: H, principal: crate::application::principal::Principal) -> Self {
+    Self { inner, principal }
+  }
+
+  /// Get the principal directives are authorized for
+  pub fn principal(&self) -> &crate::application::principal::Principal {
+    &self.principal
+  }
+
+  /// Get the wrapped handler
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+
+  /// Unwrap the handler
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+}
+
+impl<D, H> crate::application::directive_handler::DirectiveHandler<D> for AuthorizingHandler<H>
+where
+  D: crate::application::directive::Directive,
+  H: crate::application::directive_handler::DirectiveHandler<D> + Authorize<D>,
+{
+  fn handle(&self, directive: D) -> crate::result::hex_result::HexResult<()> {
+    self.inner.authorize(&self.principal, &directive)?;
+    self.inner.handle(directive)
+  }
+
+  fn preview(&self, directive: &D) -> crate::result::hex_result::HexResult<String> {
+    self.inner.authorize(&self.principal, directive)?;
+    self.inner.preview(directive)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::directive_handler::DirectiveHandler;
+
+  struct DeleteAccount;
+
+  impl crate::application::directive::Directive for DeleteAccount {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      Ok(())
+    }
+  }
+
+  #[derive(Default)]
+  struct DeleteAccountHandler {
+    deleted: std::sync::atomic::AtomicUsize,
+  }
+
+  impl DirectiveHandler<DeleteAccount> for DeleteAccountHandler {
+    fn handle(&self, _directive: DeleteAccount) -> crate::result::hex_result::HexResult<()> {
+      self
+        .deleted
+        .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+      Ok(())
+    }
+  }
+
+  impl Authorize<DeleteAccount> for DeleteAccountHandler {
+    fn authorize(
+      &self,
+      principal: &crate::application::principal::Principal,
+      _directive: &DeleteAccount,
+    ) -> crate::result::hex_result::HexResult<()> {
+      principal.require_role("admin")
+    }
+  }
+
+  fn deleted(handler: &AuthorizingHandler<DeleteAccountHandler>) -> usize {
+    handler
+      .inner()
+      .deleted
+      .load(std::sync::atomic::Ordering::SeqCst)
+  }
+
+  #[test]
+  fn test_permitted_principal_reaches_inner_handler() {
+    let admin = crate::application::principal::Principal::new("root").with_role("admin");
+    let handler = AuthorizingHandler::new(DeleteAccountHandler::default(), admin);
+
+    handler.handle(DeleteAccount).unwrap();
+    assert_eq!(deleted(&handler), 1);
+  }
+
+  #[test]
+  fn test_denied_principal_gets_forbidden_without_side_effects() {
+    let guest = crate::application::principal::Principal::new("guest");
+    let handler = AuthorizingHandler::new(DeleteAccountHandler::default(), guest);
+
+    let err = handler.handle(DeleteAccount).unwrap_err();
+    assert_eq!(err.code(), crate::error::codes::auth::FORBIDDEN);
+    assert!(err.is_forbidden());
+    assert_eq!(err.context()["required_role"], "admin");
+    assert!(handler.preview(&DeleteAccount).unwrap_err().is_forbidden());
+    assert_eq!(deleted(&handler), 0);
+  }
+}
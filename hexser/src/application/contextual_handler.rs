@@ -0,0 +1,59 @@
+//! Handler traits that receive the request context.
+//!
+//! Variants of DirectiveHandler and QueryHandler whose `handle` takes a
+//! `&RequestContext` first, for handlers that need the trace id, tenant, or
+//! locale. Run them through `RequestContext::execute` and `RequestContext::query`,
+//! which also make the context current and tag returned errors with it.
+//!
+//! Revision History
+//! - 2026-10-19T10:35:00Z @AI: Take RequestContext, renamed from HexContext.
+//! - 2026-10-19T08:05:00Z @AI: Initial ContextualDirectiveHandler and ContextualQueryHandler.
+
+/// Directive handler that receives the request context.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{ContextualDirectiveHandler, Directive, RequestContext};
+/// use hexser::HexResult;
+///
+/// struct Archive;
+///
+/// impl Directive for Archive {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// struct ArchiveHandler;
+///
+/// impl ContextualDirectiveHandler<Archive> for ArchiveHandler {
+///     fn handle(&self, ctx: &RequestContext, _directive: Archive) -> HexResult<()> {
+///         assert_eq!(ctx.tenant(), Some("acme"));
+///         Ok(())
+///     }
+/// }
+///
+/// RequestContext::new("trace-7").with_tenant("acme").execute(&ArchiveHandler, Archive).unwrap();
+/// ```
+pub trait ContextualDirectiveHandler<D>
+where
+  D: crate::application::directive::Directive,
+{
+  /// Handle the execution of a directive within `ctx`.
+  fn handle(
+    &self,
+    ctx: &crate::application::request_context::RequestContext,
+    directive: D,
+  ) -> crate::result::hex_result::HexResult<()>;
+}
+
+/// Query handler that receives the request context.
+pub trait ContextualQueryHandler<Q, R> {
+  /// Handle the execution of a query within `ctx`.
+  fn handle(
+    &self,
+    ctx: &crate::application::request_context::RequestContext,
+    query: Q,
+  ) -> crate::result::hex_result::HexResult<R>;
+}
@@ -6,6 +6,7 @@
 //! and enabling patterns like event sourcing and directive validation.
 //!
 //! Revision History
+//! - 2026-10-19T08:45:00Z @AI: Add into_event for handlers that publish on success.
 //! - 2025-10-01T00:01:00Z @AI: Renamed from Command to Directive to better reflect intent.
 //! - 2025-10-01T00:00:00Z @AI: Initial Command trait definition for CQRS write operations.
 
@@ -41,6 +42,17 @@ pub trait Directive {
   /// Returns `Ok(())` if the directive is valid, or an error describing
   /// validation failures.
   fn validate(&self) -> crate::result::hex_result::HexResult<()>;
+
+  /// The event announcing that this directive succeeded, if any.
+  ///
+  /// `EventEmittingHandler` calls this before handing the directive to its
+  /// inner handler and publishes the event only if handling returns `Ok`.
+  /// Defaults to `None`, which publishes nothing. It borrows `self` because
+  /// the directive itself is still needed by the handler afterwards.
+  #[allow(clippy::wrong_self_convention)]
+  fn into_event(&self) -> Option<Box<dyn crate::domain::domain_event::DomainEvent>> {
+    None
+  }
 }
 
 #[cfg(test)]
@@ -75,4 +87,9 @@ mod tests {
     let directive = TestDirective { value: -5 };
     assert!(directive.validate().is_err());
   }
+
+  #[test]
+  fn test_into_event_defaults_to_none() {
+    assert!(TestDirective { value: 1 }.into_event().is_none());
+  }
 }
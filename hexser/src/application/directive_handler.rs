@@ -6,9 +6,14 @@
 //! Handlers may produce events as side effects of directive execution.
 //!
 //! Revision History
+//! - 2026-10-19T04:05:00Z @AI: Add preview for dry runs.
+//! - 2026-10-18T21:30:00Z @AI: Add handle_batch with per-item results.
 //! - 2025-10-01T00:01:00Z @AI: Renamed from CommandHandler to DirectiveHandler.
 //! - 2025-10-01T00:00:00Z @AI: Initial CommandHandler trait definition for command execution.
 
+/// Preview text returned by handlers that do not override `preview`.
+pub const NO_PREVIEW: &str = "no preview available";
+
 /// Trait for handlers that execute directives.
 ///
 /// Directive handlers contain the logic to execute directives, coordinating
@@ -52,6 +57,34 @@ where
   /// Returns `Ok(())` if the directive was successfully executed, or an error
   /// describing what went wrong.
   fn handle(&self, directive: D) -> crate::result::hex_result::HexResult<()>;
+
+  /// Describe what `handle` would do with `directive`, without doing it.
+  ///
+  /// Implementations may read state but must not mutate it. Used by
+  /// `DryRunHandler` so operators can confirm destructive directives before
+  /// applying them. Defaults to `NO_PREVIEW`.
+  fn preview(&self, _directive: &D) -> crate::result::hex_result::HexResult<String> {
+    Ok(String::from(NO_PREVIEW))
+  }
+
+  /// Handle a batch of directives, reporting a result per item.
+  ///
+  /// Each result is paired with the directive's index in `directives`, so a
+  /// failing row does not abort the rest of a bulk import. The default runs
+  /// `handle` for every directive in order and is non-transactional: effects
+  /// of successful items remain even when others fail. Handlers needing
+  /// all-or-nothing semantics should override this and wrap the batch in a
+  /// transaction.
+  fn handle_batch(
+    &self,
+    directives: Vec<D>,
+  ) -> Vec<(usize, crate::result::hex_result::HexResult<()>)> {
+    directives
+      .into_iter()
+      .enumerate()
+      .map(|(index, directive)| (index, self.handle(directive)))
+      .collect()
+  }
 }
 
 #[cfg(test)]
@@ -76,10 +109,56 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_preview_defaults_to_no_preview() {
+    assert_eq!(
+      TestHandler.preview(&TestDirective { value: 5 }).unwrap(),
+      NO_PREVIEW
+    );
+  }
+
   #[test]
   fn test_directive_handler_execution() {
     let handler = TestHandler;
     let directive = TestDirective { value: 5 };
     assert!(handler.handle(directive).is_ok());
   }
+
+  struct RejectNegativeHandler {
+    handled: std::cell::RefCell<Vec<i32>>,
+  }
+
+  impl DirectiveHandler<TestDirective> for RejectNegativeHandler {
+    fn handle(&self, directive: TestDirective) -> crate::result::hex_result::HexResult<()> {
+      if directive.value < 0 {
+        return Result::Err(crate::error::hex_error::Hexserror::validation(
+          "Value must not be negative",
+        ));
+      }
+      self.handled.borrow_mut().push(directive.value);
+      Result::Ok(())
+    }
+  }
+
+  #[test]
+  fn test_handle_batch_continues_after_failure() {
+    let handler = RejectNegativeHandler {
+      handled: std::cell::RefCell::new(Vec::new()),
+    };
+    let results = handler.handle_batch(vec![
+      TestDirective { value: 1 },
+      TestDirective { value: -1 },
+      TestDirective { value: 3 },
+    ]);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+      results
+        .iter()
+        .map(|(i, r)| (*i, r.is_ok()))
+        .collect::<Vec<_>>(),
+      vec![(0, true), (1, false), (2, true)]
+    );
+    assert_eq!(*handler.handled.borrow(), vec![1, 3]);
+  }
 }
@@ -0,0 +1,210 @@
+//! Dry-run wrapper for directive handlers.
+//!
+//! DryRunHandler validates each directive and asks the inner handler for a
+//! preview of its effect instead of executing it. Previews are collected so
+//! an operator can review them and then replay the directives through the
+//! inner handler once confirmed.
+//!
+//! Revision History
+//! - 2026-10-19T04:05:00Z @AI: Initial DryRunHandler wrapper.
+
+/// Handler wrapper that previews directives instead of executing them.
+///
+/// `handle` validates the directive, calls the inner handler's `preview`,
+/// records the text, and returns without side effects. Handlers that do not
+/// override `preview` report `NO_PREVIEW`.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{Directive, DirectiveHandler, DryRunHandler};
+/// use hexser::HexResult;
+///
+/// struct DeleteInactiveUsers {
+///     days: u32,
+/// }
+///
+/// impl Directive for DeleteInactiveUsers {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// struct DeleteInactiveUsersHandler {
+///     last_seen_days: std::sync::Mutex<Vec<(String, u32)>>,
+/// }
+///
+/// impl DeleteInactiveUsersHandler {
+///     fn matching(&self, days: u32) -> Vec<String> {
+///         let users = self.last_seen_days.lock().unwrap();
+///         users.iter().filter(|(_, d)| *d > days).map(|(n, _)| n.clone()).collect()
+///     }
+/// }
+///
+/// impl DirectiveHandler<DeleteInactiveUsers> for DeleteInactiveUsersHandler {
+///     fn handle(&self, directive: DeleteInactiveUsers) -> HexResult<()> {
+///         let doomed = self.matching(directive.days);
+///         self.last_seen_days.lock().unwrap().retain(|(n, _)| !doomed.contains(n));
+///         Ok(())
+///     }
+///
+///     fn preview(&self, directive: &DeleteInactiveUsers) -> HexResult<String> {
+///         let doomed = self.matching(directive.days);
+///         Ok(format!("would delete {} users: {}", doomed.len(), doomed.join(", ")))
+///     }
+/// }
+///
+/// let handler = DryRunHandler::new(DeleteInactiveUsersHandler {
+///     last_seen_days: std::sync::Mutex::new(vec![
+///         (String::from("ada"), 400),
+///         (String::from("bob"), 3),
+///     ]),
+/// });
+///
+/// handler.handle(DeleteInactiveUsers { days: 365 }).unwrap();
+/// assert_eq!(handler.previews(), vec!["would delete 1 users: ada"]);
+/// assert_eq!(handler.inner().last_seen_days.lock().unwrap().len(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct DryRunHandler<H> {
+  inner: H,
+  previews: std::sync::Mutex<Vec<String>>,
+}
+
+impl<H> DryRunHandler<H> {
+  /// Wrap `inner` so directives are previewed rather than executed
+  pub fn new(inner: H) -> Self {
+    Self {
+      inner,
+      previews: std::sync::Mutex::new(Vec::new()),
+    }
+  }
+
+  /// Previews recorded so far, in handling order
+  pub fn previews(&self) -> Vec<String> {
+    self.lock().clone()
+  }
+
+  /// Remove and return the recorded previews
+  pub fn take_previews(&self) -> Vec<String> {
+    std::mem::take(&mut *self.lock())
+  }
+
+  /// Get the wrapped handler, e.g. to execute a confirmed directive
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+
+  /// Unwrap the handler
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+    self
+      .previews
+      .lock()
+      .unwrap_or_else(std::sync::PoisonError::into_inner)
+  }
+}
+
+impl<D, H> crate::application::directive_handler::DirectiveHandler<D> for DryRunHandler<H>
+where
+  D: crate::application::directive::Directive,
+  H: crate::application::directive_handler::DirectiveHandler<D>,
+{
+  fn handle(&self, directive: D) -> crate::result::hex_result::HexResult<()> {
+    let preview = self.preview(&directive)?;
+    self.lock().push(preview);
+    Ok(())
+  }
+
+  fn preview(&self, directive: &D) -> crate::result::hex_result::HexResult<String> {
+    directive.validate()?;
+    self.inner.preview(directive)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::directive_handler::DirectiveHandler;
+
+  struct Purge {
+    count: u32,
+  }
+
+  impl crate::application::directive::Directive for Purge {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      if self.count == 0 {
+        return Err(crate::error::hex_error::Hexserror::validation(
+          "count must be positive",
+        ));
+      }
+      Ok(())
+    }
+  }
+
+  #[derive(Default)]
+  struct PurgeHandler {
+    executed: std::sync::atomic::AtomicU32,
+  }
+
+  impl DirectiveHandler<Purge> for PurgeHandler {
+    fn handle(&self, directive: Purge) -> crate::result::hex_result::HexResult<()> {
+      self
+        .executed
+        .fetch_add(directive.count, std::sync::atomic::Ordering::SeqCst);
+      Ok(())
+    }
+
+    fn preview(&self, directive: &Purge) -> crate::result::hex_result::HexResult<String> {
+      Ok(format!("would purge {} rows", directive.count))
+    }
+  }
+
+  struct SilentHandler;
+
+  impl DirectiveHandler<Purge> for SilentHandler {
+    fn handle(&self, _directive: Purge) -> crate::result::hex_result::HexResult<()> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_records_preview_without_executing() {
+    let handler = DryRunHandler::new(PurgeHandler::default());
+    handler.handle(Purge { count: 3 }).unwrap();
+    handler.handle(Purge { count: 4 }).unwrap();
+
+    assert_eq!(
+      handler.take_previews(),
+      vec!["would purge 3 rows", "would purge 4 rows"]
+    );
+    assert!(handler.previews().is_empty());
+    assert_eq!(
+      handler
+        .inner()
+        .executed
+        .load(std::sync::atomic::Ordering::SeqCst),
+      0
+    );
+  }
+
+  #[test]
+  fn test_invalid_directive_is_rejected() {
+    let handler = DryRunHandler::new(PurgeHandler::default());
+    assert!(handler.handle(Purge { count: 0 }).is_err());
+    assert!(handler.previews().is_empty());
+  }
+
+  #[test]
+  fn test_handler_without_preview_reports_default() {
+    let handler = DryRunHandler::new(SilentHandler);
+    handler.handle(Purge { count: 1 }).unwrap();
+    assert_eq!(
+      handler.previews(),
+      vec![crate::application::directive_handler::NO_PREVIEW]
+    );
+  }
+}
@@ -0,0 +1,199 @@
+//! Type-based routing of domain events to handlers.
+//!
+//! Replaying a mixed event stream, for example to rebuild a read model,
+//! needs each event sent to the code that understands its type.
+//! EventDispatcher maps `DomainEvent::event_type` strings to handler
+//! closures and routes `&dyn DomainEvent` trait objects to them, so
+//! projectors can consume heterogeneous streams without a hand-written
+//! match on every type.
+//!
+//! Revision History
+//! - 2026-10-19T06:35:00Z @AI: Initial EventDispatcher.
+
+type EventHandler = std::boxed::Box<
+  dyn Fn(&dyn crate::domain::domain_event::DomainEvent) -> crate::result::hex_result::HexResult<()>
+    + Send
+    + Sync,
+>;
+
+/// Routes events to the handler registered for their type.
+///
+/// Each event type has at most one handler; registering a type again
+/// replaces its handler. Dispatching an event with no registered handler
+/// returns a not-found error rather than silently dropping it, so a
+/// projector cannot skip events it was never taught about.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::EventDispatcher;
+/// use hexser::domain::DomainEvent;
+///
+/// struct OrderPlaced {
+///     order_id: String,
+/// }
+///
+/// impl DomainEvent for OrderPlaced {
+///     fn event_type(&self) -> &str {
+///         "OrderPlaced"
+///     }
+///
+///     fn aggregate_id(&self) -> String {
+///         self.order_id.clone()
+///     }
+/// }
+///
+/// let dispatcher = EventDispatcher::new().on("OrderPlaced", |event| {
+///     println!("projecting order {}", event.aggregate_id());
+///     Ok(())
+/// });
+///
+/// let event = OrderPlaced { order_id: String::from("o-1") };
+/// assert!(dispatcher.dispatch(&event).is_ok());
+/// ```
+#[derive(Default)]
+pub struct EventDispatcher {
+  handlers: std::collections::HashMap<String, EventHandler>,
+}
+
+impl EventDispatcher {
+  /// Create a dispatcher with no handlers
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Register `handler` for events of `event_type` (builder pattern)
+  pub fn on<F>(mut self, event_type: &str, handler: F) -> Self
+  where
+    F: Fn(&dyn crate::domain::domain_event::DomainEvent) -> crate::result::hex_result::HexResult<()>
+      + Send
+      + Sync
+      + 'static,
+  {
+    self
+      .handlers
+      .insert(String::from(event_type), std::boxed::Box::new(handler));
+    self
+  }
+
+  /// Check whether a handler is registered for `event_type`
+  pub fn handles(&self, event_type: &str) -> bool {
+    self.handlers.contains_key(event_type)
+  }
+
+  /// Registered event types, in sorted order
+  pub fn event_types(&self) -> Vec<&str> {
+    let mut types: Vec<&str> = self.handlers.keys().map(String::as_str).collect();
+    types.sort_unstable();
+    types
+  }
+
+  /// Invoke the handler registered for `event`'s type
+  ///
+  /// # Errors
+  ///
+  /// Returns a not-found error (`E_HEX_400`) when no handler is registered
+  /// for the event type, and otherwise whatever the handler returns.
+  pub fn dispatch(
+    &self,
+    event: &dyn crate::domain::domain_event::DomainEvent,
+  ) -> crate::result::hex_result::HexResult<()> {
+    match self.handlers.get(event.event_type()) {
+      Some(handler) => handler(event),
+      None => Err(
+        crate::error::hex_error::Hexserror::not_found("EventHandler", event.event_type())
+          .with_next_step("Register a handler for this event type with EventDispatcher::on"),
+      ),
+    }
+  }
+}
+
+impl std::fmt::Debug for EventDispatcher {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("EventDispatcher")
+      .field("event_types", &self.event_types())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Event {
+    kind: &'static str,
+    id: &'static str,
+  }
+
+  impl crate::domain::domain_event::DomainEvent for Event {
+    fn event_type(&self) -> &str {
+      self.kind
+    }
+
+    fn aggregate_id(&self) -> String {
+      String::from(self.id)
+    }
+  }
+
+  #[test]
+  fn test_dispatch_routes_two_event_types() {
+    let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let (placed, shipped) = (std::sync::Arc::clone(&log), std::sync::Arc::clone(&log));
+    let dispatcher = EventDispatcher::new()
+      .on("OrderPlaced", move |event| {
+        placed
+          .lock()
+          .unwrap()
+          .push(format!("placed {}", event.aggregate_id()));
+        Ok(())
+      })
+      .on("OrderShipped", move |event| {
+        shipped
+          .lock()
+          .unwrap()
+          .push(format!("shipped {}", event.aggregate_id()));
+        Ok(())
+      });
+
+    let stream = [
+      Event {
+        kind: "OrderPlaced",
+        id: "o-1",
+      },
+      Event {
+        kind: "OrderShipped",
+        id: "o-1",
+      },
+      Event {
+        kind: "OrderPlaced",
+        id: "o-2",
+      },
+    ];
+    for event in &stream {
+      dispatcher.dispatch(event).unwrap();
+    }
+
+    assert_eq!(
+      *log.lock().unwrap(),
+      vec!["placed o-1", "shipped o-1", "placed o-2"]
+    );
+    assert_eq!(
+      dispatcher.event_types(),
+      vec!["OrderPlaced", "OrderShipped"]
+    );
+  }
+
+  #[test]
+  fn test_unregistered_type_is_an_error() {
+    let dispatcher = EventDispatcher::new().on("OrderPlaced", |_| Ok(()));
+    let err = dispatcher
+      .dispatch(&Event {
+        kind: "OrderCancelled",
+        id: "o-1",
+      })
+      .unwrap_err();
+
+    assert_eq!(err.code(), crate::error::codes::resource::NOT_FOUND);
+    assert!(!dispatcher.handles("OrderCancelled"));
+  }
+}
@@ -0,0 +1,270 @@
+//! Handler wrapper that publishes a directive's event on success.
+//!
+//! Most successful directives are announced by a matching domain event,
+//! `RegisterUser` by `UserRegistered` and so on. Rather than every handler
+//! building an envelope and calling a publisher, directives describe their
+//! event through `Directive::into_event` and EventEmittingHandler publishes
+//! it once the inner handler succeeds.
+//!
+//! Revision History
+//! - 2026-10-19T08:45:00Z @AI: Initial EventEmittingHandler.
+
+/// Handler wrapper publishing `Directive::into_event` after a successful `handle`.
+///
+/// The event is taken from the directive before it is handled, then wrapped
+/// in a CloudEvents envelope with `source` and an id from the wrapper's id
+/// generator (`evt-1`, `evt-2`, ... unless replaced). Nothing is published
+/// when the directive has no event or the inner handler fails.
+///
+/// Publishing happens after the inner handler's effects are committed, so a
+/// publish failure is returned even though the directive took effect. Use a
+/// transactional outbox behind the publisher when the two must not diverge.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{Directive, DirectiveHandler, EventEmittingHandler};
+/// use hexser::domain::DomainEvent;
+/// use hexser::ports::events::{CloudEventsEnvelope, EventPublisher};
+/// use hexser::HexResult;
+///
+/// struct UserRegistered {
+///     user_id: String,
+/// }
+///
+/// impl DomainEvent for UserRegistered {
+///     fn event_type(&self) -> &str {
+///         "UserRegistered"
+///     }
+///
+///     fn aggregate_id(&self) -> String {
+///         self.user_id.clone()
+///     }
+/// }
+///
+/// struct RegisterUser {
+///     user_id: String,
+/// }
+///
+/// impl Directive for RegisterUser {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+///
+///     fn into_event(&self) -> Option<Box<dyn DomainEvent>> {
+///         Some(Box::new(UserRegistered { user_id: self.user_id.clone() }))
+///     }
+/// }
+///
+/// struct RegisterUserHandler;
+///
+/// impl DirectiveHandler<RegisterUser> for RegisterUserHandler {
+///     fn handle(&self, _directive: RegisterUser) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// type Envelope = CloudEventsEnvelope<Box<dyn DomainEvent>>;
+///
+/// #[derive(Default)]
+/// struct LogPublisher {
+///     subjects: std::sync::Mutex<Vec<String>>,
+/// }
+///
+/// impl EventPublisher<Box<dyn DomainEvent>> for LogPublisher {
+///     fn publish(&self, envelope: &Envelope) -> HexResult<()> {
+///         self.subjects.lock().unwrap().push(envelope.subject.clone().unwrap_or_default());
+///         Ok(())
+///     }
+///
+///     fn publish_batch(&self, envelopes: &[Envelope]) -> HexResult<()> {
+///         envelopes.iter().try_for_each(|envelope| self.publish(envelope))
+///     }
+/// }
+///
+/// let handler = EventEmittingHandler::new(RegisterUserHandler, LogPublisher::default(), "/users");
+///
+/// handler.handle(RegisterUser { user_id: String::from("u-1") }).unwrap();
+/// assert_eq!(*handler.publisher().subjects.lock().unwrap(), vec!["u-1"]);
+/// ```
+pub struct EventEmittingHandler<H, P> {
+  inner: H,
+  publisher: P,
+  source: String,
+  ids: std::sync::Arc<dyn crate::ports::id_generator::IdGenerator<String>>,
+}
+
+impl<H, P> EventEmittingHandler<H, P> {
+  /// Wrap `inner`, publishing events from `source` through `publisher`
+  pub fn new(inner: H, publisher: P, source: impl Into<String>) -> Self {
+    Self {
+      inner,
+      publisher,
+      source: source.into(),
+      ids: std::sync::Arc::new(
+        crate::adapters::id_generator::SequentialGenerator::with_prefix("evt-"),
+      ),
+    }
+  }
+
+  /// Take envelope ids from `ids` (builder pattern)
+  pub fn with_id_generator(
+    mut self,
+    ids: std::sync::Arc<dyn crate::ports::id_generator::IdGenerator<String>>,
+  ) -> Self {
+    self.ids = ids;
+    self
+  }
+
+  /// Get the wrapped handler
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+
+  /// Get the publisher events are sent to
+  pub fn publisher(&self) -> &P {
+    &self.publisher
+  }
+}
+
+impl<D, H, P> crate::application::directive_handler::DirectiveHandler<D>
+  for EventEmittingHandler<H, P>
+where
+  D: crate::application::directive::Directive,
+  H: crate::application::directive_handler::DirectiveHandler<D>,
+  P: crate::ports::events::event_publisher::EventPublisher<
+      Box<dyn crate::domain::domain_event::DomainEvent>,
+    >,
+{
+  fn handle(&self, directive: D) -> crate::result::hex_result::HexResult<()> {
+    let event = directive.into_event();
+    self.inner.handle(directive)?;
+    let Some(event) = event else {
+      return Ok(());
+    };
+    let envelope =
+      crate::ports::events::cloud_events_envelope::CloudEventsEnvelope::from_domain_event(
+        self.ids.next_id()?,
+        self.source.clone(),
+        event,
+      );
+    self.publisher.publish(&envelope).map_err(|e| {
+      e.with_context("event_type", &envelope.r#type)
+        .with_next_step("The directive was applied; republish the event or use an outbox")
+    })
+  }
+
+  fn preview(&self, directive: &D) -> crate::result::hex_result::HexResult<String> {
+    self.inner.preview(directive)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::directive_handler::DirectiveHandler;
+
+  struct Renamed {
+    id: u64,
+  }
+
+  impl crate::domain::domain_event::DomainEvent for Renamed {
+    fn event_type(&self) -> &str {
+      "Renamed"
+    }
+
+    fn aggregate_id(&self) -> String {
+      self.id.to_string()
+    }
+  }
+
+  struct Rename {
+    id: u64,
+    name: String,
+  }
+
+  impl crate::application::directive::Directive for Rename {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      Ok(())
+    }
+
+    fn into_event(&self) -> Option<Box<dyn crate::domain::domain_event::DomainEvent>> {
+      Some(Box::new(Renamed { id: self.id }))
+    }
+  }
+
+  struct RenameHandler;
+
+  impl DirectiveHandler<Rename> for RenameHandler {
+    fn handle(&self, directive: Rename) -> crate::result::hex_result::HexResult<()> {
+      if directive.name.is_empty() {
+        return Err(crate::error::hex_error::Hexserror::validation(
+          "Name must not be empty",
+        ));
+      }
+      Ok(())
+    }
+  }
+
+  #[derive(Default)]
+  struct RecordingPublisher {
+    published: std::sync::Mutex<Vec<(String, String)>>,
+  }
+
+  impl
+    crate::ports::events::event_publisher::EventPublisher<
+      Box<dyn crate::domain::domain_event::DomainEvent>,
+    > for RecordingPublisher
+  {
+    fn publish(
+      &self,
+      envelope: &crate::ports::events::cloud_events_envelope::CloudEventsEnvelope<
+        Box<dyn crate::domain::domain_event::DomainEvent>,
+      >,
+    ) -> crate::HexResult<()> {
+      self
+        .published
+        .lock()
+        .unwrap()
+        .push((envelope.id.clone(), envelope.r#type.clone()));
+      Ok(())
+    }
+
+    fn publish_batch(
+      &self,
+      envelopes: &[crate::ports::events::cloud_events_envelope::CloudEventsEnvelope<
+        Box<dyn crate::domain::domain_event::DomainEvent>,
+      >],
+    ) -> crate::HexResult<()> {
+      envelopes
+        .iter()
+        .try_for_each(|envelope| self.publish(envelope))
+    }
+  }
+
+  #[test]
+  fn test_publishes_event_only_on_success() {
+    let handler =
+      EventEmittingHandler::new(RenameHandler, RecordingPublisher::default(), "/accounts");
+
+    handler
+      .handle(Rename {
+        id: 7,
+        name: String::from("Ada"),
+      })
+      .unwrap();
+    assert!(
+      handler
+        .handle(Rename {
+          id: 8,
+          name: String::new(),
+        })
+        .is_err()
+    );
+
+    assert_eq!(
+      *handler.publisher().published.lock().unwrap(),
+      vec![(String::from("evt-1"), String::from("Renamed"))]
+    );
+  }
+}
@@ -6,13 +6,49 @@
 //! handlers, supporting the CQRS (Command Query Responsibility Segregation) pattern.
 //!
 //! Revision History
+//! - 2026-10-19T10:35:00Z @AI: Rename hex_context to request_context.
+//! - 2026-10-19T08:45:00Z @AI: Add EventEmittingHandler publishing Directive::into_event.
+//! - 2026-10-19T08:05:00Z @AI: Add HexContext and contextual handler traits.
+//! - 2026-10-19T07:25:00Z @AI: Add RecordingHandler and replay behind serde features.
+//! - 2026-10-19T06:35:00Z @AI: Add EventDispatcher for type-based event routing.
+//! - 2026-10-19T05:55:00Z @AI: Add Principal and AuthorizingHandler authorization hook.
+//! - 2026-10-19T04:05:00Z @AI: Add DryRunHandler preview wrapper.
+//! - 2026-10-19T02:35:00Z @AI: Add AsyncDirectiveHandler behind the async feature.
+//! - 2026-10-18T22:15:00Z @AI: Add SafeHandler panic-catching wrapper.
+//! - 2026-10-18T10:30:00Z @AI: Add Validator builder for composing directive validation.
 //! - 2025-10-01T00:01:00Z @AI: Renamed Command to Directive for better intent representation.
 //! - 2025-10-01T00:00:00Z @AI: Initial Phase 1 application module structure.
 
+#[cfg(feature = "async")]
+pub mod async_directive_handler;
+pub mod authorizing_handler;
+pub mod contextual_handler;
 pub mod directive;
 pub mod directive_handler;
+pub mod dry_run_handler;
+pub mod event_dispatcher;
+pub mod event_emitting_handler;
+pub mod principal;
 pub mod query_handler;
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+pub mod recording_handler;
+pub mod request_context;
+pub mod safe_handler;
+pub mod validator;
 
+#[cfg(feature = "async")]
+pub use async_directive_handler::{AsyncDirectiveHandler, CancellationToken, TimeoutHandler};
+pub use authorizing_handler::{Authorize, AuthorizingHandler};
+pub use contextual_handler::{ContextualDirectiveHandler, ContextualQueryHandler};
 pub use directive::Directive;
 pub use directive_handler::DirectiveHandler;
+pub use dry_run_handler::DryRunHandler;
+pub use event_dispatcher::EventDispatcher;
+pub use event_emitting_handler::EventEmittingHandler;
+pub use principal::Principal;
 pub use query_handler::QueryHandler;
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+pub use recording_handler::{RecordingHandler, replay};
+pub use request_context::RequestContext;
+pub use safe_handler::SafeHandler;
+pub use validator::Validator;
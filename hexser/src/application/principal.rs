@@ -0,0 +1,72 @@
+//! Caller identity for authorization checks.
+//!
+//! A Principal names who is issuing a directive and which roles they hold.
+//! It is deliberately minimal, a role-based identity that `Authorize`
+//! implementations inspect; applications with richer permission models can
+//! encode permissions as roles or wrap their own identity type.
+//!
+//! Revision History
+//! - 2026-10-19T05:55:00Z @AI: Initial role-based Principal.
+
+/// Role-based caller identity.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::Principal;
+///
+/// let alice = Principal::new("alice").with_role("admin");
+/// assert!(alice.has_role("admin"));
+/// assert!(alice.require_role("auditor").unwrap_err().is_forbidden());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+  id: String,
+  roles: std::collections::BTreeSet<String>,
+}
+
+impl Principal {
+  /// Create a principal with no roles
+  pub fn new(id: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      roles: std::collections::BTreeSet::new(),
+    }
+  }
+
+  /// Grant a role (builder pattern)
+  pub fn with_role(mut self, role: impl Into<String>) -> Self {
+    self.roles.insert(role.into());
+    self
+  }
+
+  /// Identifier of the caller, such as a user id or service name
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Roles held, in sorted order
+  pub fn roles(&self) -> impl Iterator<Item = &str> {
+    self.roles.iter().map(String::as_str)
+  }
+
+  /// Check whether the principal holds `role`
+  pub fn has_role(&self, role: &str) -> bool {
+    self.roles.contains(role)
+  }
+
+  /// Succeed if the principal holds `role`, otherwise return `E_FORBIDDEN`
+  pub fn require_role(&self, role: &str) -> crate::result::hex_result::HexResult<()> {
+    if self.has_role(role) {
+      return Ok(());
+    }
+    Err(
+      crate::error::hex_error::Hexserror::forbidden(&format!(
+        "Principal '{}' lacks role '{}'",
+        self.id, role
+      ))
+      .with_context("principal", &self.id)
+      .with_context("required_role", role),
+    )
+  }
+}
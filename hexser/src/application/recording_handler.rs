@@ -0,0 +1,246 @@
+//! Directive recording and replay for reproducing bugs.
+//!
+//! RecordingHandler serializes every incoming directive to a DirectiveLog
+//! before delegating, and `replay` feeds a recorded log through a handler
+//! in the original order. Recording a production session and replaying it
+//! against fresh state reproduces the sequence that led to a bug.
+//! Directives are stored as JSON, so this module requires the `serde` and
+//! `serde_json` features and directives implementing `Serialize` and
+//! `DeserializeOwned`.
+//!
+//! Revision History
+//! - 2026-10-19T07:25:00Z @AI: Initial RecordingHandler and replay.
+
+/// Handler wrapper that logs each directive before executing it.
+///
+/// Directives are recorded as they arrive, including ones the inner
+/// handler then rejects, because a failing directive is usually the one
+/// being reproduced. If serialization or the log write fails, the
+/// directive is not executed. `preview` is delegated without recording.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::adapters::InMemoryDirectiveLog;
+/// use hexser::application::{replay, Directive, DirectiveHandler, RecordingHandler};
+/// use hexser::HexResult;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Deposit {
+///     amount: u64,
+/// }
+///
+/// impl Directive for Deposit {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Account {
+///     balance: std::sync::atomic::AtomicU64,
+/// }
+///
+/// impl DirectiveHandler<Deposit> for Account {
+///     fn handle(&self, directive: Deposit) -> HexResult<()> {
+///         self.balance.fetch_add(directive.amount, std::sync::atomic::Ordering::SeqCst);
+///         Ok(())
+///     }
+/// }
+///
+/// let recorder = RecordingHandler::new(Account::default(), InMemoryDirectiveLog::new());
+/// recorder.handle(Deposit { amount: 5 }).unwrap();
+/// recorder.handle(Deposit { amount: 7 }).unwrap();
+///
+/// let fresh = Account::default();
+/// replay::<Deposit, _, _>(recorder.log(), &fresh).unwrap();
+/// assert_eq!(fresh.balance.into_inner(), 12);
+/// ```
+#[derive(Debug)]
+pub struct RecordingHandler<H, L> {
+  inner: H,
+  log: L,
+}
+
+impl<H, L> RecordingHandler<H, L> {
+  /// Wrap `inner`, recording its directives to `log`
+  pub fn new(inner: H, log: L) -> Self {
+    Self { inner, log }
+  }
+
+  /// Get the wrapped handler
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+
+  /// Get the log directives are recorded to
+  pub fn log(&self) -> &L {
+    &self.log
+  }
+
+  /// Unwrap the handler and the log
+  pub fn into_parts(self) -> (H, L) {
+    (self.inner, self.log)
+  }
+}
+
+impl<D, H, L> crate::application::directive_handler::DirectiveHandler<D> for RecordingHandler<H, L>
+where
+  D: crate::application::directive::Directive + serde::Serialize,
+  H: crate::application::directive_handler::DirectiveHandler<D>,
+  L: crate::ports::directive_log::DirectiveLog,
+{
+  fn handle(&self, directive: D) -> crate::result::hex_result::HexResult<()> {
+    let record = serde_json::to_string(&directive).map_err(|e| {
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::MAPPING_FAILURE,
+        &format!("Failed to serialize directive for the replay log: {}", e),
+      )
+    })?;
+    self.log.append(record)?;
+    self.inner.handle(directive)
+  }
+
+  fn preview(&self, directive: &D) -> crate::result::hex_result::HexResult<String> {
+    self.inner.preview(directive)
+  }
+}
+
+/// Re-apply every directive recorded in `log` to `handler`, in order.
+///
+/// All records are deserialized before any is handled, so a corrupt log
+/// fails without partially applying it. Like `handle_batch`, each outcome
+/// is paired with the record's index and a failing directive does not stop
+/// the ones after it.
+///
+/// # Errors
+///
+/// Returns the log's error if it cannot be read, or `E_HEX_202` naming the
+/// record index if a record does not deserialize as `D`.
+pub fn replay<D, L, H>(
+  log: &L,
+  handler: &H,
+) -> crate::result::hex_result::HexResult<Vec<(usize, crate::result::hex_result::HexResult<()>)>>
+where
+  D: crate::application::directive::Directive + serde::de::DeserializeOwned,
+  L: crate::ports::directive_log::DirectiveLog + ?Sized,
+  H: crate::application::directive_handler::DirectiveHandler<D> + ?Sized,
+{
+  let directives = log
+    .records()?
+    .iter()
+    .enumerate()
+    .map(|(index, record)| {
+      serde_json::from_str::<D>(record).map_err(|e| {
+        crate::error::hex_error::Hexserror::adapter(
+          crate::error::codes::adapter::MAPPING_FAILURE,
+          &format!("Failed to deserialize replay log record: {}", e),
+        )
+        .with_context("record", index)
+      })
+    })
+    .collect::<crate::result::hex_result::HexResult<Vec<D>>>()?;
+
+  Ok(
+    directives
+      .into_iter()
+      .enumerate()
+      .map(|(index, directive)| (index, handler.handle(directive)))
+      .collect(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    application::directive_handler::DirectiveHandler, ports::directive_log::DirectiveLog,
+  };
+
+  #[derive(serde::Serialize, serde::Deserialize)]
+  enum CartDirective {
+    Add { sku: String, quantity: u32 },
+    Remove { sku: String },
+  }
+
+  impl crate::application::directive::Directive for CartDirective {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      Ok(())
+    }
+  }
+
+  #[derive(Default)]
+  struct Cart {
+    lines: std::sync::Mutex<std::collections::BTreeMap<String, u32>>,
+  }
+
+  impl DirectiveHandler<CartDirective> for Cart {
+    fn handle(&self, directive: CartDirective) -> crate::result::hex_result::HexResult<()> {
+      let mut lines = self.lines.lock().unwrap();
+      match directive {
+        CartDirective::Add { sku, quantity } => *lines.entry(sku).or_default() += quantity,
+        CartDirective::Remove { sku } => {
+          lines
+            .remove(&sku)
+            .ok_or_else(|| crate::error::hex_error::Hexserror::not_found("CartLine", &sku))?;
+        }
+      }
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_replay_reaches_identical_state() {
+    let recorder = RecordingHandler::new(
+      Cart::default(),
+      crate::adapters::in_memory_directive_log::InMemoryDirectiveLog::new(),
+    );
+    let add = |sku: &str, quantity| CartDirective::Add {
+      sku: String::from(sku),
+      quantity,
+    };
+    recorder.handle(add("apple", 2)).unwrap();
+    recorder.handle(add("pear", 1)).unwrap();
+    recorder.handle(add("apple", 3)).unwrap();
+    assert!(
+      recorder
+        .handle(CartDirective::Remove {
+          sku: String::from("kiwi")
+        })
+        .is_err()
+    );
+    recorder
+      .handle(CartDirective::Remove {
+        sku: String::from("pear"),
+      })
+      .unwrap();
+    assert_eq!(recorder.log().len(), 5);
+
+    let fresh = Cart::default();
+    let outcomes = replay(recorder.log(), &fresh).unwrap();
+
+    assert_eq!(outcomes.len(), 5);
+    assert!(outcomes[3].1.is_err());
+    assert_eq!(
+      *fresh.lines.lock().unwrap(),
+      *recorder.inner().lines.lock().unwrap()
+    );
+  }
+
+  #[test]
+  fn test_corrupt_record_fails_before_applying_anything() {
+    let log = crate::adapters::in_memory_directive_log::InMemoryDirectiveLog::new();
+    log
+      .append(String::from(r#"{"Remove":{"sku":"a"}}"#))
+      .unwrap();
+    log.append(String::from("not json")).unwrap();
+
+    let cart = Cart::default();
+    cart.lines.lock().unwrap().insert(String::from("a"), 1);
+    let err = replay::<CartDirective, _, _>(&log, &cart).unwrap_err();
+
+    assert_eq!(err.code(), crate::error::codes::adapter::MAPPING_FAILURE);
+    assert_eq!(err.context()["record"], "1");
+    assert_eq!(cart.lines.lock().unwrap().len(), 1);
+  }
+}
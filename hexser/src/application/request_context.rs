@@ -0,0 +1,317 @@
+//! Request context propagated to handlers.
+//!
+//! A RequestContext carries the cross-cutting facts about the current request,
+//! its trace id, tenant, and locale, so they reach every handler without
+//! being copied into each directive or query. Contextual handlers receive
+//! it explicitly; code further down the call stack that cannot take it as a
+//! parameter reads it back with `RequestContext::current`.
+//!
+//! # Propagation
+//!
+//! - `execute` and `query` make the context current for the duration of the
+//!   handler call and attach its trace id (and tenant, if set) to any error
+//!   the handler returns.
+//! - `scope` makes the context current on the calling thread only. It is
+//!   not inherited by threads started inside the scope, and must not be held
+//!   across an `.await`, since the task may resume on another thread.
+//! - With the `async` feature, `scope_async` makes the context current for
+//!   one future wherever it is polled. Tasks spawned from inside it do not
+//!   inherit it; wrap them in their own `scope_async` with a clone.
+//! - `current` returns the innermost scope, whichever kind it is: a `scope`
+//!   or `execute` inside a `scope_async` future shadows the async context
+//!   until it returns.
+//!
+//! Revision History
+//! - 2026-10-19T10:35:00Z @AI: Rename HexContext to RequestContext to free the derive's name.
+//! - 2026-10-19T10:25:00Z @AI: Let the innermost scope win; scope_async sets the context per poll.
+//! - 2026-10-19T08:05:00Z @AI: Initial HexContext with thread and task scopes.
+
+/// Context key under which `attach` records the trace id.
+pub const TRACE_ID_KEY: &str = "trace_id";
+
+/// Context key under which `attach` records the tenant.
+pub const TENANT_KEY: &str = "tenant";
+
+std::thread_local! {
+  static THREAD_CONTEXT: std::cell::RefCell<Option<RequestContext>> =
+    const { std::cell::RefCell::new(None) };
+}
+
+/// Trace id, tenant, and locale of the request being handled.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{ContextualQueryHandler, RequestContext};
+/// use hexser::{HexResult, Hexserror};
+///
+/// struct FindInvoice {
+///     number: u32,
+/// }
+///
+/// struct InvoiceLookup;
+///
+/// impl ContextualQueryHandler<FindInvoice, String> for InvoiceLookup {
+///     fn handle(&self, ctx: &RequestContext, query: FindInvoice) -> HexResult<String> {
+///         let tenant = ctx.tenant().unwrap_or("default");
+///         Err(Hexserror::not_found("Invoice", &format!("{}/{}", tenant, query.number)))
+///     }
+/// }
+///
+/// let ctx = RequestContext::new("trace-42").with_tenant("acme");
+/// let err = ctx.query(&InvoiceLookup, FindInvoice { number: 7 }).unwrap_err();
+/// assert_eq!(err.context()["trace_id"], "trace-42");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+  trace_id: String,
+  tenant: Option<String>,
+  locale: Option<String>,
+}
+
+impl RequestContext {
+  /// Create a context for the request identified by `trace_id`
+  pub fn new(trace_id: impl Into<String>) -> Self {
+    Self {
+      trace_id: trace_id.into(),
+      tenant: None,
+      locale: None,
+    }
+  }
+
+  /// Set the tenant (builder pattern)
+  pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+    self.tenant = Some(tenant.into());
+    self
+  }
+
+  /// Set the locale, such as `"en-GB"` (builder pattern)
+  pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+    self.locale = Some(locale.into());
+    self
+  }
+
+  /// Trace id correlating logs and errors of this request
+  pub fn trace_id(&self) -> &str {
+    &self.trace_id
+  }
+
+  /// Tenant the request acts for, if any
+  pub fn tenant(&self) -> Option<&str> {
+    self.tenant.as_deref()
+  }
+
+  /// Locale for user-facing text, if any
+  pub fn locale(&self) -> Option<&str> {
+    self.locale.as_deref()
+  }
+
+  /// Add the trace id and tenant to `error`'s context.
+  ///
+  /// Keys the error already carries are left untouched, so an error that
+  /// crossed from another request keeps its own trace id.
+  pub fn attach(
+    &self,
+    error: crate::error::hex_error::Hexserror,
+  ) -> crate::error::hex_error::Hexserror {
+    let mut error = error;
+    if !error.context().contains_key(TRACE_ID_KEY) {
+      error = error.with_context(TRACE_ID_KEY, &self.trace_id);
+    }
+    if let Some(tenant) = &self.tenant
+      && !error.context().contains_key(TENANT_KEY)
+    {
+      error = error.with_context(TENANT_KEY, tenant);
+    }
+    error
+  }
+
+  /// Run `f` with this context current on the calling thread.
+  ///
+  /// The previous context is restored afterwards, even if `f` panics.
+  pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<RequestContext>);
+
+    impl Drop for Restore {
+      fn drop(&mut self) {
+        let previous = self.0.take();
+        THREAD_CONTEXT.with(|current| *current.borrow_mut() = previous);
+      }
+    }
+
+    let previous = THREAD_CONTEXT.with(|current| current.replace(Some(self.clone())));
+    let _restore = Restore(previous);
+    f()
+  }
+
+  /// Run `future` with this context current wherever it is polled.
+  ///
+  /// Each poll runs inside `scope`, so the context follows the future across
+  /// threads and nested scopes within it still take precedence.
+  #[cfg(feature = "async")]
+  pub async fn scope_async<F: std::future::Future>(self, future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    std::future::poll_fn(|cx| self.scope(|| future.as_mut().poll(cx))).await
+  }
+
+  /// The context of the innermost enclosing scope, if any
+  pub fn current() -> Option<Self> {
+    THREAD_CONTEXT.with(|current| current.borrow().clone())
+  }
+
+  /// Handle `directive` within this context.
+  ///
+  /// The context is current during the call and errors are passed through
+  /// `attach`.
+  pub fn execute<D, H>(&self, handler: &H, directive: D) -> crate::result::hex_result::HexResult<()>
+  where
+    D: crate::application::directive::Directive,
+    H: crate::application::contextual_handler::ContextualDirectiveHandler<D> + ?Sized,
+  {
+    self
+      .scope(|| handler.handle(self, directive))
+      .map_err(|e| self.attach(e))
+  }
+
+  /// Handle `query` within this context.
+  ///
+  /// The context is current during the call and errors are passed through
+  /// `attach`.
+  pub fn query<Q, R, H>(&self, handler: &H, query: Q) -> crate::result::hex_result::HexResult<R>
+  where
+    H: crate::application::contextual_handler::ContextualQueryHandler<Q, R> + ?Sized,
+  {
+    self
+      .scope(|| handler.handle(self, query))
+      .map_err(|e| self.attach(e))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Rename {
+    name: String,
+  }
+
+  impl crate::application::directive::Directive for Rename {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      Ok(())
+    }
+  }
+
+  struct RenameHandler;
+
+  impl crate::application::contextual_handler::ContextualDirectiveHandler<Rename> for RenameHandler {
+    fn handle(
+      &self,
+      ctx: &RequestContext,
+      directive: Rename,
+    ) -> crate::result::hex_result::HexResult<()> {
+      assert_eq!(RequestContext::current().as_ref(), Some(ctx));
+      Err(crate::error::hex_error::Hexserror::validation(&format!(
+        "Name '{}' is taken",
+        directive.name
+      )))
+    }
+  }
+
+  #[test]
+  fn test_execute_attaches_trace_id_and_tenant() {
+    let ctx = RequestContext::new("trace-1").with_tenant("acme");
+    let err = ctx
+      .execute(
+        &RenameHandler,
+        Rename {
+          name: String::from("bob"),
+        },
+      )
+      .unwrap_err();
+
+    assert_eq!(err.context()[TRACE_ID_KEY], "trace-1");
+    assert_eq!(err.context()[TENANT_KEY], "acme");
+    assert!(RequestContext::current().is_none());
+  }
+
+  #[test]
+  fn test_attach_keeps_existing_trace_id() {
+    let err = RequestContext::new("outer").attach(
+      crate::error::hex_error::Hexserror::validation("bad").with_context(TRACE_ID_KEY, "inner"),
+    );
+
+    assert_eq!(err.context()[TRACE_ID_KEY], "inner");
+    assert!(!err.context().contains_key(TENANT_KEY));
+  }
+
+  #[test]
+  fn test_nested_scopes_restore_outer_context() {
+    let outer = RequestContext::new("outer");
+    let inner = RequestContext::new("inner").with_locale("de-DE");
+
+    outer.scope(|| {
+      inner.scope(|| {
+        assert_eq!(RequestContext::current().unwrap().locale(), Some("de-DE"));
+      });
+      assert_eq!(RequestContext::current().unwrap().trace_id(), "outer");
+      let other_thread = std::thread::spawn(RequestContext::current).join().unwrap();
+      assert!(other_thread.is_none());
+    });
+    assert!(RequestContext::current().is_none());
+  }
+
+  #[cfg(feature = "async")]
+  #[tokio::test]
+  async fn test_scope_async_survives_await() {
+    let trace_id = RequestContext::new("task")
+      .scope_async(async {
+        tokio::task::yield_now().await;
+        RequestContext::current().map(|ctx| ctx.trace_id().to_string())
+      })
+      .await;
+
+    assert_eq!(trace_id.as_deref(), Some("task"));
+    assert!(RequestContext::current().is_none());
+  }
+
+  #[cfg(feature = "async")]
+  #[tokio::test]
+  async fn test_execute_inside_scope_async_shadows_task_context() {
+    let outer = RequestContext::new("task-a");
+    let (err, after) = outer
+      .clone()
+      .scope_async(async {
+        tokio::task::yield_now().await;
+        let err = RequestContext::new("request-b")
+          .execute(
+            &RenameHandler,
+            Rename {
+              name: String::from("bob"),
+            },
+          )
+          .unwrap_err();
+        tokio::task::yield_now().await;
+        (err, RequestContext::current())
+      })
+      .await;
+
+    assert_eq!(err.context()[TRACE_ID_KEY], "request-b");
+    assert_eq!(after, Some(outer));
+    assert!(RequestContext::current().is_none());
+  }
+
+  #[test]
+  #[cfg(feature = "async")]
+  fn test_scope_async_inside_scope_wins_while_polled() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+      .build()
+      .unwrap();
+    RequestContext::new("outer").scope(|| {
+      let inner = runtime.block_on(
+        RequestContext::new("inner").scope_async(async { RequestContext::current().unwrap() }),
+      );
+      assert_eq!(inner.trace_id(), "inner");
+      assert_eq!(RequestContext::current().unwrap().trace_id(), "outer");
+    });
+  }
+}
@@ -0,0 +1,181 @@
+//! Panic-catching wrapper for directive and query handlers.
+//!
+//! SafeHandler runs an inner handler under `std::panic::catch_unwind` and
+//! converts a panic into a `Hexserror` so one faulty handler cannot take
+//! down a worker. It is a safety net for unexpected bugs, not a control-flow
+//! mechanism: handlers should still report failures by returning errors.
+//!
+//! Revision History
+//! - 2026-10-19T11:05:00Z @AI: PANIC_CODE re-exports codes::handler::PANIC.
+//! - 2026-10-19T04:05:00Z @AI: Forward preview to the inner handler.
+//! - 2026-10-18T22:15:00Z @AI: Initial SafeHandler wrapper.
+
+/// Error code reported when a wrapped handler panics
+pub use crate::error::codes::handler::PANIC as PANIC_CODE;
+
+/// Handler wrapper converting panics into adapter errors.
+///
+/// The inner handler must be `RefUnwindSafe`: after a caught panic the same
+/// handler keeps serving requests, so it must not expose state left half
+/// updated by the panic. Handlers holding `RefCell` or similar interior
+/// mutability do not qualify; `Mutex` does, since it poisons on panic. The
+/// directive or query itself is consumed by the call, so it is never observed
+/// after a panic and needs no such bound.
+///
+/// The process panic hook still runs, so the panic message is printed as
+/// usual before it is returned in the error.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{Directive, DirectiveHandler, SafeHandler};
+/// use hexser::HexResult;
+///
+/// struct Import;
+///
+/// impl Directive for Import {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// struct FlakyHandler;
+///
+/// impl DirectiveHandler<Import> for FlakyHandler {
+///     fn handle(&self, _directive: Import) -> HexResult<()> {
+///         panic!("row 7 is malformed")
+///     }
+/// }
+///
+/// let handler = SafeHandler::new(FlakyHandler);
+/// let err = handler.handle(Import).unwrap_err();
+/// assert!(err.to_string().contains("row 7 is malformed"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SafeHandler<H> {
+  inner: H,
+}
+
+impl<H> SafeHandler<H> {
+  /// Wrap `inner` so its panics become errors
+  pub fn new(inner: H) -> Self {
+    Self { inner }
+  }
+
+  /// Get the wrapped handler
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+
+  /// Unwrap the handler
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+
+  /// Run `f`, converting a panic into an adapter error
+  fn guard<R>(
+    f: impl FnOnce() -> crate::result::hex_result::HexResult<R>,
+  ) -> crate::result::hex_result::HexResult<R> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+      Err(
+        crate::error::hex_error::Hexserror::adapter(
+          PANIC_CODE,
+          &format!("Handler panicked: {}", panic_message(payload.as_ref())),
+        )
+        .with_next_step("Inspect the handler for the bug that caused the panic")
+        .with_suggestion("Return a Hexserror instead of panicking for expected failures"),
+      )
+    })
+  }
+}
+
+/// Extract the message from a panic payload
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    message
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    message
+  } else {
+    "unknown panic payload"
+  }
+}
+
+impl<D, H> crate::application::directive_handler::DirectiveHandler<D> for SafeHandler<H>
+where
+  D: crate::application::directive::Directive,
+  H: crate::application::directive_handler::DirectiveHandler<D> + std::panic::RefUnwindSafe,
+{
+  fn handle(&self, directive: D) -> crate::result::hex_result::HexResult<()> {
+    Self::guard(|| self.inner.handle(directive))
+  }
+
+  fn preview(&self, directive: &D) -> crate::result::hex_result::HexResult<String> {
+    Self::guard(|| self.inner.preview(directive))
+  }
+}
+
+impl<Q, R, H> crate::application::query_handler::QueryHandler<Q, R> for SafeHandler<H>
+where
+  H: crate::application::query_handler::QueryHandler<Q, R> + std::panic::RefUnwindSafe,
+{
+  fn handle(&self, query: Q) -> crate::result::hex_result::HexResult<R> {
+    Self::guard(|| self.inner.handle(query))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::{DirectiveHandler, QueryHandler};
+
+  struct Noop;
+
+  impl crate::application::directive::Directive for Noop {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      Ok(())
+    }
+  }
+
+  struct PanickingHandler;
+
+  impl DirectiveHandler<Noop> for PanickingHandler {
+    fn handle(&self, _directive: Noop) -> crate::result::hex_result::HexResult<()> {
+      panic!("boom {}", 42)
+    }
+  }
+
+  struct LookupHandler;
+
+  impl QueryHandler<u64, String> for LookupHandler {
+    fn handle(&self, id: u64) -> crate::result::hex_result::HexResult<String> {
+      if id == 0 {
+        panic!("id must be positive");
+      }
+      Ok(format!("item-{}", id))
+    }
+  }
+
+  #[test]
+  fn test_panicking_directive_handler_yields_error() {
+    let err = DirectiveHandler::handle(&SafeHandler::new(PanickingHandler), Noop).unwrap_err();
+
+    match err {
+      crate::error::hex_error::Hexserror::Adapter(inner) => {
+        assert_eq!(inner.code, PANIC_CODE);
+        assert!(crate::error::codes::is_registered(&inner.code));
+        assert_eq!(inner.message, "Handler panicked: boom 42");
+      }
+      other => panic!("Expected Adapter error, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_query_handler_passes_results_through_and_catches_panics() {
+    let handler = SafeHandler::new(LookupHandler);
+
+    assert_eq!(QueryHandler::handle(&handler, 3).unwrap(), "item-3");
+    let err = QueryHandler::handle(&handler, 0).unwrap_err();
+    assert!(err.to_string().contains("id must be positive"));
+    assert_eq!(QueryHandler::handle(&handler, 4).unwrap(), "item-4");
+  }
+}
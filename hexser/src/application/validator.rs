@@ -0,0 +1,246 @@
+//! Validator builder for composing directive validation rules.
+//!
+//! Writing `Directive::validate` by hand usually means a chain of early
+//! returns, so callers only ever learn about the first problem. Validator
+//! evaluates every rule, collects each failure as a FieldViolation, and
+//! produces a single ValidationError describing all of them.
+//!
+//! Revision History
+//! - 2026-10-19T11:35:00Z @AI: Add Validator::rule; delegate shared rules to domain::validation.
+//! - 2026-10-19T05:25:00Z @AI: Share failure folding with domain::validation.
+//! - 2026-10-18T10:30:00Z @AI: Initial Validator with chainable field rules.
+
+/// Chainable builder accumulating field validation failures.
+///
+/// Rules never short-circuit: every rule is evaluated and every failure is
+/// recorded. Call `finish` to turn the accumulated failures into a result.
+/// A single failure keeps its own code and field; several failures are
+/// reported under `codes::validation::MULTIPLE_FAILURES` with one
+/// FieldViolation per failed rule.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{Directive, Validator};
+/// use hexser::HexResult;
+///
+/// struct CreateUserDirective {
+///     email: String,
+///     age: u32,
+/// }
+///
+/// impl Directive for CreateUserDirective {
+///     fn validate(&self) -> HexResult<()> {
+///         Validator::new()
+///             .require_non_empty("email", &self.email)
+///             .require_range("age", self.age, 18, 130)
+///             .finish()
+///     }
+/// }
+///
+/// let directive = CreateUserDirective { email: String::new(), age: 12 };
+/// match directive.validate() {
+///     Err(hexser::Hexserror::Validation(err)) => assert_eq!(err.violations.len(), 2),
+///     _ => panic!("expected validation error"),
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Validator {
+  violations: Vec<crate::error::field_violation::FieldViolation>,
+}
+
+impl Validator {
+  /// Create a validator with no recorded failures.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Record the outcome of a `domain::validation` rule.
+  ///
+  /// Lets directives reuse the domain rules, e.g. `validation::length`,
+  /// with the same codes and messages as value objects.
+  pub fn rule(mut self, result: crate::domain::validation::RuleResult) -> Self {
+    if let Result::Err(violation) = result {
+      self.violations.push(violation);
+    }
+    self
+  }
+
+  /// Require a string value to contain at least one non-whitespace character.
+  pub fn require_non_empty(self, field: &str, value: &str) -> Self {
+    self.rule(crate::domain::validation::non_empty(field, value))
+  }
+
+  /// Require a value to lie within `min..=max`.
+  pub fn require_range<T>(self, field: &str, value: T, min: T, max: T) -> Self
+  where
+    T: PartialOrd + std::fmt::Display,
+  {
+    self.rule(crate::domain::validation::range(field, value, min, max))
+  }
+
+  /// Require a value to be strictly greater than `bound`.
+  pub fn require_greater_than<T>(self, field: &str, value: T, bound: T) -> Self
+  where
+    T: PartialOrd + std::fmt::Display,
+  {
+    let valid = value > bound;
+    let message = format!("{} must be greater than {}, got {}", field, bound, value);
+    self.check(
+      field,
+      valid,
+      crate::error::codes::validation::OUT_OF_RANGE,
+      message,
+    )
+  }
+
+  /// Require an optional value to be present.
+  pub fn require_some<T>(self, field: &str, value: Option<&T>) -> Self {
+    let message = format!("{} is required", field);
+    self.check(
+      field,
+      value.is_some(),
+      crate::error::codes::validation::REQUIRED_FIELD,
+      message,
+    )
+  }
+
+  /// Require an arbitrary condition, recording `message` when it is false.
+  pub fn require_that(self, field: &str, condition: bool, message: &str) -> Self {
+    self.check(
+      field,
+      condition,
+      crate::error::codes::validation::INVALID_FORMAT,
+      message,
+    )
+  }
+
+  /// Returns true when no rule has failed so far.
+  pub fn is_valid(&self) -> bool {
+    self.violations.is_empty()
+  }
+
+  /// Returns the failures recorded so far.
+  pub fn violations(&self) -> &[crate::error::field_violation::FieldViolation] {
+    &self.violations
+  }
+
+  /// Convert the accumulated failures into a validation result.
+  pub fn finish(self) -> crate::result::hex_result::HexResult<()> {
+    crate::domain::validation::into_result(self.violations)
+  }
+
+  fn check(mut self, field: &str, valid: bool, code: &str, message: impl Into<String>) -> Self {
+    if !valid {
+      self
+        .violations
+        .push(crate::error::field_violation::FieldViolation::new(
+          field, code, message,
+        ));
+    }
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn validation_error(
+    result: crate::result::hex_result::HexResult<()>,
+  ) -> crate::error::validation_error::ValidationError {
+    match result {
+      Result::Err(crate::error::hex_error::Hexserror::Validation(err)) => err,
+      other => panic!("expected validation error, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_validator_passes_when_all_rules_hold() {
+    let result = Validator::new()
+      .require_non_empty("name", "Alice")
+      .require_range("age", 30, 0, 130)
+      .require_that("email", "a@b.c".contains('@'), "email must contain @")
+      .finish();
+
+    assert!(result.is_ok());
+  }
+
+  #[test]
+  fn test_validator_accumulates_all_failures() {
+    let err = validation_error(
+      Validator::new()
+        .require_non_empty("name", "  ")
+        .require_range("age", 200, 0, 130)
+        .require_some::<String>("email", None)
+        .finish(),
+    );
+
+    assert_eq!(err.code, crate::error::codes::validation::MULTIPLE_FAILURES);
+    let fields: Vec<&str> = err.violations.iter().map(|v| v.field.as_str()).collect();
+    assert_eq!(fields, vec!["name", "age", "email"]);
+    assert_eq!(
+      err.violations[1].code,
+      crate::error::codes::validation::OUT_OF_RANGE
+    );
+  }
+
+  #[test]
+  fn test_validator_single_failure_keeps_field() {
+    let err = validation_error(Validator::new().require_non_empty("name", "").finish());
+
+    assert_eq!(err.code, crate::error::codes::validation::REQUIRED_FIELD);
+    assert_eq!(err.field, Some(String::from("name")));
+    assert_eq!(err.violations.len(), 1);
+  }
+
+  #[test]
+  fn test_validator_rule_matches_domain_rules() {
+    // Test: Validator rules produce the same violations as domain::validation.
+    // Justification: Directive and value object messages must not drift apart.
+    let validator = Validator::new()
+      .require_non_empty("name", "")
+      .require_range("age", 200, 0, 130)
+      .rule(crate::domain::validation::length("code", "x", 2, 4));
+
+    let expected = vec![
+      crate::domain::validation::non_empty("name", "").unwrap_err(),
+      crate::domain::validation::range("age", 200, 0, 130).unwrap_err(),
+      crate::domain::validation::length("code", "x", 2, 4).unwrap_err(),
+    ];
+    assert_eq!(validator.violations(), expected.as_slice());
+    assert!(Validator::new().rule(Result::Ok(())).is_valid());
+  }
+
+  #[test]
+  fn test_validator_order_total_check() {
+    // Test: The CQRS tutorial's order-total rule expressed with Validator.
+    // Justification: Directive::validate should not need hand-written early returns.
+    struct CreateOrderDirective {
+      customer_id: String,
+      total: f64,
+    }
+
+    impl crate::application::directive::Directive for CreateOrderDirective {
+      fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+        Validator::new()
+          .require_non_empty("customer_id", &self.customer_id)
+          .require_greater_than("total", self.total, 0.0)
+          .finish()
+      }
+    }
+
+    let valid = CreateOrderDirective {
+      customer_id: String::from("customer-1"),
+      total: 99.99,
+    };
+    assert!(crate::application::directive::Directive::validate(&valid).is_ok());
+
+    let invalid = CreateOrderDirective {
+      customer_id: String::from("customer-1"),
+      total: 0.0,
+    };
+    let err = validation_error(crate::application::directive::Directive::validate(&invalid));
+    assert_eq!(err.field, Some(String::from("total")));
+  }
+}
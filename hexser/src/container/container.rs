@@ -7,6 +7,9 @@
 //! for Singleton instances.
 //!
 //! Revision History
+//! - 2026-10-19T12:15:00Z @AI: Report duplicate registrations through ContainerError.
+//! - 2026-10-19T12:05:00Z @AI: Report unregistered services as ContainerError::ServiceNotFound.
+//! - 2026-10-18T17:00:00Z @AI: Add register_factory with resolution-chain cycle detection.
 //! - 2025-10-02T20:45:00Z @AI: Clean async-only implementation with tokio::sync::RwLock.
 //! - 2025-10-02T20:40:00Z @AI: Simplify to tokio::sync::RwLock when container feature enabled.
 //! - 2025-10-02T20:35:00Z @AI: Fix async compatibility by using tokio::sync::RwLock.
@@ -20,10 +23,12 @@
 /// Uses tokio::sync::RwLock for async compatibility.
 pub struct Container {
   inner: std::sync::Arc<ContainerInner>,
+  /// Services being constructed by factory providers on this resolution path
+  resolving: Vec<String>,
 }
 
 struct ContainerInner {
-  services: tokio::sync::RwLock<std::collections::HashMap<String, ServiceEntry>>,
+  services: tokio::sync::RwLock<std::collections::HashMap<String, std::sync::Arc<ServiceEntry>>>,
 }
 
 struct ServiceEntry {
@@ -45,6 +50,7 @@ impl Container {
       inner: std::sync::Arc::new(ContainerInner {
         services: tokio::sync::RwLock::const_new(std::collections::HashMap::new()),
       }),
+      resolving: Vec::new(),
     }
   }
 
@@ -68,22 +74,61 @@ impl Container {
 
     if services.contains_key(&name) {
       return Err(
-        crate::error::hex_error::Hexserror::validation(&format!(
-          "Service {} already registered",
-          name
-        ))
-        .with_next_step("Use different service name or remove existing registration"),
+        crate::container::container_error::ContainerError::duplicate_registration(name).into(),
       );
     }
 
     let boxed_provider: Box<dyn crate::container::provider::Provider<T>> = Box::new(provider);
     services.insert(
       name,
-      ServiceEntry {
+      std::sync::Arc::new(ServiceEntry {
         scope,
         factory: std::sync::Arc::new(boxed_provider),
         singleton_cache: tokio::sync::RwLock::const_new(None),
-      },
+      }),
+    );
+
+    Ok(())
+  }
+
+  /// Register service built by a factory that resolves its own dependencies
+  ///
+  /// The factory receives the container while building, so it can call
+  /// `resolve` for the services it depends on. A factory that (directly or
+  /// transitively) resolves the service it is building gets a circular
+  /// dependency error.
+  ///
+  /// # Arguments
+  /// * `name` - Unique service identifier
+  /// * `factory` - Factory creating instances from container dependencies
+  /// * `scope` - Lifetime scope for instances
+  ///
+  /// # Errors
+  /// Returns error if service already exists
+  pub async fn register_factory<T: 'static + Send + Sync>(
+    &self,
+    name: impl Into<String>,
+    factory: impl crate::container::factory_provider::FactoryProvider<T> + 'static,
+    scope: crate::container::scope::Scope,
+  ) -> crate::result::hex_result::HexResult<()> {
+    let name = name.into();
+    let mut services = self.inner.services.write().await;
+
+    if services.contains_key(&name) {
+      return Err(
+        crate::container::container_error::ContainerError::duplicate_registration(name).into(),
+      );
+    }
+
+    let boxed_factory: Box<dyn crate::container::factory_provider::FactoryProvider<T>> =
+      Box::new(factory);
+    services.insert(
+      name,
+      std::sync::Arc::new(ServiceEntry {
+        scope,
+        factory: std::sync::Arc::new(boxed_factory),
+        singleton_cache: tokio::sync::RwLock::const_new(None),
+      }),
     );
 
     Ok(())
@@ -100,11 +145,28 @@ impl Container {
     &self,
     name: &str,
   ) -> crate::result::hex_result::HexResult<std::sync::Arc<T>> {
-    let services = self.inner.services.read().await;
+    if self.resolving.iter().any(|n| n == name) {
+      let mut cycle = self.resolving.clone();
+      cycle.push(String::from(name));
+      return Err(
+        crate::container::container_error::ContainerError::circular_dependency(cycle).into(),
+      );
+    }
 
-    let entry = services
+    // Release the registry lock before building so factories can resolve
+    // their own dependencies without holding it.
+    let entry = self
+      .inner
+      .services
+      .read()
+      .await
       .get(name)
-      .ok_or_else(|| crate::error::hex_error::Hexserror::not_found("Service", name))?;
+      .cloned()
+      .ok_or_else(|| {
+        crate::error::hex_error::Hexserror::from(
+          crate::container::container_error::ContainerError::service_not_found(name),
+        )
+      })?;
 
     match entry.scope {
       crate::container::scope::Scope::Singleton => {
@@ -116,32 +178,44 @@ impl Container {
           });
         }
 
-        let provider = entry
-          .factory
-          .downcast_ref::<Box<dyn crate::container::provider::Provider<T>>>()
-          .ok_or_else(|| {
-            crate::error::hex_error::Hexserror::adapter("E_CNT_005", "Provider type mismatch")
-          })?;
-
-        let instance = provider.provide()?;
+        let instance = self.create::<T>(name, &entry, "E_CNT_005").await?;
         let arc_instance = std::sync::Arc::new(instance);
         *cache = Some(arc_instance.clone() as std::sync::Arc<dyn std::any::Any + Send + Sync>);
         Ok(arc_instance)
       }
       crate::container::scope::Scope::Transient => {
-        let provider = entry
-          .factory
-          .downcast_ref::<Box<dyn crate::container::provider::Provider<T>>>()
-          .ok_or_else(|| {
-            crate::error::hex_error::Hexserror::adapter("E_CNT_006", "Provider type mismatch")
-          })?;
-
-        let instance = provider.provide()?;
+        let instance = self.create::<T>(name, &entry, "E_CNT_006").await?;
         Ok(std::sync::Arc::new(instance))
       }
     }
   }
 
+  /// Build an instance from a plain or factory provider entry
+  async fn create<T: 'static + Send + Sync>(
+    &self,
+    name: &str,
+    entry: &ServiceEntry,
+    mismatch_code: &str,
+  ) -> crate::result::hex_result::HexResult<T> {
+    if let Some(provider) = entry
+      .factory
+      .downcast_ref::<Box<dyn crate::container::provider::Provider<T>>>()
+    {
+      return provider.provide();
+    }
+
+    let factory = entry
+      .factory
+      .downcast_ref::<Box<dyn crate::container::factory_provider::FactoryProvider<T>>>()
+      .ok_or_else(|| {
+        crate::error::hex_error::Hexserror::adapter(mismatch_code, "Provider type mismatch")
+      })?;
+
+    let mut scoped = self.clone();
+    scoped.resolving.push(String::from(name));
+    factory.build(&scoped).await
+  }
+
   /// Check if service is registered
   pub async fn contains(&self, name: &str) -> bool {
     self.inner.services.read().await.contains_key(name)
@@ -173,11 +247,7 @@ impl Container {
 
     if services.contains_key(&name) {
       return Err(
-        crate::error::hex_error::Hexserror::validation(&format!(
-          "Service {} already registered",
-          name
-        ))
-        .with_next_step("Use different service name or remove existing registration"),
+        crate::container::container_error::ContainerError::duplicate_registration(name).into(),
       );
     }
 
@@ -185,11 +255,11 @@ impl Container {
       Box::new(provider);
     services.insert(
       name,
-      ServiceEntry {
+      std::sync::Arc::new(ServiceEntry {
         scope,
         factory: std::sync::Arc::new(boxed_provider),
         singleton_cache: tokio::sync::RwLock::const_new(None),
-      },
+      }),
     );
 
     Ok(())
@@ -210,9 +280,11 @@ impl Container {
   ) -> crate::result::hex_result::HexResult<std::sync::Arc<T>> {
     let services = self.inner.services.read().await;
 
-    let entry = services
-      .get(name)
-      .ok_or_else(|| crate::error::hex_error::Hexserror::not_found("Service", name))?;
+    let entry = services.get(name).ok_or_else(|| {
+      crate::error::hex_error::Hexserror::from(
+        crate::container::container_error::ContainerError::service_not_found(name),
+      )
+    })?;
 
     match entry.scope {
       crate::container::scope::Scope::Singleton => {
@@ -255,6 +327,7 @@ impl Clone for Container {
   fn clone(&self) -> Self {
     Self {
       inner: std::sync::Arc::clone(&self.inner),
+      resolving: self.resolving.clone(),
     }
   }
 }
@@ -321,7 +394,12 @@ mod tests {
       .register("test", provider2, crate::container::scope::Scope::Singleton)
       .await;
 
-    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert_eq!(
+      err.code(),
+      crate::error::codes::container::DUPLICATE_REGISTRATION
+    );
+    assert!(err.to_string().contains("Service already registered: test"));
   }
 
   #[tokio::test]
@@ -342,4 +420,102 @@ mod tests {
     assert!(container2.contains("shared").await);
     assert_eq!(container2.service_count().await, 1);
   }
+
+  struct Config {
+    url: String,
+  }
+
+  struct ConfigProvider;
+
+  impl crate::container::provider::Provider<Config> for ConfigProvider {
+    fn provide(&self) -> crate::result::hex_result::HexResult<Config> {
+      Ok(Config {
+        url: String::from("postgres://localhost"),
+      })
+    }
+  }
+
+  struct Repository {
+    url: String,
+  }
+
+  #[tokio::test]
+  async fn test_factory_resolves_dependencies() {
+    // Test: A factory builds its service from dependencies in the same container.
+    // Justification: Factories enable dependency graphs beyond leaf services.
+    let container = Container::new();
+    container
+      .register(
+        "config",
+        ConfigProvider,
+        crate::container::scope::Scope::Singleton,
+      )
+      .await
+      .unwrap();
+    container
+      .register_factory(
+        "repository",
+        |c: Container| async move {
+          let config = c.resolve::<Config>("config").await?;
+          Ok(Repository {
+            url: config.url.clone(),
+          })
+        },
+        crate::container::scope::Scope::Transient,
+      )
+      .await
+      .unwrap();
+
+    let repository = container.resolve::<Repository>("repository").await.unwrap();
+    assert_eq!(repository.url, "postgres://localhost");
+  }
+
+  #[tokio::test]
+  async fn test_factory_cycle_is_reported() {
+    // Test: Reentrant resolution of an in-progress service fails instead of deadlocking.
+    // Justification: A singleton factory resolving itself would otherwise wait on its own cache lock.
+    let container = Container::new();
+    container
+      .register_factory(
+        "a",
+        |c: Container| async move {
+          c.resolve::<TestService>("b")
+            .await
+            .map(|s| TestService { value: s.value })
+        },
+        crate::container::scope::Scope::Singleton,
+      )
+      .await
+      .unwrap();
+    container
+      .register_factory(
+        "b",
+        |c: Container| async move {
+          c.resolve::<TestService>("a")
+            .await
+            .map(|s| TestService { value: s.value })
+        },
+        crate::container::scope::Scope::Singleton,
+      )
+      .await
+      .unwrap();
+
+    let err = match container.resolve::<TestService>("a").await {
+      Err(err) => err,
+      Ok(_) => panic!("Expected circular dependency error"),
+    };
+    match err {
+      crate::error::hex_error::Hexserror::Adapter(adapter_error) => {
+        assert_eq!(
+          crate::error::RichError::code(&adapter_error),
+          crate::error::codes::container::CIRCULAR_DEPENDENCY
+        );
+        assert_eq!(
+          crate::error::RichError::message(&adapter_error),
+          "Circular dependency detected: a -> b -> a"
+        );
+      }
+      other => panic!("Expected adapter error, got {:?}", other),
+    }
+  }
 }
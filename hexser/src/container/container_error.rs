@@ -6,6 +6,8 @@
 //! actionable remediation steps.
 //!
 //! Revision History
+//! - 2026-10-19T12:05:00Z @AI: Test service-not-found through a real Container resolve.
+//! - 2026-10-18T16:30:00Z @AI: Add stable codes and From<ContainerError> for Hexserror.
 //! - 2025-10-02T20:00:00Z @AI: Initial container error types for Phase 6.
 
 /// Errors specific to dependency injection container operations
@@ -65,62 +67,91 @@ impl ContainerError {
       service_name: service_name.into(),
     }
   }
-}
 
-impl std::fmt::Display for ContainerError {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+  /// Stable error code for this failure kind
+  pub fn code(&self) -> &'static str {
     match self {
-      Self::ServiceNotFound { service_name } => {
-        write!(f, "Service not found: {}", service_name)?;
-        write!(f, "\nNext Steps: Register the service before resolving")?;
-        write!(
-          f,
-          "\nSuggestion: container.register::<{}>(provider, scope)?",
-          service_name
-        )
-      }
+      Self::ServiceNotFound { .. } => crate::error::codes::container::SERVICE_NOT_FOUND,
+      Self::CircularDependency { .. } => crate::error::codes::container::CIRCULAR_DEPENDENCY,
+      Self::ProviderFailed { .. } => crate::error::codes::container::PROVIDER_FAILED,
+      Self::DuplicateRegistration { .. } => crate::error::codes::container::DUPLICATE_REGISTRATION,
+    }
+  }
+
+  fn message(&self) -> String {
+    match self {
+      Self::ServiceNotFound { service_name } => format!("Service not found: {}", service_name),
       Self::CircularDependency { cycle } => {
-        write!(f, "Circular dependency detected: {}", cycle.join(" -> "))?;
-        write!(
-          f,
-          "\nNext Steps: Break the cycle by introducing an interface or removing dependency"
-        )?;
-        write!(
-          f,
-          "\nSuggestion: Use dependency inversion to break circular references"
-        )
+        format!("Circular dependency detected: {}", cycle.join(" -> "))
       }
       Self::ProviderFailed {
         service_name,
         reason,
-      } => {
-        write!(f, "Provider failed for {}: {}", service_name, reason)?;
-        write!(
-          f,
-          "\nNext Steps: Check provider implementation and dependencies"
-        )?;
-        write!(
-          f,
-          "\nSuggestion: Ensure all dependencies are registered and provider logic is correct"
-        )
-      }
+      } => format!("Provider failed for {}: {}", service_name, reason),
       Self::DuplicateRegistration { service_name } => {
-        write!(f, "Service already registered: {}", service_name)?;
-        write!(
-          f,
-          "\nNext Steps: Remove duplicate registration or use different service name"
-        )?;
-        write!(
-          f,
-          "\nSuggestion: Check if service is registered elsewhere in the application"
-        )
+        format!("Service already registered: {}", service_name)
       }
     }
   }
+
+  fn next_step(&self) -> &'static str {
+    match self {
+      Self::ServiceNotFound { .. } => "Register the service before resolving",
+      Self::CircularDependency { .. } => {
+        "Break the cycle by introducing an interface or removing dependency"
+      }
+      Self::ProviderFailed { .. } => "Check provider implementation and dependencies",
+      Self::DuplicateRegistration { .. } => {
+        "Remove duplicate registration or use different service name"
+      }
+    }
+  }
+
+  fn suggestion(&self) -> String {
+    match self {
+      Self::ServiceNotFound { service_name } => {
+        format!("container.register::<{}>(provider, scope)?", service_name)
+      }
+      Self::CircularDependency { .. } => {
+        String::from("Use dependency inversion to break circular references")
+      }
+      Self::ProviderFailed { .. } => {
+        String::from("Ensure all dependencies are registered and provider logic is correct")
+      }
+      Self::DuplicateRegistration { .. } => {
+        String::from("Check if service is registered elsewhere in the application")
+      }
+    }
+  }
+}
+
+impl std::fmt::Display for ContainerError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.message())?;
+    write!(f, "\nNext Steps: {}", self.next_step())?;
+    write!(f, "\nSuggestion: {}", self.suggestion())
+  }
 }
 
 impl std::error::Error for ContainerError {}
 
+/// Converts into an adapter error so container failures propagate with `?`
+///
+/// The code identifies the failure kind, the message, next step, and
+/// suggestion match the Display output, and the original ContainerError is
+/// kept as the error source.
+impl From<ContainerError> for crate::error::hex_error::Hexserror {
+  fn from(err: ContainerError) -> Self {
+    let adapter_error = crate::error::adapter_error::AdapterError::new(err.code(), err.message());
+    let adapter_error = crate::error::RichError::with_next_step(adapter_error, err.next_step());
+    let adapter_error = crate::error::RichError::with_suggestion(adapter_error, err.suggestion());
+    crate::error::hex_error::Hexserror::Adapter(crate::error::RichError::with_source(
+      adapter_error,
+      err,
+    ))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -158,4 +189,39 @@ mod tests {
     let err = ContainerError::duplicate_registration("MyService");
     assert!(err.to_string().contains("already registered"));
   }
+
+  #[tokio::test]
+  async fn test_missing_dependency_propagates_as_hexserror() {
+    // Test: Resolving an unregistered service yields the container's SERVICE_NOT_FOUND code.
+    // Justification: Container failures must flow through HexResult boundaries with stable codes.
+    let container = crate::container::container::Container::new();
+    let err = match container.resolve::<String>("UserRepository").await {
+      Err(err) => err,
+      Ok(_) => panic!("Expected service not found error"),
+    };
+    match &err {
+      crate::error::hex_error::Hexserror::Adapter(adapter_error) => {
+        assert_eq!(
+          crate::error::RichError::code(adapter_error),
+          crate::error::codes::container::SERVICE_NOT_FOUND
+        );
+        assert_eq!(
+          crate::error::RichError::message(adapter_error),
+          "Service not found: UserRepository"
+        );
+      }
+      other => panic!("Expected adapter error, got {:?}", other),
+    }
+    let source = std::error::Error::source(&err).expect("container error kept as source");
+    assert!(source.to_string().contains("Register the service"));
+
+    let err = match container.resolve_async::<String>("UserRepository").await {
+      Err(err) => err,
+      Ok(_) => panic!("Expected service not found error"),
+    };
+    assert_eq!(
+      err.code(),
+      crate::error::codes::container::SERVICE_NOT_FOUND
+    );
+  }
 }
@@ -0,0 +1,84 @@
+//! Factory provider trait for services with container-resolved dependencies.
+//!
+//! Plain providers construct services in isolation. A factory provider
+//! receives the container during construction, so it can resolve its own
+//! dependencies (configuration, repositories, clients) from the same
+//! container and build real dependency graphs instead of only leaf services.
+//!
+//! Revision History
+//! - 2026-10-18T17:00:00Z @AI: Initial FactoryProvider trait with closure support.
+
+/// Provider that resolves its dependencies from the container while building
+///
+/// The container passed to `build` tracks the chain of services currently
+/// being constructed. Resolving a service that is already in that chain
+/// fails with a circular dependency error instead of deadlocking.
+///
+/// Async closures taking an owned `Container` implement this trait, which
+/// is a cheap `Arc` clone sharing the same registrations.
+///
+/// # Example
+///
+/// ```rust
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// use hexser::container::{Container, Provider, Scope};
+///
+/// struct Config {
+///     url: String,
+/// }
+///
+/// struct ConfigProvider;
+/// impl Provider<Config> for ConfigProvider {
+///     fn provide(&self) -> hexser::HexResult<Config> {
+///         Ok(Config { url: String::from("postgres://localhost") })
+///     }
+/// }
+///
+/// struct Repository {
+///     url: String,
+/// }
+///
+/// let container = Container::new();
+/// container.register("config", ConfigProvider, Scope::Singleton).await.unwrap();
+/// container
+///     .register_factory(
+///         "repository",
+///         |c: Container| async move {
+///             let config = c.resolve::<Config>("config").await?;
+///             Ok(Repository { url: config.url.clone() })
+///         },
+///         Scope::Singleton,
+///     )
+///     .await
+///     .unwrap();
+///
+/// let repository = container.resolve::<Repository>("repository").await.unwrap();
+/// assert_eq!(repository.url, "postgres://localhost");
+/// # });
+/// ```
+#[async_trait::async_trait]
+pub trait FactoryProvider<T>: Send + Sync {
+  /// Create a new instance, resolving dependencies from `container`
+  ///
+  /// # Errors
+  /// Returns Hexserror if a dependency cannot be resolved or creation fails
+  async fn build(
+    &self,
+    container: &crate::container::container::Container,
+  ) -> crate::result::hex_result::HexResult<T>;
+}
+
+#[async_trait::async_trait]
+impl<T, F, Fut> FactoryProvider<T> for F
+where
+  F: Fn(crate::container::container::Container) -> Fut + Send + Sync,
+  Fut: std::future::Future<Output = crate::result::hex_result::HexResult<T>> + Send,
+  T: Send + 'static,
+{
+  async fn build(
+    &self,
+    container: &crate::container::container::Container,
+  ) -> crate::result::hex_result::HexResult<T> {
+    (self)(container.clone()).await
+  }
+}
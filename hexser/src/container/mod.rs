@@ -6,11 +6,13 @@
 //! an infrastructure concern that manages domain, port, and adapter instances.
 //!
 //! Revision History
+//! - 2026-10-18T17:00:00Z @AI: Add FactoryProvider for container-aware construction.
 //! - 2025-10-02T20:30:00Z @AI: Add async provider support for Phase 6.2.
 //! - 2025-10-02T20:00:00Z @AI: Initial Phase 6 container module implementation.
 
 pub mod container;
 pub mod container_error;
+pub mod factory_provider;
 pub mod provider;
 pub mod scope;
 
@@ -18,7 +20,8 @@ pub mod scope;
 pub mod async_provider;
 
 pub use self::{
-  container::Container, container_error::ContainerError, provider::Provider, scope::Scope,
+  container::Container, container_error::ContainerError, factory_provider::FactoryProvider,
+  provider::Provider, scope::Scope,
 };
 
 #[cfg(feature = "container")]
@@ -6,6 +6,9 @@
 //! bounded contexts. Events capture the intent and meaning behind state changes.
 //!
 //! Revision History
+//! - 2026-10-19T03:05:00Z @AI: Add event_version and compact to_json envelope.
+//! - 2026-10-19T02:15:00Z @AI: Add partition_key defaulting to aggregate_id.
+//! - 2026-10-18T14:00:00Z @AI: Implement DomainEvent for Box and Arc so trait objects can be stored.
 //! - 2025-10-01T00:00:00Z @AI: Initial DomainEvent trait definition with metadata.
 
 /// Trait for domain events representing significant occurrences.
@@ -40,6 +43,101 @@ pub trait DomainEvent {
 
   /// Returns the identifier of the aggregate that produced this event.
   fn aggregate_id(&self) -> String;
+
+  /// Returns the key that keeps related events ordered in partitioned transports.
+  ///
+  /// Events sharing a key must be delivered in publish order. Defaults to the
+  /// aggregate id; override to order by a coarser key (e.g. a tenant) or
+  /// return `None` when ordering does not matter.
+  fn partition_key(&self) -> Option<String> {
+    Some(self.aggregate_id())
+  }
+
+  /// Returns the schema version of this event's payload.
+  ///
+  /// Bump it when the payload shape changes so consumers can upcast older
+  /// events. Defaults to 1.
+  fn event_version(&self) -> u32 {
+    1
+  }
+
+  /// Serializes the event into a compact JSON envelope.
+  ///
+  /// The envelope is `{"type", "aggregate_id", "version", "data"}`, where
+  /// `data` is the event itself serialized through its `Serialize` impl. It
+  /// is a lighter alternative to `CloudEventsEnvelope` for internal pub/sub
+  /// and its field names are stable. Requires the `serde` and `serde_json`
+  /// features and is only callable on concrete event types implementing
+  /// `Serialize`, not through `dyn DomainEvent`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an adapter error with code `E_HEX_202` when serialization fails.
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  fn to_json(&self) -> crate::result::hex_result::HexResult<String>
+  where
+    Self: serde::Serialize + Sized,
+  {
+    let envelope = CompactEnvelope {
+      event_type: self.event_type(),
+      aggregate_id: self.aggregate_id(),
+      version: self.event_version(),
+      data: self,
+    };
+    serde_json::to_string(&envelope).map_err(|e| {
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::MAPPING_FAILURE,
+        &format!("Event serialization failed: {}", e),
+      )
+    })
+  }
+}
+
+/// Wire shape produced by `DomainEvent::to_json`.
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[derive(serde::Serialize)]
+struct CompactEnvelope<'a, D> {
+  #[serde(rename = "type")]
+  event_type: &'a str,
+  aggregate_id: String,
+  version: u32,
+  data: &'a D,
+}
+
+impl<E: DomainEvent + ?Sized> DomainEvent for Box<E> {
+  fn event_type(&self) -> &str {
+    (**self).event_type()
+  }
+
+  fn aggregate_id(&self) -> String {
+    (**self).aggregate_id()
+  }
+
+  fn partition_key(&self) -> Option<String> {
+    (**self).partition_key()
+  }
+
+  fn event_version(&self) -> u32 {
+    (**self).event_version()
+  }
+}
+
+impl<E: DomainEvent + ?Sized> DomainEvent for std::sync::Arc<E> {
+  fn event_type(&self) -> &str {
+    (**self).event_type()
+  }
+
+  fn aggregate_id(&self) -> String {
+    (**self).aggregate_id()
+  }
+
+  fn partition_key(&self) -> Option<String> {
+    (**self).partition_key()
+  }
+
+  fn event_version(&self) -> u32 {
+    (**self).event_version()
+  }
 }
 
 #[cfg(test)]
@@ -70,6 +168,50 @@ mod tests {
     assert_eq!(event.event_type(), "UserCreated");
   }
 
+  #[test]
+  fn test_partition_key_defaults_to_aggregate_id() {
+    let event = TestUserCreated {
+      user_id: String::from("123"),
+      email: String::from("test@example.com"),
+    };
+    assert_eq!(event.partition_key(), Some(String::from("123")));
+    let boxed: Box<dyn DomainEvent> = Box::new(event);
+    assert_eq!(boxed.partition_key(), Some(String::from("123")));
+  }
+
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  #[test]
+  fn test_to_json_produces_compact_envelope() {
+    #[derive(serde::Serialize)]
+    struct OrderShipped {
+      order_id: String,
+      carrier: String,
+    }
+
+    impl DomainEvent for OrderShipped {
+      fn event_type(&self) -> &str {
+        "OrderShipped"
+      }
+
+      fn aggregate_id(&self) -> String {
+        self.order_id.clone()
+      }
+
+      fn event_version(&self) -> u32 {
+        2
+      }
+    }
+
+    let event = OrderShipped {
+      order_id: String::from("o-1"),
+      carrier: String::from("UPS"),
+    };
+    assert_eq!(
+      event.to_json().unwrap(),
+      r#"{"type":"OrderShipped","aggregate_id":"o-1","version":2,"data":{"order_id":"o-1","carrier":"UPS"}}"#
+    );
+  }
+
   #[test]
   fn test_domain_event_aggregate_id() {
     let event = TestUserCreated {
@@ -7,6 +7,8 @@
 //! The identity type is defined via an associated type for maximum flexibility.
 //!
 //! Revision History
+//! - 2026-10-19T10:45:00Z @AI: Move entity_id and entity_eq to a reflexive Identified trait.
+//! - 2026-10-18T12:00:00Z @AI: Add entity_id accessor and entity_eq identity comparison.
 //! - 2025-10-09T09:43:00Z @AI: Rename Entity to HexEntity for consistency.
 //! - 2025-10-01T00:00:00Z @AI: Initial Entity trait definition with associated type Id.
 
@@ -29,11 +31,81 @@
 ///     type Id = String;
 /// }
 /// ```
+///
+/// # Identity Equality
+///
+/// Entities that expose their identity implement `Identified`, whose
+/// `entity_eq` compares entities by `entity_id`. Implement it manually, or use
+/// `#[derive(HexEntity)]` which implements it for a field named `id`.
+/// Adding `#[hex(eq_by_id)]` to the derive also generates `PartialEq`, `Eq`,
+/// and `Hash` using only the `id` field, so
+/// `==` follows identity semantics; it therefore cannot be combined with
+/// `#[derive(PartialEq)]`. Keep a derived `PartialEq` instead when `==` should
+/// compare full state, and call `entity_eq` where identity is meant.
+///
+/// ```rust
+/// use hexser::domain::Identified;
+///
+/// #[derive(hexser::HexEntity)]
+/// #[hex(eq_by_id)]
+/// struct Account {
+///     id: u64,
+///     balance: i64,
+/// }
+///
+/// let loaded = Account { id: 7, balance: 100 };
+/// let updated = Account { id: 7, balance: 250 };
+/// assert!(loaded.entity_eq(&updated));
+/// assert!(loaded == updated);
+/// ```
 pub trait HexEntity {
   /// The type used to uniquely identify this entity.
   type Id;
 }
 
+/// Entities that expose their identity.
+///
+/// Kept apart from HexEntity so that identity comparison is only available
+/// where an identity exists; `entity_eq` is therefore reflexive.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::domain::{HexEntity, Identified};
+///
+/// struct Order {
+///     number: u32,
+/// }
+///
+/// impl HexEntity for Order {
+///     type Id = u32;
+/// }
+///
+/// impl Identified for Order {
+///     fn entity_id(&self) -> &u32 {
+///         &self.number
+///     }
+/// }
+///
+/// let order = Order { number: 7 };
+/// assert!(order.entity_eq(&order));
+/// ```
+pub trait Identified: HexEntity {
+  /// Returns this entity's identity.
+  ///
+  /// Named `entity_id` rather than `id` so it never shadows or conflicts with
+  /// an inherent `id()` accessor defined on the entity itself.
+  fn entity_id(&self) -> &Self::Id;
+
+  /// Returns true if both entities have equal identities.
+  fn entity_eq(&self, other: &Self) -> bool
+  where
+    Self::Id: PartialEq,
+  {
+    self.entity_id() == other.entity_id()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -47,6 +119,12 @@ mod tests {
     type Id = u64;
   }
 
+  impl Identified for TestUser {
+    fn entity_id(&self) -> &u64 {
+      &self.id
+    }
+  }
+
   #[test]
   fn test_entity_trait_compiles() {
     let user = TestUser {
@@ -55,4 +133,33 @@ mod tests {
     };
     let _id_type: <TestUser as HexEntity>::Id = user.id;
   }
+
+  #[test]
+  fn test_entity_eq_compares_identity_only() {
+    let loaded = TestUser {
+      id: 1,
+      name: String::from("Before"),
+    };
+    let updated = TestUser {
+      id: 1,
+      name: String::from("After"),
+    };
+    let other = TestUser {
+      id: 2,
+      name: String::from("Before"),
+    };
+
+    assert!(loaded.entity_eq(&updated));
+    assert!(!loaded.entity_eq(&other));
+  }
+
+  #[test]
+  fn test_entity_eq_is_reflexive() {
+    let user = TestUser {
+      id: 3,
+      name: String::from("Same"),
+    };
+
+    assert!(user.entity_eq(&user));
+  }
 }
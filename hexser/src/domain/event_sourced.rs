@@ -0,0 +1,187 @@
+//! EventSourced trait for rebuilding aggregate state from events.
+//!
+//! An event-sourced aggregate derives its state entirely from the events it
+//! has produced. Applying each stored event in order to a fresh instance
+//! reconstructs the current state, which pairs with the EventStore port:
+//! `EventStore::load` returns the stream and `EventSourced::replay` folds it.
+//!
+//! Revision History
+//! - 2026-10-18T14:30:00Z @AI: Initial EventSourced trait with replay.
+
+/// Trait for aggregates whose state is rebuilt by applying domain events.
+///
+/// `EventSourced` is independent of `Aggregate`: `Aggregate` guards the
+/// invariants of the current state, while `EventSourced` describes how that
+/// state is derived from history. An event-sourced aggregate root usually
+/// implements both, and can call `check_invariants` after `replay` to verify
+/// the rebuilt state. `apply` must not fail or have side effects, since it is
+/// also used for events that were accepted in the past.
+///
+/// The event type is associated rather than `dyn DomainEvent` so `apply` can
+/// match on the aggregate's own event enum without downcasting, mirroring the
+/// generic event type of `EventStore<E>`.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::domain::{DomainEvent, EventSourced};
+///
+/// enum AccountEvent {
+///     Deposited { account_id: String, amount: i64 },
+///     Withdrawn { account_id: String, amount: i64 },
+/// }
+///
+/// impl DomainEvent for AccountEvent {
+///     fn event_type(&self) -> &str {
+///         match self {
+///             AccountEvent::Deposited { .. } => "Deposited",
+///             AccountEvent::Withdrawn { .. } => "Withdrawn",
+///         }
+///     }
+///
+///     fn aggregate_id(&self) -> String {
+///         match self {
+///             AccountEvent::Deposited { account_id, .. } => account_id.clone(),
+///             AccountEvent::Withdrawn { account_id, .. } => account_id.clone(),
+///         }
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Account {
+///     balance: i64,
+/// }
+///
+/// impl EventSourced for Account {
+///     type Event = AccountEvent;
+///
+///     fn apply(&mut self, event: &AccountEvent) {
+///         match event {
+///             AccountEvent::Deposited { amount, .. } => self.balance += amount,
+///             AccountEvent::Withdrawn { amount, .. } => self.balance -= amount,
+///         }
+///     }
+/// }
+///
+/// let account = Account::replay(&[
+///     AccountEvent::Deposited { account_id: "a".into(), amount: 100 },
+///     AccountEvent::Withdrawn { account_id: "a".into(), amount: 30 },
+/// ]);
+/// assert_eq!(account.balance, 70);
+/// ```
+pub trait EventSourced {
+  /// The domain event type this aggregate produces and consumes.
+  type Event: crate::domain::domain_event::DomainEvent;
+
+  /// Apply a single event, mutating state to reflect it.
+  fn apply(&mut self, event: &Self::Event);
+
+  /// Apply a sequence of events in order to the current state.
+  fn apply_all(&mut self, events: &[Self::Event]) {
+    for event in events {
+      self.apply(event);
+    }
+  }
+
+  /// Rebuild state by folding events over a default instance.
+  fn replay(events: &[Self::Event]) -> Self
+  where
+    Self: Default,
+  {
+    let mut state = Self::default();
+    state.apply_all(events);
+    state
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Clone, Debug)]
+  enum CartEvent {
+    ItemAdded { cart_id: String, sku: String },
+    ItemRemoved { cart_id: String, sku: String },
+    CheckedOut { cart_id: String },
+  }
+
+  impl crate::domain::domain_event::DomainEvent for CartEvent {
+    fn event_type(&self) -> &str {
+      match self {
+        CartEvent::ItemAdded { .. } => "ItemAdded",
+        CartEvent::ItemRemoved { .. } => "ItemRemoved",
+        CartEvent::CheckedOut { .. } => "CheckedOut",
+      }
+    }
+
+    fn aggregate_id(&self) -> String {
+      match self {
+        CartEvent::ItemAdded { cart_id, .. }
+        | CartEvent::ItemRemoved { cart_id, .. }
+        | CartEvent::CheckedOut { cart_id } => cart_id.clone(),
+      }
+    }
+  }
+
+  #[derive(Debug, Default, PartialEq)]
+  struct Cart {
+    skus: Vec<String>,
+    checked_out: bool,
+  }
+
+  impl EventSourced for Cart {
+    type Event = CartEvent;
+
+    fn apply(&mut self, event: &CartEvent) {
+      match event {
+        CartEvent::ItemAdded { sku, .. } => self.skus.push(sku.clone()),
+        CartEvent::ItemRemoved { sku, .. } => self.skus.retain(|s| s != sku),
+        CartEvent::CheckedOut { .. } => self.checked_out = true,
+      }
+    }
+  }
+
+  fn cart_history() -> Vec<CartEvent> {
+    vec![
+      CartEvent::ItemAdded {
+        cart_id: String::from("c1"),
+        sku: String::from("apple"),
+      },
+      CartEvent::ItemAdded {
+        cart_id: String::from("c1"),
+        sku: String::from("pear"),
+      },
+      CartEvent::ItemRemoved {
+        cart_id: String::from("c1"),
+        sku: String::from("apple"),
+      },
+      CartEvent::CheckedOut {
+        cart_id: String::from("c1"),
+      },
+    ]
+  }
+
+  #[test]
+  fn test_replay_rebuilds_state() {
+    let cart = Cart::replay(&cart_history());
+
+    assert_eq!(
+      cart,
+      Cart {
+        skus: vec![String::from("pear")],
+        checked_out: true,
+      }
+    );
+  }
+
+  #[test]
+  fn test_replay_from_event_store() {
+    // Test: A stream appended to an EventStore rebuilds the same aggregate.
+    // Justification: EventStore and EventSourced are designed to be used together.
+    let mut store = crate::adapters::in_memory_event_store::InMemoryEventStore::new();
+    crate::ports::event_store::EventStore::append(&mut store, "c1", cart_history()).unwrap();
+
+    let events = crate::ports::event_store::EventStore::load(&store, "c1").unwrap();
+    assert_eq!(Cart::replay(&events), Cart::replay(&cart_history()));
+  }
+}
@@ -3,9 +3,13 @@
 //! The domain layer is the heart of hexagonal architecture, containing all
 //! business logic without any dependencies on infrastructure or frameworks.
 //! This module provides traits for entities, value objects, aggregates,
-//! domain events, and domain services.
+//! domain events, and domain services, plus reusable validation rules.
 //!
 //! Revision History
+//! - 2026-10-19T10:45:00Z @AI: Re-export Identified.
+//! - 2026-10-19T08:25:00Z @AI: Add Timestamped trait for created_at/updated_at entities.
+//! - 2026-10-19T05:25:00Z @AI: Add validation module with the Validate trait and rules.
+//! - 2026-10-18T14:30:00Z @AI: Add event_sourced module for rebuilding aggregates from events.
 //! - 2025-10-08T23:35:00Z @AI: Add MCP domain module for Model Context Protocol support.
 //! - 2025-10-01T00:00:00Z @AI: Initial Phase 1 domain module structure.
 
@@ -13,6 +17,9 @@ pub mod aggregate;
 pub mod domain_event;
 pub mod domain_service;
 pub mod entity;
+pub mod event_sourced;
+pub mod timestamped;
+pub mod validation;
 pub mod value_object;
 
 #[cfg(feature = "mcp")]
@@ -21,5 +28,8 @@ pub mod mcp;
 pub use aggregate::Aggregate;
 pub use domain_event::DomainEvent;
 pub use domain_service::DomainService;
-pub use entity::HexEntity;
+pub use entity::{HexEntity, Identified};
+pub use event_sourced::EventSourced;
+pub use timestamped::Timestamped;
+pub use validation::Validate;
 pub use value_object::HexValueItem;
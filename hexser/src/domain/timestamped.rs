@@ -0,0 +1,60 @@
+//! Timestamped trait for entities tracking creation and modification time.
+//!
+//! Most entities carry `created_at` and `updated_at`. This trait names the
+//! convention so infrastructure can maintain it generically: `touch` bumps
+//! `updated_at` from a `Clock`, and `TimestampingRepository` in
+//! `crate::adapters` touches every entity it saves. `#[derive(Timestamped)]`
+//! implements the trait for structs with `created_at` and `updated_at`
+//! fields of type `std::time::SystemTime`.
+//!
+//! Revision History
+//! - 2026-10-19T08:25:00Z @AI: Initial Timestamped trait.
+
+/// Trait for entities recording when they were created and last updated.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::domain::Timestamped;
+///
+/// struct Note {
+///     created_at: std::time::SystemTime,
+///     updated_at: std::time::SystemTime,
+/// }
+///
+/// impl Timestamped for Note {
+///     fn created_at(&self) -> std::time::SystemTime {
+///         self.created_at
+///     }
+///
+///     fn updated_at(&self) -> std::time::SystemTime {
+///         self.updated_at
+///     }
+///
+///     fn set_updated_at(&mut self, at: std::time::SystemTime) {
+///         self.updated_at = at;
+///     }
+/// }
+///
+/// let mut note = Note {
+///     created_at: std::time::UNIX_EPOCH,
+///     updated_at: std::time::UNIX_EPOCH,
+/// };
+/// note.touch(&hexser::adapters::FixedClock::at_unix_secs(60));
+/// assert!(note.updated_at() > note.created_at());
+/// ```
+pub trait Timestamped {
+  /// When the entity was created
+  fn created_at(&self) -> std::time::SystemTime;
+
+  /// When the entity was last modified
+  fn updated_at(&self) -> std::time::SystemTime;
+
+  /// Overwrite the modification time
+  fn set_updated_at(&mut self, at: std::time::SystemTime);
+
+  /// Set the modification time to the clock's current time
+  fn touch(&mut self, clock: &dyn crate::ports::clock::Clock) {
+    self.set_updated_at(clock.now());
+  }
+}
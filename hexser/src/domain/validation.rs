@@ -0,0 +1,254 @@
+//! Validation vocabulary for domain types.
+//!
+//! Value objects and other domain types check their invariants with the
+//! same small set of rules: non-empty, length, range, and character-class
+//! patterns. Each rule returns `Ok(())` or a FieldViolation, and
+//! `check_all` evaluates every rule and folds the failures into one
+//! ValidationError, so callers learn about all problems at once instead of
+//! only the first.
+//!
+//! Revision History
+//! - 2026-10-19T05:25:00Z @AI: Initial Validate trait and reusable rule functions.
+
+/// Result of a single validation rule
+pub type RuleResult = Result<(), crate::error::field_violation::FieldViolation>;
+
+/// Trait for domain types that can check their own invariants.
+///
+/// `#[derive(HexValueItem)]` with `#[hex(validate)]` implements
+/// `HexValueItem::validate` by calling this trait, and for newtypes also
+/// generates a `TryFrom` constructor that rejects invalid values.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::domain::validation::{self, Validate};
+/// use hexser::HexResult;
+///
+/// struct Username(String);
+///
+/// impl Validate for Username {
+///     fn validate(&self) -> HexResult<()> {
+///         validation::check_all([
+///             validation::length("username", &self.0, 3, 20),
+///             validation::chars("username", &self.0, "letters, digits or '_'", |c| {
+///                 c.is_ascii_alphanumeric() || c == '_'
+///             }),
+///         ])
+///     }
+/// }
+///
+/// assert!(Username(String::from("ada_99")).validate().is_ok());
+/// match Username(String::from("a!")).validate() {
+///     Err(hexser::Hexserror::Validation(err)) => assert_eq!(err.violations.len(), 2),
+///     _ => panic!("expected validation error"),
+/// }
+/// ```
+pub trait Validate {
+  /// Check this value's invariants, reporting every failed rule.
+  fn validate(&self) -> crate::result::hex_result::HexResult<()>;
+}
+
+/// Evaluate every rule and fold the failures into a single result.
+///
+/// A single failure keeps its own code and field; several failures are
+/// reported under `codes::validation::MULTIPLE_FAILURES` with one
+/// FieldViolation per failed rule.
+pub fn check_all(
+  rules: impl IntoIterator<Item = RuleResult>,
+) -> crate::result::hex_result::HexResult<()> {
+  into_result(rules.into_iter().filter_map(Result::err).collect())
+}
+
+/// Turn accumulated violations into a validation result.
+pub fn into_result(
+  violations: Vec<crate::error::field_violation::FieldViolation>,
+) -> crate::result::hex_result::HexResult<()> {
+  let error = match violations.len() {
+    0 => return Result::Ok(()),
+    1 => {
+      let violation = &violations[0];
+      crate::error::validation_error::ValidationError::new(
+        violation.code.clone(),
+        violation.message.clone(),
+      )
+      .with_field(violation.field.clone())
+    }
+    count => crate::error::validation_error::ValidationError::new(
+      crate::error::codes::validation::MULTIPLE_FAILURES,
+      format!("{} validation failures", count),
+    ),
+  };
+
+  Result::Err(crate::error::hex_error::Hexserror::Validation(
+    error.with_violations(violations),
+  ))
+}
+
+/// Require a string to contain at least one non-whitespace character.
+pub fn non_empty(field: &str, value: &str) -> RuleResult {
+  rule(
+    field,
+    !value.trim().is_empty(),
+    crate::error::codes::validation::REQUIRED_FIELD,
+    || format!("{} is required", field),
+  )
+}
+
+/// Require a string to have between `min` and `max` characters, inclusive.
+pub fn length(field: &str, value: &str, min: usize, max: usize) -> RuleResult {
+  let count = value.chars().count();
+  rule(
+    field,
+    count >= min && count <= max,
+    crate::error::codes::validation::OUT_OF_RANGE,
+    || {
+      format!(
+        "{} must be between {} and {} characters, got {}",
+        field, min, max, count
+      )
+    },
+  )
+}
+
+/// Require a value to lie within `min..=max`.
+pub fn range<T>(field: &str, value: T, min: T, max: T) -> RuleResult
+where
+  T: PartialOrd + std::fmt::Display,
+{
+  rule(
+    field,
+    value >= min && value <= max,
+    crate::error::codes::validation::OUT_OF_RANGE,
+    || {
+      format!(
+        "{} must be between {} and {}, got {}",
+        field, min, max, value
+      )
+    },
+  )
+}
+
+/// Require every character of a string to satisfy `allowed`.
+///
+/// `description` names the allowed characters in the failure message.
+pub fn chars(
+  field: &str,
+  value: &str,
+  description: &str,
+  allowed: impl Fn(char) -> bool,
+) -> RuleResult {
+  let invalid = value.chars().find(|c| !allowed(*c));
+  rule(
+    field,
+    invalid.is_none(),
+    crate::error::codes::validation::INVALID_FORMAT,
+    || {
+      format!(
+        "{} may only contain {}, found {:?}",
+        field,
+        description,
+        invalid.unwrap_or_default()
+      )
+    },
+  )
+}
+
+/// Require a string to start with `prefix`.
+pub fn starts_with(field: &str, value: &str, prefix: &str) -> RuleResult {
+  rule(
+    field,
+    value.starts_with(prefix),
+    crate::error::codes::validation::INVALID_FORMAT,
+    || format!("{} must start with '{}'", field, prefix),
+  )
+}
+
+/// Require a string to contain `needle`.
+pub fn contains(field: &str, value: &str, needle: &str) -> RuleResult {
+  rule(
+    field,
+    value.contains(needle),
+    crate::error::codes::validation::INVALID_FORMAT,
+    || format!("{} must contain '{}'", field, needle),
+  )
+}
+
+fn rule(field: &str, valid: bool, code: &str, message: impl FnOnce() -> String) -> RuleResult {
+  if valid {
+    Result::Ok(())
+  } else {
+    Result::Err(crate::error::field_violation::FieldViolation::new(
+      field,
+      code,
+      message(),
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn validation_error(
+    result: crate::result::hex_result::HexResult<()>,
+  ) -> crate::error::validation_error::ValidationError {
+    match result {
+      Result::Err(crate::error::hex_error::Hexserror::Validation(err)) => err,
+      other => panic!("expected validation error, got {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_rules_pass_for_valid_values() {
+    assert!(
+      check_all([
+        non_empty("name", "Ada"),
+        length("name", "Ada", 1, 3),
+        range("age", 36, 0, 130),
+        chars("code", "AB12", "ASCII letters or digits", |c| c
+          .is_ascii_alphanumeric()),
+        starts_with("sku", "SKU-1", "SKU-"),
+        contains("email", "a@b.c", "@"),
+      ])
+      .is_ok()
+    );
+  }
+
+  #[test]
+  fn test_check_all_accumulates_failures() {
+    let err = validation_error(check_all([
+      non_empty("name", " "),
+      length("code", "héllo", 1, 4),
+      chars("code", "héllo", "ASCII letters", |c| {
+        c.is_ascii_alphabetic()
+      }),
+    ]));
+
+    assert_eq!(err.code, crate::error::codes::validation::MULTIPLE_FAILURES);
+    let codes: Vec<&str> = err.violations.iter().map(|v| v.code.as_str()).collect();
+    assert_eq!(
+      codes,
+      vec![
+        crate::error::codes::validation::REQUIRED_FIELD,
+        crate::error::codes::validation::OUT_OF_RANGE,
+        crate::error::codes::validation::INVALID_FORMAT,
+      ]
+    );
+    assert_eq!(
+      err.violations[1].message,
+      "code must be between 1 and 4 characters, got 5"
+    );
+    assert_eq!(
+      err.violations[2].message,
+      "code may only contain ASCII letters, found 'é'"
+    );
+  }
+
+  #[test]
+  fn test_single_failure_keeps_field() {
+    let err = validation_error(check_all([range("qty", 0, 1, 10)]));
+    assert_eq!(err.code, crate::error::codes::validation::OUT_OF_RANGE);
+    assert_eq!(err.field, Some(String::from("qty")));
+  }
+}
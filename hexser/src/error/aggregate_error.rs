@@ -0,0 +1,122 @@
+//! Aggregate error type for batch operations.
+//!
+//! Provides AggregateError struct bundling several Hexserror values into
+//! one, so batch paths can fail with a single error without losing the
+//! detail of each failed item. Sub-errors keep their order and are
+//! serialized as an `errors` array.
+//!
+//! Revision History
+//! - 2026-10-19T12:45:00Z @AI: Render context through the shared write_context helper.
+//! - 2026-10-19T12:35:00Z @AI: Keep the severity override private behind severity_override.
+//! - 2026-10-19T11:45:00Z @AI: Leave severity serialization to Hexserror.
+//! - 2026-10-19T01:55:00Z @AI: Initial AggregateError struct.
+
+/// Aggregate error wrapping the failures of a batch
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AggregateError {
+  /// Error code, `codes::batch::AGGREGATE`
+  pub code: String,
+  /// Summary of how many operations failed
+  pub message: String,
+  /// The individual failures, in the order they occurred
+  pub errors: Vec<crate::error::hex_error::Hexserror>,
+  #[cfg_attr(feature = "serde", serde(default, skip_serializing))]
+  severity: Option<crate::error::severity::Severity>,
+  /// Key/value breadcrumbs attached while the error propagates
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")
+  )]
+  pub context: std::collections::BTreeMap<String, String>,
+  /// Optional source code location
+  #[cfg_attr(
+    feature = "serde",
+    serde(skip_serializing_if = "crate::error::env_control::should_skip_location")
+  )]
+  pub location: Option<crate::error::source_location::SourceLocation>,
+}
+
+impl AggregateError {
+  /// Create new aggregate error from the failures of a batch
+  pub fn new(errors: Vec<crate::error::hex_error::Hexserror>) -> Self {
+    let message = if errors.len() == 1 {
+      String::from("1 operation failed")
+    } else {
+      format!("{} operations failed", errors.len())
+    };
+    Self {
+      code: String::from(crate::error::codes::batch::AGGREGATE),
+      message,
+      errors,
+      severity: None,
+      context: std::collections::BTreeMap::new(),
+      location: None,
+    }
+  }
+
+  /// Override severity (builder pattern)
+  pub fn with_severity(mut self, severity: crate::error::severity::Severity) -> Self {
+    self.severity = Some(severity);
+    self
+  }
+
+  /// Severity set with `with_severity`, if any
+  pub fn severity_override(&self) -> Option<crate::error::severity::Severity> {
+    self.severity
+  }
+
+  /// Attach a context key/value pair (builder pattern)
+  ///
+  /// Distinct keys accumulate; repeating a key replaces its earlier value.
+  pub fn with_context(mut self, key: impl Into<String>, value: impl std::fmt::Display) -> Self {
+    self.context.insert(key.into(), value.to_string());
+    self
+  }
+
+  /// Add source location (builder pattern)
+  pub fn with_location(mut self, location: crate::error::source_location::SourceLocation) -> Self {
+    self.location = Some(location);
+    self
+  }
+}
+
+impl std::fmt::Display for AggregateError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Error [{}]: {}", self.code, self.message)?;
+
+    for (index, err) in self.errors.iter().enumerate() {
+      let nested = err.to_string().replace('\n', "\n    ");
+      write!(f, "\n  {}. {}", index + 1, nested)?;
+    }
+
+    crate::error::context::write_context(f, &self.context)?;
+
+    if let Some(ref location) = self.location {
+      write!(f, "\nSource: {}", location)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl std::error::Error for AggregateError {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_display_lists_each_sub_error() {
+    let err = AggregateError::new(vec![
+      crate::error::hex_error::Hexserror::validation("Title is empty"),
+      crate::error::hex_error::Hexserror::not_found("Order", "42"),
+    ]);
+
+    let display = err.to_string();
+    assert!(display.starts_with("Error [E_AGGREGATE]: 2 operations failed\n  1. "));
+    assert!(display.contains("Title is empty"));
+    assert!(display.contains("\n  2. "));
+    assert!(display.contains("42"));
+  }
+}
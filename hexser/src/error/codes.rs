@@ -3,9 +3,24 @@
 //! This module provides a centralized registry of all error codes used
 //! throughout the hex crate. Each error code is documented with its meaning,
 //! when it occurs, and how to resolve it. Error codes follow the format
-//! E_HEX_XXX where XXX is a three-digit number.
+//! E_HEX_XXX where XXX is a three-digit number. A few cross-cutting codes
+//! are deliberately unnumbered: `E_AGGREGATE`, which wraps other codes,
+//! `E_FORBIDDEN` for authorization, `E_TIMEOUT`, `E_PANIC`, `E_CANCELLED`
+//! and `E_LOCK`. `all_codes` enumerates every registered code so projects
+//! can assert that no undocumented codes are used.
 //!
 //! Revision History
+//! - 2026-10-19T11:25:00Z @AI: Add handler::CANCELLED for cancelled async directives.
+//! - 2026-10-19T11:15:00Z @AI: Add lock::POISONED for poisoned shared locks.
+//! - 2026-10-19T11:05:00Z @AI: Add handler::PANIC for panics caught by SafeHandler.
+//! - 2026-10-19T10:55:00Z @AI: Add timeout::EXCEEDED for query timeouts.
+//! - 2026-10-19T06:15:00Z @AI: Add adapter::UNSUPPORTED_OPERATION.
+//! - 2026-10-19T05:55:00Z @AI: Add auth::FORBIDDEN for denied authorization checks.
+//! - 2026-10-19T04:25:00Z @AI: Add port::TYPE_MISMATCH for PortRegistry lookups.
+//! - 2026-10-19T01:55:00Z @AI: Add batch::AGGREGATE for aggregated batch failures.
+//! - 2026-10-18T21:00:00Z @AI: Add all_codes enumeration and is_registered checker.
+//! - 2026-10-18T16:30:00Z @AI: Add container codes for ContainerError conversion.
+//! - 2026-10-18T10:30:00Z @AI: Add validation::MULTIPLE_FAILURES for accumulated validation errors.
 //! - 2025-10-01T00:02:00Z @AI: Initial error code registry for Phase 1.
 
 /// Domain layer error codes (E_HEX_001 - E_HEX_099).
@@ -48,6 +63,12 @@ pub mod port {
   /// Occurs when a port operation times out.
   /// Resolution: Increase timeout or check port responsiveness.
   pub const PORT_TIMEOUT: &str = "E_HEX_102";
+
+  /// Port type mismatch.
+  ///
+  /// Occurs when a registered adapter is retrieved as a different port type.
+  /// Resolution: Request the same trait object type used at registration.
+  pub const TYPE_MISMATCH: &str = "E_HEX_103";
 }
 
 /// Adapter layer error codes (E_HEX_200 - E_HEX_299).
@@ -69,6 +90,12 @@ pub mod adapter {
   /// Occurs when mapping between representations fails.
   /// Resolution: Verify data structure compatibility.
   pub const MAPPING_FAILURE: &str = "E_HEX_202";
+
+  /// Operation not supported by this adapter.
+  ///
+  /// Occurs when calling an optional port method the adapter does not implement.
+  /// Resolution: Override the method in the adapter or use a supported alternative.
+  pub const UNSUPPORTED_OPERATION: &str = "E_HEX_203";
 }
 
 /// Validation error codes (E_HEX_300 - E_HEX_399).
@@ -90,6 +117,12 @@ pub mod validation {
   /// Occurs when value exceeds allowed range.
   /// Resolution: Provide value within valid range.
   pub const OUT_OF_RANGE: &str = "E_HEX_302";
+
+  /// Multiple validation failures.
+  ///
+  /// Occurs when several fields fail validation at once.
+  /// Resolution: Inspect each listed field violation and correct the input.
+  pub const MULTIPLE_FAILURES: &str = "E_HEX_303";
 }
 
 /// Resource error codes (E_HEX_400 - E_HEX_499).
@@ -134,33 +167,178 @@ pub mod io {
   pub const IO_FAILURE: &str = "E_HEX_502";
 }
 
+/// Dependency injection container error codes (E_HEX_600 - E_HEX_699).
+pub mod container {
+  /// Service not registered.
+  ///
+  /// Occurs when resolving a service that was never registered.
+  /// Resolution: Register the service before resolving it.
+  pub const SERVICE_NOT_FOUND: &str = "E_HEX_600";
+
+  /// Circular dependency.
+  ///
+  /// Occurs when services depend on each other in a cycle.
+  /// Resolution: Break the cycle with dependency inversion.
+  pub const CIRCULAR_DEPENDENCY: &str = "E_HEX_601";
+
+  /// Provider failure.
+  ///
+  /// Occurs when a provider cannot create a service instance.
+  /// Resolution: Check the provider and its dependencies.
+  pub const PROVIDER_FAILED: &str = "E_HEX_602";
+
+  /// Duplicate registration.
+  ///
+  /// Occurs when registering a service name twice.
+  /// Resolution: Remove the duplicate or use a different name.
+  pub const DUPLICATE_REGISTRATION: &str = "E_HEX_603";
+}
+
+/// Batch operation error codes.
+pub mod batch {
+  /// Several operations failed.
+  ///
+  /// Occurs when a batch reports all of its failures as one error.
+  /// Resolution: Inspect each sub-error and retry the failed items.
+  pub const AGGREGATE: &str = "E_AGGREGATE";
+}
+
+/// Authorization error codes.
+pub mod auth {
+  /// Caller is not allowed to perform the operation.
+  ///
+  /// Occurs when an authorization check denies a directive for the principal.
+  /// Resolution: Act as a principal holding the required role or permission.
+  pub const FORBIDDEN: &str = "E_FORBIDDEN";
+}
+
+/// Timeout error codes.
+pub mod timeout {
+  /// Operation exceeded its time budget.
+  ///
+  /// Occurs when a repository query runs past the timeout it was given.
+  /// Resolution: Narrow the filter, add a limit, or allow a longer timeout.
+  pub const EXCEEDED: &str = "E_TIMEOUT";
+}
+
+/// Handler error codes.
+pub mod handler {
+  /// Handler panicked instead of returning an error.
+  ///
+  /// Occurs when a handler wrapped in SafeHandler panics.
+  /// Resolution: Fix the bug behind the panic; return a Hexserror for expected failures.
+  pub const PANIC: &str = "E_PANIC";
+
+  /// Directive was cancelled before it completed.
+  ///
+  /// Occurs when an async directive's cancellation token fires or its timeout passes.
+  /// Resolution: Retry the directive if the cancellation was not intended.
+  pub const CANCELLED: &str = "E_CANCELLED";
+}
+
+/// Lock error codes.
+pub mod lock {
+  /// Shared lock is poisoned.
+  ///
+  /// Occurs when a previous operation panicked while holding a shared lock.
+  /// Resolution: Recreate the shared resource and fix the panicking operation.
+  pub const POISONED: &str = "E_LOCK";
+}
+
+/// Every registered error code, in numeric order.
+const ALL_CODES: &[&str] = &[
+  domain::INVARIANT_EMPTY,
+  domain::INVARIANT_VIOLATION,
+  domain::INVALID_STATE_TRANSITION,
+  port::COMMUNICATION_FAILURE,
+  port::PORT_NOT_FOUND,
+  port::PORT_TIMEOUT,
+  port::TYPE_MISMATCH,
+  adapter::DB_CONNECTION_FAILURE,
+  adapter::API_FAILURE,
+  adapter::MAPPING_FAILURE,
+  adapter::UNSUPPORTED_OPERATION,
+  validation::REQUIRED_FIELD,
+  validation::INVALID_FORMAT,
+  validation::OUT_OF_RANGE,
+  validation::MULTIPLE_FAILURES,
+  resource::NOT_FOUND,
+  resource::ALREADY_EXISTS,
+  resource::CONFLICT,
+  io::FILE_NOT_FOUND,
+  io::PERMISSION_DENIED,
+  io::IO_FAILURE,
+  container::SERVICE_NOT_FOUND,
+  container::CIRCULAR_DEPENDENCY,
+  container::PROVIDER_FAILED,
+  container::DUPLICATE_REGISTRATION,
+  batch::AGGREGATE,
+  auth::FORBIDDEN,
+  timeout::EXCEEDED,
+  handler::PANIC,
+  handler::CANCELLED,
+  lock::POISONED,
+];
+
+/// All error codes defined in this registry.
+///
+/// A unit test in this module checks the list against the constants declared
+/// in the source file, so a new constant cannot be added without listing it.
+///
+/// # Example
+///
+/// ```rust
+/// let codes = hexser::error::codes::all_codes();
+/// assert!(codes.contains(&hexser::error::codes::adapter::API_FAILURE));
+/// ```
+pub fn all_codes() -> &'static [&'static str] {
+  ALL_CODES
+}
+
+/// Check whether a code is defined in this registry.
+///
+/// Useful in a project test that scans its own sources for codes passed to
+/// error constructors and rejects ad-hoc, undocumented ones.
+///
+/// # Example
+///
+/// ```rust
+/// assert!(hexser::error::codes::is_registered("E_HEX_200"));
+/// assert!(!hexser::error::codes::is_registered("E_MY_ADHOC"));
+/// ```
+pub fn is_registered(code: &str) -> bool {
+  ALL_CODES.contains(&code)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
   #[test]
-  fn test_error_codes_unique() {
-    let codes = vec![
-      domain::INVARIANT_EMPTY,
-      domain::INVARIANT_VIOLATION,
-      domain::INVALID_STATE_TRANSITION,
-      port::COMMUNICATION_FAILURE,
-      port::PORT_NOT_FOUND,
-      port::PORT_TIMEOUT,
-      adapter::DB_CONNECTION_FAILURE,
-      adapter::API_FAILURE,
-      adapter::MAPPING_FAILURE,
-      validation::REQUIRED_FIELD,
-      validation::INVALID_FORMAT,
-      validation::OUT_OF_RANGE,
-      resource::NOT_FOUND,
-      resource::ALREADY_EXISTS,
-      resource::CONFLICT,
-      io::FILE_NOT_FOUND,
-      io::PERMISSION_DENIED,
-      io::IO_FAILURE,
-    ];
+  fn test_all_codes_matches_declared_constants() {
+    // Test: Every `pub const` code in this file appears in all_codes, and vice versa.
+    // Justification: The enumeration must stay in sync with the defined constants.
+    let source = include_str!("codes.rs");
+    let declared: std::collections::BTreeSet<&str> = source
+      .lines()
+      .map(str::trim)
+      .filter(|line| line.starts_with("pub const ") && line.ends_with("\";"))
+      .filter_map(|line| line.split('"').nth(1))
+      .collect();
+    let listed: std::collections::BTreeSet<&str> = all_codes().iter().copied().collect();
+
+    assert_eq!(declared, listed);
+  }
+
+  #[test]
+  fn test_is_registered() {
+    assert!(is_registered(container::SERVICE_NOT_FOUND));
+    assert!(!is_registered("E_CNT_004"));
+  }
 
+  #[test]
+  fn test_error_codes_unique() {
+    let codes = all_codes();
     let unique_codes: std::collections::HashSet<_> = codes.iter().collect();
     assert_eq!(
       codes.len(),
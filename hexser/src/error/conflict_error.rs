@@ -5,6 +5,11 @@
 //! Includes context about the conflicting resource.
 //!
 //! Revision History
+//! - 2026-10-19T12:45:00Z @AI: Render context through the shared write_context helper.
+//! - 2026-10-19T12:35:00Z @AI: Keep the severity override private behind severity_override.
+//! - 2026-10-19T11:45:00Z @AI: Leave severity serialization to Hexserror.
+//! - 2026-10-18T19:40:00Z @AI: Add key/value context breadcrumbs.
+//! - 2026-10-18T12:30:00Z @AI: Add optional severity override.
 //! - 2025-10-09T21:51:00Z @AI: Add conditional source location serialization via env_control.
 //! - 2025-10-09T21:22:00Z @AI: Add Serde support for rich errors.
 //! - 2025-10-06T02:00:00Z @AI: Fix merge conflict duplicates.
@@ -20,6 +25,14 @@ pub struct ConflictError {
   pub message: String,
   /// Optional ID of existing conflicting resource
   pub existing_id: Option<String>,
+  #[cfg_attr(feature = "serde", serde(default, skip_serializing))]
+  severity: Option<crate::error::severity::Severity>,
+  /// Key/value breadcrumbs attached while the error propagates
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")
+  )]
+  pub context: std::collections::BTreeMap<String, String>,
   /// Optional source code location
   #[cfg_attr(
     feature = "serde",
@@ -35,6 +48,8 @@ impl ConflictError {
       code: String::from(crate::error::codes::resource::CONFLICT),
       message: message.into(),
       existing_id: None,
+      severity: None,
+      context: std::collections::BTreeMap::new(),
       location: None,
     }
   }
@@ -45,6 +60,25 @@ impl ConflictError {
     self
   }
 
+  /// Override severity (builder pattern)
+  pub fn with_severity(mut self, severity: crate::error::severity::Severity) -> Self {
+    self.severity = Some(severity);
+    self
+  }
+
+  /// Severity set with `with_severity`, if any
+  pub fn severity_override(&self) -> Option<crate::error::severity::Severity> {
+    self.severity
+  }
+
+  /// Attach a context key/value pair (builder pattern)
+  ///
+  /// Distinct keys accumulate; repeating a key replaces its earlier value.
+  pub fn with_context(mut self, key: impl Into<String>, value: impl std::fmt::Display) -> Self {
+    self.context.insert(key.into(), value.to_string());
+    self
+  }
+
   /// Add source location (builder pattern)
   pub fn with_location(mut self, location: crate::error::source_location::SourceLocation) -> Self {
     self.location = Some(location);
@@ -65,6 +99,8 @@ impl std::fmt::Display for ConflictError {
       "\nNext Steps: Resolve conflict or use different identifier"
     )?;
 
+    crate::error::context::write_context(f, &self.context)?;
+
     if let Some(ref location) = self.location {
       write!(f, "\nSource: {}", location)?;
     }
@@ -0,0 +1,47 @@
+//! Shared rendering of error context breadcrumbs.
+//!
+//! Every rich error struct keeps `with_context` key/value pairs in a
+//! BTreeMap and prints them the same way in its Display output. The helper
+//! here writes that line once so the format cannot drift between structs.
+//!
+//! Revision History
+//! - 2026-10-19T12:45:00Z @AI: Extract write_context from the error Display impls.
+
+/// Write context as `\nContext: key=value ...` in key order; nothing when empty.
+pub(crate) fn write_context(
+  f: &mut std::fmt::Formatter<'_>,
+  context: &std::collections::BTreeMap<String, String>,
+) -> std::fmt::Result {
+  if context.is_empty() {
+    return Ok(());
+  }
+  write!(f, "\nContext:")?;
+  for (key, value) in context {
+    write!(f, " {}={}", key, value)?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  struct Shown(std::collections::BTreeMap<String, String>);
+
+  impl std::fmt::Display for Shown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      super::write_context(f, &self.0)
+    }
+  }
+
+  #[test]
+  fn test_write_context_sorted_and_empty() {
+    let mut context = std::collections::BTreeMap::new();
+    assert_eq!(Shown(context.clone()).to_string(), "");
+
+    context.insert(String::from("user_id"), String::from("7"));
+    context.insert(String::from("order_id"), String::from("42"));
+    assert_eq!(
+      Shown(context).to_string(),
+      "\nContext: order_id=42 user_id=7"
+    );
+  }
+}
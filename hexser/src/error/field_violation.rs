@@ -0,0 +1,55 @@
+//! Field violation type for accumulated validation failures.
+//!
+//! A FieldViolation records a single failed rule for a single field.
+//! ValidationError carries a list of them when several fields are
+//! validated together and all failures are reported at once.
+//!
+//! Revision History
+//! - 2026-10-18T10:30:00Z @AI: Initial FieldViolation struct.
+
+/// A single validation rule failure for a named field
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldViolation {
+  /// Field name that failed validation
+  pub field: String,
+
+  /// Error code from codes::validation module
+  pub code: String,
+
+  /// Human-readable error message
+  pub message: String,
+}
+
+impl FieldViolation {
+  /// Create new field violation
+  pub fn new(
+    field: impl Into<String>,
+    code: impl Into<String>,
+    message: impl Into<String>,
+  ) -> Self {
+    Self {
+      field: field.into(),
+      code: code.into(),
+      message: message.into(),
+    }
+  }
+}
+
+impl std::fmt::Display for FieldViolation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "[{}] {}: {}", self.code, self.field, self.message)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_field_violation_display() {
+    let violation = FieldViolation::new("email", "E_HEX_300", "Email is required");
+    let display = format!("{}", violation);
+    assert_eq!(display, "[E_HEX_300] email: Email is required");
+  }
+}
@@ -6,6 +6,17 @@
 //! and suggestions for remediation. Designed for both humans and AI agents.
 //!
 //! Revision History
+//! - 2026-10-19T12:35:00Z @AI: Read severity overrides through severity_override.
+//! - 2026-10-19T11:45:00Z @AI: Serialize the effective severity for every variant.
+//! - 2026-10-19T05:55:00Z @AI: Add forbidden constructor and is_forbidden.
+//! - 2026-10-19T04:35:00Z @AI: Add localized rendering through a MessageCatalog.
+//! - 2026-10-19T01:55:00Z @AI: Add Aggregate variant, aggregate constructor and sub_errors.
+//! - 2026-10-19T00:35:00Z @AI: Add with_redacted_context and with_fields.
+//! - 2026-10-19T00:05:00Z @AI: Add not_found_fmt accepting any Display id.
+//! - 2026-10-18T23:45:00Z @AI: Add layer classification and is_* variant helpers.
+//! - 2026-10-18T23:00:00Z @AI: Add code accessor.
+//! - 2026-10-18T19:40:00Z @AI: Add with_context breadcrumbs and context accessor.
+//! - 2026-10-18T12:30:00Z @AI: Add severity accessor, per-variant defaults, and with_severity builder.
 //! - 2025-10-09T21:22:00Z @AI: Add Serde support for rich errors.
 //! - 2025-10-06T00:00:00Z @AI: Refactor to wrap layer-specific error structs for Phase 1.
 //! - 2025-10-01T00:00:00Z @AI: Initial Hexserror enum with rich error information.
@@ -16,6 +27,7 @@ use crate::error::RichError;
 ///
 /// Wraps layer-specific error types with full error chaining support.
 /// Implements std::error::Error for seamless integration with Rust ecosystem.
+/// With the `serde` feature, JSON always carries the effective `severity`.
 ///
 /// # Example
 ///
@@ -32,7 +44,7 @@ use crate::error::RichError;
 /// }
 /// ```
 #[derive(Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum Hexserror {
   /// Domain layer error
   Domain(crate::error::domain_error::DomainError),
@@ -46,6 +58,8 @@ pub enum Hexserror {
   NotFound(crate::error::not_found_error::NotFoundError),
   /// Conflict error
   Conflict(crate::error::conflict_error::ConflictError),
+  /// Several errors from a batch operation
+  Aggregate(crate::error::aggregate_error::AggregateError),
 }
 
 impl Hexserror {
@@ -92,11 +106,54 @@ impl Hexserror {
     ))
   }
 
+  /// Create not found error from any displayable id
+  ///
+  /// Shorthand for `not_found(resource, &id.to_string())` so numeric and
+  /// newtype ids need no conversion at the call site.
+  pub fn not_found_fmt(resource: &str, id: impl std::fmt::Display) -> Self {
+    Self::not_found(resource, &id.to_string())
+  }
+
   /// Create conflict error
   pub fn conflict(message: &str) -> Self {
     Self::Conflict(crate::error::conflict_error::ConflictError::new(message))
   }
 
+  /// Create an authorization denial with code `E_FORBIDDEN`
+  ///
+  /// Denials are domain errors: who may do what is a business rule.
+  pub fn forbidden(message: &str) -> Self {
+    Self::domain(crate::error::codes::auth::FORBIDDEN, message)
+      .with_next_step("Retry as a principal permitted to perform this operation")
+  }
+
+  /// Combine the failures of a batch into one error
+  ///
+  /// The result has code `E_AGGREGATE`, lists every sub-error in its
+  /// Display output, and serializes them as an `errors` array.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// let err = hexser::Hexserror::aggregate(vec![
+  ///     hexser::Hexserror::validation("Title is empty"),
+  ///     hexser::Hexserror::not_found("Article", "a-1"),
+  /// ]);
+  /// assert_eq!(err.code(), "E_AGGREGATE");
+  /// assert_eq!(err.sub_errors().len(), 2);
+  /// ```
+  pub fn aggregate(errors: Vec<Hexserror>) -> Self {
+    Self::Aggregate(crate::error::aggregate_error::AggregateError::new(errors))
+  }
+
+  /// Get the errors wrapped by an aggregate; empty for other variants
+  pub fn sub_errors(&self) -> &[Hexserror] {
+    match self {
+      Self::Aggregate(err) => &err.errors,
+      _ => &[],
+    }
+  }
+
   /// Add next step (builder pattern)
   pub fn with_next_step(self, step: &str) -> Self {
     match self {
@@ -145,6 +202,229 @@ impl Hexserror {
     }
   }
 
+  /// Override severity (builder pattern)
+  pub fn with_severity(self, severity: crate::error::severity::Severity) -> Self {
+    match self {
+      Self::Domain(err) => Self::Domain(err.with_severity(severity)),
+      Self::Port(err) => Self::Port(err.with_severity(severity)),
+      Self::Adapter(err) => Self::Adapter(err.with_severity(severity)),
+      Self::Validation(err) => Self::Validation(err.with_severity(severity)),
+      Self::NotFound(err) => Self::NotFound(err.with_severity(severity)),
+      Self::Conflict(err) => Self::Conflict(err.with_severity(severity)),
+      Self::Aggregate(err) => Self::Aggregate(err.with_severity(severity)),
+    }
+  }
+
+  /// Attach a context key/value breadcrumb (builder pattern)
+  ///
+  /// Breadcrumbs such as `user_id` or `order_id` accumulate across calls as
+  /// the error propagates; repeating a key replaces its earlier value. They
+  /// appear in Display output and as a `context` map in serialized JSON.
+  pub fn with_context(self, key: &str, value: impl std::fmt::Display) -> Self {
+    match self {
+      Self::Domain(err) => Self::Domain(err.with_context(key, value)),
+      Self::Port(err) => Self::Port(err.with_context(key, value)),
+      Self::Adapter(err) => Self::Adapter(err.with_context(key, value)),
+      Self::Validation(err) => Self::Validation(err.with_context(key, value)),
+      Self::NotFound(err) => Self::NotFound(err.with_context(key, value)),
+      Self::Conflict(err) => Self::Conflict(err.with_context(key, value)),
+      Self::Aggregate(err) => Self::Aggregate(err.with_context(key, value)),
+    }
+  }
+
+  /// Attach a context key whose value must never be rendered
+  ///
+  /// Records that `key` was involved without disclosing it; the value is
+  /// stored as `***`. Equivalent to passing a `Redacted` value to `with_context`.
+  pub fn with_redacted_context(self, key: &str) -> Self {
+    self.with_context(key, crate::error::redaction::REDACTED)
+  }
+
+  /// Attach every field of `fields` as a context breadcrumb
+  ///
+  /// Fields marked `#[hex(redact)]` on a `HexContext` derive arrive already
+  /// replaced by `***`, so secrets cannot leak through this path.
+  pub fn with_fields(self, fields: &impl crate::error::redaction::ContextFields) -> Self {
+    fields
+      .context_fields()
+      .into_iter()
+      .fold(self, |err, (key, value)| err.with_context(key, value))
+  }
+
+  /// Render this error's message in `locale` using `catalog`
+  ///
+  /// The catalog template for this code and locale is filled from the
+  /// context breadcrumbs, plus `resource` and `id` for not-found errors and
+  /// `field` for validation errors. Falls back to the untranslated message
+  /// when the catalog has no template or the template names a value the
+  /// error does not carry.
+  pub fn localized(
+    &self,
+    catalog: &crate::error::message_catalog::MessageCatalog,
+    locale: &str,
+  ) -> String {
+    let Some(template) = catalog.lookup(self.code(), locale) else {
+      return self.default_message();
+    };
+    let mut values: Vec<(&str, &str)> = self
+      .context()
+      .iter()
+      .map(|(key, value)| (key.as_str(), value.as_str()))
+      .collect();
+    match self {
+      Self::NotFound(err) => {
+        values.push(("resource", &err.resource));
+        values.push(("id", &err.id));
+      }
+      Self::Validation(err) => values.extend(err.field.as_deref().map(|f| ("field", f))),
+      _ => {}
+    }
+    let args: Vec<(&str, &dyn std::fmt::Display)> = values
+      .iter()
+      .map(|(key, value)| (*key, value as &dyn std::fmt::Display))
+      .collect();
+    let complete = crate::error::message_template::placeholders(template)
+      .iter()
+      .all(|name| args.iter().any(|(key, _)| key == name));
+    if complete {
+      crate::error::message_template::render(template, &args)
+    } else {
+      self.default_message()
+    }
+  }
+
+  /// Untranslated message, without code, guidance, or context
+  fn default_message(&self) -> String {
+    match self {
+      Self::Domain(err) => err.message.clone(),
+      Self::Port(err) => err.message.clone(),
+      Self::Adapter(err) => err.message.clone(),
+      Self::Validation(err) => err.message.clone(),
+      Self::NotFound(err) => format!("{} not found with id '{}'", err.resource, err.id),
+      Self::Conflict(err) => err.message.clone(),
+      Self::Aggregate(err) => err.message.clone(),
+    }
+  }
+
+  /// Get context breadcrumbs attached via `with_context`, ordered by key
+  pub fn context(&self) -> &std::collections::BTreeMap<String, String> {
+    match self {
+      Self::Domain(err) => &err.context,
+      Self::Port(err) => &err.context,
+      Self::Adapter(err) => &err.context,
+      Self::Validation(err) => &err.context,
+      Self::NotFound(err) => &err.context,
+      Self::Conflict(err) => &err.context,
+      Self::Aggregate(err) => &err.context,
+    }
+  }
+
+  /// Get the architectural layer that produced this error
+  ///
+  /// Validation, not-found, conflict, and aggregate errors are cross-cutting
+  /// and have no layer.
+  pub fn layer(&self) -> Option<crate::graph::layer::Layer> {
+    match self {
+      Self::Domain(_) => Some(crate::graph::layer::Layer::Domain),
+      Self::Port(_) => Some(crate::graph::layer::Layer::Port),
+      Self::Adapter(_) => Some(crate::graph::layer::Layer::Adapter),
+      Self::Validation(_) | Self::NotFound(_) | Self::Conflict(_) | Self::Aggregate(_) => None,
+    }
+  }
+
+  /// Check whether this is a domain error
+  pub fn is_domain(&self) -> bool {
+    matches!(self, Self::Domain(_))
+  }
+
+  /// Check whether this is a port error
+  pub fn is_port(&self) -> bool {
+    matches!(self, Self::Port(_))
+  }
+
+  /// Check whether this is an adapter error
+  pub fn is_adapter(&self) -> bool {
+    matches!(self, Self::Adapter(_))
+  }
+
+  /// Check whether this is a validation error
+  pub fn is_validation(&self) -> bool {
+    matches!(self, Self::Validation(_))
+  }
+
+  /// Check whether this is a not found error
+  pub fn is_not_found(&self) -> bool {
+    matches!(self, Self::NotFound(_))
+  }
+
+  /// Check whether this is a conflict error
+  pub fn is_conflict(&self) -> bool {
+    matches!(self, Self::Conflict(_))
+  }
+
+  /// Check whether this is an authorization denial
+  pub fn is_forbidden(&self) -> bool {
+    self.code() == crate::error::codes::auth::FORBIDDEN
+  }
+
+  /// Check whether this is an aggregate of batch errors
+  pub fn is_aggregate(&self) -> bool {
+    matches!(self, Self::Aggregate(_))
+  }
+
+  /// Get the error code of the wrapped error
+  pub fn code(&self) -> &str {
+    match self {
+      Self::Domain(err) => &err.code,
+      Self::Port(err) => &err.code,
+      Self::Adapter(err) => &err.code,
+      Self::Validation(err) => &err.code,
+      Self::NotFound(err) => &err.code,
+      Self::Conflict(err) => &err.code,
+      Self::Aggregate(err) => &err.code,
+    }
+  }
+
+  /// Get severity, using the explicit override if set or the default otherwise
+  ///
+  /// The override is set with `with_severity` and read back with each error
+  /// struct's `severity_override`; without one, `default_severity` applies.
+  /// With the `serde` feature this effective value is what gets serialized,
+  /// and deserializing it restores it as the override.
+  pub fn severity(&self) -> crate::error::severity::Severity {
+    let explicit = match self {
+      Self::Domain(err) => err.severity_override(),
+      Self::Port(err) => err.severity_override(),
+      Self::Adapter(err) => err.severity_override(),
+      Self::Validation(err) => err.severity_override(),
+      Self::NotFound(err) => err.severity_override(),
+      Self::Conflict(err) => err.severity_override(),
+      Self::Aggregate(err) => err.severity_override(),
+    };
+    explicit.unwrap_or_else(|| self.default_severity())
+  }
+
+  /// Get default severity for this error's variant and code
+  ///
+  /// Adapter connection failures are Critical, missing resources and
+  /// conflicts are Warning, aggregates take the highest severity among
+  /// their sub-errors, and all other errors are Error.
+  pub fn default_severity(&self) -> crate::error::severity::Severity {
+    match self {
+      Self::Adapter(err) if err.code == crate::error::codes::adapter::DB_CONNECTION_FAILURE => {
+        crate::error::severity::Severity::Critical
+      }
+      Self::NotFound(_) | Self::Conflict(_) => crate::error::severity::Severity::Warning,
+      Self::Aggregate(err) if !err.errors.is_empty() => err
+        .errors
+        .iter()
+        .map(Hexserror::severity)
+        .max()
+        .unwrap_or(crate::error::severity::Severity::Error),
+      _ => crate::error::severity::Severity::Error,
+    }
+  }
+
   /// Add existing ID to conflict error (builder pattern)
   pub fn with_existing_id(self, id: &str) -> Self {
     match self {
@@ -163,6 +443,7 @@ impl std::fmt::Display for Hexserror {
       Self::Validation(err) => write!(f, "{}", err),
       Self::NotFound(err) => write!(f, "{}", err),
       Self::Conflict(err) => write!(f, "{}", err),
+      Self::Aggregate(err) => write!(f, "{}", err),
     }
   }
 }
@@ -176,10 +457,53 @@ impl std::error::Error for Hexserror {
       Self::Validation(err) => err.source(),
       Self::NotFound(err) => err.source(),
       Self::Conflict(err) => err.source(),
+      Self::Aggregate(err) => err.source(),
     }
   }
 }
 
+/// Variant payload serialized together with the effective severity
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct WithSeverity<'a, E: serde::Serialize> {
+  #[serde(flatten)]
+  error: &'a E,
+  severity: crate::error::severity::Severity,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hexserror {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let severity = self.severity();
+    match self {
+      Self::Domain(error) => serialize_variant(serializer, 0, "Domain", error, severity),
+      Self::Port(error) => serialize_variant(serializer, 1, "Port", error, severity),
+      Self::Adapter(error) => serialize_variant(serializer, 2, "Adapter", error, severity),
+      Self::Validation(error) => serialize_variant(serializer, 3, "Validation", error, severity),
+      Self::NotFound(error) => serialize_variant(serializer, 4, "NotFound", error, severity),
+      Self::Conflict(error) => serialize_variant(serializer, 5, "Conflict", error, severity),
+      Self::Aggregate(error) => serialize_variant(serializer, 6, "Aggregate", error, severity),
+    }
+  }
+}
+
+/// Serialize one externally tagged variant with its effective severity
+#[cfg(feature = "serde")]
+fn serialize_variant<S: serde::Serializer, E: serde::Serialize>(
+  serializer: S,
+  index: u32,
+  variant: &'static str,
+  error: &E,
+  severity: crate::error::severity::Severity,
+) -> Result<S::Ok, S::Error> {
+  serializer.serialize_newtype_variant(
+    "Hexserror",
+    index,
+    variant,
+    &WithSeverity { error, severity },
+  )
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -203,6 +527,49 @@ mod tests {
     assert!(matches!(err, Hexserror::NotFound(_)));
   }
 
+  #[test]
+  fn test_redacted_context_never_renders_value() {
+    struct Login {
+      user: &'static str,
+      password: &'static str,
+    }
+
+    impl crate::error::redaction::ContextFields for Login {
+      fn context_fields(&self) -> Vec<(&'static str, String)> {
+        vec![
+          ("user", self.user.to_string()),
+          ("password", String::from(crate::error::redaction::REDACTED)),
+        ]
+      }
+    }
+
+    let login = Login {
+      user: "alice",
+      password: "hunter2",
+    };
+    let err = Hexserror::validation("Bad credentials")
+      .with_fields(&login)
+      .with_redacted_context("otp")
+      .with_context(
+        "token",
+        crate::error::redaction::Redacted::new(login.password),
+      );
+
+    assert_eq!(err.context()["user"], "alice");
+    assert_eq!(err.context()["password"], "***");
+    assert_eq!(err.context()["otp"], "***");
+    assert_eq!(err.context()["token"], "***");
+    assert!(!err.to_string().contains("hunter2"));
+  }
+
+  #[test]
+  fn test_not_found_fmt_accepts_display_ids() {
+    assert_eq!(
+      Hexserror::not_found_fmt("User", 123).to_string(),
+      Hexserror::not_found("User", "123").to_string()
+    );
+  }
+
   #[test]
   fn test_error_display() {
     let err = Hexserror::validation("Test message");
@@ -268,6 +635,173 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_default_severity_by_variant() {
+    assert_eq!(
+      Hexserror::validation("Invalid").severity(),
+      crate::error::severity::Severity::Error
+    );
+    assert_eq!(
+      Hexserror::domain("E_HEX_002", "Broken invariant").severity(),
+      crate::error::severity::Severity::Error
+    );
+    assert_eq!(
+      Hexserror::adapter(
+        crate::error::codes::adapter::DB_CONNECTION_FAILURE,
+        "Connection lost"
+      )
+      .severity(),
+      crate::error::severity::Severity::Critical
+    );
+    assert_eq!(
+      Hexserror::not_found("User", "1").severity(),
+      crate::error::severity::Severity::Warning
+    );
+  }
+
+  #[test]
+  fn test_with_severity_overrides_default() {
+    let err = Hexserror::validation("Deprecated field used")
+      .with_severity(crate::error::severity::Severity::Info);
+
+    assert_eq!(err.severity(), crate::error::severity::Severity::Info);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_effective_severity_serialized() {
+    let err =
+      Hexserror::validation("Invalid").with_severity(crate::error::severity::Severity::Warning);
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(json["Validation"]["severity"], "Warning");
+
+    let json = serde_json::to_value(Hexserror::not_found("User", "1")).unwrap();
+    assert_eq!(json["NotFound"]["severity"], "Warning");
+
+    let err = Hexserror::aggregate(vec![Hexserror::adapter(
+      crate::error::codes::adapter::DB_CONNECTION_FAILURE,
+      "Connection lost",
+    )]);
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(json["Aggregate"]["severity"], "Critical");
+    assert_eq!(
+      json["Aggregate"]["errors"][0]["Adapter"]["severity"],
+      "Critical"
+    );
+
+    let back: Hexserror = serde_json::from_value(json).unwrap();
+    assert_eq!(back.severity(), crate::error::severity::Severity::Critical);
+  }
+
+  #[test]
+  fn test_layer_classification_per_variant() {
+    let cases = [
+      (
+        Hexserror::domain("E_HEX_001", "Empty"),
+        Some(crate::graph::layer::Layer::Domain),
+      ),
+      (
+        Hexserror::port("E_HEX_100", "Unreachable"),
+        Some(crate::graph::layer::Layer::Port),
+      ),
+      (
+        Hexserror::adapter("E_HEX_200", "Down"),
+        Some(crate::graph::layer::Layer::Adapter),
+      ),
+      (Hexserror::validation("Invalid"), None),
+      (Hexserror::not_found("User", "1"), None),
+      (Hexserror::conflict("Exists"), None),
+      (
+        Hexserror::aggregate(vec![Hexserror::conflict("Exists")]),
+        None,
+      ),
+    ];
+
+    for (err, layer) in &cases {
+      assert_eq!(err.layer(), *layer, "{:?}", err);
+      let flags = [
+        err.is_domain(),
+        err.is_port(),
+        err.is_adapter(),
+        err.is_validation(),
+        err.is_not_found(),
+        err.is_conflict(),
+        err.is_aggregate(),
+      ];
+      assert_eq!(flags.iter().filter(|f| **f).count(), 1, "{:?}", err);
+    }
+    assert!(cases[0].0.is_domain());
+    assert!(cases[1].0.is_port());
+    assert!(cases[2].0.is_adapter());
+    assert!(cases[3].0.is_validation());
+    assert!(cases[4].0.is_not_found());
+    assert!(cases[5].0.is_conflict());
+    assert!(cases[6].0.is_aggregate());
+  }
+
+  #[test]
+  fn test_code_accessor() {
+    assert_eq!(Hexserror::domain("E_HEX_001", "Empty").code(), "E_HEX_001");
+    assert_eq!(
+      Hexserror::not_found("User", "1").code(),
+      crate::error::codes::resource::NOT_FOUND
+    );
+  }
+
+  #[test]
+  fn test_with_context_accumulates_distinct_keys() {
+    let err = Hexserror::not_found("Order", "42")
+      .with_context("user_id", 7)
+      .with_context("order_id", "42")
+      .with_context("user_id", 8);
+
+    assert_eq!(err.context().len(), 2);
+    assert_eq!(err.context()["user_id"], "8");
+    assert!(format!("{}", err).contains("\nContext: order_id=42 user_id=8"));
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_context_serialized_as_map() {
+    let err = Hexserror::adapter("E_HEX_200", "Failed").with_context("request_id", "r-1");
+    let json = serde_json::to_string(&err).unwrap();
+    assert!(json.contains("\"context\":{\"request_id\":\"r-1\"}"));
+
+    let json = serde_json::to_string(&Hexserror::validation("Invalid")).unwrap();
+    assert!(!json.contains("context"));
+  }
+
+  #[test]
+  fn test_aggregate_keeps_sub_errors() {
+    let err = Hexserror::aggregate(vec![
+      Hexserror::validation("Title is empty"),
+      Hexserror::adapter(
+        crate::error::codes::adapter::DB_CONNECTION_FAILURE,
+        "Connection lost",
+      ),
+    ])
+    .with_context("batch_id", "b-1");
+
+    assert_eq!(err.code(), crate::error::codes::batch::AGGREGATE);
+    assert_eq!(err.sub_errors().len(), 2);
+    assert!(err.sub_errors()[0].is_validation());
+    assert_eq!(err.severity(), crate::error::severity::Severity::Critical);
+    assert_eq!(err.context()["batch_id"], "b-1");
+    assert!(err.to_string().contains("Connection lost"));
+    assert!(Hexserror::validation("Invalid").sub_errors().is_empty());
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_aggregate_serialized_with_errors_array() {
+    let err = Hexserror::aggregate(vec![Hexserror::not_found("User", "1")]);
+    let json = serde_json::to_value(&err).unwrap();
+
+    let errors = json["Aggregate"]["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["NotFound"]["resource"], "User");
+  }
+
   #[test]
   fn test_error_source_chaining() {
     let inner = std::io::Error::new(std::io::ErrorKind::NotFound, "file not found");
@@ -0,0 +1,114 @@
+//! Translated error messages keyed by error code and locale.
+//!
+//! Error codes stay stable while the text shown to users varies by
+//! language. A MessageCatalog maps a code and a locale such as `de` or
+//! `pt-BR` to a template in the `message_template` syntax; placeholders are
+//! filled from the error's context breadcrumbs when it is rendered with
+//! `Hexserror::localized`.
+//!
+//! Revision History
+//! - 2026-10-19T04:35:00Z @AI: Initial MessageCatalog.
+
+/// Message templates keyed by error code and locale.
+///
+/// Lookups for a regional locale such as `pt-BR` fall back to its language
+/// (`pt`) when no regional template exists.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::error::{Hexserror, MessageCatalog};
+///
+/// let catalog = MessageCatalog::new()
+///     .with_message("E_APP_QUOTA", "de", "{user} hat das Limit von {limit} erreicht");
+///
+/// let err = Hexserror::domain("E_APP_QUOTA", "Quota exceeded")
+///     .with_context("user", "alice")
+///     .with_context("limit", 100);
+///
+/// assert_eq!(err.localized(&catalog, "de-AT"), "alice hat das Limit von 100 erreicht");
+/// assert_eq!(err.localized(&catalog, "fr"), "Quota exceeded");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageCatalog {
+  messages: std::collections::HashMap<String, std::collections::HashMap<String, String>>,
+}
+
+impl MessageCatalog {
+  /// Create an empty catalog
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add the template for `code` in `locale` (builder pattern)
+  pub fn with_message(mut self, code: &str, locale: &str, template: impl Into<String>) -> Self {
+    self.insert(code, locale, template);
+    self
+  }
+
+  /// Add the template for `code` in `locale`, replacing any earlier one
+  pub fn insert(&mut self, code: &str, locale: &str, template: impl Into<String>) {
+    self
+      .messages
+      .entry(String::from(code))
+      .or_default()
+      .insert(normalize(locale), template.into());
+  }
+
+  /// Template for `code` in `locale`, falling back to the locale's language
+  pub fn lookup(&self, code: &str, locale: &str) -> Option<&str> {
+    let templates = self.messages.get(code)?;
+    let locale = normalize(locale);
+    templates
+      .get(&locale)
+      .or_else(|| {
+        let (language, _) = locale.split_once('-')?;
+        templates.get(language)
+      })
+      .map(String::as_str)
+  }
+}
+
+/// Lowercase and use `-` as the separator, so `pt_BR` matches `pt-br`.
+fn normalize(locale: &str) -> String {
+  locale.replace('_', "-").to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_lookup_falls_back_to_language() {
+    let catalog = MessageCatalog::new()
+      .with_message("E_X", "pt", "erro")
+      .with_message("E_X", "pt-BR", "erro no Brasil");
+
+    assert_eq!(catalog.lookup("E_X", "pt_BR"), Some("erro no Brasil"));
+    assert_eq!(catalog.lookup("E_X", "pt-PT"), Some("erro"));
+    assert_eq!(catalog.lookup("E_X", "en"), None);
+    assert_eq!(catalog.lookup("E_Y", "pt"), None);
+  }
+
+  #[test]
+  fn test_localized_interpolates_not_found_fields() {
+    let catalog = MessageCatalog::new().with_message(
+      crate::error::codes::resource::NOT_FOUND,
+      "de",
+      "{resource} mit ID '{id}' nicht gefunden",
+    );
+    let err = crate::error::hex_error::Hexserror::not_found("User", "42");
+    assert_eq!(
+      err.localized(&catalog, "de"),
+      "User mit ID '42' nicht gefunden"
+    );
+    assert_eq!(err.localized(&catalog, "en"), "User not found with id '42'");
+  }
+
+  #[test]
+  fn test_missing_placeholder_falls_back_to_default_message() {
+    let catalog = MessageCatalog::new().with_message("E_X", "de", "Bestellung {order_id} fehlt");
+    let err = crate::error::hex_error::Hexserror::domain("E_X", "Order missing");
+    assert_eq!(err.localized(&catalog, "de"), "Order missing");
+  }
+}
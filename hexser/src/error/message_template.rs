@@ -0,0 +1,232 @@
+//! Message templates keyed by error code.
+//!
+//! Keeps recurring error messages ("User 42 not found") in one place.
+//! Templates use `{name}` placeholders that are filled from named arguments;
+//! `{{` and `}}` produce literal braces. A few built-in templates cover the
+//! standard codes, and applications can register their own (or override the
+//! built-ins) at startup with `register_template`.
+//!
+//! A placeholder without a matching argument panics in debug builds so the
+//! mistake surfaces in tests, and is left verbatim in release builds.
+//!
+//! # Example
+//!
+//! ```rust
+//! hexser::error::message_template::register_template("E_APP_QUOTA", "{user} exceeded {limit} requests");
+//!
+//! let err = hexser::hex_error_template!(domain: "E_APP_QUOTA", user = "alice", limit = 100);
+//! std::assert!(err.to_string().contains("alice exceeded 100 requests"));
+//! ```
+//!
+//! Revision History
+//! - 2026-10-19T04:35:00Z @AI: Add placeholders for checking arguments before rendering.
+//! - 2026-10-19T00:05:00Z @AI: Initial code-keyed message templates with named interpolation.
+
+static REGISTERED: std::sync::OnceLock<
+  std::sync::RwLock<std::collections::HashMap<String, String>>,
+> = std::sync::OnceLock::new();
+
+fn registered() -> &'static std::sync::RwLock<std::collections::HashMap<String, String>> {
+  REGISTERED.get_or_init(|| std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+/// Built-in template for a standard error code, if one exists.
+fn builtin_template(code: &str) -> Option<&'static str> {
+  match code {
+    crate::error::codes::resource::NOT_FOUND => Some("{resource} not found with id '{id}'"),
+    crate::error::codes::resource::ALREADY_EXISTS => {
+      Some("{resource} already exists with id '{id}'")
+    }
+    crate::error::codes::validation::REQUIRED_FIELD => Some("Field '{field}' is required"),
+    crate::error::codes::validation::OUT_OF_RANGE => {
+      Some("{field} must be between {min} and {max}")
+    }
+    crate::error::codes::port::PORT_TIMEOUT => Some("Port '{port}' timed out after {ms}ms"),
+    _ => None,
+  }
+}
+
+/// Registers `template` for `code`, replacing any earlier or built-in template.
+pub fn register_template(code: &str, template: impl Into<String>) {
+  registered()
+    .write()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+    .insert(String::from(code), template.into());
+}
+
+/// Returns the template for `code`: a registered one first, then a built-in.
+pub fn template_for(code: &str) -> Option<String> {
+  let registered = registered()
+    .read()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+    .get(code)
+    .cloned();
+  registered.or_else(|| builtin_template(code).map(String::from))
+}
+
+/// Interpolates named `args` into `template`.
+///
+/// # Panics
+///
+/// In debug builds, panics when the template references a name that is
+/// not present in `args`.
+pub fn render(template: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+  let mut out = String::with_capacity(template.len());
+  let mut rest = template;
+  while let Some(pos) = rest.find(['{', '}']) {
+    out.push_str(&rest[..pos]);
+    let tail = &rest[pos..];
+    if tail.starts_with("{{") || tail.starts_with("}}") {
+      out.push_str(&tail[..1]);
+      rest = &tail[2..];
+      continue;
+    }
+    match (tail.starts_with('{'), tail.find('}')) {
+      (true, Some(end)) => {
+        let name = &tail[1..end];
+        match args.iter().find(|(key, _)| *key == name) {
+          Some((_, value)) => out.push_str(&value.to_string()),
+          None => {
+            if cfg!(debug_assertions) {
+              panic!("error template '{template}' references missing argument '{name}'");
+            }
+            out.push_str(&tail[..=end]);
+          }
+        }
+        rest = &tail[end + 1..];
+      }
+      _ => {
+        out.push_str(&tail[..1]);
+        rest = &tail[1..];
+      }
+    }
+  }
+  out.push_str(rest);
+  out
+}
+
+/// Names of the `{name}` placeholders in `template`, in order of appearance.
+///
+/// Escaped braces are skipped, so callers can check that every argument is
+/// available before calling `render`.
+pub fn placeholders(template: &str) -> Vec<&str> {
+  let mut names = Vec::new();
+  let mut rest = template;
+  while let Some(pos) = rest.find(['{', '}']) {
+    let tail = &rest[pos..];
+    if tail.starts_with("{{") || tail.starts_with("}}") {
+      rest = &tail[2..];
+      continue;
+    }
+    match (tail.starts_with('{'), tail.find('}')) {
+      (true, Some(end)) => {
+        names.push(&tail[1..end]);
+        rest = &tail[end + 1..];
+      }
+      _ => rest = &tail[1..],
+    }
+  }
+  names
+}
+
+/// Renders the template registered for `code` with `args`.
+///
+/// Codes without a template render as `CODE: name=value, ...` so the
+/// arguments are never lost.
+pub fn render_code(code: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+  match template_for(code) {
+    Some(template) => render(&template, args),
+    None if args.is_empty() => String::from(code),
+    None => {
+      let pairs: Vec<String> = args.iter().map(|(k, v)| format!("{k}={v}")).collect();
+      format!("{code}: {}", pairs.join(", "))
+    }
+  }
+}
+
+/// Builds a message or error from the template registered for an error code.
+///
+/// - `hex_error_template!(CODE, name = value, ...)` returns the rendered `String`.
+/// - `hex_error_template!(domain: CODE, ...)`, `port:` and `adapter:` return a
+///   `Hexserror` of that layer carrying `CODE` and the rendered message.
+///
+/// # Example
+///
+/// ```rust
+/// let msg = hexser::hex_error_template!(
+///   hexser::error::codes::validation::OUT_OF_RANGE,
+///   field = "quantity",
+///   min = 1,
+///   max = 99,
+/// );
+/// std::assert_eq!(msg, "quantity must be between 1 and 99");
+/// ```
+#[macro_export]
+macro_rules! hex_error_template {
+  (domain: $code:expr $(, $name:ident = $value:expr)* $(,)?) => {
+    $crate::error::Hexserror::domain($code, &$crate::hex_error_template!($code $(, $name = $value)*))
+  };
+  (port: $code:expr $(, $name:ident = $value:expr)* $(,)?) => {
+    $crate::error::Hexserror::port($code, &$crate::hex_error_template!($code $(, $name = $value)*))
+  };
+  (adapter: $code:expr $(, $name:ident = $value:expr)* $(,)?) => {
+    $crate::error::Hexserror::adapter($code, &$crate::hex_error_template!($code $(, $name = $value)*))
+  };
+  ($code:expr $(, $name:ident = $value:expr)* $(,)?) => {
+    $crate::error::message_template::render_code(
+      $code,
+      &[$((::std::stringify!($name), &$value as &dyn ::std::fmt::Display)),*],
+    )
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_render_interpolates_and_escapes() {
+    let rendered = render(
+      "{{{name}}} has {count} items",
+      &[("name", &"cart"), ("count", &3)],
+    );
+    std::assert_eq!(rendered, "{cart} has 3 items");
+  }
+
+  #[test]
+  fn test_render_code_uses_builtin_and_registered_templates() {
+    let msg = render_code(
+      crate::error::codes::resource::NOT_FOUND,
+      &[("resource", &"User"), ("id", &42)],
+    );
+    std::assert_eq!(msg, "User not found with id '42'");
+
+    register_template("E_TEST_TEMPLATE", "Order {id} is {state}");
+    let err = crate::hex_error_template!(domain: "E_TEST_TEMPLATE", id = 7, state = "closed");
+    std::assert_eq!(err.code(), "E_TEST_TEMPLATE");
+    std::assert!(err.to_string().contains("Order 7 is closed"));
+  }
+
+  #[test]
+  fn test_placeholders_skip_escaped_braces() {
+    std::assert_eq!(
+      placeholders("{{literal}} {user} has {count}"),
+      std::vec!["user", "count"]
+    );
+  }
+
+  #[test]
+  fn test_render_code_without_template_keeps_args() {
+    std::assert_eq!(
+      render_code("E_TEST_UNKNOWN", &[("id", &1)]),
+      "E_TEST_UNKNOWN: id=1"
+    );
+  }
+
+  #[cfg(debug_assertions)]
+  #[test]
+  #[should_panic(expected = "missing argument 'id'")]
+  fn test_render_missing_arg_panics_in_debug() {
+    render("User {id} not found", &[]);
+  }
+}
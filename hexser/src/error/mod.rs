@@ -21,6 +21,13 @@
 //! ```
 //!
 //! Revision History
+//! - 2026-10-19T12:45:00Z @AI: Add context module with the shared write_context helper.
+//! - 2026-10-19T04:35:00Z @AI: Add message_catalog module for localized messages.
+//! - 2026-10-19T01:55:00Z @AI: Add aggregate_error module for batch failures.
+//! - 2026-10-19T00:35:00Z @AI: Add redaction module with Redacted and ContextFields.
+//! - 2026-10-19T00:05:00Z @AI: Add message_template module and hex_error_template macro.
+//! - 2026-10-18T12:30:00Z @AI: Add severity module.
+//! - 2026-10-18T10:30:00Z @AI: Add field_violation module for accumulated validation failures.
 //! - 2025-10-09T21:51:00Z @AI: Add env_control module for conditional source location serialization.
 //! - 2025-10-06T03:00:00Z @AI: Add error construction macros for Phase 2.
 //! - 2025-10-06T01:00:00Z @AI: Add RichError trait and LayerError generic for Phase 1.
@@ -28,18 +35,28 @@
 //! - 2025-10-01T00:00:00Z @AI: Initial Phase 1 error module structure.
 
 pub mod adapter_error;
+pub mod aggregate_error;
 pub mod codes;
 pub mod conflict_error;
+pub mod context;
 pub mod domain_error;
 pub mod env_control;
+pub mod field_violation;
 pub mod hex_error;
 pub mod layer_error;
+pub mod message_catalog;
+pub mod message_template;
 pub mod not_found_error;
 pub mod port_error;
+pub mod redaction;
 pub mod rich_error;
+pub mod severity;
 pub mod source_location;
 pub mod validation_error;
 
 pub use codes as error_codes;
 pub use hex_error::Hexserror;
+pub use message_catalog::MessageCatalog;
+pub use redaction::{ContextFields, REDACTED, Redacted};
 pub use rich_error::RichError;
+pub use severity::Severity;
@@ -0,0 +1,88 @@
+//! Redaction of sensitive values in error context and debug output.
+//!
+//! Provides the Redacted wrapper, whose Display and Debug output is always
+//! `***`, and the ContextFields trait used to attach a struct's fields to an
+//! error as context breadcrumbs. `#[derive(HexContext)]` implements
+//! ContextFields and honours `#[hex(redact)]` (value replaced by `***`) and
+//! `#[hex(skip)]` (field omitted) on individual fields.
+//!
+//! Revision History
+//! - 2026-10-19T00:35:00Z @AI: Initial Redacted wrapper and ContextFields trait.
+
+/// Placeholder rendered in place of redacted values.
+pub const REDACTED: &str = "***";
+
+/// Wrapper that hides its value from Display and Debug output.
+///
+/// Use it for secret fields (passwords, tokens) so that `{:?}` on the
+/// enclosing struct and error context never print the real value.
+///
+/// # Example
+///
+/// ```rust
+/// #[derive(Debug)]
+/// struct Credentials {
+///     user: String,
+///     password: hexser::error::Redacted<String>,
+/// }
+///
+/// let creds = Credentials {
+///     user: String::from("alice"),
+///     password: hexser::error::Redacted::new(String::from("hunter2")),
+/// };
+/// assert_eq!(format!("{:?}", creds), "Credentials { user: \"alice\", password: *** }");
+/// assert_eq!(creds.password.expose(), "hunter2");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Redacted<T>(T);
+
+impl<T> Redacted<T> {
+  /// Wrap a sensitive value
+  pub fn new(value: T) -> Self {
+    Self(value)
+  }
+
+  /// Borrow the real value; call sites make every disclosure explicit
+  pub fn expose(&self) -> &T {
+    &self.0
+  }
+
+  /// Unwrap the real value
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T> std::fmt::Display for Redacted<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(REDACTED)
+  }
+}
+
+impl<T> std::fmt::Debug for Redacted<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(REDACTED)
+  }
+}
+
+/// Types that can render their fields as error context breadcrumbs.
+///
+/// Usually derived with `#[derive(HexContext)]`; see the module docs for
+/// the supported field attributes. Consumed by `Hexserror::with_fields`.
+pub trait ContextFields {
+  /// Field names paired with their rendered (possibly redacted) values
+  fn context_fields(&self) -> Vec<(&'static str, String)>;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_redacted_hides_value_in_display_and_debug() {
+    let token = Redacted::new("secret-token");
+    assert_eq!(token.to_string(), REDACTED);
+    assert_eq!(format!("{:?}", token), REDACTED);
+    assert_eq!(*token.expose(), "secret-token");
+  }
+}
@@ -0,0 +1,52 @@
+//! Severity levels for classifying errors.
+//!
+//! Severity lets logging and alerting middleware route errors without
+//! inspecting codes: a validation failure is user-facing, while a lost
+//! database connection is an operational alert. Hexserror derives a default
+//! severity from its variant and code, which callers can override with
+//! `Hexserror::with_severity`.
+//!
+//! Revision History
+//! - 2026-10-18T12:30:00Z @AI: Initial Severity enum.
+
+/// Error severity, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+  /// Informational, no action required
+  Info,
+  /// Unexpected but recoverable condition
+  Warning,
+  /// Operation failed
+  Error,
+  /// Operational failure requiring immediate attention
+  Critical,
+}
+
+impl std::fmt::Display for Severity {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let label = match self {
+      Self::Info => "info",
+      Self::Warning => "warning",
+      Self::Error => "error",
+      Self::Critical => "critical",
+    };
+    write!(f, "{}", label)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_severity_ordering() {
+    assert!(Severity::Info < Severity::Warning);
+    assert!(Severity::Error < Severity::Critical);
+  }
+
+  #[test]
+  fn test_severity_display() {
+    assert_eq!(format!("{}", Severity::Critical), "critical");
+  }
+}
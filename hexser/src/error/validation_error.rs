@@ -5,6 +5,12 @@
 //! Includes field-specific context and actionable guidance.
 //!
 //! Revision History
+//! - 2026-10-19T12:45:00Z @AI: Render context through the shared write_context helper.
+//! - 2026-10-19T12:35:00Z @AI: Keep the severity override private behind severity_override.
+//! - 2026-10-19T11:45:00Z @AI: Leave severity serialization to Hexserror.
+//! - 2026-10-18T19:40:00Z @AI: Add key/value context breadcrumbs.
+//! - 2026-10-18T12:30:00Z @AI: Add optional severity override.
+//! - 2026-10-18T10:30:00Z @AI: Add violations list for multi-field validation failures.
 //! - 2025-10-09T21:51:00Z @AI: Add conditional source location serialization via env_control.
 //! - 2025-10-09T21:22:00Z @AI: Add Serde support for rich errors.
 //! - 2025-10-09T09:56:00Z @AI: Remove unused Display and Formatter imports per NO use STATEMENTS rule.
@@ -20,6 +26,20 @@ pub struct ValidationError {
   pub message: String,
   /// Optional field name that failed validation
   pub field: Option<String>,
+  /// Individual field failures when several fields failed at once
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "Vec::is_empty")
+  )]
+  pub violations: Vec<crate::error::field_violation::FieldViolation>,
+  #[cfg_attr(feature = "serde", serde(default, skip_serializing))]
+  severity: Option<crate::error::severity::Severity>,
+  /// Key/value breadcrumbs attached while the error propagates
+  #[cfg_attr(
+    feature = "serde",
+    serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")
+  )]
+  pub context: std::collections::BTreeMap<String, String>,
   /// Optional source code location
   #[cfg_attr(
     feature = "serde",
@@ -35,6 +55,9 @@ impl ValidationError {
       code: code.into(),
       message: message.into(),
       field: None,
+      violations: Vec::new(),
+      severity: None,
+      context: std::collections::BTreeMap::new(),
       location: None,
     }
   }
@@ -45,6 +68,34 @@ impl ValidationError {
     self
   }
 
+  /// Add individual field violations (builder pattern)
+  pub fn with_violations(
+    mut self,
+    violations: impl IntoIterator<Item = crate::error::field_violation::FieldViolation>,
+  ) -> Self {
+    self.violations.extend(violations);
+    self
+  }
+
+  /// Override severity (builder pattern)
+  pub fn with_severity(mut self, severity: crate::error::severity::Severity) -> Self {
+    self.severity = Some(severity);
+    self
+  }
+
+  /// Severity set with `with_severity`, if any
+  pub fn severity_override(&self) -> Option<crate::error::severity::Severity> {
+    self.severity
+  }
+
+  /// Attach a context key/value pair (builder pattern)
+  ///
+  /// Distinct keys accumulate; repeating a key replaces its earlier value.
+  pub fn with_context(mut self, key: impl Into<String>, value: impl std::fmt::Display) -> Self {
+    self.context.insert(key.into(), value.to_string());
+    self
+  }
+
   /// Add source location (builder pattern)
   pub fn with_location(mut self, location: crate::error::source_location::SourceLocation) -> Self {
     self.location = Some(location);
@@ -64,6 +115,12 @@ impl std::fmt::Display for ValidationError {
       write!(f, "Error [{}]: {}", self.code, self.message)?;
     }
 
+    for violation in &self.violations {
+      write!(f, "\n  - {}", violation)?;
+    }
+
+    crate::error::context::write_context(f, &self.context)?;
+
     if let Some(ref location) = self.location {
       write!(f, "\nSource: {}", location)?;
     }
@@ -101,4 +158,17 @@ mod tests {
     assert!(display.contains("username"));
     assert!(display.contains("E_HEX_300"));
   }
+
+  #[test]
+  fn test_validation_error_with_violations() {
+    let err = ValidationError::new("E_HEX_303", "2 validation failures").with_violations(vec![
+      crate::error::field_violation::FieldViolation::new("name", "E_HEX_300", "name is required"),
+      crate::error::field_violation::FieldViolation::new("age", "E_HEX_302", "age out of range"),
+    ]);
+
+    assert_eq!(err.violations.len(), 2);
+    let display = format!("{}", err);
+    assert!(display.contains("name: name is required"));
+    assert!(display.contains("age: age out of range"));
+  }
 }
@@ -1,213 +1,367 @@
 //! Graph analysis algorithms for architectural insights.
 //!
 //! Provides cycle detection, coupling metrics, and component analysis.
+//! Cycles and transitive dependencies are memoized in the graph's analysis
+//! cache unless it was built with `GraphBuilder::without_analysis_cache`.
 //!
 //! Revision History
+//! - 2026-10-19T05:35:00Z @AI: Memoize detect_cycles; add cached transitive_dependencies.
+//! - 2026-10-18T15:00:00Z @AI: Add find_path shortest dependency path search.
+//! - 2026-10-18T10:00:00Z @AI: Wire into graph module; align with reference-based HexGraph API.
 //! - 2025-10-02T14:00:00Z @AI: Initial analysis implementation for Phase 4.
 
 /// Graph analyzer with algorithmic analysis
 pub struct GraphAnalysis<'g> {
-    graph: &'g crate::graph::hex_graph::HexGraph,
+  graph: &'g crate::graph::hex_graph::HexGraph,
 }
 
 /// Coupling metrics for a component
 #[derive(Debug, Clone, PartialEq)]
 pub struct CouplingMetrics {
-    pub afferent: usize,
-    pub efferent: usize,
-    pub instability: f64,
+  pub afferent: usize,
+  pub efferent: usize,
+  pub instability: f64,
 }
 
 impl<'g> GraphAnalysis<'g> {
-    /// Create analyzer for graph
-    pub fn new(graph: &'g crate::graph::hex_graph::HexGraph) -> Self {
-        Self { graph }
-    }
+  /// Create analyzer for graph
+  pub fn new(graph: &'g crate::graph::hex_graph::HexGraph) -> Self {
+    Self { graph }
+  }
 
-    /// Detect circular dependencies using DFS
-    pub fn detect_cycles(&self) -> Vec<Vec<crate::graph::node_id::NodeId>> {
-        let mut cycles = Vec::new();
-        let mut visited = std::collections::HashSet::new();
-        let mut rec_stack = std::collections::HashSet::new();
-        let mut path = Vec::new();
-
-        for node in self.graph.nodes() {
-            if !visited.contains(&node.id) {
-                self.dfs_cycle_detect(
-                    &node.id,
-                    &mut visited,
-                    &mut rec_stack,
-                    &mut path,
-                    &mut cycles,
-                );
-            }
-        }
+  /// Detect circular dependencies using DFS
+  ///
+  /// The result is computed once per graph and then served from its cache.
+  pub fn detect_cycles(&self) -> Vec<Vec<crate::graph::node_id::NodeId>> {
+    self.graph.inner.cache.cycles(|| self.compute_cycles())
+  }
+
+  fn compute_cycles(&self) -> Vec<Vec<crate::graph::node_id::NodeId>> {
+    let mut cycles = Vec::new();
+    let mut visited = std::collections::HashSet::new();
+    let mut rec_stack = std::collections::HashSet::new();
+    let mut path = Vec::new();
 
-        cycles
+    for node in self.graph.nodes() {
+      if !visited.contains(&node.id) {
+        self.dfs_cycle_detect(
+          &node.id,
+          &mut visited,
+          &mut rec_stack,
+          &mut path,
+          &mut cycles,
+        );
+      }
     }
 
-    fn dfs_cycle_detect(
-        &self,
-        node_id: &crate::graph::node_id::NodeId,
-        visited: &mut std::collections::HashSet<crate::graph::node_id::NodeId>,
-        rec_stack: &mut std::collections::HashSet<crate::graph::node_id::NodeId>,
-        path: &mut Vec<crate::graph::node_id::NodeId>,
-        cycles: &mut Vec<Vec<crate::graph::node_id::NodeId>>,
-    ) {
-        visited.insert(*node_id);
-        rec_stack.insert(*node_id);
-        path.push(*node_id);
-
-        for edge in self.graph.edges_from(*node_id) {
-            if !visited.contains(&edge.target) {
-                self.dfs_cycle_detect(&edge.target, visited, rec_stack, path, cycles);
-            } else if rec_stack.contains(&edge.target) {
-                let cycle_start = path.iter().position(|&id| id == edge.target).unwrap();
-                cycles.push(path[cycle_start..].to_vec());
-            }
-        }
+    cycles
+  }
+
+  fn dfs_cycle_detect(
+    &self,
+    node_id: &crate::graph::node_id::NodeId,
+    visited: &mut std::collections::HashSet<crate::graph::node_id::NodeId>,
+    rec_stack: &mut std::collections::HashSet<crate::graph::node_id::NodeId>,
+    path: &mut Vec<crate::graph::node_id::NodeId>,
+    cycles: &mut Vec<Vec<crate::graph::node_id::NodeId>>,
+  ) {
+    visited.insert(*node_id);
+    rec_stack.insert(*node_id);
+    path.push(*node_id);
 
-        path.pop();
-        rec_stack.remove(node_id);
+    for edge in self.graph.edges_from(node_id) {
+      if !visited.contains(&edge.target) {
+        self.dfs_cycle_detect(&edge.target, visited, rec_stack, path, cycles);
+      } else if rec_stack.contains(&edge.target) {
+        let cycle_start = path.iter().position(|&id| id == edge.target).unwrap();
+        cycles.push(path[cycle_start..].to_vec());
+      }
     }
 
-    /// Calculate coupling metrics for a node
-    pub fn calculate_coupling(
-        &self,
-        node_id: crate::graph::node_id::NodeId,
-    ) -> Option<CouplingMetrics> {
-        if self.graph.get_node(node_id).is_none() {
-            return None;
+    path.pop();
+    rec_stack.remove(node_id);
+  }
+
+  /// Every node reachable from `node_id` by following outgoing edges
+  ///
+  /// Returned in NodeId order. `node_id` itself is included only when it
+  /// lies on a cycle. Results are cached per node.
+  pub fn transitive_dependencies(
+    &self,
+    node_id: crate::graph::node_id::NodeId,
+  ) -> Vec<crate::graph::node_id::NodeId> {
+    self.graph.inner.cache.transitive_dependencies(node_id, || {
+      let mut reached = std::collections::BTreeSet::new();
+      let mut stack = vec![node_id];
+      while let Some(current) = stack.pop() {
+        for edge in self.graph.edges_from(&current) {
+          if reached.insert(edge.target) {
+            stack.push(edge.target);
+          }
         }
+      }
+      reached.into_iter().collect()
+    })
+  }
 
-        let afferent = self.graph.edges_to(node_id).count();
-        let efferent = self.graph.edges_from(node_id).count();
-        let total = afferent + efferent;
-
-        let instability = if total == 0 {
-            0.0
-        } else {
-            efferent as f64 / total as f64
-        };
-
-        Some(CouplingMetrics {
-            afferent,
-            efferent,
-            instability,
-        })
-    }
+  /// Calculate coupling metrics for a node
+  pub fn calculate_coupling(
+    &self,
+    node_id: crate::graph::node_id::NodeId,
+  ) -> Option<CouplingMetrics> {
+    self.graph.get_node(&node_id)?;
 
-    /// Find leaf nodes (no outgoing edges)
-    pub fn find_leaf_nodes(&self) -> Vec<&crate::graph::hex_node::HexNode> {
-        self.graph
-            .nodes()
-            .filter(|node| self.graph.edges_from(node.id).count() == 0)
-            .collect()
-    }
+    let afferent = self.graph.edges_to(&node_id).len();
+    let efferent = self.graph.edges_from(&node_id).len();
+    let total = afferent + efferent;
+
+    let instability = if total == 0 {
+      0.0
+    } else {
+      efferent as f64 / total as f64
+    };
 
-    /// Find root nodes (no incoming edges)
-    pub fn find_root_nodes(&self) -> Vec<&crate::graph::hex_node::HexNode> {
-        self.graph
-            .nodes()
-            .filter(|node| self.graph.edges_to(node.id).count() == 0)
-            .collect()
+    Some(CouplingMetrics {
+      afferent,
+      efferent,
+      instability,
+    })
+  }
+
+  /// Find the shortest dependency path between two nodes using BFS
+  ///
+  /// Returns the node ids from `from` to `to` inclusive, or None if `to`
+  /// is unreachable. A node always has a path of length one to itself.
+  pub fn find_path(
+    &self,
+    from: crate::graph::node_id::NodeId,
+    to: crate::graph::node_id::NodeId,
+  ) -> Option<Vec<crate::graph::node_id::NodeId>> {
+    self.graph.get_node(&from)?;
+    let mut previous = std::collections::HashMap::new();
+    let mut queue = std::collections::VecDeque::from([from]);
+    previous.insert(from, from);
+
+    while let Some(current) = queue.pop_front() {
+      if current == to {
+        let mut path = vec![to];
+        let mut step = to;
+        while step != from {
+          step = previous[&step];
+          path.push(step);
+        }
+        path.reverse();
+        return Some(path);
+      }
+      for edge in self.graph.edges_from(&current) {
+        if let std::collections::hash_map::Entry::Vacant(entry) = previous.entry(edge.target) {
+          entry.insert(current);
+          queue.push_back(edge.target);
+        }
+      }
     }
+
+    None
+  }
+
+  /// Find leaf nodes (no outgoing edges)
+  pub fn find_leaf_nodes(&self) -> Vec<&'g crate::graph::hex_node::HexNode> {
+    self
+      .graph
+      .nodes()
+      .filter(|node| self.graph.edges_from(&node.id).is_empty())
+      .collect()
+  }
+
+  /// Find root nodes (no incoming edges)
+  pub fn find_root_nodes(&self) -> Vec<&'g crate::graph::hex_node::HexNode> {
+    self
+      .graph
+      .nodes()
+      .filter(|node| self.graph.edges_to(&node.id).is_empty())
+      .collect()
+  }
 }
 
 impl crate::graph::hex_graph::HexGraph {
-    /// Create analyzer for this graph
-    pub fn analysis(&self) -> GraphAnalysis {
-        GraphAnalysis::new(self)
-    }
+  /// Create analyzer for this graph
+  pub fn analysis(&self) -> GraphAnalysis<'_> {
+    GraphAnalysis::new(self)
+  }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_detect_no_cycles() {
-        let graph = crate::graph::builder::GraphBuilder::new()
-            .add_node(crate::graph::hex_node::HexNode::new(
-                crate::graph::node_id::NodeId::from_name("A"),
-                crate::graph::layer::Layer::Domain,
-                crate::graph::role::Role::Entity,
-                "A",
-                "test",
-            ))
-            .add_node(crate::graph::hex_node::HexNode::new(
-                crate::graph::node_id::NodeId::from_name("B"),
-                crate::graph::layer::Layer::Domain,
-                crate::graph::role::Role::Entity,
-                "B",
-                "test",
-            ))
-            .build();
-
-        let cycles = graph.analysis().detect_cycles();
-        assert_eq!(cycles.len(), 0);
-    }
+  #[test]
+  fn test_detect_no_cycles() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .add_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("A"),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "A",
+        "test",
+      ))
+      .add_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("B"),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "B",
+        "test",
+      ))
+      .build();
+
+    let cycles = graph.analysis().detect_cycles();
+    assert_eq!(cycles.len(), 0);
+  }
+
+  #[test]
+  fn test_coupling_metrics() {
+    let node_a = crate::graph::node_id::NodeId::from_name("A");
+    let node_b = crate::graph::node_id::NodeId::from_name("B");
+
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .add_node(crate::graph::hex_node::HexNode::new(
+        node_a,
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "A",
+        "test",
+      ))
+      .add_node(crate::graph::hex_node::HexNode::new(
+        node_b,
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "B",
+        "test",
+      ))
+      .add_edge(crate::graph::hex_edge::HexEdge::new(
+        node_a,
+        node_b,
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .build();
+
+    let metrics = graph.analysis().calculate_coupling(node_a).unwrap();
+    assert_eq!(metrics.efferent, 1);
+    assert_eq!(metrics.afferent, 0);
+  }
 
-    #[test]
-    fn test_coupling_metrics() {
-        let node_a = crate::graph::node_id::NodeId::from_name("A");
-        let node_b = crate::graph::node_id::NodeId::from_name("B");
-
-        let graph = crate::graph::builder::GraphBuilder::new()
-            .add_node(crate::graph::hex_node::HexNode::new(
-                node_a,
-                crate::graph::layer::Layer::Domain,
-                crate::graph::role::Role::Entity,
-                "A",
-                "test",
-            ))
-            .add_node(crate::graph::hex_node::HexNode::new(
-                node_b,
-                crate::graph::layer::Layer::Domain,
-                crate::graph::role::Role::Entity,
-                "B",
-                "test",
-            ))
-            .add_edge(crate::graph::hex_edge::HexEdge::new(
-                node_a,
-                node_b,
-                crate::graph::relationship::Relationship::Depends,
-            ))
-            .build();
-
-        let metrics = graph.analysis().calculate_coupling(node_a).unwrap();
-        assert_eq!(metrics.efferent, 1);
-        assert_eq!(metrics.afferent, 0);
+  #[test]
+  fn test_find_leaf_nodes() {
+    let node_a = crate::graph::node_id::NodeId::from_name("A");
+    let node_b = crate::graph::node_id::NodeId::from_name("B");
+
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .add_node(crate::graph::hex_node::HexNode::new(
+        node_a,
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "A",
+        "test",
+      ))
+      .add_node(crate::graph::hex_node::HexNode::new(
+        node_b,
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "B",
+        "test",
+      ))
+      .add_edge(crate::graph::hex_edge::HexEdge::new(
+        node_a,
+        node_b,
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .build();
+
+    let leaves = graph.analysis().find_leaf_nodes();
+    assert_eq!(leaves.len(), 1);
+  }
+
+  #[test]
+  fn test_find_path_shortest() {
+    let ids: Vec<crate::graph::node_id::NodeId> = ["A", "B", "C", "D"]
+      .iter()
+      .map(|n| crate::graph::node_id::NodeId::from_name(n))
+      .collect();
+    let mut builder = crate::graph::builder::GraphBuilder::new();
+    for (id, name) in ids.iter().zip(["A", "B", "C", "D"]) {
+      builder = builder.add_node(crate::graph::hex_node::HexNode::new(
+        *id,
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        "test",
+      ));
+    }
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (0, 2)] {
+      builder = builder.add_edge(crate::graph::hex_edge::HexEdge::new(
+        ids[from],
+        ids[to],
+        crate::graph::relationship::Relationship::Depends,
+      ));
     }
+    let graph = builder.build();
 
-    #[test]
-    fn test_find_leaf_nodes() {
-        let node_a = crate::graph::node_id::NodeId::from_name("A");
-        let node_b = crate::graph::node_id::NodeId::from_name("B");
-
-        let graph = crate::graph::builder::GraphBuilder::new()
-            .add_node(crate::graph::hex_node::HexNode::new(
-                node_a,
-                crate::graph::layer::Layer::Domain,
-                crate::graph::role::Role::Entity,
-                "A",
-                "test",
-            ))
-            .add_node(crate::graph::hex_node::HexNode::new(
-                node_b,
-                crate::graph::layer::Layer::Domain,
-                crate::graph::role::Role::Entity,
-                "B",
-                "test",
-            ))
-            .add_edge(crate::graph::hex_edge::HexEdge::HexEdge::new(
-                node_a,
-                node_b,
-                crate::graph::relationship::Relationship::Depends,
-            ))
-            .build();
-
-        let leaves = graph.analysis().find_leaf_nodes();
-        assert_eq!(leaves.len(), 1);
+    let path = graph.analysis().find_path(ids[0], ids[3]).unwrap();
+    assert_eq!(path, vec![ids[0], ids[2], ids[3]]);
+    assert!(graph.analysis().find_path(ids[3], ids[0]).is_none());
+  }
+
+  fn cyclic_graph(cached: bool) -> crate::graph::hex_graph::HexGraph {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let mut builder = crate::graph::builder::GraphBuilder::new();
+    if !cached {
+      builder = builder.without_analysis_cache();
+    }
+    for name in ["A", "B", "C", "D"] {
+      builder = builder.add_node(crate::graph::hex_node::HexNode::new(
+        id(name),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        "test",
+      ));
     }
+    for (from, to) in [("A", "B"), ("B", "C"), ("C", "A"), ("C", "D")] {
+      builder = builder.add_edge(crate::graph::hex_edge::HexEdge::new(
+        id(from),
+        id(to),
+        crate::graph::relationship::Relationship::Depends,
+      ));
+    }
+    builder.build()
+  }
+
+  #[test]
+  fn test_cached_analyses_are_consistent() {
+    let graph = cyclic_graph(true);
+    assert!(graph.is_analysis_cached());
+
+    let first = graph.analysis().detect_cycles();
+    assert_eq!(first.len(), 1);
+    assert_eq!(graph.analysis().detect_cycles(), first);
+    assert_eq!(graph.clone().analysis().detect_cycles(), first);
+
+    let a = crate::graph::node_id::NodeId::from_name("A");
+    let mut expected: Vec<_> = ["A", "B", "C", "D"]
+      .into_iter()
+      .map(crate::graph::node_id::NodeId::from_name)
+      .collect();
+    expected.sort();
+    assert_eq!(graph.analysis().transitive_dependencies(a), expected);
+    assert_eq!(graph.analysis().transitive_dependencies(a), expected);
+
+    let uncached = cyclic_graph(false);
+    assert!(!uncached.is_analysis_cached());
+    assert_eq!(uncached.analysis().detect_cycles(), first);
+    assert_eq!(uncached.analysis().transitive_dependencies(a), expected);
+  }
+
+  #[test]
+  fn test_transitive_dependencies_of_leaf_is_empty() {
+    let graph = cyclic_graph(true);
+    let d = crate::graph::node_id::NodeId::from_name("D");
+    assert!(graph.analysis().transitive_dependencies(d).is_empty());
+  }
 }
@@ -0,0 +1,105 @@
+//! Memoized results of expensive graph analyses.
+//!
+//! HexGraph is immutable, so analyses such as cycle detection and
+//! transitive dependency closure are pure functions of the graph. The
+//! cache lives next to the nodes and edges behind the graph's `Arc`: it is
+//! shared by every clone, and its entries stay valid for the graph's whole
+//! lifetime without any invalidation.
+//!
+//! Memory tradeoff: the cycle list is kept once, while transitive
+//! dependencies are kept per queried node and can reach O(nodes²) entries
+//! if every node is queried. Graphs built with
+//! `GraphBuilder::without_analysis_cache` recompute on every call instead.
+//!
+//! Revision History
+//! - 2026-10-19T05:35:00Z @AI: Initial AnalysisCache for cycles and transitive dependencies.
+
+/// Lazily filled analysis results for one immutable graph
+#[derive(Debug)]
+pub(crate) struct AnalysisCache {
+  enabled: bool,
+  cycles: std::sync::OnceLock<Vec<Vec<crate::graph::node_id::NodeId>>>,
+  transitive: std::sync::Mutex<
+    std::collections::HashMap<crate::graph::node_id::NodeId, Vec<crate::graph::node_id::NodeId>>,
+  >,
+}
+
+impl AnalysisCache {
+  /// Create an empty cache; a disabled cache always recomputes
+  pub(crate) fn new(enabled: bool) -> Self {
+    Self {
+      enabled,
+      cycles: std::sync::OnceLock::new(),
+      transitive: std::sync::Mutex::new(std::collections::HashMap::new()),
+    }
+  }
+
+  /// Whether results are memoized
+  pub(crate) fn is_enabled(&self) -> bool {
+    self.enabled
+  }
+
+  /// Cycles of the graph, computed by `compute` on first use
+  pub(crate) fn cycles(
+    &self,
+    compute: impl FnOnce() -> Vec<Vec<crate::graph::node_id::NodeId>>,
+  ) -> Vec<Vec<crate::graph::node_id::NodeId>> {
+    if !self.enabled {
+      return compute();
+    }
+    self.cycles.get_or_init(compute).clone()
+  }
+
+  /// Transitive dependencies of `id`, computed by `compute` on first use
+  pub(crate) fn transitive_dependencies(
+    &self,
+    id: crate::graph::node_id::NodeId,
+    compute: impl FnOnce() -> Vec<crate::graph::node_id::NodeId>,
+  ) -> Vec<crate::graph::node_id::NodeId> {
+    if !self.enabled {
+      return compute();
+    }
+    self
+      .transitive
+      .lock()
+      .unwrap_or_else(std::sync::PoisonError::into_inner)
+      .entry(id)
+      .or_insert_with(compute)
+      .clone()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_enabled_cache_computes_once() {
+    let cache = AnalysisCache::new(true);
+    let id = crate::graph::node_id::NodeId::from_name("A");
+    let calls = std::cell::Cell::new(0);
+    let compute = || {
+      calls.set(calls.get() + 1);
+      vec![id]
+    };
+
+    assert_eq!(cache.transitive_dependencies(id, compute), vec![id]);
+    assert_eq!(cache.transitive_dependencies(id, compute), vec![id]);
+    assert_eq!(calls.get(), 1);
+  }
+
+  #[test]
+  fn test_disabled_cache_recomputes() {
+    let cache = AnalysisCache::new(false);
+    let calls = std::cell::Cell::new(0);
+    let compute = || {
+      calls.set(calls.get() + 1);
+      Vec::new()
+    };
+
+    cache.cycles(compute);
+    cache.cycles(compute);
+    assert_eq!(calls.get(), 2);
+    assert!(!cache.is_enabled());
+  }
+}
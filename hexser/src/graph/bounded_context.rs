@@ -0,0 +1,161 @@
+//! Bounded contexts inferred from module paths.
+//!
+//! Conventionally structured crates put each bounded context in its own
+//! top-level module, `myapp::billing::...`, `myapp::shipping::...`. Taking
+//! the second path segment as the context name therefore groups the graph
+//! DDD-style without any annotations. Projects with other layouts register
+//! module prefix overrides on BoundedContexts. Exporters cluster nodes by
+//! context through `ExportOptions::cluster_by_context`.
+//!
+//! Revision History
+//! - 2026-10-19T08:35:00Z @AI: Initial BoundedContexts and HexGraph::contexts.
+
+/// Rules mapping module paths to bounded context names.
+///
+/// A module path gets the context of the longest override prefix it equals
+/// or is nested under; otherwise its second `::` segment. Paths with a
+/// single segment and no override have no context.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::BoundedContexts;
+///
+/// let contexts = BoundedContexts::new().with_override("myapp::legacy::invoicing", "billing");
+///
+/// assert_eq!(contexts.context_of("myapp::billing::invoice").as_deref(), Some("billing"));
+/// assert_eq!(contexts.context_of("myapp::legacy::invoicing::pdf").as_deref(), Some("billing"));
+/// assert_eq!(contexts.context_of("myapp"), None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BoundedContexts {
+  overrides: std::collections::BTreeMap<String, String>,
+}
+
+impl BoundedContexts {
+  /// Create rules using only the second-segment heuristic
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Assign `context` to `module_prefix` and its submodules (builder pattern)
+  pub fn with_override(
+    mut self,
+    module_prefix: impl Into<String>,
+    context: impl Into<String>,
+  ) -> Self {
+    self.overrides.insert(module_prefix.into(), context.into());
+    self
+  }
+
+  /// Bounded context of a module path, if one can be determined
+  pub fn context_of(&self, module_path: &str) -> Option<String> {
+    let overridden = self
+      .overrides
+      .iter()
+      .filter(|(prefix, _)| {
+        module_path == prefix.as_str()
+          || module_path
+            .strip_prefix(prefix.as_str())
+            .is_some_and(|rest| rest.starts_with("::"))
+      })
+      .max_by_key(|(prefix, _)| prefix.len());
+    match overridden {
+      Some((_, context)) => Some(context.clone()),
+      None => module_path
+        .split("::")
+        .nth(1)
+        .filter(|segment| !segment.is_empty())
+        .map(String::from),
+    }
+  }
+
+  /// Group the nodes of `graph` by context, omitting nodes without one
+  pub fn group(
+    &self,
+    graph: &crate::graph::hex_graph::HexGraph,
+  ) -> std::collections::HashMap<String, Vec<crate::graph::node_id::NodeId>> {
+    let mut contexts: std::collections::HashMap<String, Vec<crate::graph::node_id::NodeId>> =
+      std::collections::HashMap::new();
+    for node in graph.nodes() {
+      if let Some(context) = self.context_of(&node.module_path) {
+        contexts.entry(context).or_default().push(node.id);
+      }
+    }
+    contexts
+  }
+}
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Group node ids by bounded context inferred from their module paths.
+  ///
+  /// Uses the second module path segment (`myapp::billing::...` is
+  /// `billing`); nodes whose path has a single segment are omitted. Ids in
+  /// each group are in ascending order. Use `contexts_with` to apply
+  /// overrides.
+  pub fn contexts(&self) -> std::collections::HashMap<String, Vec<crate::graph::node_id::NodeId>> {
+    self.contexts_with(&BoundedContexts::new())
+  }
+
+  /// Group node ids by bounded context using custom rules
+  pub fn contexts_with(
+    &self,
+    rules: &BoundedContexts,
+  ) -> std::collections::HashMap<String, Vec<crate::graph::node_id::NodeId>> {
+    rules.group(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn graph() -> crate::graph::hex_graph::HexGraph {
+    let node = |name: &str, module_path: &str| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        module_path,
+      )
+    };
+    crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Invoice", "shop::billing::invoice"))
+      .with_node(node("Payment", "shop::billing"))
+      .with_node(node("Parcel", "shop::shipping::parcel"))
+      .with_node(node("LegacyTax", "shop::legacy::tax"))
+      .with_node(node("Main", "shop"))
+      .build()
+  }
+
+  #[test]
+  fn test_contexts_use_second_path_segment() {
+    let contexts = graph().contexts();
+    let id = crate::graph::node_id::NodeId::from_name;
+    let mut billing = vec![id("Invoice"), id("Payment")];
+    billing.sort();
+
+    assert_eq!(contexts.len(), 3);
+    assert_eq!(contexts["billing"], billing);
+    assert_eq!(contexts["shipping"], vec![id("Parcel")]);
+    assert_eq!(contexts["legacy"], vec![id("LegacyTax")]);
+  }
+
+  #[test]
+  fn test_override_wins_by_longest_prefix() {
+    let rules = BoundedContexts::new()
+      .with_override("shop::legacy", "archive")
+      .with_override("shop::legacy::tax", "billing");
+    let contexts = graph().contexts_with(&rules);
+
+    assert!(!contexts.contains_key("legacy"));
+    assert!(!contexts.contains_key("archive"));
+    assert_eq!(contexts["billing"].len(), 3);
+    assert_eq!(
+      rules.context_of("shop::legacy_v2::x").as_deref(),
+      Some("legacy_v2")
+    );
+    assert_eq!(rules.context_of("shop::legacy").as_deref(), Some("archive"));
+  }
+}
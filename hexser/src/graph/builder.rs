@@ -6,6 +6,11 @@
 //! invalid relationships are detected.
 //!
 //! Revision History
+//! - 2026-10-19T08:55:00Z @AI: Add node_id_strategy to collapse generic instantiations.
+//! - 2026-10-19T06:25:00Z @AI: Add exclude_modules to drop components by module path.
+//! - 2026-10-19T05:35:00Z @AI: Add without_analysis_cache opt-out.
+//! - 2026-10-19T05:15:00Z @AI: Add with_app_version for graph provenance.
+//! - 2026-10-18T13:00:00Z @AI: Order nodes and edges deterministically on build.
 //! - 2025-10-02T12:30:00Z @AI: Add add_node and add_edge alias methods.
 //! - 2025-10-01T00:03:00Z @AI: Initial GraphBuilder implementation for Phase 2.
 
@@ -34,6 +39,10 @@ pub struct GraphBuilder {
   nodes: Vec<crate::graph::hex_node::HexNode>,
   edges: Vec<crate::graph::hex_edge::HexEdge>,
   description: String,
+  app_version: Option<String>,
+  analysis_cache: bool,
+  excluded_modules: Vec<String>,
+  node_id_strategy: crate::graph::node_id::NodeIdStrategy,
 }
 
 impl GraphBuilder {
@@ -43,6 +52,10 @@ impl GraphBuilder {
       nodes: Vec::new(),
       edges: Vec::new(),
       description: String::from("Hexagonal Architecture Graph"),
+      app_version: None,
+      analysis_cache: true,
+      excluded_modules: Vec::new(),
+      node_id_strategy: crate::graph::node_id::NodeIdStrategy::default(),
     }
   }
 
@@ -52,6 +65,63 @@ impl GraphBuilder {
     self
   }
 
+  /// Record the application version in the graph's build info.
+  pub fn with_app_version(mut self, app_version: &str) -> Self {
+    self.app_version = Some(String::from(app_version));
+    self
+  }
+
+  /// Recompute analyses such as cycle detection on every call.
+  ///
+  /// Built graphs memoize analysis results by default, trading memory for
+  /// speed on repeated queries; use this for very large graphs that are
+  /// analyzed only once.
+  pub fn without_analysis_cache(mut self) -> Self {
+    self.analysis_cache = false;
+    self
+  }
+
+  /// Leave out nodes whose module path matches any of `patterns`.
+  ///
+  /// A plain pattern such as `my_app::examples` matches that module and
+  /// everything below it. In a pattern containing `*`, each `*` matches any
+  /// run of characters, `::` included, and the whole path must match; a
+  /// trailing `::*` also matches the module itself, so `*::tests::*` drops
+  /// every `tests` module. Edges to or from excluded nodes are dropped with
+  /// them. Repeated calls add to the exclusions.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use hexser::graph::{GraphBuilder, HexNode, Layer, NodeId, Role};
+  ///
+  /// let graph = GraphBuilder::new()
+  ///     .exclude_modules(vec![String::from("*::tests::*")])
+  ///     .with_node(HexNode::new(NodeId::from_name("User"), Layer::Domain, Role::Entity, "User", "app::domain"))
+  ///     .with_node(HexNode::new(NodeId::from_name("Fake"), Layer::Domain, Role::Entity, "Fake", "app::domain::tests"))
+  ///     .build();
+  ///
+  /// assert_eq!(graph.node_count(), 1);
+  /// ```
+  pub fn exclude_modules(mut self, patterns: Vec<String>) -> Self {
+    self.excluded_modules.extend(patterns);
+    self
+  }
+
+  /// Choose how node ids relate to component type names.
+  ///
+  /// The default, `FullyQualifiedWithGenerics`, keeps the ids nodes were
+  /// added with; registered components already use their full type name,
+  /// so `Repo<User>` and `Repo<Order>` stay distinct. `TypeName` re-derives
+  /// every node id from the base of its `type_name` on build, rewriting
+  /// edges to match and merging nodes that end up with the same id (the
+  /// last one added wins). See `NodeIdStrategy` for the snapshot stability
+  /// tradeoff.
+  pub fn node_id_strategy(mut self, strategy: crate::graph::node_id::NodeIdStrategy) -> Self {
+    self.node_id_strategy = strategy;
+    self
+  }
+
   /// Add a node to the graph.
   pub fn with_node(mut self, node: crate::graph::hex_node::HexNode) -> Self {
     self.nodes.push(node);
@@ -88,26 +158,65 @@ impl GraphBuilder {
 
   /// Build the immutable graph.
   ///
-  /// Consumes the builder and returns a HexGraph. Validates that all
-  /// edges reference existing nodes.
-  pub fn build(self) -> crate::graph::hex_graph::HexGraph {
-    let mut node_map = std::collections::HashMap::new();
+  /// Consumes the builder and returns a HexGraph. Nodes are keyed by
+  /// NodeId and edges are sorted by source, target, and relationship, so
+  /// the resulting graph is identical regardless of insertion order (for
+  /// example, inventory link order).
+  pub fn build(mut self) -> crate::graph::hex_graph::HexGraph {
+    if self.node_id_strategy == crate::graph::node_id::NodeIdStrategy::TypeName {
+      self.rekey_nodes();
+    }
+
+    let mut node_map = std::collections::BTreeMap::new();
+    let mut excluded = std::collections::HashSet::new();
 
     for node in self.nodes {
+      if self
+        .excluded_modules
+        .iter()
+        .any(|pattern| module_matches(pattern, node.module_path()))
+      {
+        excluded.insert(*node.id());
+        continue;
+      }
       node_map.insert(node.id().clone(), node);
     }
 
-    let metadata = crate::graph::metadata::GraphMetadata::new(&self.description);
+    self
+      .edges
+      .retain(|e| !excluded.contains(e.source()) && !excluded.contains(e.target()));
+
+    self
+      .edges
+      .sort_by_key(|e| (*e.source(), *e.target(), e.relationship));
+
+    let mut metadata = crate::graph::metadata::GraphMetadata::new(&self.description);
+    metadata.build_info.app_version = self.app_version;
 
     let inner = std::sync::Arc::new(crate::graph::hex_graph::GraphInner {
       nodes: node_map,
       edges: self.edges,
       metadata,
+      cache: crate::graph::analysis_cache::AnalysisCache::new(self.analysis_cache),
     });
 
     crate::graph::hex_graph::HexGraph { inner }
   }
 
+  /// Re-derive node ids under the configured strategy, rewriting edges
+  fn rekey_nodes(&mut self) {
+    let mut renamed = std::collections::HashMap::new();
+    for node in &mut self.nodes {
+      let id = self.node_id_strategy.node_id(&node.type_name);
+      renamed.insert(node.id, id);
+      node.id = id;
+    }
+    for edge in &mut self.edges {
+      edge.source = renamed.get(&edge.source).copied().unwrap_or(edge.source);
+      edge.target = renamed.get(&edge.target).copied().unwrap_or(edge.target);
+    }
+  }
+
   /// Validate the graph structure before building.
   ///
   /// Returns Ok(()) if valid, or an error describing the issue.
@@ -150,6 +259,40 @@ impl GraphBuilder {
   }
 }
 
+/// Check `path` against an `exclude_modules` pattern.
+fn module_matches(pattern: &str, path: &str) -> bool {
+  if pattern.contains('*') {
+    return glob_matches(pattern.as_bytes(), path.as_bytes())
+      || glob_matches(pattern.as_bytes(), format!("{}::", path).as_bytes());
+  }
+  path == pattern
+    || path
+      .strip_prefix(pattern)
+      .is_some_and(|rest| rest.starts_with("::"))
+}
+
+/// Match `text` against `pattern`, where `*` matches any byte sequence.
+fn glob_matches(pattern: &[u8], text: &[u8]) -> bool {
+  let (mut p, mut t) = (0, 0);
+  let mut backtrack: Option<(usize, usize)> = None;
+  while t < text.len() {
+    if p < pattern.len() && pattern[p] == b'*' {
+      backtrack = Some((p, t));
+      p += 1;
+    } else if p < pattern.len() && pattern[p] == text[t] {
+      p += 1;
+      t += 1;
+    } else if let Some((star, matched)) = backtrack {
+      p = star + 1;
+      t = matched + 1;
+      backtrack = Some((star, matched + 1));
+    } else {
+      return false;
+    }
+  }
+  pattern[p..].iter().all(|&b| b == b'*')
+}
+
 impl Default for GraphBuilder {
   fn default() -> Self {
     Self::new()
@@ -239,4 +382,151 @@ mod tests {
     let builder = GraphBuilder::new().with_edge(edge);
     assert!(builder.validate().is_err());
   }
+
+  #[test]
+  fn test_build_order_is_deterministic() {
+    // Test: Insertion order must not affect node or edge ordering.
+    // Justification: inventory link order varies between builds, breaking snapshots and diffs.
+    let names = [
+      "Order",
+      "OrderRepository",
+      "PgOrderRepository",
+      "PlaceOrder",
+    ];
+    let nodes: Vec<crate::graph::hex_node::HexNode> = names
+      .iter()
+      .map(|name| {
+        crate::graph::hex_node::HexNode::new(
+          crate::graph::node_id::NodeId::from_name(name),
+          crate::graph::layer::Layer::Domain,
+          crate::graph::role::Role::Entity,
+          name,
+          "domain",
+        )
+      })
+      .collect();
+    let edges: Vec<crate::graph::hex_edge::HexEdge> = [
+      ("PgOrderRepository", "OrderRepository"),
+      ("PlaceOrder", "Order"),
+      ("OrderRepository", "Order"),
+      ("PlaceOrder", "OrderRepository"),
+    ]
+    .iter()
+    .map(|(from, to)| {
+      crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name(from),
+        crate::graph::node_id::NodeId::from_name(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    })
+    .collect();
+
+    let forward = GraphBuilder::new()
+      .with_nodes(nodes.clone())
+      .with_edges(edges.clone())
+      .build();
+    let reversed = GraphBuilder::new()
+      .with_nodes(nodes.into_iter().rev().collect())
+      .with_edges(edges.into_iter().rev().collect())
+      .build();
+
+    let forward_ids: Vec<_> = forward.nodes().map(|n| n.id).collect();
+    let reversed_ids: Vec<_> = reversed.nodes().map(|n| n.id).collect();
+    assert_eq!(forward_ids, reversed_ids);
+    assert_eq!(forward.edges(), reversed.edges());
+  }
+
+  #[test]
+  fn test_exclude_modules_drops_matching_nodes_and_their_edges() {
+    let node = |name: &str, module: &str| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        module,
+      )
+    };
+    let graph = GraphBuilder::new()
+      .with_nodes(vec![
+        node("Order", "shop::domain"),
+        node("FakeOrder", "shop::domain::tests"),
+        node("Demo", "shop_examples::demo"),
+        node("Helper", "shop::domain::tests_support"),
+      ])
+      .with_edge(crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name("FakeOrder"),
+        crate::graph::node_id::NodeId::from_name("Order"),
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .exclude_modules(vec![String::from("*::tests::*")])
+      .exclude_modules(vec![String::from("shop_examples")])
+      .build();
+
+    let names: Vec<&str> = graph.nodes().map(|n| n.type_name.as_str()).collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&"Order") && names.contains(&"Helper"));
+    assert_eq!(graph.edge_count(), 0);
+  }
+
+  #[test]
+  fn test_module_patterns() {
+    assert!(module_matches("app::examples", "app::examples"));
+    assert!(module_matches("app::examples", "app::examples::cli"));
+    assert!(!module_matches("app::examples", "app::examples_old"));
+    assert!(module_matches("*::tests::*", "app::tests"));
+    assert!(module_matches("*::tests::*", "app::a::tests::b"));
+    assert!(!module_matches("*::tests::*", "app::tests_util"));
+    assert!(module_matches("*_example*", "crate_example::main"));
+  }
+
+  #[test]
+  fn test_node_id_strategy_keeps_or_merges_generic_instantiations() {
+    struct Repo<T>(std::marker::PhantomData<T>);
+    struct User;
+    struct Order;
+
+    fn component<T: 'static>(role: crate::graph::role::Role) -> crate::graph::hex_node::HexNode {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::of::<T>(),
+        crate::graph::layer::Layer::Adapter,
+        role,
+        std::any::type_name::<T>(),
+        "app::adapters",
+      )
+    }
+    let builder = || {
+      GraphBuilder::new()
+        .with_node(component::<Repo<User>>(
+          crate::graph::role::Role::Repository,
+        ))
+        .with_node(component::<Repo<Order>>(
+          crate::graph::role::Role::Repository,
+        ))
+        .with_node(component::<User>(crate::graph::role::Role::Entity))
+        .with_edge(crate::graph::hex_edge::HexEdge::new(
+          crate::graph::node_id::NodeId::of::<Repo<User>>(),
+          crate::graph::node_id::NodeId::of::<User>(),
+          crate::graph::relationship::Relationship::Depends,
+        ))
+    };
+
+    let qualified = builder().build();
+    assert_eq!(qualified.node_count(), 3);
+    assert_ne!(
+      crate::graph::node_id::NodeId::of::<Repo<User>>(),
+      crate::graph::node_id::NodeId::of::<Repo<Order>>()
+    );
+
+    let collapsed = builder()
+      .node_id_strategy(crate::graph::node_id::NodeIdStrategy::TypeName)
+      .build();
+    let repo = crate::graph::node_id::NodeId::from_name("Repo");
+    assert_eq!(collapsed.node_count(), 2);
+    assert!(collapsed.get_node(&repo).is_some());
+    assert_eq!(
+      collapsed.edges_from(&repo)[0].target,
+      crate::graph::node_id::NodeId::from_name("User")
+    );
+  }
 }
@@ -0,0 +1,257 @@
+//! Scriptable command interface for querying architecture graphs.
+//!
+//! Parses simple subcommands and returns formatted text, so applications can
+//! expose graph queries from a thin `main` without hexser shipping a binary.
+//! Every command delegates to existing graph query and analysis methods.
+//!
+//! # Commands
+//!
+//! - `layers` - list nodes grouped by layer
+//! - `cycles` - list dependency cycles
+//! - `path <from> <to>` - shortest dependency path between two type names
+//! - `health` - architecture health report
+//! - `export <dot|mermaid|json>` - render the graph (requires `visualization`)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! // Body of a thin `main` in the application's own bin target
+//! let args: Vec<String> = std::env::args().skip(1).collect();
+//! match hexser::graph::cli::run(&args) {
+//!     Ok(output) => println!("{}", output),
+//!     Err(e) => eprintln!("{}", e),
+//! }
+//! ```
+//!
+//! Revision History
+//! - 2026-10-18T15:00:00Z @AI: Initial graph CLI entrypoint.
+
+/// Usage text returned for missing or unknown commands
+pub const USAGE: &str = "Usage: <command> [args]
+Commands:
+  layers                      List nodes grouped by layer
+  cycles                      List dependency cycles
+  path <from> <to>            Shortest dependency path between two types
+  health                      Architecture health report
+  export <dot|mermaid|json>   Render the graph";
+
+/// Run a command against the graph built from registered components
+pub fn run(args: &[String]) -> crate::result::hex_result::HexResult<String> {
+  let graph = crate::graph::hex_graph::HexGraph::current();
+  run_on(&graph, args)
+}
+
+/// Run a command against the given graph
+pub fn run_on(
+  graph: &crate::graph::hex_graph::HexGraph,
+  args: &[String],
+) -> crate::result::hex_result::HexResult<String> {
+  let args: Vec<&str> = args.iter().map(String::as_str).collect();
+  match args.as_slice() {
+    ["layers"] => Ok(layers(graph)),
+    ["cycles"] => Ok(cycles(graph)),
+    ["path", from, to] => path(graph, from, to),
+    ["health"] => Ok(graph.health_report().to_string()),
+    ["export", format] => export(graph, format),
+    _ => Err(
+      crate::error::hex_error::Hexserror::validation(&format!(
+        "Unrecognized command: '{}'",
+        args.join(" ")
+      ))
+      .with_field("command")
+      .with_next_step(USAGE),
+    ),
+  }
+}
+
+fn layers(graph: &crate::graph::hex_graph::HexGraph) -> String {
+  let mut lines = Vec::new();
+  for layer in [
+    crate::graph::layer::Layer::Domain,
+    crate::graph::layer::Layer::Port,
+    crate::graph::layer::Layer::Adapter,
+    crate::graph::layer::Layer::Application,
+    crate::graph::layer::Layer::Infrastructure,
+    crate::graph::layer::Layer::Unknown,
+  ] {
+    let nodes = graph.query().layer(layer).execute();
+    if nodes.is_empty() {
+      continue;
+    }
+    lines.push(format!("{} ({})", layer, nodes.len()));
+    for node in nodes {
+      lines.push(format!("  {}", node.type_name));
+    }
+  }
+  lines.join("\n")
+}
+
+fn cycles(graph: &crate::graph::hex_graph::HexGraph) -> String {
+  let cycles = graph.analysis().detect_cycles();
+  if cycles.is_empty() {
+    return String::from("No cycles detected");
+  }
+  cycles
+    .iter()
+    .map(|cycle| {
+      let mut names: Vec<&str> = cycle.iter().map(|id| type_name_of(graph, id)).collect();
+      names.push(type_name_of(graph, &cycle[0]));
+      names.join(" -> ")
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn path(
+  graph: &crate::graph::hex_graph::HexGraph,
+  from: &str,
+  to: &str,
+) -> crate::result::hex_result::HexResult<String> {
+  let from_node = resolve(graph, from)?;
+  let to_node = resolve(graph, to)?;
+  match graph.analysis().find_path(from_node.id, to_node.id) {
+    Some(ids) => Ok(
+      ids
+        .iter()
+        .map(|id| type_name_of(graph, id))
+        .collect::<Vec<_>>()
+        .join(" -> "),
+    ),
+    None => Ok(format!("No path from {} to {}", from, to)),
+  }
+}
+
+#[cfg(feature = "visualization")]
+fn export(
+  graph: &crate::graph::hex_graph::HexGraph,
+  format: &str,
+) -> crate::result::hex_result::HexResult<String> {
+  match format {
+    "dot" => graph.to_dot(),
+    "mermaid" => graph.to_mermaid(),
+    "json" => graph.to_json(),
+    other => Err(
+      crate::error::hex_error::Hexserror::validation(&format!(
+        "Unsupported export format: '{}'",
+        other
+      ))
+      .with_field("format")
+      .with_next_step("Use one of: dot, mermaid, json"),
+    ),
+  }
+}
+
+#[cfg(not(feature = "visualization"))]
+fn export(
+  _graph: &crate::graph::hex_graph::HexGraph,
+  _format: &str,
+) -> crate::result::hex_result::HexResult<String> {
+  Err(
+    crate::error::hex_error::Hexserror::validation("Export requires the visualization feature")
+      .with_field("format")
+      .with_next_step("Enable the `visualization` feature of hexser"),
+  )
+}
+
+/// Find a node by exact type name or by its last path segment
+fn resolve<'g>(
+  graph: &'g crate::graph::hex_graph::HexGraph,
+  name: &str,
+) -> crate::result::hex_result::HexResult<&'g crate::graph::hex_node::HexNode> {
+  graph
+    .nodes()
+    .find(|n| n.type_name == name || n.type_name.rsplit("::").next() == Some(name))
+    .ok_or_else(|| crate::error::hex_error::Hexserror::not_found("Node", name))
+}
+
+fn type_name_of<'g>(
+  graph: &'g crate::graph::hex_graph::HexGraph,
+  id: &crate::graph::node_id::NodeId,
+) -> &'g str {
+  graph.get_node(id).map_or("?", |n| n.type_name.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+  }
+
+  fn sample_graph() -> crate::graph::hex_graph::HexGraph {
+    let node = |name: &str, layer, role| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        layer,
+        role,
+        &format!("app::{}", name),
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name(from),
+        crate::graph::node_id::NodeId::from_name(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "User",
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+      ))
+      .with_node(node(
+        "UserRepository",
+        crate::graph::layer::Layer::Port,
+        crate::graph::role::Role::Repository,
+      ))
+      .with_node(node(
+        "PgUserRepository",
+        crate::graph::layer::Layer::Adapter,
+        crate::graph::role::Role::Adapter,
+      ))
+      .with_edge(edge("PgUserRepository", "UserRepository"))
+      .with_edge(edge("UserRepository", "User"))
+      .build()
+  }
+
+  #[test]
+  fn test_layers_command() {
+    let output = run_on(&sample_graph(), &args("layers")).unwrap();
+    assert!(output.contains("Domain (1)\n  app::User"));
+    assert!(output.contains("Adapter (1)"));
+  }
+
+  #[test]
+  fn test_path_command_resolves_short_names() {
+    let output = run_on(&sample_graph(), &args("path PgUserRepository User")).unwrap();
+    assert_eq!(
+      output,
+      "app::PgUserRepository -> app::UserRepository -> app::User"
+    );
+
+    let output = run_on(&sample_graph(), &args("path User PgUserRepository")).unwrap();
+    assert_eq!(output, "No path from User to PgUserRepository");
+  }
+
+  #[test]
+  fn test_cycles_command() {
+    let output = run_on(&sample_graph(), &args("cycles")).unwrap();
+    assert_eq!(output, "No cycles detected");
+  }
+
+  #[test]
+  fn test_unknown_command_and_node_are_errors() {
+    assert!(run_on(&sample_graph(), &args("frobnicate")).is_err());
+    assert!(run_on(&sample_graph(), &args("path User Missing")).is_err());
+  }
+
+  #[cfg(feature = "visualization")]
+  #[test]
+  fn test_export_command() {
+    let output = run_on(&sample_graph(), &args("export mermaid")).unwrap();
+    assert!(output.contains("app::User"));
+  }
+}
@@ -0,0 +1,431 @@
+//! Structural diff between two snapshots of a HexGraph.
+//!
+//! GraphDiff compares an older and a newer graph, typically exported from
+//! two git revisions, and lists the nodes and edges that were added or
+//! removed. Computed with analysis, it also reports the cycles and layer
+//! violations the newer graph introduces. `summary` renders the result as
+//! Markdown suitable for a pull request comment.
+//!
+//! Revision History
+//! - 2026-10-19T04:15:00Z @AI: Initial GraphDiff with Markdown summary.
+
+/// Differences between an older and a newer graph.
+///
+/// Nodes are matched by id and edges by source, target, and relationship.
+/// Node lists are ordered by node id; edge lists follow graph edge order.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::{GraphDiff, HexGraph, HexNode, Layer, NodeId, Role};
+///
+/// let old = HexGraph::builder()
+///     .with_node(HexNode::new(NodeId::from_name("UserRepository"), Layer::Port, Role::Repository, "UserRepository", "ports"))
+///     .build();
+/// let new = HexGraph::builder()
+///     .with_node(HexNode::new(NodeId::from_name("PgUsers"), Layer::Adapter, Role::Adapter, "PgUsers", "adapters"))
+///     .build();
+///
+/// let diff = old.diff(&new);
+/// assert!(diff.summary().starts_with("added 1 adapter, removed 1 port"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphDiff {
+  /// Nodes present only in the newer graph.
+  pub added_nodes: Vec<crate::graph::hex_node::HexNode>,
+
+  /// Nodes present only in the older graph.
+  pub removed_nodes: Vec<crate::graph::hex_node::HexNode>,
+
+  /// Edges present only in the newer graph, with endpoint type names.
+  pub added_edges: Vec<EdgeChange>,
+
+  /// Edges present only in the older graph, with endpoint type names.
+  pub removed_edges: Vec<EdgeChange>,
+
+  /// Cycles in the newer graph over a node set absent from the older one,
+  /// as type names in cycle order; None when computed without analysis.
+  pub new_cycles: Option<Vec<Vec<String>>>,
+
+  /// Layer violations in the newer graph but not the older one; None when
+  /// computed without analysis or without the `analysis` feature.
+  pub new_layer_violations: Option<Vec<EdgeChange>>,
+}
+
+/// An added or removed edge, or a layer violation, described by type names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdgeChange {
+  /// Type name of the source node.
+  pub source: String,
+
+  /// Type name of the target node.
+  pub target: String,
+
+  /// Relationship of the edge.
+  pub relationship: crate::graph::relationship::Relationship,
+}
+
+impl std::fmt::Display for EdgeChange {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "`{}` {} `{}`",
+      self.source, self.relationship, self.target
+    )
+  }
+}
+
+impl GraphDiff {
+  /// Compare nodes and edges of `old` and `new`.
+  pub fn new(
+    old: &crate::graph::hex_graph::HexGraph,
+    new: &crate::graph::hex_graph::HexGraph,
+  ) -> Self {
+    Self {
+      added_nodes: Self::missing_nodes(new, old),
+      removed_nodes: Self::missing_nodes(old, new),
+      added_edges: Self::missing_edges(new, old),
+      removed_edges: Self::missing_edges(old, new),
+      new_cycles: None,
+      new_layer_violations: None,
+    }
+  }
+
+  /// Compare `old` and `new`, also reporting newly introduced cycles and
+  /// layer violations.
+  pub fn with_analysis(
+    old: &crate::graph::hex_graph::HexGraph,
+    new: &crate::graph::hex_graph::HexGraph,
+  ) -> Self {
+    let existing: std::collections::HashSet<Vec<crate::graph::node_id::NodeId>> = old
+      .analysis()
+      .detect_cycles()
+      .into_iter()
+      .map(Self::cycle_key)
+      .collect();
+    let new_cycles = new
+      .analysis()
+      .detect_cycles()
+      .into_iter()
+      .filter(|cycle| !existing.contains(&Self::cycle_key(cycle.clone())))
+      .map(|cycle| cycle.iter().map(|id| Self::type_name(new, id)).collect())
+      .collect();
+
+    Self {
+      new_cycles: Some(new_cycles),
+      new_layer_violations: Self::new_layer_violations(old, new),
+      ..Self::new(old, new)
+    }
+  }
+
+  /// True when nothing was added, removed, or introduced.
+  pub fn is_empty(&self) -> bool {
+    self.added_nodes.is_empty()
+      && self.removed_nodes.is_empty()
+      && self.added_edges.is_empty()
+      && self.removed_edges.is_empty()
+      && self.new_cycles.as_ref().is_none_or(Vec::is_empty)
+      && self.new_layer_violations.as_ref().is_none_or(Vec::is_empty)
+  }
+
+  /// Markdown summary for a pull request comment.
+  ///
+  /// The first line is a headline such as `added 2 adapters, removed 1
+  /// port, introduced 1 cycle`, followed by a bullet per change.
+  pub fn summary(&self) -> String {
+    if self.is_empty() {
+      return String::from("no architecture changes");
+    }
+
+    let mut headline = Vec::new();
+    for (verb, nodes) in [
+      ("added", &self.added_nodes),
+      ("removed", &self.removed_nodes),
+    ] {
+      for layer in LAYERS {
+        let count = nodes.iter().filter(|n| n.layer == layer).count();
+        if count > 0 {
+          headline.push(format!("{} {}", verb, count_of(count, layer_noun(layer))));
+        }
+      }
+    }
+    for (verb, edges) in [
+      ("added", &self.added_edges),
+      ("removed", &self.removed_edges),
+    ] {
+      if !edges.is_empty() {
+        headline.push(format!("{} {}", verb, count_of(edges.len(), "edge")));
+      }
+    }
+    if let Some(cycles) = self.new_cycles.as_ref().filter(|c| !c.is_empty()) {
+      headline.push(format!("introduced {}", count_of(cycles.len(), "cycle")));
+    }
+    if let Some(violations) = self.new_layer_violations.as_ref().filter(|v| !v.is_empty()) {
+      headline.push(format!(
+        "introduced {}",
+        count_of(violations.len(), "layer violation")
+      ));
+    }
+
+    let mut lines = vec![headline.join(", "), String::new()];
+    for (verb, nodes) in [
+      ("Added", &self.added_nodes),
+      ("Removed", &self.removed_nodes),
+    ] {
+      for node in nodes {
+        lines.push(format!(
+          "- {} {} `{}` (`{}`)",
+          verb,
+          layer_noun(node.layer),
+          node.type_name,
+          node.module_path
+        ));
+      }
+    }
+    for (verb, edges) in [
+      ("Added", &self.added_edges),
+      ("Removed", &self.removed_edges),
+    ] {
+      for edge in edges {
+        lines.push(format!("- {} edge {}", verb, edge));
+      }
+    }
+    for cycle in self.new_cycles.iter().flatten() {
+      let mut names: Vec<String> = cycle.iter().map(|name| format!("`{}`", name)).collect();
+      names.extend(names.first().cloned());
+      lines.push(format!("- New cycle: {}", names.join(" → ")));
+    }
+    for violation in self.new_layer_violations.iter().flatten() {
+      lines.push(format!("- New layer violation: {}", violation));
+    }
+    lines.join("\n")
+  }
+
+  fn missing_nodes(
+    from: &crate::graph::hex_graph::HexGraph,
+    other: &crate::graph::hex_graph::HexGraph,
+  ) -> Vec<crate::graph::hex_node::HexNode> {
+    from
+      .nodes()
+      .filter(|n| other.get_node(&n.id).is_none())
+      .cloned()
+      .collect()
+  }
+
+  fn missing_edges(
+    from: &crate::graph::hex_graph::HexGraph,
+    other: &crate::graph::hex_graph::HexGraph,
+  ) -> Vec<EdgeChange> {
+    from
+      .edges()
+      .iter()
+      .filter(|e| {
+        !other
+          .edges_from(&e.source)
+          .iter()
+          .any(|o| o.target == e.target && o.relationship == e.relationship)
+      })
+      .map(|e| Self::edge_change(from, &e.source, &e.target, e.relationship))
+      .collect()
+  }
+
+  #[cfg(feature = "analysis")]
+  fn new_layer_violations(
+    old: &crate::graph::hex_graph::HexGraph,
+    new: &crate::graph::hex_graph::HexGraph,
+  ) -> Option<Vec<EdgeChange>> {
+    let violations = |graph: &crate::graph::hex_graph::HexGraph| {
+      graph
+        .validation()
+        .validate_layer_dependencies()
+        .err()
+        .unwrap_or_default()
+    };
+    let mut existing: std::collections::HashSet<_> = violations(old)
+      .into_iter()
+      .map(|v| (v.from, v.to))
+      .collect();
+    let mut introduced = Vec::new();
+    for violation in violations(new) {
+      if existing.insert((violation.from, violation.to)) {
+        let relationship = new
+          .edges_from(&violation.from)
+          .iter()
+          .find(|e| e.target == violation.to)
+          .map_or(crate::graph::relationship::Relationship::Depends, |e| {
+            e.relationship
+          });
+        introduced.push(Self::edge_change(
+          new,
+          &violation.from,
+          &violation.to,
+          relationship,
+        ));
+      }
+    }
+    Some(introduced)
+  }
+
+  #[cfg(not(feature = "analysis"))]
+  fn new_layer_violations(
+    _old: &crate::graph::hex_graph::HexGraph,
+    _new: &crate::graph::hex_graph::HexGraph,
+  ) -> Option<Vec<EdgeChange>> {
+    None
+  }
+
+  fn edge_change(
+    graph: &crate::graph::hex_graph::HexGraph,
+    source: &crate::graph::node_id::NodeId,
+    target: &crate::graph::node_id::NodeId,
+    relationship: crate::graph::relationship::Relationship,
+  ) -> EdgeChange {
+    EdgeChange {
+      source: Self::type_name(graph, source),
+      target: Self::type_name(graph, target),
+      relationship,
+    }
+  }
+
+  fn type_name(
+    graph: &crate::graph::hex_graph::HexGraph,
+    id: &crate::graph::node_id::NodeId,
+  ) -> String {
+    graph
+      .get_node(id)
+      .map_or_else(|| id.to_string(), |n| n.type_name.clone())
+  }
+
+  fn cycle_key(
+    mut cycle: Vec<crate::graph::node_id::NodeId>,
+  ) -> Vec<crate::graph::node_id::NodeId> {
+    cycle.sort();
+    cycle
+  }
+}
+
+const LAYERS: [crate::graph::layer::Layer; 6] = [
+  crate::graph::layer::Layer::Domain,
+  crate::graph::layer::Layer::Port,
+  crate::graph::layer::Layer::Adapter,
+  crate::graph::layer::Layer::Application,
+  crate::graph::layer::Layer::Infrastructure,
+  crate::graph::layer::Layer::Unknown,
+];
+
+fn layer_noun(layer: crate::graph::layer::Layer) -> &'static str {
+  match layer {
+    crate::graph::layer::Layer::Domain => "domain component",
+    crate::graph::layer::Layer::Port => "port",
+    crate::graph::layer::Layer::Adapter => "adapter",
+    crate::graph::layer::Layer::Application => "application component",
+    crate::graph::layer::Layer::Infrastructure => "infrastructure component",
+    crate::graph::layer::Layer::Unknown => "unclassified component",
+  }
+}
+
+fn count_of(count: usize, noun: &str) -> String {
+  if count == 1 {
+    format!("1 {}", noun)
+  } else {
+    format!("{} {}s", count, noun)
+  }
+}
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Diff this graph against a newer snapshot.
+  pub fn diff(&self, newer: &Self) -> GraphDiff {
+    GraphDiff::new(self, newer)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn node(name: &str, layer: crate::graph::layer::Layer) -> crate::graph::hex_node::HexNode {
+    crate::graph::hex_node::HexNode::new(
+      crate::graph::node_id::NodeId::from_name(name),
+      layer,
+      crate::graph::role::Role::Entity,
+      name,
+      "test",
+    )
+  }
+
+  fn depends(from: &str, to: &str) -> crate::graph::hex_edge::HexEdge {
+    crate::graph::hex_edge::HexEdge::new(
+      crate::graph::node_id::NodeId::from_name(from),
+      crate::graph::node_id::NodeId::from_name(to),
+      crate::graph::relationship::Relationship::Depends,
+    )
+  }
+
+  fn old_graph() -> crate::graph::hex_graph::HexGraph {
+    crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Orders", crate::graph::layer::Layer::Port))
+      .with_node(node("Service", crate::graph::layer::Layer::Application))
+      .with_node(node("Order", crate::graph::layer::Layer::Domain))
+      .with_edge(depends("Service", "Orders"))
+      .build()
+  }
+
+  fn new_graph() -> crate::graph::hex_graph::HexGraph {
+    crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Service", crate::graph::layer::Layer::Application))
+      .with_node(node("Order", crate::graph::layer::Layer::Domain))
+      .with_node(node("PgOrders", crate::graph::layer::Layer::Adapter))
+      .with_node(node("HttpOrders", crate::graph::layer::Layer::Adapter))
+      .with_edge(depends("Service", "PgOrders"))
+      .with_edge(depends("PgOrders", "HttpOrders"))
+      .with_edge(depends("HttpOrders", "PgOrders"))
+      .with_edge(depends("Order", "PgOrders"))
+      .build()
+  }
+
+  #[test]
+  fn test_lists_added_and_removed_nodes_and_edges() {
+    let diff = old_graph().diff(&new_graph());
+    let names = |nodes: &[crate::graph::hex_node::HexNode]| {
+      let mut names: Vec<String> = nodes.iter().map(|n| n.type_name.clone()).collect();
+      names.sort();
+      names
+    };
+    assert_eq!(names(&diff.added_nodes), vec!["HttpOrders", "PgOrders"]);
+    assert_eq!(names(&diff.removed_nodes), vec!["Orders"]);
+    assert_eq!(diff.added_edges.len(), 4);
+    assert_eq!(diff.removed_edges[0].target, "Orders");
+    assert_eq!(diff.new_cycles, None);
+    assert_eq!(
+      diff.summary().lines().next().unwrap(),
+      "added 2 adapters, removed 1 port, added 4 edges, removed 1 edge"
+    );
+  }
+
+  #[test]
+  fn test_analysis_reports_introduced_cycles() {
+    let diff = GraphDiff::with_analysis(&old_graph(), &new_graph());
+    let cycles = diff.new_cycles.as_ref().unwrap();
+    assert_eq!(cycles.len(), 1);
+    let summary = diff.summary();
+    assert!(summary.contains("introduced 1 cycle"));
+    assert!(summary.contains("- New cycle: `"));
+    assert!(summary.contains("- Added adapter `PgOrders` (`test`)"));
+  }
+
+  #[cfg(feature = "analysis")]
+  #[test]
+  fn test_analysis_reports_introduced_layer_violations() {
+    let diff = GraphDiff::with_analysis(&old_graph(), &new_graph());
+    let violations = diff.new_layer_violations.unwrap();
+    let order = violations.iter().find(|v| v.source == "Order").unwrap();
+    assert_eq!(order.target, "PgOrders");
+  }
+
+  #[test]
+  fn test_identical_graphs_have_no_changes() {
+    let diff = GraphDiff::with_analysis(&old_graph(), &old_graph());
+    assert!(diff.is_empty());
+    assert_eq!(diff.summary(), "no architecture changes");
+  }
+}
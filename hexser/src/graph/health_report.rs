@@ -0,0 +1,449 @@
+//! Architecture health report computed from a HexGraph in a single call.
+//!
+//! Bundles the most useful graph metrics (per-layer counts, cycles, layer
+//! violations, orphans, dependency hotspots, and ports with too many
+//! methods) into one value with a readable Display implementation, suitable
+//! for printing in CI logs.
+//!
+//! Revision History
+//! - 2026-10-19T02:25:00Z @AI: Flag wide ports from recorded trait method counts.
+//! - 2026-10-18T10:00:00Z @AI: Initial HealthReport implementation.
+
+/// Number of most-depended-upon nodes included in a report.
+const HOTSPOT_LIMIT: usize = 3;
+
+/// Method count above which a port is reported as wide.
+///
+/// Ports with many methods usually mix several client concerns and are
+/// candidates for interface segregation.
+pub const WIDE_PORT_METHOD_LIMIT: u32 = 7;
+
+/// Node and edge counts for a single layer.
+///
+/// Edges are attributed to the layer of their source node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerSummary {
+  /// The layer these counts describe.
+  pub layer: crate::graph::layer::Layer,
+
+  /// Number of nodes in the layer.
+  pub node_count: usize,
+
+  /// Number of edges originating from nodes in the layer.
+  pub edge_count: usize,
+}
+
+/// A node that many other nodes depend upon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyHotspot {
+  /// Identifier of the depended-upon node.
+  pub node_id: crate::graph::node_id::NodeId,
+
+  /// Type name of the depended-upon node.
+  pub type_name: String,
+
+  /// Number of incoming edges.
+  pub dependents: usize,
+}
+
+/// A port trait declaring more methods than `WIDE_PORT_METHOD_LIMIT`.
+///
+/// Counts come from the `required_methods` and `default_methods` node
+/// metadata recorded by `#[hex_port]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WidePort {
+  /// Identifier of the port node.
+  pub node_id: crate::graph::node_id::NodeId,
+
+  /// Type name of the port node.
+  pub type_name: String,
+
+  /// Methods without a default body.
+  pub required_methods: u32,
+
+  /// Methods with a default body.
+  pub default_methods: u32,
+}
+
+/// Summary of architecture health metrics for a graph.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::{HexGraph, HexNode, NodeId, Layer, Role};
+///
+/// let graph = HexGraph::builder()
+///     .with_node(HexNode::new(
+///         NodeId::from_name("User"),
+///         Layer::Domain,
+///         Role::Entity,
+///         "User",
+///         "domain",
+///     ))
+///     .build();
+///
+/// let report = graph.health_report();
+/// assert_eq!(report.node_count, 1);
+/// assert_eq!(report.orphan_count, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+  /// Total number of nodes.
+  pub node_count: usize,
+
+  /// Total number of edges.
+  pub edge_count: usize,
+
+  /// Per-layer counts for every layer that has at least one node.
+  pub layers: Vec<LayerSummary>,
+
+  /// Number of dependency cycles detected.
+  pub cycle_count: usize,
+
+  /// Number of layer dependency violations, or None when the `analysis`
+  /// feature is disabled.
+  pub layer_violations: Option<usize>,
+
+  /// Number of nodes with neither incoming nor outgoing edges.
+  pub orphan_count: usize,
+
+  /// Up to three most-depended-upon nodes, highest first.
+  pub most_depended_upon: Vec<DependencyHotspot>,
+
+  /// Ports with more than `WIDE_PORT_METHOD_LIMIT` methods, widest first.
+  pub wide_ports: Vec<WidePort>,
+}
+
+impl HealthReport {
+  /// Compute a health report for the given graph.
+  pub fn from_graph(graph: &crate::graph::hex_graph::HexGraph) -> Self {
+    let layers = [
+      crate::graph::layer::Layer::Domain,
+      crate::graph::layer::Layer::Port,
+      crate::graph::layer::Layer::Adapter,
+      crate::graph::layer::Layer::Application,
+      crate::graph::layer::Layer::Infrastructure,
+      crate::graph::layer::Layer::Unknown,
+    ]
+    .into_iter()
+    .filter_map(|layer| {
+      let node_count = graph.nodes_by_layer(layer).len();
+      if node_count == 0 {
+        return None;
+      }
+      let edge_count = graph
+        .edges()
+        .iter()
+        .filter(|e| graph.get_node(&e.source).map(|n| n.layer()) == Some(layer))
+        .count();
+      Some(LayerSummary {
+        layer,
+        node_count,
+        edge_count,
+      })
+    })
+    .collect();
+
+    let orphan_count = graph
+      .nodes()
+      .filter(|n| graph.edges_from(&n.id).is_empty() && graph.edges_to(&n.id).is_empty())
+      .count();
+
+    let mut most_depended_upon: Vec<DependencyHotspot> = graph
+      .nodes()
+      .map(|n| DependencyHotspot {
+        node_id: n.id,
+        type_name: n.type_name.clone(),
+        dependents: graph.edges_to(&n.id).len(),
+      })
+      .filter(|h| h.dependents > 0)
+      .collect();
+    most_depended_upon.sort_by(|a, b| {
+      b.dependents
+        .cmp(&a.dependents)
+        .then_with(|| a.type_name.cmp(&b.type_name))
+    });
+    most_depended_upon.truncate(HOTSPOT_LIMIT);
+
+    let count = |node: &crate::graph::hex_node::HexNode, key: &str| {
+      node
+        .get_metadata(key)
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(0)
+    };
+    let mut wide_ports: Vec<WidePort> = graph
+      .nodes_by_layer(crate::graph::layer::Layer::Port)
+      .into_iter()
+      .map(|n| WidePort {
+        node_id: n.id,
+        type_name: n.type_name.clone(),
+        required_methods: count(
+          n,
+          crate::registry::node_builder::REQUIRED_METHODS_METADATA_KEY,
+        ),
+        default_methods: count(
+          n,
+          crate::registry::node_builder::DEFAULT_METHODS_METADATA_KEY,
+        ),
+      })
+      .filter(|p| p.required_methods + p.default_methods > WIDE_PORT_METHOD_LIMIT)
+      .collect();
+    wide_ports.sort_by(|a, b| {
+      (b.required_methods + b.default_methods)
+        .cmp(&(a.required_methods + a.default_methods))
+        .then_with(|| a.type_name.cmp(&b.type_name))
+    });
+
+    Self {
+      node_count: graph.node_count(),
+      edge_count: graph.edge_count(),
+      layers,
+      cycle_count: graph.analysis().detect_cycles().len(),
+      layer_violations: Self::count_layer_violations(graph),
+      orphan_count,
+      most_depended_upon,
+      wide_ports,
+    }
+  }
+
+  #[cfg(feature = "analysis")]
+  fn count_layer_violations(graph: &crate::graph::hex_graph::HexGraph) -> Option<usize> {
+    match graph.validation().validate_layer_dependencies() {
+      Ok(()) => Some(0),
+      Err(violations) => Some(violations.len()),
+    }
+  }
+
+  #[cfg(not(feature = "analysis"))]
+  fn count_layer_violations(_graph: &crate::graph::hex_graph::HexGraph) -> Option<usize> {
+    None
+  }
+}
+
+impl std::fmt::Display for HealthReport {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "Architecture Health Report")?;
+    writeln!(f, "  Nodes: {}", self.node_count)?;
+    writeln!(f, "  Edges: {}", self.edge_count)?;
+    writeln!(f, "  By Layer:")?;
+    for summary in &self.layers {
+      writeln!(
+        f,
+        "    {}: {} nodes, {} edges",
+        summary.layer, summary.node_count, summary.edge_count
+      )?;
+    }
+    writeln!(f, "  Cycles: {}", self.cycle_count)?;
+    match self.layer_violations {
+      Some(count) => writeln!(f, "  Layer violations: {}", count)?,
+      None => writeln!(
+        f,
+        "  Layer violations: not analyzed (enable `analysis` feature)"
+      )?,
+    }
+    writeln!(f, "  Orphans: {}", self.orphan_count)?;
+    write!(f, "  Most depended upon:")?;
+    if self.most_depended_upon.is_empty() {
+      write!(f, " none")?;
+    }
+    for (rank, hotspot) in self.most_depended_upon.iter().enumerate() {
+      write!(
+        f,
+        "\n    {}. {} ({} dependents)",
+        rank + 1,
+        hotspot.type_name,
+        hotspot.dependents
+      )?;
+    }
+    for port in &self.wide_ports {
+      write!(
+        f,
+        "\n  Wide port: {} ({} required, {} defaulted methods)",
+        port.type_name, port.required_methods, port.default_methods
+      )?;
+    }
+    Ok(())
+  }
+}
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Compute an architecture health report for this graph.
+  pub fn health_report(&self) -> HealthReport {
+    HealthReport::from_graph(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  fn node(
+    name: &str,
+    layer: crate::graph::layer::Layer,
+    role: crate::graph::role::Role,
+  ) -> crate::graph::hex_node::HexNode {
+    crate::graph::hex_node::HexNode::new(
+      crate::graph::node_id::NodeId::from_name(name),
+      layer,
+      role,
+      name,
+      "test",
+    )
+  }
+
+  fn edge(from: &str, to: &str) -> crate::graph::hex_edge::HexEdge {
+    crate::graph::hex_edge::HexEdge::new(
+      crate::graph::node_id::NodeId::from_name(from),
+      crate::graph::node_id::NodeId::from_name(to),
+      crate::graph::relationship::Relationship::Depends,
+    )
+  }
+
+  fn sample_graph() -> crate::graph::hex_graph::HexGraph {
+    crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "User",
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+      ))
+      .with_node(node(
+        "UserRepository",
+        crate::graph::layer::Layer::Port,
+        crate::graph::role::Role::Repository,
+      ))
+      .with_node(node(
+        "PgUserRepository",
+        crate::graph::layer::Layer::Adapter,
+        crate::graph::role::Role::Adapter,
+      ))
+      .with_node(node(
+        "Logger",
+        crate::graph::layer::Layer::Infrastructure,
+        crate::graph::role::Role::Adapter,
+      ))
+      .with_edge(edge("UserRepository", "User"))
+      .with_edge(edge("PgUserRepository", "UserRepository"))
+      .with_edge(edge("PgUserRepository", "User"))
+      .build()
+  }
+
+  #[test]
+  fn test_health_report_counts() {
+    let report = sample_graph().health_report();
+
+    assert_eq!(report.node_count, 4);
+    assert_eq!(report.edge_count, 3);
+    assert_eq!(report.cycle_count, 0);
+    assert_eq!(report.orphan_count, 1);
+
+    let adapter = report
+      .layers
+      .iter()
+      .find(|s| s.layer == crate::graph::layer::Layer::Adapter)
+      .unwrap();
+    assert_eq!(adapter.node_count, 1);
+    assert_eq!(adapter.edge_count, 2);
+  }
+
+  #[test]
+  fn test_health_report_hotspots_ranked() {
+    let report = sample_graph().health_report();
+
+    let names: Vec<&str> = report
+      .most_depended_upon
+      .iter()
+      .map(|h| h.type_name.as_str())
+      .collect();
+    assert_eq!(names, vec!["User", "UserRepository"]);
+    assert_eq!(report.most_depended_upon[0].dependents, 2);
+  }
+
+  #[test]
+  fn test_health_report_detects_cycle() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "A",
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+      ))
+      .with_node(node(
+        "B",
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+      ))
+      .with_edge(edge("A", "B"))
+      .with_edge(edge("B", "A"))
+      .build();
+
+    assert_eq!(graph.health_report().cycle_count, 1);
+  }
+
+  #[test]
+  fn test_health_report_display() {
+    let display = format!("{}", sample_graph().health_report());
+
+    assert!(display.contains("Nodes: 4"));
+    assert!(display.contains("Orphans: 1"));
+    assert!(display.contains("1. User (2 dependents)"));
+  }
+
+  #[test]
+  fn test_health_report_flags_wide_ports() {
+    let port = |name: &str, required: u32, defaulted: u32| {
+      let metadata = crate::registry::node_builder::build_metadata_from_info(
+        &crate::registry::node_info::NodeInfo::new(
+          crate::graph::layer::Layer::Port,
+          crate::graph::role::Role::Repository,
+          "unused",
+          "test",
+        )
+        .with_method_counts(required, defaulted),
+      );
+      crate::graph::hex_node::HexNode::with_metadata(
+        crate::graph::node_id::NodeId::from_name(name),
+        crate::graph::layer::Layer::Port,
+        crate::graph::role::Role::Repository,
+        name,
+        "test",
+        metadata,
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(port("NarrowPort", 2, 1))
+      .with_node(port("WidePort", 6, 3))
+      .with_node(port("WidestPort", 10, 0))
+      .build();
+
+    let report = graph.health_report();
+    let names: Vec<&str> = report
+      .wide_ports
+      .iter()
+      .map(|p| p.type_name.as_str())
+      .collect();
+    assert_eq!(names, vec!["WidestPort", "WidePort"]);
+    assert!(
+      report
+        .to_string()
+        .contains("Wide port: WidePort (6 required, 3 defaulted methods)")
+    );
+    assert!(sample_graph().health_report().wide_ports.is_empty());
+  }
+
+  #[cfg(feature = "analysis")]
+  #[test]
+  fn test_health_report_layer_violations() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "User",
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+      ))
+      .with_node(node(
+        "PgUserRepository",
+        crate::graph::layer::Layer::Adapter,
+        crate::graph::role::Role::Adapter,
+      ))
+      .with_edge(edge("User", "PgUserRepository"))
+      .build();
+
+    assert_eq!(graph.health_report().layer_violations, Some(1));
+  }
+}
@@ -6,6 +6,7 @@
 //! Edges are immutable once created.
 //!
 //! Revision History
+//! - 2026-10-18T23:30:00Z @AI: Add weight for dependency strength.
 //! - 2025-10-01T00:03:00Z @AI: Initial HexEdge implementation for Phase 2.
 
 /// Represents a directed edge between two nodes in the graph.
@@ -38,6 +39,9 @@ pub struct HexEdge {
 
   /// Additional metadata about this edge.
   pub metadata: std::collections::HashMap<String, String>,
+
+  /// Dependency strength; 1 unless the relationship was declared repeatedly.
+  pub weight: u32,
 }
 
 impl HexEdge {
@@ -52,6 +56,7 @@ impl HexEdge {
       target,
       relationship,
       metadata: std::collections::HashMap::new(),
+      weight: 1,
     }
   }
 
@@ -67,9 +72,21 @@ impl HexEdge {
       target,
       relationship,
       metadata,
+      weight: 1,
     }
   }
 
+  /// Set the dependency strength (builder pattern).
+  pub fn with_weight(mut self, weight: u32) -> Self {
+    self.weight = weight;
+    self
+  }
+
+  /// Get the dependency strength.
+  pub fn weight(&self) -> u32 {
+    self.weight
+  }
+
   /// Get the source node ID.
   pub fn source(&self) -> &crate::graph::node_id::NodeId {
     &self.source
@@ -119,6 +136,17 @@ impl std::fmt::Display for HexEdge {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_hex_edge_weight_defaults_to_one() {
+    let edge = HexEdge::new(
+      crate::graph::node_id::NodeId::from_name("A"),
+      crate::graph::node_id::NodeId::from_name("B"),
+      crate::graph::relationship::Relationship::Depends,
+    );
+    assert_eq!(edge.weight(), 1);
+    assert_eq!(edge.with_weight(4).weight(), 4);
+  }
+
   #[test]
   fn test_hex_edge_creation() {
     let source = crate::graph::node_id::NodeId::from_name("Source");
@@ -6,6 +6,19 @@
 //! using GraphBuilder and cannot be modified after creation.
 //!
 //! Revision History
+//! - 2026-10-19T06:25:00Z @AI: Add rebuild_with for filtered current graphs.
+//! - 2026-10-19T05:35:00Z @AI: Hold an AnalysisCache and add is_analysis_cached.
+//! - 2026-10-19T05:15:00Z @AI: Keep the app version when extracting a neighborhood.
+//! - 2026-10-19T03:45:00Z @AI: Add node() returning a NodeRef handle.
+//! - 2026-10-19T03:35:00Z @AI: Memoize current() and add rebuild().
+//! - 2026-10-19T03:25:00Z @AI: Add check_node_policies for declared dependency policies.
+//! - 2026-10-19T03:15:00Z @AI: Add to_arrow_ipc behind the arrow feature.
+//! - 2026-10-18T22:30:00Z @AI: Add neighborhood subgraph extraction.
+//! - 2026-10-18T21:15:00Z @AI: Stream save_visualization output to the file.
+//! - 2026-10-18T20:00:00Z @AI: Add nodes_by_tag.
+//! - 2026-10-18T17:30:00Z @AI: Add adjacency_matrix for external graph analysis.
+//! - 2026-10-18T13:00:00Z @AI: Store nodes in a BTreeMap so iteration order is deterministic.
+//! - 2026-10-18T11:30:00Z @AI: Add export_with_options for filtered exports.
 //! - 2025-10-02T14:00:00Z @AI: Rename nodes_in_layer to nodes_by_layer and nodes_by_role to nodes_by_role for better API naming.
 //! - 2025-10-01T00:03:00Z @AI: Initial immutable HexGraph implementation for Phase 2.
 
@@ -39,24 +52,87 @@ pub struct HexGraph {
 #[derive(Debug)]
 pub(crate) struct GraphInner {
   pub(crate) nodes:
-    std::collections::HashMap<crate::graph::node_id::NodeId, crate::graph::hex_node::HexNode>,
+    std::collections::BTreeMap<crate::graph::node_id::NodeId, crate::graph::hex_node::HexNode>,
   pub(crate) edges: Vec<crate::graph::hex_edge::HexEdge>,
   pub(crate) metadata: crate::graph::metadata::GraphMetadata,
+  pub(crate) cache: crate::graph::analysis_cache::AnalysisCache,
 }
 
+/// Graph built from the registry, shared by `current` and replaced by `rebuild`.
+static CURRENT: std::sync::OnceLock<std::sync::RwLock<std::sync::Arc<HexGraph>>> =
+  std::sync::OnceLock::new();
+
 impl HexGraph {
   /// Get the current graph built from registered components
+  ///
+  /// The graph is built on the first call and memoized; later calls return
+  /// a clone of the same `Arc`. This is thread-safe: concurrent first calls
+  /// build the graph exactly once, and every caller sees the same snapshot
+  /// until `rebuild` replaces it.
+  ///
+  /// No filters are applied by default: every registered component,
+  /// including those in test modules and examples, becomes a node. Call
+  /// `rebuild_with` once at startup to exclude them.
   pub fn current() -> std::sync::Arc<Self> {
-    std::sync::Arc::new(crate::registry::component_registry::ComponentRegistry::build_graph())
+    let current = Self::memoized();
+    let guard = current
+      .read()
+      .unwrap_or_else(std::sync::PoisonError::into_inner);
+    std::sync::Arc::clone(&guard)
+  }
+
+  /// Rebuild the memoized graph from the registry and return it
+  ///
+  /// Use after the set of registered components may have changed, e.g.
+  /// following a project refresh. `Arc`s handed out earlier keep the old
+  /// snapshot; subsequent `current` calls return the new one.
+  pub fn rebuild() -> std::sync::Arc<Self> {
+    Self::rebuild_with(crate::graph::builder::GraphBuilder::new())
+  }
+
+  /// Rebuild the memoized graph starting from a configured `builder`
+  ///
+  /// Like `rebuild`, but registered components pass through `builder`, so
+  /// its settings (module exclusions, app version, ...) shape what
+  /// `current` returns from now on.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use hexser::graph::{GraphBuilder, HexGraph};
+  ///
+  /// let graph = HexGraph::rebuild_with(
+  ///     GraphBuilder::new().exclude_modules(vec![String::from("*::tests::*")]),
+  /// );
+  /// assert!(std::sync::Arc::ptr_eq(&graph, &HexGraph::current()));
+  /// ```
+  pub fn rebuild_with(builder: crate::graph::builder::GraphBuilder) -> std::sync::Arc<Self> {
+    let graph = std::sync::Arc::new(
+      crate::registry::component_registry::ComponentRegistry::build_graph_with(builder),
+    );
+    let mut guard = Self::memoized()
+      .write()
+      .unwrap_or_else(std::sync::PoisonError::into_inner);
+    *guard = std::sync::Arc::clone(&graph);
+    graph
+  }
+
+  fn memoized() -> &'static std::sync::RwLock<std::sync::Arc<Self>> {
+    CURRENT.get_or_init(|| {
+      std::sync::RwLock::new(std::sync::Arc::new(
+        crate::registry::component_registry::ComponentRegistry::build_graph(),
+      ))
+    })
   }
 
   /// Create a new empty graph.
   pub fn new() -> Self {
     Self {
       inner: std::sync::Arc::new(GraphInner {
-        nodes: std::collections::HashMap::new(),
+        nodes: std::collections::BTreeMap::new(),
         edges: Vec::new(),
         metadata: crate::graph::metadata::GraphMetadata::default(),
+        cache: crate::graph::analysis_cache::AnalysisCache::new(true),
       }),
     }
   }
@@ -97,6 +173,32 @@ impl HexGraph {
     )
   }
 
+  /// Export nodes and edges as Arrow IPC streams
+  #[cfg(feature = "arrow")]
+  pub fn to_arrow_ipc(
+    &self,
+  ) -> crate::result::hex_result::HexResult<
+    crate::graph::visualization::adapters::arrow_exporter::ArrowIpcExport,
+  > {
+    crate::graph::visualization::adapters::arrow_exporter::ArrowExporter::new().export(self)
+  }
+
+  /// Export with a custom exporter, hiding nodes excluded by `options`
+  #[cfg(feature = "visualization")]
+  pub fn export_with_options(
+    &self,
+    exporter: &dyn crate::graph::visualization::ports::format_exporter::FormatExporter,
+    options: crate::graph::visualization::domain::export_options::ExportOptions,
+  ) -> crate::result::hex_result::HexResult<String> {
+    let use_case =
+      crate::graph::visualization::application::export_graph::ExportGraph::new(exporter)
+        .with_options(options);
+    use_case.execute(
+      self,
+      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+    )
+  }
+
   /// Save visualization to file
   #[cfg(feature = "visualization")]
   pub fn save_visualization(
@@ -106,19 +208,25 @@ impl HexGraph {
   ) -> crate::result::hex_result::HexResult<()> {
     let use_case =
       crate::graph::visualization::application::export_graph::ExportGraph::new(exporter);
-    let content = use_case.execute(
-      self,
-      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
-    )?;
-
-    std::fs::write(path, content).map_err(|e| {
+    let io_error = |e: std::io::Error| {
       crate::error::hex_error::Hexserror::adapter(
         crate::error::codes::io::IO_FAILURE,
         &format!("Failed to write file: {}", e),
       )
       .with_next_step("Check file path and permissions")
       .with_suggestion("Verify directory exists and is writable")
-    })
+    };
+
+    let file = std::fs::File::create(path).map_err(io_error)?;
+    let mut writer = std::io::BufWriter::new(file);
+    use_case
+      .execute_to(
+        self,
+        crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+        &mut writer,
+      )
+      .map_err(io_error)?;
+    std::io::Write::flush(&mut writer).map_err(io_error)
   }
 
   /// Create a new graph builder.
@@ -159,6 +267,16 @@ impl HexGraph {
     self.inner.nodes.get(id)
   }
 
+  /// Get a navigable handle to a node by its ID.
+  pub fn node(
+    &self,
+    id: &crate::graph::node_id::NodeId,
+  ) -> Option<crate::graph::node_ref::NodeRef<'_>> {
+    self
+      .get_node(id)
+      .map(|node| crate::graph::node_ref::NodeRef::new(self, node))
+  }
+
   /// Print human-readable summary of graph
   pub fn pretty_print(&self) {
     println!("Hexagonal Architecture Graph:");
@@ -179,12 +297,12 @@ impl HexGraph {
     }
   }
 
-  /// Get all nodes in the graph.
+  /// Get all nodes in the graph, ordered by NodeId.
   pub fn nodes(&self) -> impl Iterator<Item = &crate::graph::hex_node::HexNode> {
     self.inner.nodes.values()
   }
 
-  /// Get all edges in the graph.
+  /// Get all edges in the graph, ordered by source, target, and relationship.
   pub fn edges(&self) -> &[crate::graph::hex_edge::HexEdge] {
     &self.inner.edges
   }
@@ -215,6 +333,16 @@ impl HexGraph {
       .collect()
   }
 
+  /// Get nodes carrying a tag.
+  pub fn nodes_by_tag(&self, tag: &str) -> Vec<&crate::graph::hex_node::HexNode> {
+    self
+      .inner
+      .nodes
+      .values()
+      .filter(|n| n.has_tag(tag))
+      .collect()
+  }
+
   /// Get edges from a specific node.
   pub fn edges_from(
     &self,
@@ -241,6 +369,19 @@ impl HexGraph {
       .collect()
   }
 
+  /// Check each node's `#[hex(forbid_depends_layer)]` policy against its edges.
+  pub fn check_node_policies(&self) -> Vec<crate::graph::policy::PolicyViolation> {
+    crate::graph::policy::check_node_policies(self)
+  }
+
+  /// Whether analysis results such as cycles are memoized for this graph.
+  ///
+  /// True unless the graph was built with
+  /// `GraphBuilder::without_analysis_cache`.
+  pub fn is_analysis_cached(&self) -> bool {
+    self.inner.cache.is_enabled()
+  }
+
   /// Get graph metadata.
   pub fn metadata(&self) -> &crate::graph::metadata::GraphMetadata {
     &self.inner.metadata
@@ -250,6 +391,85 @@ impl HexGraph {
   pub fn is_empty(&self) -> bool {
     self.inner.nodes.is_empty()
   }
+
+  /// Get the directed adjacency matrix of the graph.
+  ///
+  /// Returns the node ordering (sorted by NodeId) and a square matrix where
+  /// `matrix[i][j]` is 1 if any edge leads from node `i` to node `j`.
+  /// Edges whose endpoints are not nodes of this graph are ignored.
+  pub fn adjacency_matrix(&self) -> (Vec<crate::graph::node_id::NodeId>, Vec<Vec<u8>>) {
+    let order: Vec<crate::graph::node_id::NodeId> = self.inner.nodes.keys().cloned().collect();
+    let index: std::collections::HashMap<&crate::graph::node_id::NodeId, usize> =
+      order.iter().enumerate().map(|(i, id)| (id, i)).collect();
+
+    let mut matrix = vec![vec![0u8; order.len()]; order.len()];
+    for edge in &self.inner.edges {
+      if let (Some(&from), Some(&to)) = (index.get(edge.source()), index.get(edge.target())) {
+        matrix[from][to] = 1;
+      }
+    }
+    (order, matrix)
+  }
+
+  /// Get the subgraph of nodes within `depth` hops of `id`.
+  ///
+  /// Edges are followed in both directions. Depth 0 yields the node alone
+  /// and depth 1 adds its direct neighbors. The result keeps every edge
+  /// between included nodes and can be exported like any other graph. An
+  /// unknown `id` yields an empty graph.
+  pub fn neighborhood(&self, id: &crate::graph::node_id::NodeId, depth: usize) -> Self {
+    let mut included = std::collections::BTreeSet::new();
+    if self.inner.nodes.contains_key(id) {
+      included.insert(*id);
+    }
+
+    let mut frontier: Vec<crate::graph::node_id::NodeId> = included.iter().cloned().collect();
+    for _ in 0..depth {
+      let mut next = Vec::new();
+      for edge in &self.inner.edges {
+        let neighbor = if frontier.contains(edge.source()) {
+          edge.target()
+        } else if frontier.contains(edge.target()) {
+          edge.source()
+        } else {
+          continue;
+        };
+        if self.inner.nodes.contains_key(neighbor) && included.insert(*neighbor) {
+          next.push(*neighbor);
+        }
+      }
+      if next.is_empty() {
+        break;
+      }
+      frontier = next;
+    }
+
+    let mut builder =
+      crate::graph::builder::GraphBuilder::new().with_description(&self.inner.metadata.description);
+    if let Some(app_version) = &self.inner.metadata.build_info.app_version {
+      builder = builder.with_app_version(app_version);
+    }
+    if !self.is_analysis_cached() {
+      builder = builder.without_analysis_cache();
+    }
+    builder
+      .with_nodes(
+        included
+          .iter()
+          .filter_map(|node_id| self.inner.nodes.get(node_id).cloned())
+          .collect(),
+      )
+      .with_edges(
+        self
+          .inner
+          .edges
+          .iter()
+          .filter(|e| included.contains(e.source()) && included.contains(e.target()))
+          .cloned()
+          .collect(),
+      )
+      .build()
+  }
 }
 
 impl Default for HexGraph {
@@ -262,6 +482,17 @@ impl Default for HexGraph {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_current_is_memoized_until_rebuild() {
+    let first = HexGraph::current();
+    let second = HexGraph::current();
+    assert!(std::sync::Arc::ptr_eq(&first, &second));
+
+    let rebuilt = HexGraph::rebuild();
+    assert_eq!(rebuilt.node_count(), first.node_count());
+    assert!(std::sync::Arc::ptr_eq(&rebuilt, &HexGraph::current()));
+  }
+
   #[test]
   fn test_empty_graph() {
     let graph = HexGraph::new();
@@ -287,4 +518,117 @@ mod tests {
     let graph = HexGraph::default();
     assert!(graph.is_empty());
   }
+
+  #[test]
+  fn test_nodes_by_tag() {
+    let graph = HexGraph::builder()
+      .with_node(
+        crate::graph::hex_node::HexNode::new(
+          crate::graph::node_id::NodeId::from_name("Customer"),
+          crate::graph::layer::Layer::Domain,
+          crate::graph::role::Role::Entity,
+          "Customer",
+          "app",
+        )
+        .with_tags(["pii"]),
+      )
+      .with_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("Order"),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "Order",
+        "app",
+      ))
+      .build();
+
+    let tagged = graph.nodes_by_tag("pii");
+    assert_eq!(tagged.len(), 1);
+    assert_eq!(tagged[0].type_name, "Customer");
+    assert!(graph.nodes_by_tag("external").is_empty());
+  }
+
+  #[test]
+  fn test_neighborhood_expands_by_depth_in_both_directions() {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let node = |name: &str| {
+      crate::graph::hex_node::HexNode::new(
+        id(name),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        id(from),
+        id(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    // A -> B -> C -> D, and E -> B
+    let graph = HexGraph::builder()
+      .with_nodes(["A", "B", "C", "D", "E"].into_iter().map(node).collect())
+      .with_edges(vec![
+        edge("A", "B"),
+        edge("B", "C"),
+        edge("C", "D"),
+        edge("E", "B"),
+      ])
+      .build();
+    let names = |g: &HexGraph| {
+      let mut names: Vec<String> = g.nodes().map(|n| n.type_name().to_string()).collect();
+      names.sort();
+      names
+    };
+
+    let alone = graph.neighborhood(&id("B"), 0);
+    assert_eq!(names(&alone), vec!["B"]);
+    assert_eq!(alone.edge_count(), 0);
+
+    let direct = graph.neighborhood(&id("B"), 1);
+    assert_eq!(names(&direct), vec!["A", "B", "C", "E"]);
+    assert_eq!(direct.edge_count(), 3);
+
+    assert_eq!(graph.neighborhood(&id("B"), 2).node_count(), 5);
+    assert!(graph.neighborhood(&id("Missing"), 3).is_empty());
+  }
+
+  #[test]
+  fn test_adjacency_matrix() {
+    let node = |name: &str| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name(from),
+        crate::graph::node_id::NodeId::from_name(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    let graph = HexGraph::builder()
+      .with_node(node("C"))
+      .with_node(node("A"))
+      .with_node(node("B"))
+      .with_edge(edge("A", "B"))
+      .with_edge(edge("C", "A"))
+      .build();
+
+    let (order, matrix) = graph.adjacency_matrix();
+    assert_eq!(
+      order,
+      vec![
+        crate::graph::node_id::NodeId::from_name("A"),
+        crate::graph::node_id::NodeId::from_name("B"),
+        crate::graph::node_id::NodeId::from_name("C"),
+      ]
+    );
+    assert_eq!(matrix, vec![vec![0, 1, 0], vec![0, 0, 0], vec![1, 0, 0]]);
+  }
 }
@@ -6,6 +6,7 @@
 //! by their unique NodeId.
 //!
 //! Revision History
+//! - 2026-10-18T20:00:00Z @AI: Add free-form tags for custom grouping.
 //! - 2025-10-01T00:03:00Z @AI: Initial HexNode implementation for Phase 2.
 
 /// Represents a component node in the hexagonal architecture graph.
@@ -45,6 +46,9 @@ pub struct HexNode {
 
   /// Additional metadata about this node.
   pub metadata: std::collections::HashMap<String, String>,
+
+  /// Free-form labels (e.g. `pii`, `external`) orthogonal to layer and role.
+  pub tags: Vec<String>,
 }
 
 impl HexNode {
@@ -63,6 +67,7 @@ impl HexNode {
       type_name: String::from(type_name),
       module_path: String::from(module_path),
       metadata: std::collections::HashMap::new(),
+      tags: Vec::new(),
     }
   }
 
@@ -82,9 +87,20 @@ impl HexNode {
       type_name: String::from(type_name),
       module_path: String::from(module_path),
       metadata,
+      tags: Vec::new(),
     }
   }
 
+  /// Attach tags to this node (builder pattern).
+  pub fn with_tags<I, S>(mut self, tags: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    self.tags.extend(tags.into_iter().map(Into::into));
+    self
+  }
+
   /// Get the node's unique identifier.
   pub fn id(&self) -> &crate::graph::node_id::NodeId {
     &self.id
@@ -124,6 +140,11 @@ impl HexNode {
   pub fn has_role(&self, role: crate::graph::role::Role) -> bool {
     self.role == role
   }
+
+  /// Check if this node carries a specific tag.
+  pub fn has_tag(&self, tag: &str) -> bool {
+    self.tags.iter().any(|t| t == tag)
+  }
 }
 
 impl std::fmt::Display for HexNode {
@@ -6,6 +6,7 @@
 //! dependency rules in hexagonal architecture.
 //!
 //! Revision History
+//! - 2026-10-19T00:55:00Z @AI: Add case-insensitive from_name lookup.
 //! - 2025-10-01T00:00:00Z @AI: Initial Layer enum definition for architecture layers.
 
 /// Enum representing architectural layers in hexagonal architecture.
@@ -54,6 +55,20 @@ impl Layer {
       Self::Unknown => "Unknown",
     }
   }
+
+  /// Looks up a layer by its `as_str` name, ignoring ASCII case.
+  pub fn from_name(name: &str) -> Option<Self> {
+    [
+      Self::Domain,
+      Self::Port,
+      Self::Adapter,
+      Self::Application,
+      Self::Infrastructure,
+      Self::Unknown,
+    ]
+    .into_iter()
+    .find(|layer| layer.as_str().eq_ignore_ascii_case(name))
+  }
 }
 
 impl std::fmt::Display for Layer {
@@ -78,6 +93,13 @@ mod tests {
     assert_eq!(Layer::Port.as_str(), "Port");
   }
 
+  #[test]
+  fn test_layer_from_name() {
+    assert_eq!(Layer::from_name("adapter"), Some(Layer::Adapter));
+    assert_eq!(Layer::from_name("Domain"), Some(Layer::Domain));
+    assert_eq!(Layer::from_name("Core"), None);
+  }
+
   #[test]
   fn test_layer_display() {
     let layer = Layer::Domain;
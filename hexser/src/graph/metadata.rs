@@ -6,6 +6,8 @@
 //! copied when graphs are constructed.
 //!
 //! Revision History
+//! - 2026-10-19T10:15:00Z @AI: Document built_at as the graph construction time.
+//! - 2026-10-19T05:15:00Z @AI: Add BuildInfo provenance to GraphMetadata.
 //! - 2025-10-01T00:03:00Z @AI: Initial metadata types for Phase 2.
 
 /// Metadata for the entire graph.
@@ -25,37 +27,29 @@ pub struct GraphMetadata {
 
   /// Additional custom metadata.
   pub attributes: std::collections::HashMap<String, String>,
+
+  /// Which hexser and application build produced this graph.
+  pub build_info: BuildInfo,
 }
 
 impl GraphMetadata {
   /// Create new graph metadata with current timestamp.
   pub fn new(description: &str) -> Self {
-    Self {
-      created_at: Self::current_timestamp(),
-      version: 1,
-      description: String::from(description),
-      attributes: std::collections::HashMap::new(),
-    }
+    Self::with_version(description, 1)
   }
 
   /// Create graph metadata with specific version.
   pub fn with_version(description: &str, version: u64) -> Self {
+    let created_at = current_timestamp();
     Self {
-      created_at: Self::current_timestamp(),
+      created_at,
       version,
       description: String::from(description),
       attributes: std::collections::HashMap::new(),
+      build_info: BuildInfo::at(created_at),
     }
   }
 
-  /// Get current Unix timestamp.
-  fn current_timestamp() -> u64 {
-    std::time::SystemTime::now()
-      .duration_since(std::time::UNIX_EPOCH)
-      .unwrap()
-      .as_secs()
-  }
-
   /// Get an attribute value.
   pub fn get_attribute(&self, key: &str) -> Option<&String> {
     self.attributes.get(key)
@@ -68,6 +62,100 @@ impl Default for GraphMetadata {
   }
 }
 
+/// Provenance of a graph: the hexser version and time it was built, plus
+/// an optional application version.
+///
+/// Exporters include it in their output so two diagrams can be told apart
+/// and correlated with deploys.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::GraphBuilder;
+///
+/// let graph = GraphBuilder::new().with_app_version("2.3.1").build();
+/// let info = &graph.metadata().build_info;
+/// assert_eq!(info.app_version.as_deref(), Some("2.3.1"));
+/// assert!(info.to_string().starts_with(&format!("hexser {}", info.crate_version)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+  /// Version of the hexser crate that built the graph.
+  pub crate_version: String,
+
+  /// When the graph was constructed (Unix timestamp).
+  ///
+  /// This is the `GraphBuilder::build` time at runtime, not the time the
+  /// application was compiled.
+  pub built_at: u64,
+
+  /// Version of the application the graph describes, if known.
+  pub app_version: Option<String>,
+}
+
+impl BuildInfo {
+  /// Build info for the running hexser version at the current time.
+  pub fn new() -> Self {
+    Self::at(current_timestamp())
+  }
+
+  /// Build info for the running hexser version at `built_at`.
+  pub fn at(built_at: u64) -> Self {
+    Self {
+      crate_version: String::from(env!("CARGO_PKG_VERSION")),
+      built_at,
+      app_version: None,
+    }
+  }
+
+  /// Set the application version (builder pattern).
+  pub fn with_app_version(mut self, app_version: &str) -> Self {
+    self.app_version = Some(String::from(app_version));
+    self
+  }
+
+  /// Key-value pairs for formats with a metadata map, such as JSON or
+  /// Arrow schemas. `app_version` is omitted when unset.
+  pub fn entries(&self) -> Vec<(&'static str, String)> {
+    let mut entries = vec![
+      ("hexser_version", self.crate_version.clone()),
+      ("built_at", self.built_at.to_string()),
+    ];
+    if let Some(app_version) = &self.app_version {
+      entries.push(("app_version", app_version.clone()));
+    }
+    entries
+  }
+}
+
+impl Default for BuildInfo {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl std::fmt::Display for BuildInfo {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "hexser {}, built at {}",
+      self.crate_version, self.built_at
+    )?;
+    if let Some(app_version) = &self.app_version {
+      write!(f, ", app {}", app_version)?;
+    }
+    Ok(())
+  }
+}
+
+/// Get current Unix timestamp.
+fn current_timestamp() -> u64 {
+  std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap()
+    .as_secs()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -91,4 +179,29 @@ mod tests {
     let metadata = GraphMetadata::default();
     assert!(metadata.description.contains("Hexagonal"));
   }
+
+  #[test]
+  fn test_build_info_defaults_to_crate_version() {
+    let metadata = GraphMetadata::new("Test");
+    assert_eq!(metadata.build_info.crate_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(metadata.build_info.built_at, metadata.created_at);
+    assert_eq!(metadata.build_info.app_version, None);
+  }
+
+  #[test]
+  fn test_build_info_display_and_entries() {
+    let info = BuildInfo::at(1_700_000_000).with_app_version("2.3.1");
+    assert_eq!(
+      info.to_string(),
+      format!(
+        "hexser {}, built at 1700000000, app 2.3.1",
+        info.crate_version
+      )
+    );
+    assert_eq!(
+      info.entries().last().unwrap(),
+      &("app_version", String::from("2.3.1"))
+    );
+    assert_eq!(BuildInfo::at(0).entries().len(), 2);
+  }
 }
@@ -0,0 +1,120 @@
+//! One-line graph metrics for trend logs.
+//!
+//! Appending `metrics_line` to a log on every build gives a time series of
+//! architecture size and coupling that can be charted without extra
+//! infrastructure. The line is plain `key=value` pairs separated by single
+//! spaces, always with the same keys in the same order, so it splits
+//! trivially and stays comparable across releases; new keys are only ever
+//! appended at the end.
+//!
+//! Revision History
+//! - 2026-10-19T06:55:00Z @AI: Initial HexGraph::metrics_line.
+
+/// Layers reported by `metrics_line`, in column order
+const LAYERS: [crate::graph::layer::Layer; 6] = [
+  crate::graph::layer::Layer::Domain,
+  crate::graph::layer::Layer::Port,
+  crate::graph::layer::Layer::Adapter,
+  crate::graph::layer::Layer::Application,
+  crate::graph::layer::Layer::Infrastructure,
+  crate::graph::layer::Layer::Unknown,
+];
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Summarize the graph as one machine-parseable `key=value` line.
+  ///
+  /// Keys, in order: `timestamp` (the graph's build time in Unix seconds),
+  /// `nodes`, `edges`, `cycles`, then the node count of every layer in
+  /// lowercase (`domain`, `port`, `adapter`, `application`,
+  /// `infrastructure`, `unknown`), including layers with no nodes. The line
+  /// has no trailing newline.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use hexser::graph::{GraphBuilder, HexNode, Layer, NodeId, Role};
+  ///
+  /// let graph = GraphBuilder::new()
+  ///     .with_node(HexNode::new(NodeId::from_name("User"), Layer::Domain, Role::Entity, "User", "app"))
+  ///     .build();
+  ///
+  /// let line = graph.metrics_line();
+  /// assert!(line.starts_with("timestamp="));
+  /// assert!(line.ends_with("nodes=1 edges=0 cycles=0 domain=1 port=0 adapter=0 application=0 infrastructure=0 unknown=0"));
+  /// ```
+  pub fn metrics_line(&self) -> String {
+    let mut fields = vec![
+      format!("timestamp={}", self.metadata().build_info.built_at),
+      format!("nodes={}", self.node_count()),
+      format!("edges={}", self.edge_count()),
+      format!("cycles={}", self.analysis().detect_cycles().len()),
+    ];
+    for layer in LAYERS {
+      fields.push(format!(
+        "{}={}",
+        layer.as_str().to_lowercase(),
+        self.nodes_by_layer(layer).len()
+      ));
+    }
+    fields.join(" ")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn test_metrics_line_has_stable_keys_and_counts() {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let node = |name: &str, layer| {
+      crate::graph::hex_node::HexNode::new(
+        id(name),
+        layer,
+        crate::graph::role::Role::Entity,
+        name,
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        id(from),
+        id(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Order", crate::graph::layer::Layer::Domain))
+      .with_node(node("Money", crate::graph::layer::Layer::Domain))
+      .with_node(node("OrderRepo", crate::graph::layer::Layer::Port))
+      .with_edge(edge("Order", "Money"))
+      .with_edge(edge("Money", "Order"))
+      .with_edge(edge("OrderRepo", "Order"))
+      .build();
+
+    let line = graph.metrics_line();
+    let pairs: Vec<(&str, &str)> = line
+      .split(' ')
+      .map(|pair| pair.split_once('=').unwrap())
+      .collect();
+    let keys: Vec<&str> = pairs.iter().map(|(key, _)| *key).collect();
+
+    assert_eq!(
+      keys,
+      vec![
+        "timestamp",
+        "nodes",
+        "edges",
+        "cycles",
+        "domain",
+        "port",
+        "adapter",
+        "application",
+        "infrastructure",
+        "unknown",
+      ]
+    );
+    assert_eq!(pairs[0].1, graph.metadata().build_info.built_at.to_string());
+    assert!(line.ends_with(
+      "nodes=3 edges=3 cycles=1 domain=2 port=1 adapter=0 application=0 infrastructure=0 unknown=0"
+    ));
+  }
+}
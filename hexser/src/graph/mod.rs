@@ -6,26 +6,65 @@
 //! analysis algorithms, and visualization capabilities.
 //!
 //! Revision History
+//! - 2026-10-19T08:55:00Z @AI: Re-export NodeIdStrategy.
+//! - 2026-10-19T08:35:00Z @AI: Add bounded_context module for module-path context grouping.
+//! - 2026-10-19T06:55:00Z @AI: Add metrics_line module for trend logs.
+//! - 2026-10-19T06:05:00Z @AI: Add tree module for terminal dependency trees.
+//! - 2026-10-19T05:35:00Z @AI: Add internal analysis_cache module.
+//! - 2026-10-19T04:15:00Z @AI: Add diff module for comparing graph snapshots.
+//! - 2026-10-19T03:45:00Z @AI: Add NodeRef borrowed node handle.
+//! - 2026-10-19T03:25:00Z @AI: Add policy module for per-node dependency policies.
+//! - 2026-10-19T01:05:00Z @AI: Add naming module for directive/query intent inference.
+//! - 2026-10-19T00:55:00Z @AI: Add feature-gated spec module for user-defined architecture rules.
+//! - 2026-10-19T00:25:00Z @AI: Add traversal module with lazy bfs/dfs iterators.
+//! - 2026-10-18T20:30:00Z @AI: Add visitor module for custom graph walks.
+//! - 2026-10-18T15:00:00Z @AI: Add cli module for scriptable graph queries.
+//! - 2026-10-18T10:00:00Z @AI: Wire analysis, query, and feature-gated validation modules; add health_report.
 //! - 2025-10-01T00:03:00Z @AI: Phase 2 implementation with graph core.
 //! - 2025-10-01T00:00:00Z @AI: Initial placeholder for graph module structure.
 
+pub mod analysis;
+mod analysis_cache;
+pub mod bounded_context;
 pub mod builder;
+pub mod cli;
+pub mod diff;
+pub mod health_report;
 pub mod hex_edge;
 pub mod hex_graph;
 pub mod hex_node;
 pub mod layer;
 pub mod metadata;
+pub mod metrics_line;
+pub mod naming;
 pub mod node_id;
+pub mod node_ref;
+pub mod policy;
+pub mod query;
 pub mod relationship;
 pub mod role;
+#[cfg(feature = "analysis")]
+pub mod spec;
+pub mod traversal;
+pub mod tree;
+#[cfg(feature = "analysis")]
+pub mod validation;
+pub mod visitor;
 pub mod visualization;
 
+pub use bounded_context::BoundedContexts;
 pub use builder::GraphBuilder;
+pub use diff::GraphDiff;
+pub use health_report::HealthReport;
 pub use hex_edge::HexEdge;
 pub use hex_graph::HexGraph;
 pub use hex_node::HexNode;
 pub use layer::Layer;
-pub use metadata::GraphMetadata;
-pub use node_id::NodeId;
+pub use metadata::{BuildInfo, GraphMetadata};
+pub use node_id::{NodeId, NodeIdStrategy};
+pub use node_ref::NodeRef;
+pub use policy::PolicyViolation;
 pub use relationship::Relationship;
 pub use role::Role;
+pub use traversal::{Bfs, Dfs};
+pub use visitor::GraphVisitor;
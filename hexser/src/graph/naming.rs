@@ -0,0 +1,112 @@
+//! Intent inference from directive and query type names.
+//!
+//! CQRS components are conventionally named verb-first (`CreateOrder`,
+//! `ListArticlesQuery`). `infer_intent` recovers the verb and its target
+//! with these rules, applied in order:
+//!
+//! 1. Drop the module path (everything up to the last `::`) and generics.
+//! 2. Drop one trailing `Directive`, `Command`, or `Query` suffix.
+//! 3. Split the rest on CamelCase word boundaries (acronym runs such as
+//!    `HTTP` stay together).
+//! 4. The first word is the verb; a following `Up`, `In`, `Out`, or `Off`
+//!    particle joins it (`SignUp`, `LogIn`).
+//! 5. The remaining words, joined, are the target. Without a target
+//!    (e.g. just `Refresh`) nothing is inferred.
+//!
+//! Revision History
+//! - 2026-10-19T01:05:00Z @AI: Initial naming-based intent inference.
+
+/// Verb and target recovered from a component name
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InferredIntent {
+  /// Action the component performs, e.g. `Create`
+  pub verb: String,
+  /// Subject the action applies to, e.g. `Order`
+  pub target: String,
+}
+
+const SUFFIXES: [&str; 3] = ["Directive", "Command", "Query"];
+const PARTICLES: [&str; 4] = ["Up", "In", "Out", "Off"];
+
+/// Infer the intent encoded in a directive or query type name
+///
+/// # Example
+///
+/// ```rust
+/// let intent = hexser::graph::naming::infer_intent("app::orders::CreateOrderDirective").unwrap();
+/// assert_eq!(intent.verb, "Create");
+/// assert_eq!(intent.target, "Order");
+/// ```
+pub fn infer_intent(type_name: &str) -> Option<InferredIntent> {
+  let base = type_name.split('<').next().unwrap_or(type_name);
+  let mut name = base.rsplit("::").next().unwrap_or(base);
+  if let Some(stripped) = SUFFIXES
+    .iter()
+    .find_map(|suffix| name.strip_suffix(suffix).filter(|rest| !rest.is_empty()))
+  {
+    name = stripped;
+  }
+
+  let words = split_camel_case(name);
+  let (verb_len, verb) = match words.as_slice() {
+    [first, second, ..] if PARTICLES.contains(second) => (2, format!("{}{}", first, second)),
+    [first, ..] => (1, String::from(*first)),
+    [] => return None,
+  };
+  let target = words[verb_len..].concat();
+  if target.is_empty() {
+    return None;
+  }
+  Some(InferredIntent { verb, target })
+}
+
+/// Split `CreateHTTPRoute` into `["Create", "HTTP", "Route"]`
+fn split_camel_case(name: &str) -> Vec<&str> {
+  let chars: Vec<(usize, char)> = name.char_indices().collect();
+  let mut words = Vec::new();
+  let mut start = 0;
+  for i in 1..chars.len() {
+    let (idx, c) = chars[i];
+    let prev = chars[i - 1].1;
+    let next_is_lower = chars.get(i + 1).is_some_and(|(_, n)| n.is_lowercase());
+    let boundary = c.is_uppercase()
+      && (prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower));
+    if boundary {
+      words.push(&name[start..idx]);
+      start = idx;
+    }
+  }
+  if start < name.len() {
+    words.push(&name[start..]);
+  }
+  words
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn intent(name: &str) -> Option<(String, String)> {
+    infer_intent(name).map(|i| (i.verb, i.target))
+  }
+
+  fn pair(verb: &str, target: &str) -> Option<(String, String)> {
+    Some((String::from(verb), String::from(target)))
+  }
+
+  #[test]
+  fn test_infer_intent_rules() {
+    assert_eq!(intent("CreateOrderDirective"), pair("Create", "Order"));
+    assert_eq!(intent("app::ListArticlesQuery"), pair("List", "Articles"));
+    assert_eq!(intent("GetUserProfile"), pair("Get", "UserProfile"));
+    assert_eq!(intent("SignUpUser"), pair("SignUp", "User"));
+    assert_eq!(intent("FetchHTTPRoute"), pair("Fetch", "HTTPRoute"));
+    assert_eq!(
+      intent("app::ListOrders<app::Tenant>"),
+      pair("List", "Orders")
+    );
+    assert_eq!(intent("SignUp"), None);
+    assert_eq!(intent("Refresh"), None);
+    assert_eq!(intent("Query"), None);
+  }
+}
@@ -6,6 +6,8 @@
 //! compile time, with fallback to string-based IDs for dynamic cases.
 //!
 //! Revision History
+//! - 2026-10-19T08:55:00Z @AI: Add NodeIdStrategy for base-name versus generic-qualified ids.
+//! - 2026-10-18T13:00:00Z @AI: Derive Ord for deterministic graph ordering.
 //! - 2025-10-02T12:00:00Z @AI: Add from_type_name method for registry compatibility.
 //! - 2025-10-01T00:00:00Z @AI: Initial NodeId struct with type-based identification.
 
@@ -25,7 +27,7 @@
 /// let id2 = NodeId::of::<MyComponent>();
 /// assert_eq!(id1, id2);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NodeId(u64);
 
 impl NodeId {
@@ -67,6 +69,44 @@ impl NodeId {
   }
 }
 
+/// How node ids are derived from component type names.
+///
+/// `std::any::type_name` reports generic instantiations in full, so
+/// `Repo<User>` and `Repo<Order>` are different components by default.
+/// Collapsing them to the base name keeps ids stable when type parameters
+/// are renamed or moved between modules, which makes graph snapshots less
+/// noisy, at the cost of merging every instantiation (and any same-named
+/// types in different modules) into one node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodeIdStrategy {
+  /// Hash only the base type name, without module path or generics.
+  TypeName,
+  /// Hash the full type name, module path and generic arguments included.
+  #[default]
+  FullyQualifiedWithGenerics,
+}
+
+impl NodeIdStrategy {
+  /// The NodeId of a component with the given type name under this strategy
+  pub fn node_id(&self, type_name: &str) -> NodeId {
+    match self {
+      Self::TypeName => NodeId::from_name(Self::base_name(type_name)),
+      Self::FullyQualifiedWithGenerics => NodeId::from_type_name(type_name),
+    }
+  }
+
+  /// Strip generic arguments and the module path from a type name
+  ///
+  /// `app::repo::Repo<app::User>` becomes `Repo`.
+  pub fn base_name(type_name: &str) -> &str {
+    let without_generics = type_name.split('<').next().unwrap_or(type_name);
+    without_generics
+      .rsplit("::")
+      .next()
+      .unwrap_or(without_generics)
+  }
+}
+
 impl std::fmt::Display for NodeId {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "NodeId({})", self.0)
@@ -115,6 +155,19 @@ mod tests {
     assert!(value > 0);
   }
 
+  #[test]
+  fn test_strategy_base_name_strips_path_and_generics() {
+    assert_eq!(
+      NodeIdStrategy::base_name("app::repo::Repo<app::User, u8>"),
+      "Repo"
+    );
+    assert_eq!(NodeIdStrategy::base_name("User"), "User");
+    assert_eq!(
+      NodeIdStrategy::FullyQualifiedWithGenerics.node_id("app::User"),
+      NodeId::from_type_name("app::User")
+    );
+  }
+
   #[test]
   fn test_node_id_display() {
     let id = NodeId::from_name("test");
@@ -0,0 +1,176 @@
+//! Borrowed node handle for navigating a HexGraph.
+//!
+//! NodeRef pairs a node with the graph it belongs to, so neighbours can be
+//! reached directly from the handle instead of looking ids up again. It is
+//! a thin layer over the id-based API and is `Copy`.
+//!
+//! Revision History
+//! - 2026-10-19T03:45:00Z @AI: Initial NodeRef handle.
+
+/// A node borrowed from a graph, able to walk to its neighbours.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::{HexEdge, HexGraph, HexNode, Layer, NodeId, Relationship, Role};
+///
+/// let service = NodeId::from_name("OrderService");
+/// let repo = NodeId::from_name("OrderRepository");
+/// let graph = HexGraph::builder()
+///     .with_node(HexNode::new(service, Layer::Application, Role::UseCase, "OrderService", "app"))
+///     .with_node(HexNode::new(repo, Layer::Port, Role::Repository, "OrderRepository", "ports"))
+///     .with_edge(HexEdge::new(service, repo, Relationship::Depends))
+///     .build();
+///
+/// let node = graph.node(&service).unwrap();
+/// let deps = node.dependencies();
+/// assert_eq!(deps[0].info().type_name(), "OrderRepository");
+/// assert_eq!(deps[0].dependents()[0].id(), &service);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NodeRef<'g> {
+  graph: &'g crate::graph::hex_graph::HexGraph,
+  node: &'g crate::graph::hex_node::HexNode,
+}
+
+impl<'g> NodeRef<'g> {
+  pub(crate) fn new(
+    graph: &'g crate::graph::hex_graph::HexGraph,
+    node: &'g crate::graph::hex_node::HexNode,
+  ) -> Self {
+    Self { graph, node }
+  }
+
+  /// The node's id.
+  pub fn id(&self) -> &'g crate::graph::node_id::NodeId {
+    &self.node.id
+  }
+
+  /// The underlying node.
+  pub fn info(&self) -> &'g crate::graph::hex_node::HexNode {
+    self.node
+  }
+
+  /// The graph this node belongs to.
+  pub fn graph(&self) -> &'g crate::graph::hex_graph::HexGraph {
+    self.graph
+  }
+
+  /// Nodes this node has edges to, in edge order without duplicates.
+  ///
+  /// Edge targets missing from the graph are skipped.
+  pub fn dependencies(&self) -> Vec<NodeRef<'g>> {
+    self.neighbours(self.graph.edges_from(self.id()), |edge| edge.target())
+  }
+
+  /// Nodes with edges to this node, in edge order without duplicates.
+  pub fn dependents(&self) -> Vec<NodeRef<'g>> {
+    self.neighbours(self.graph.edges_to(self.id()), |edge| edge.source())
+  }
+
+  /// Outgoing edges of this node.
+  pub fn outgoing(&self) -> Vec<&'g crate::graph::hex_edge::HexEdge> {
+    self.graph.edges_from(self.id())
+  }
+
+  /// Incoming edges of this node.
+  pub fn incoming(&self) -> Vec<&'g crate::graph::hex_edge::HexEdge> {
+    self.graph.edges_to(self.id())
+  }
+
+  fn neighbours(
+    &self,
+    edges: Vec<&'g crate::graph::hex_edge::HexEdge>,
+    end: impl Fn(&'g crate::graph::hex_edge::HexEdge) -> &'g crate::graph::node_id::NodeId,
+  ) -> Vec<NodeRef<'g>> {
+    let mut seen = std::collections::HashSet::new();
+    edges
+      .into_iter()
+      .map(end)
+      .filter(|id| seen.insert(**id))
+      .filter_map(|id| self.graph.node(id))
+      .collect()
+  }
+}
+
+impl PartialEq for NodeRef<'_> {
+  fn eq(&self, other: &Self) -> bool {
+    std::ptr::eq(self.graph, other.graph) && self.node.id == other.node.id
+  }
+}
+
+impl Eq for NodeRef<'_> {}
+
+#[cfg(test)]
+mod tests {
+  fn graph() -> crate::graph::hex_graph::HexGraph {
+    let node = |name: &str, layer| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        layer,
+        crate::graph::role::Role::Entity,
+        name,
+        "test",
+      )
+    };
+    let edge = |from: &str, to: &str, rel| {
+      crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name(from),
+        crate::graph::node_id::NodeId::from_name(to),
+        rel,
+      )
+    };
+    crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Handler", crate::graph::layer::Layer::Application))
+      .with_node(node("Repo", crate::graph::layer::Layer::Port))
+      .with_node(node("PgRepo", crate::graph::layer::Layer::Adapter))
+      .with_edge(edge(
+        "Handler",
+        "Repo",
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .with_edge(edge(
+        "Handler",
+        "Repo",
+        crate::graph::relationship::Relationship::Invokes,
+      ))
+      .with_edge(edge(
+        "PgRepo",
+        "Repo",
+        crate::graph::relationship::Relationship::Implements,
+      ))
+      .build()
+  }
+
+  #[test]
+  fn test_navigates_without_relooking_up_ids() {
+    let graph = graph();
+    let handler = graph
+      .node(&crate::graph::node_id::NodeId::from_name("Handler"))
+      .unwrap();
+
+    let deps = handler.dependencies();
+    assert_eq!(deps.len(), 1);
+    assert_eq!(handler.outgoing().len(), 2);
+
+    let repo = deps[0];
+    let mut names: Vec<&str> = repo
+      .dependents()
+      .iter()
+      .map(|n| n.info().type_name())
+      .collect();
+    names.sort();
+    assert_eq!(names, vec!["Handler", "PgRepo"]);
+    assert!(repo.dependents().contains(&handler));
+    assert!(repo.dependencies().is_empty());
+  }
+
+  #[test]
+  fn test_unknown_id_has_no_handle() {
+    assert!(
+      graph()
+        .node(&crate::graph::node_id::NodeId::from_name("Missing"))
+        .is_none()
+    );
+  }
+}
@@ -0,0 +1,157 @@
+//! Per-node dependency policies declared next to the code they govern.
+//!
+//! Components opt in with `#[hex(forbid_depends_layer = "adapter")]`, which
+//! is recorded in the node's `forbid_depends_layer` metadata. Checking walks
+//! each node's outgoing edges and reports every edge whose target lies in a
+//! forbidden layer. Unlike the global layer rules in `analysis`, a policy
+//! only binds the node that declares it.
+//!
+//! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Initial node policy check.
+
+/// An edge that breaks a node's declared dependency policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyViolation {
+  /// Node declaring the policy.
+  pub node_id: crate::graph::node_id::NodeId,
+
+  /// Type name of the node declaring the policy.
+  pub type_name: String,
+
+  /// Node the forbidden edge points to.
+  pub target_id: crate::graph::node_id::NodeId,
+
+  /// Type name of the target node.
+  pub target_type_name: String,
+
+  /// The forbidden layer the target belongs to.
+  pub forbidden_layer: crate::graph::layer::Layer,
+
+  /// Relationship of the offending edge.
+  pub relationship: crate::graph::relationship::Relationship,
+}
+
+impl std::fmt::Display for PolicyViolation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} {} {}, but must not depend on the {} layer",
+      self.type_name, self.relationship, self.target_type_name, self.forbidden_layer
+    )
+  }
+}
+
+/// Layers a node forbids, parsed from its `forbid_depends_layer` metadata.
+///
+/// Unrecognized layer names are ignored.
+pub fn forbidden_layers(node: &crate::graph::hex_node::HexNode) -> Vec<crate::graph::layer::Layer> {
+  node
+    .get_metadata(crate::registry::node_builder::FORBID_DEPENDS_LAYER_METADATA_KEY)
+    .map(|layers| {
+      layers
+        .split(',')
+        .filter_map(|name| crate::graph::layer::Layer::from_name(name.trim()))
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Check every node's declared policy against its outgoing edges.
+///
+/// Violations are ordered by source node, then by edge order.
+pub fn check_node_policies(graph: &crate::graph::hex_graph::HexGraph) -> Vec<PolicyViolation> {
+  let mut violations = Vec::new();
+  for node in graph.nodes() {
+    let forbidden = forbidden_layers(node);
+    if forbidden.is_empty() {
+      continue;
+    }
+    for edge in graph.edges_from(node.id()) {
+      let target = match graph.get_node(edge.target()) {
+        Some(target) if forbidden.contains(&target.layer()) => target,
+        _ => continue,
+      };
+      violations.push(PolicyViolation {
+        node_id: *node.id(),
+        type_name: node.type_name.clone(),
+        target_id: *target.id(),
+        target_type_name: target.type_name.clone(),
+        forbidden_layer: target.layer(),
+        relationship: edge.relationship(),
+      });
+    }
+  }
+  violations
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn node(
+    name: &str,
+    layer: crate::graph::layer::Layer,
+    forbid: Option<&str>,
+  ) -> crate::graph::hex_node::HexNode {
+    let mut metadata = std::collections::HashMap::new();
+    if let Some(layers) = forbid {
+      metadata.insert(
+        String::from(crate::registry::node_builder::FORBID_DEPENDS_LAYER_METADATA_KEY),
+        String::from(layers),
+      );
+    }
+    crate::graph::hex_node::HexNode::with_metadata(
+      crate::graph::node_id::NodeId::from_name(name),
+      layer,
+      crate::graph::role::Role::Entity,
+      name,
+      "test",
+      metadata,
+    )
+  }
+
+  fn depends(from: &str, to: &str) -> crate::graph::hex_edge::HexEdge {
+    crate::graph::hex_edge::HexEdge::new(
+      crate::graph::node_id::NodeId::from_name(from),
+      crate::graph::node_id::NodeId::from_name(to),
+      crate::graph::relationship::Relationship::Depends,
+    )
+  }
+
+  #[test]
+  fn test_reports_edges_into_forbidden_layer() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "Order",
+        crate::graph::layer::Layer::Domain,
+        Some("Adapter,Infrastructure"),
+      ))
+      .with_node(node("PgOrders", crate::graph::layer::Layer::Adapter, None))
+      .with_node(node("Money", crate::graph::layer::Layer::Domain, None))
+      .with_edge(depends("Order", "PgOrders"))
+      .with_edge(depends("Order", "Money"))
+      .with_edge(depends("Money", "PgOrders"))
+      .build();
+
+    let violations = check_node_policies(&graph);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].type_name, "Order");
+    assert_eq!(violations[0].target_type_name, "PgOrders");
+    assert_eq!(
+      violations[0].forbidden_layer,
+      crate::graph::layer::Layer::Adapter
+    );
+    assert!(violations[0].to_string().contains("must not depend on"));
+  }
+
+  #[test]
+  fn test_nodes_without_policy_are_unconstrained() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Order", crate::graph::layer::Layer::Domain, None))
+      .with_node(node("PgOrders", crate::graph::layer::Layer::Adapter, None))
+      .with_edge(depends("Order", "PgOrders"))
+      .build();
+
+    assert!(graph.check_node_policies().is_empty());
+  }
+}
@@ -6,6 +6,7 @@
 //! intent inference and validation of architectural rules.
 //!
 //! Revision History
+//! - 2026-10-18T13:00:00Z @AI: Derive Ord for deterministic edge ordering.
 //! - 2025-10-01T00:00:00Z @AI: Initial Relationship enum definition for graph edges.
 
 /// Enum representing relationship types between components.
@@ -21,7 +22,7 @@
 /// let rel = Relationship::Implements;
 /// assert!(matches!(rel, Relationship::Implements));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Relationship {
   /// Adapter implements a port interface.
   Implements,
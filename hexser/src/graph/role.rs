@@ -6,6 +6,7 @@
 //! Each role represents a specific responsibility or pattern in the architecture.
 //!
 //! Revision History
+//! - 2026-10-19T00:55:00Z @AI: Add case-insensitive from_name lookup.
 //! - 2025-10-01T00:01:00Z @AI: Renamed Command to Directive for consistency.
 //! - 2025-10-01T00:00:00Z @AI: Initial Role enum definition for component classification.
 
@@ -99,6 +100,31 @@ impl Role {
       Self::Unknown => "Unknown",
     }
   }
+
+  /// Looks up a role by its `as_str` name, ignoring ASCII case.
+  pub fn from_name(name: &str) -> Option<Self> {
+    [
+      Self::Entity,
+      Self::ValueObject,
+      Self::Aggregate,
+      Self::DomainEvent,
+      Self::DomainService,
+      Self::InputPort,
+      Self::OutputPort,
+      Self::Repository,
+      Self::UseCase,
+      Self::Query,
+      Self::Adapter,
+      Self::Mapper,
+      Self::Directive,
+      Self::DirectiveHandler,
+      Self::QueryHandler,
+      Self::Config,
+      Self::Unknown,
+    ]
+    .into_iter()
+    .find(|role| role.as_str().eq_ignore_ascii_case(name))
+  }
 }
 
 impl std::fmt::Display for Role {
@@ -124,6 +150,12 @@ mod tests {
     assert_eq!(Role::Directive.as_str(), "Directive");
   }
 
+  #[test]
+  fn test_role_from_name() {
+    assert_eq!(Role::from_name("repository"), Some(Role::Repository));
+    assert_eq!(Role::from_name("Service"), None);
+  }
+
   #[test]
   fn test_role_display() {
     let role = Role::Entity;
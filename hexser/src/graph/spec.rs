@@ -0,0 +1,402 @@
+//! User-defined architecture specifications checked against a HexGraph.
+//!
+//! An ArchSpec declares which dependencies are allowed ("Adapter may depend
+//! on Port and Domain") and which are forbidden outright, using selectors on
+//! layer and/or role. HexGraph::check_spec reports every edge that breaks a
+//! rule. ArchSpec::hexagonal reproduces the built-in layering rules of
+//! ArchitecturalValidator, so teams can start from it and tighten.
+//!
+//! Specs can be loaded from JSON (serde + serde_json features):
+//!
+//! ```json
+//! {
+//!   "allow": [
+//!     { "from": { "layer": "Adapter" }, "to": [{ "layer": "Port" }, { "layer": "Domain" }] }
+//!   ],
+//!   "forbid": [
+//!     { "from": { "role": "Entity" }, "to": { "role": "Repository" } }
+//!   ]
+//! }
+//! ```
+//!
+//! Revision History
+//! - 2026-10-19T00:55:00Z @AI: Initial ArchSpec with allow/forbid rules and HexGraph::check_spec.
+
+/// Matches nodes by layer and/or role; unset fields match anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Selector {
+  pub layer: Option<crate::graph::layer::Layer>,
+  pub role: Option<crate::graph::role::Role>,
+}
+
+impl Selector {
+  /// Select every node in `layer`
+  pub fn layer(layer: crate::graph::layer::Layer) -> Self {
+    Self {
+      layer: Some(layer),
+      role: None,
+    }
+  }
+
+  /// Select every node with `role`
+  pub fn role(role: crate::graph::role::Role) -> Self {
+    Self {
+      layer: None,
+      role: Some(role),
+    }
+  }
+
+  /// Check whether `node` satisfies every set field
+  pub fn matches(&self, node: &crate::graph::hex_node::HexNode) -> bool {
+    self.layer.is_none_or(|layer| node.layer == layer)
+      && self.role.is_none_or(|role| node.role == role)
+  }
+}
+
+impl std::fmt::Display for Selector {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match (self.layer, self.role) {
+      (Some(layer), Some(role)) => write!(f, "{}/{}", layer, role),
+      (Some(layer), None) => write!(f, "{}", layer),
+      (None, Some(role)) => write!(f, "role {}", role),
+      (None, None) => write!(f, "any"),
+    }
+  }
+}
+
+/// Whitelist: edges leaving a `from` node must reach one of `to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowRule {
+  pub from: Selector,
+  pub to: Vec<Selector>,
+}
+
+/// Blacklist: no edge may go from a `from` node to a `to` node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForbidRule {
+  pub from: Selector,
+  pub to: Selector,
+}
+
+/// Declared architecture to validate a graph against
+///
+/// Sources matched by no allow rule are unconstrained by allow rules; when
+/// several allow rules match a source, a target matching any of them passes.
+/// Forbid rules apply to every edge regardless of allow rules.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ArchSpec {
+  pub allow: Vec<AllowRule>,
+  pub forbid: Vec<ForbidRule>,
+}
+
+/// Which kind of rule an edge broke
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecViolationKind {
+  /// The source matched allow rules but the target matched none of them
+  NotAllowed,
+  /// The edge matched a forbid rule
+  Forbidden,
+}
+
+/// An edge that breaks the spec
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecViolation {
+  pub from: crate::graph::node_id::NodeId,
+  pub to: crate::graph::node_id::NodeId,
+  pub kind: SpecViolationKind,
+  pub reason: String,
+}
+
+impl ArchSpec {
+  /// Create an empty spec that accepts every graph
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Allow `from` nodes to depend on `to` nodes (builder pattern)
+  pub fn allow(mut self, from: Selector, to: Vec<Selector>) -> Self {
+    self.allow.push(AllowRule { from, to });
+    self
+  }
+
+  /// Forbid edges from `from` nodes to `to` nodes (builder pattern)
+  pub fn forbid(mut self, from: Selector, to: Selector) -> Self {
+    self.forbid.push(ForbidRule { from, to });
+    self
+  }
+
+  /// The layering enforced by ArchitecturalValidator, as a spec
+  ///
+  /// Domain depends only on Domain, Port on Domain and Port, Adapter on Port
+  /// and Domain; Application and Infrastructure are unconstrained.
+  pub fn hexagonal() -> Self {
+    let layer = Selector::layer;
+    Self::new()
+      .allow(
+        layer(crate::graph::layer::Layer::Domain),
+        vec![layer(crate::graph::layer::Layer::Domain)],
+      )
+      .allow(
+        layer(crate::graph::layer::Layer::Port),
+        vec![
+          layer(crate::graph::layer::Layer::Domain),
+          layer(crate::graph::layer::Layer::Port),
+        ],
+      )
+      .allow(
+        layer(crate::graph::layer::Layer::Adapter),
+        vec![
+          layer(crate::graph::layer::Layer::Port),
+          layer(crate::graph::layer::Layer::Domain),
+        ],
+      )
+  }
+
+  /// Parse a spec from JSON text
+  ///
+  /// Selector names are matched case-insensitively against `Layer::as_str`
+  /// and `Role::as_str`; unknown names are rejected rather than ignored.
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  pub fn from_json(json: &str) -> crate::result::hex_result::HexResult<Self> {
+    let raw: raw::RawSpec = serde_json::from_str(json).map_err(|e| {
+      crate::error::hex_error::Hexserror::validation(&format!("Invalid architecture spec: {}", e))
+    })?;
+    raw.resolve()
+  }
+
+  /// Load a JSON spec from a file
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  pub fn from_file(
+    path: impl AsRef<std::path::Path>,
+  ) -> crate::result::hex_result::HexResult<Self> {
+    let path = path.as_ref();
+    let json = std::fs::read_to_string(path).map_err(|e| {
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::io::FILE_NOT_FOUND,
+        &format!("Failed to read architecture spec: {}", e),
+      )
+      .with_context("spec_path", path.display())
+    })?;
+    Self::from_json(&json).map_err(|err| err.with_context("spec_path", path.display()))
+  }
+
+  fn check_edge(
+    &self,
+    source: &crate::graph::hex_node::HexNode,
+    target: &crate::graph::hex_node::HexNode,
+  ) -> Vec<SpecViolation> {
+    let mut violations = Vec::new();
+
+    let governing: Vec<&AllowRule> = self
+      .allow
+      .iter()
+      .filter(|rule| rule.from.matches(source))
+      .collect();
+    if !governing.is_empty()
+      && !governing
+        .iter()
+        .any(|rule| rule.to.iter().any(|to| to.matches(target)))
+    {
+      violations.push(SpecViolation {
+        from: source.id,
+        to: target.id,
+        kind: SpecViolationKind::NotAllowed,
+        reason: format!(
+          "{} ({}) may not depend on {} ({} {})",
+          source.type_name, source.layer, target.type_name, target.layer, target.role
+        ),
+      });
+    }
+
+    for rule in &self.forbid {
+      if rule.from.matches(source) && rule.to.matches(target) {
+        violations.push(SpecViolation {
+          from: source.id,
+          to: target.id,
+          kind: SpecViolationKind::Forbidden,
+          reason: format!(
+            "{} -> {} is forbidden ({} must not depend on {})",
+            source.type_name, target.type_name, rule.from, rule.to
+          ),
+        });
+      }
+    }
+
+    violations
+  }
+}
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Check every edge against a user-defined architecture spec
+  ///
+  /// Edges whose endpoints are not nodes of the graph are ignored.
+  pub fn check_spec(&self, spec: &ArchSpec) -> Result<(), Vec<SpecViolation>> {
+    let violations: Vec<SpecViolation> = self
+      .edges()
+      .iter()
+      .filter_map(|edge| Some((self.get_node(&edge.source)?, self.get_node(&edge.target)?)))
+      .flat_map(|(source, target)| spec.check_edge(source, target))
+      .collect();
+
+    if violations.is_empty() {
+      Ok(())
+    } else {
+      Err(violations)
+    }
+  }
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+mod raw {
+  #[derive(serde::Deserialize)]
+  #[serde(deny_unknown_fields)]
+  pub(super) struct RawSpec {
+    #[serde(default)]
+    allow: Vec<RawAllow>,
+    #[serde(default)]
+    forbid: Vec<RawForbid>,
+  }
+
+  #[derive(serde::Deserialize)]
+  #[serde(deny_unknown_fields)]
+  struct RawAllow {
+    from: RawSelector,
+    to: Vec<RawSelector>,
+  }
+
+  #[derive(serde::Deserialize)]
+  #[serde(deny_unknown_fields)]
+  struct RawForbid {
+    from: RawSelector,
+    to: RawSelector,
+  }
+
+  #[derive(serde::Deserialize)]
+  #[serde(deny_unknown_fields)]
+  struct RawSelector {
+    layer: Option<String>,
+    role: Option<String>,
+  }
+
+  impl RawSpec {
+    pub(super) fn resolve(self) -> crate::result::hex_result::HexResult<super::ArchSpec> {
+      let allow = self
+        .allow
+        .into_iter()
+        .map(|rule| {
+          Ok(super::AllowRule {
+            from: rule.from.resolve()?,
+            to: rule
+              .to
+              .into_iter()
+              .map(RawSelector::resolve)
+              .collect::<crate::result::hex_result::HexResult<_>>()?,
+          })
+        })
+        .collect::<crate::result::hex_result::HexResult<_>>()?;
+      let forbid = self
+        .forbid
+        .into_iter()
+        .map(|rule| {
+          Ok(super::ForbidRule {
+            from: rule.from.resolve()?,
+            to: rule.to.resolve()?,
+          })
+        })
+        .collect::<crate::result::hex_result::HexResult<_>>()?;
+      Ok(super::ArchSpec { allow, forbid })
+    }
+  }
+
+  impl RawSelector {
+    fn resolve(self) -> crate::result::hex_result::HexResult<super::Selector> {
+      let layer = self
+        .layer
+        .map(|name| {
+          crate::graph::layer::Layer::from_name(&name).ok_or_else(|| unknown("layer", &name))
+        })
+        .transpose()?;
+      let role = self
+        .role
+        .map(|name| {
+          crate::graph::role::Role::from_name(&name).ok_or_else(|| unknown("role", &name))
+        })
+        .transpose()?;
+      Ok(super::Selector { layer, role })
+    }
+  }
+
+  fn unknown(kind: &str, name: &str) -> crate::error::hex_error::Hexserror {
+    crate::error::hex_error::Hexserror::validation_field(
+      &format!("Unknown {} '{}' in architecture spec", kind, name),
+      kind,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::graph::{
+    hex_edge::HexEdge, hex_graph::HexGraph, hex_node::HexNode, layer::Layer, node_id::NodeId,
+    relationship::Relationship, role::Role,
+  };
+
+  fn graph(edges: &[(&str, &str)]) -> HexGraph {
+    let node =
+      |name: &str, layer, role| HexNode::new(NodeId::from_name(name), layer, role, name, "m");
+    HexGraph::builder()
+      .with_node(node("User", Layer::Domain, Role::Entity))
+      .with_node(node("UserRepo", Layer::Port, Role::Repository))
+      .with_node(node("PgUserRepo", Layer::Adapter, Role::Adapter))
+      .with_node(node("Db", Layer::Infrastructure, Role::Config))
+      .with_edges(
+        edges
+          .iter()
+          .map(|(s, t)| {
+            HexEdge::new(
+              NodeId::from_name(s),
+              NodeId::from_name(t),
+              Relationship::Depends,
+            )
+          })
+          .collect(),
+      )
+      .build()
+  }
+
+  #[test]
+  fn test_hexagonal_spec_accepts_clean_graph() {
+    let g = graph(&[("PgUserRepo", "UserRepo"), ("UserRepo", "User")]);
+    assert!(g.check_spec(&ArchSpec::hexagonal()).is_ok());
+  }
+
+  #[test]
+  fn test_check_spec_reports_not_allowed_and_forbidden_edges() {
+    let spec = ArchSpec::hexagonal().forbid(
+      Selector::role(Role::Entity),
+      Selector::role(Role::Repository),
+    );
+    let g = graph(&[("PgUserRepo", "Db"), ("User", "UserRepo")]);
+
+    let violations = g.check_spec(&spec).unwrap_err();
+    let kinds: Vec<(NodeId, SpecViolationKind)> =
+      violations.iter().map(|v| (v.from, v.kind)).collect();
+
+    assert_eq!(violations.len(), 3);
+    assert!(kinds.contains(&(
+      NodeId::from_name("PgUserRepo"),
+      SpecViolationKind::NotAllowed
+    )));
+    assert!(kinds.contains(&(NodeId::from_name("User"), SpecViolationKind::NotAllowed)));
+    assert!(kinds.contains(&(NodeId::from_name("User"), SpecViolationKind::Forbidden)));
+  }
+
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  #[test]
+  fn test_from_json_rejects_unknown_names() {
+    let err =
+      ArchSpec::from_json(r#"{"forbid": [{"from": {"layer": "Core"}, "to": {}}]}"#).unwrap_err();
+    assert!(err.to_string().contains("Unknown layer 'Core'"));
+  }
+}
@@ -0,0 +1,201 @@
+//! Lazy breadth-first and depth-first traversal over a HexGraph.
+//!
+//! HexGraph::bfs and HexGraph::dfs return iterators over the nodes reachable
+//! from a start node, following edges from source to target. Each node is
+//! yielded at most once, so cycles terminate. Neighbors are always expanded
+//! in ascending NodeId order, which makes traversal order deterministic for
+//! a given graph. Edges pointing at ids without a node are skipped.
+//!
+//! Revision History
+//! - 2026-10-19T00:25:00Z @AI: Initial Bfs and Dfs traversal iterators.
+
+/// Breadth-first iterator created by HexGraph::bfs.
+pub struct Bfs<'g> {
+  graph: &'g crate::graph::hex_graph::HexGraph,
+  queue: std::collections::VecDeque<crate::graph::node_id::NodeId>,
+  seen: std::collections::HashSet<crate::graph::node_id::NodeId>,
+}
+
+/// Depth-first (pre-order) iterator created by HexGraph::dfs.
+pub struct Dfs<'g> {
+  graph: &'g crate::graph::hex_graph::HexGraph,
+  stack: Vec<crate::graph::node_id::NodeId>,
+  seen: std::collections::HashSet<crate::graph::node_id::NodeId>,
+}
+
+/// Outgoing neighbors of `id` that exist as nodes, in ascending NodeId order.
+fn successors(
+  graph: &crate::graph::hex_graph::HexGraph,
+  id: &crate::graph::node_id::NodeId,
+) -> Vec<crate::graph::node_id::NodeId> {
+  let mut targets: Vec<crate::graph::node_id::NodeId> = graph
+    .edges_from(id)
+    .into_iter()
+    .map(|edge| *edge.target())
+    .filter(|target| graph.get_node(target).is_some())
+    .collect();
+  targets.sort();
+  targets.dedup();
+  targets
+}
+
+impl<'g> Iterator for Bfs<'g> {
+  type Item = &'g crate::graph::hex_node::HexNode;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let current = self.queue.pop_front()?;
+    for next in successors(self.graph, &current) {
+      if self.seen.insert(next) {
+        self.queue.push_back(next);
+      }
+    }
+    self.graph.get_node(&current)
+  }
+}
+
+impl<'g> Iterator for Dfs<'g> {
+  type Item = &'g crate::graph::hex_node::HexNode;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while let Some(current) = self.stack.pop() {
+      if !self.seen.insert(current) {
+        continue;
+      }
+      let unvisited = successors(self.graph, &current)
+        .into_iter()
+        .rev()
+        .filter(|next| !self.seen.contains(next));
+      self.stack.extend(unvisited);
+      return self.graph.get_node(&current);
+    }
+    None
+  }
+}
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Iterate nodes reachable from `start` in breadth-first order.
+  ///
+  /// The start node is yielded first; an unknown start yields nothing.
+  /// Nodes at the same depth come out in ascending NodeId order of their
+  /// discovery through earlier nodes.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use hexser::graph::{HexEdge, HexGraph, HexNode, Layer, NodeId, Role};
+  /// use hexser::graph::relationship::Relationship;
+  ///
+  /// let node = |name: &str| HexNode::new(NodeId::from_name(name), Layer::Domain, Role::Entity, name, "m");
+  /// let graph = HexGraph::builder()
+  ///     .with_nodes(vec![node("A"), node("B"), node("C")])
+  ///     .with_edge(HexEdge::new(NodeId::from_name("A"), NodeId::from_name("B"), Relationship::Depends))
+  ///     .with_edge(HexEdge::new(NodeId::from_name("B"), NodeId::from_name("C"), Relationship::Depends))
+  ///     .with_edge(HexEdge::new(NodeId::from_name("C"), NodeId::from_name("A"), Relationship::Depends))
+  ///     .build();
+  ///
+  /// let names: Vec<&str> = graph.bfs(&NodeId::from_name("A")).map(|n| n.type_name.as_str()).collect();
+  /// assert_eq!(names, vec!["A", "B", "C"]);
+  /// ```
+  pub fn bfs(&self, start: &crate::graph::node_id::NodeId) -> Bfs<'_> {
+    let mut queue = std::collections::VecDeque::new();
+    let mut seen = std::collections::HashSet::new();
+    if self.get_node(start).is_some() {
+      queue.push_back(*start);
+      seen.insert(*start);
+    }
+    Bfs {
+      graph: self,
+      queue,
+      seen,
+    }
+  }
+
+  /// Iterate nodes reachable from `start` in depth-first pre-order.
+  ///
+  /// The start node is yielded first; an unknown start yields nothing.
+  /// Among the successors of a node, the one with the smallest NodeId is
+  /// explored (fully) first.
+  pub fn dfs(&self, start: &crate::graph::node_id::NodeId) -> Dfs<'_> {
+    let stack = if self.get_node(start).is_some() {
+      vec![*start]
+    } else {
+      Vec::new()
+    };
+    Dfs {
+      graph: self,
+      stack,
+      seen: std::collections::HashSet::new(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::graph::{
+    hex_edge::HexEdge, hex_graph::HexGraph, hex_node::HexNode, layer::Layer, node_id::NodeId,
+    relationship::Relationship, role::Role,
+  };
+
+  fn id(name: &str) -> NodeId {
+    NodeId::from_name(name)
+  }
+
+  /// A -> {B, C}, B -> D, C -> D, D -> A, plus a dangling edge D -> Missing.
+  fn diamond_with_cycle() -> HexGraph {
+    let nodes = ["A", "B", "C", "D"]
+      .iter()
+      .map(|n| HexNode::new(id(n), Layer::Domain, Role::Entity, n, "m"))
+      .collect();
+    HexGraph::builder()
+      .with_nodes(nodes)
+      .with_edges(
+        [
+          ("A", "C"),
+          ("A", "B"),
+          ("B", "D"),
+          ("C", "D"),
+          ("D", "A"),
+          ("D", "Missing"),
+        ]
+        .into_iter()
+        .map(|(s, t)| HexEdge::new(id(s), id(t), Relationship::Depends))
+        .collect(),
+      )
+      .build()
+  }
+
+  fn names<'g>(it: impl Iterator<Item = &'g HexNode>) -> Vec<&'g str> {
+    it.map(|n| n.type_name.as_str()).collect()
+  }
+
+  /// B and C sorted by NodeId, as traversal expands them.
+  fn middle() -> (&'static str, &'static str) {
+    if id("B") < id("C") {
+      ("B", "C")
+    } else {
+      ("C", "B")
+    }
+  }
+
+  #[test]
+  fn test_bfs_visits_each_reachable_node_once() {
+    let graph = diamond_with_cycle();
+    let (first, second) = middle();
+    assert_eq!(names(graph.bfs(&id("A"))), vec!["A", first, second, "D"]);
+    assert_eq!(names(graph.bfs(&id("D"))), vec!["D", "A", first, second]);
+  }
+
+  #[test]
+  fn test_dfs_explores_smallest_successor_first() {
+    let graph = diamond_with_cycle();
+    let (first, second) = middle();
+    assert_eq!(names(graph.dfs(&id("A"))), vec!["A", first, "D", second]);
+  }
+
+  #[test]
+  fn test_traversal_from_unknown_start_is_empty() {
+    let graph = diamond_with_cycle();
+    assert_eq!(graph.bfs(&id("Missing")).count(), 0);
+    assert_eq!(graph.dfs(&id("Missing")).count(), 0);
+  }
+}
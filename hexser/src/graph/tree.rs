@@ -0,0 +1,173 @@
+//! Terminal tree rendering of dependency graphs.
+//!
+//! Renders the dependencies reachable from chosen entry points as an
+//! indented tree drawn with box-drawing characters, which reads better in a
+//! terminal than a flat node list once graphs get deep. Shared dependencies
+//! are expanded once; later occurrences are marked "(see above)", which
+//! also keeps cycles finite.
+//!
+//! Revision History
+//! - 2026-10-19T06:05:00Z @AI: Initial HexGraph::to_tree_string.
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Render the dependency tree below `roots`, `max_depth` levels deep.
+  ///
+  /// Each line shows a node's type name and layer; children are the
+  /// targets of its outgoing edges, sorted by type name. A node whose
+  /// subtree was already printed is shown again with "(see above)" instead
+  /// of repeating it, and a node cut off by `max_depth` while it still has
+  /// dependencies ends in "…". Roots not in the graph are skipped.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use hexser::graph::{GraphBuilder, HexEdge, HexNode, Layer, NodeId, Relationship, Role};
+  ///
+  /// let service = NodeId::from_name("SignUp");
+  /// let repo = NodeId::from_name("UserRepository");
+  /// let graph = GraphBuilder::new()
+  ///     .with_node(HexNode::new(service, Layer::Application, Role::Directive, "SignUp", "app"))
+  ///     .with_node(HexNode::new(repo, Layer::Port, Role::Repository, "UserRepository", "app"))
+  ///     .with_edge(HexEdge::new(service, repo, Relationship::Depends))
+  ///     .build();
+  ///
+  /// assert_eq!(
+  ///     graph.to_tree_string(&[service], 5),
+  ///     "SignUp (Application)\n└── UserRepository (Port)\n"
+  /// );
+  /// ```
+  pub fn to_tree_string(
+    &self,
+    roots: &[crate::graph::node_id::NodeId],
+    max_depth: usize,
+  ) -> String {
+    let mut out = String::new();
+    let mut expanded = std::collections::HashSet::new();
+    for root in roots {
+      if let Some(node) = self.get_node(root) {
+        self.render_tree_node(node, "", None, max_depth, &mut expanded, &mut out);
+      }
+    }
+    out
+  }
+
+  /// Append `node` and, depth permitting, its subtree to `out`.
+  ///
+  /// `branch` is `None` for roots and `Some(is_last)` for children.
+  fn render_tree_node(
+    &self,
+    node: &crate::graph::hex_node::HexNode,
+    prefix: &str,
+    branch: Option<bool>,
+    depth_left: usize,
+    expanded: &mut std::collections::HashSet<crate::graph::node_id::NodeId>,
+    out: &mut String,
+  ) {
+    let mut children: Vec<&crate::graph::hex_node::HexNode> = self
+      .edges_from(&node.id)
+      .iter()
+      .filter_map(|edge| self.get_node(&edge.target))
+      .collect();
+    children.sort_by(|a, b| a.type_name.cmp(&b.type_name).then(a.id.cmp(&b.id)));
+    children.dedup_by_key(|child| child.id);
+
+    let (connector, child_prefix) = match branch {
+      None => ("", String::from(prefix)),
+      Some(true) => ("└── ", format!("{}    ", prefix)),
+      Some(false) => ("├── ", format!("{}│   ", prefix)),
+    };
+    let label = format!("{}{}{} ({})", prefix, connector, node.type_name, node.layer);
+
+    if children.is_empty() {
+      out.push_str(&format!("{}\n", label));
+      return;
+    }
+    if expanded.contains(&node.id) {
+      out.push_str(&format!("{} (see above)\n", label));
+      return;
+    }
+    if depth_left == 0 {
+      out.push_str(&format!("{} …\n", label));
+      return;
+    }
+
+    out.push_str(&format!("{}\n", label));
+    expanded.insert(node.id);
+    let last = children.len() - 1;
+    for (index, child) in children.into_iter().enumerate() {
+      self.render_tree_node(
+        child,
+        &child_prefix,
+        Some(index == last),
+        depth_left - 1,
+        expanded,
+        out,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  fn graph() -> crate::graph::hex_graph::HexGraph {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let node = |name: &str, layer| {
+      crate::graph::hex_node::HexNode::new(
+        id(name),
+        layer,
+        crate::graph::role::Role::Entity,
+        name,
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        id(from),
+        id(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Checkout", crate::graph::layer::Layer::Application))
+      .with_node(node("OrderRepo", crate::graph::layer::Layer::Port))
+      .with_node(node("PaymentPort", crate::graph::layer::Layer::Port))
+      .with_node(node("Order", crate::graph::layer::Layer::Domain))
+      .with_node(node("Money", crate::graph::layer::Layer::Domain))
+      .with_edge(edge("Checkout", "OrderRepo"))
+      .with_edge(edge("Checkout", "PaymentPort"))
+      .with_edge(edge("OrderRepo", "Order"))
+      .with_edge(edge("PaymentPort", "Order"))
+      .with_edge(edge("Order", "Money"))
+      .with_edge(edge("Money", "Order"))
+      .build()
+  }
+
+  #[test]
+  fn test_tree_marks_shared_subtrees_and_cycles() {
+    let root = crate::graph::node_id::NodeId::from_name("Checkout");
+    assert_eq!(
+      graph().to_tree_string(&[root], 10),
+      [
+        "Checkout (Application)",
+        "├── OrderRepo (Port)",
+        "│   └── Order (Domain)",
+        "│       └── Money (Domain)",
+        "│           └── Order (Domain) (see above)",
+        "└── PaymentPort (Port)",
+        "    └── Order (Domain) (see above)",
+        "",
+      ]
+      .join("\n")
+    );
+  }
+
+  #[test]
+  fn test_tree_truncates_at_max_depth_and_skips_unknown_roots() {
+    let root = crate::graph::node_id::NodeId::from_name("Checkout");
+    let unknown = crate::graph::node_id::NodeId::from_name("Missing");
+    assert_eq!(
+      graph().to_tree_string(&[unknown, root], 1),
+      "Checkout (Application)\n├── OrderRepo (Port) …\n└── PaymentPort (Port) …\n"
+    );
+  }
+}
@@ -0,0 +1,133 @@
+//! Visitor pattern for walking a HexGraph.
+//!
+//! GraphVisitor lets callers implement one-off metrics or exports without
+//! adding a new method to HexGraph each time. HexGraph::accept drives the
+//! traversal in a deterministic order: every node sorted by NodeId, then
+//! every edge sorted by source, target, and relationship.
+//!
+//! Revision History
+//! - 2026-10-18T20:30:00Z @AI: Initial GraphVisitor trait and HexGraph::accept.
+
+/// Callback interface invoked by HexGraph::accept.
+///
+/// Both methods default to no-ops so a visitor only overrides what it needs.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::{GraphVisitor, HexGraph, HexNode, Layer, NodeId, Role};
+///
+/// #[derive(Default)]
+/// struct RoleCounter {
+///     counts: std::collections::HashMap<Role, usize>,
+/// }
+///
+/// impl GraphVisitor for RoleCounter {
+///     fn visit_node(&mut self, node: &HexNode) {
+///         *self.counts.entry(node.role()).or_insert(0) += 1;
+///     }
+/// }
+///
+/// let graph = HexGraph::builder()
+///     .with_node(HexNode::new(NodeId::from_name("User"), Layer::Domain, Role::Entity, "User", "domain"))
+///     .with_node(HexNode::new(NodeId::from_name("Order"), Layer::Domain, Role::Entity, "Order", "domain"))
+///     .build();
+///
+/// let mut counter = RoleCounter::default();
+/// graph.accept(&mut counter);
+/// assert_eq!(counter.counts[&Role::Entity], 2);
+/// ```
+pub trait GraphVisitor {
+  /// Called once per node, in NodeId order.
+  fn visit_node(&mut self, _node: &crate::graph::hex_node::HexNode) {}
+
+  /// Called once per edge after all nodes have been visited.
+  fn visit_edge(&mut self, _edge: &crate::graph::hex_edge::HexEdge) {}
+}
+
+impl crate::graph::hex_graph::HexGraph {
+  /// Walk the graph with `visitor`, visiting all nodes then all edges.
+  ///
+  /// Nodes are visited in NodeId order and edges in (source, target,
+  /// relationship) order, so repeated walks produce identical callbacks.
+  pub fn accept(&self, visitor: &mut impl GraphVisitor) {
+    for node in self.inner.nodes.values() {
+      visitor.visit_node(node);
+    }
+
+    let mut edges: Vec<&crate::graph::hex_edge::HexEdge> = self.inner.edges.iter().collect();
+    edges.sort_by_key(|e| (*e.source(), *e.target(), e.relationship()));
+    for edge in edges {
+      visitor.visit_edge(edge);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  #[derive(Default)]
+  struct Recorder {
+    events: Vec<String>,
+  }
+
+  impl crate::graph::visitor::GraphVisitor for Recorder {
+    fn visit_node(&mut self, node: &crate::graph::hex_node::HexNode) {
+      self.events.push(format!("node:{}", node.type_name()));
+    }
+
+    fn visit_edge(&mut self, edge: &crate::graph::hex_edge::HexEdge) {
+      self.events.push(format!("edge:{}", edge.relationship()));
+    }
+  }
+
+  #[test]
+  fn test_accept_visits_nodes_then_edges_deterministically() {
+    // Test: accept visits every node before any edge, in a stable order.
+    // Justification: Visitor-based exports must be reproducible across runs.
+    let node = |name: &str| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str, rel| {
+      crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name(from),
+        crate::graph::node_id::NodeId::from_name(to),
+        rel,
+      )
+    };
+    let build = |reverse: bool| {
+      let mut edges = vec![
+        edge("A", "B", crate::graph::relationship::Relationship::Depends),
+        edge(
+          "B",
+          "C",
+          crate::graph::relationship::Relationship::Implements,
+        ),
+      ];
+      if reverse {
+        edges.reverse();
+      }
+      crate::graph::hex_graph::HexGraph::builder()
+        .with_node(node("C"))
+        .with_node(node("A"))
+        .with_node(node("B"))
+        .with_edges(edges)
+        .build()
+    };
+
+    let mut first = Recorder::default();
+    build(false).accept(&mut first);
+    let mut second = Recorder::default();
+    build(true).accept(&mut second);
+
+    assert_eq!(first.events.len(), 5);
+    assert!(first.events[..3].iter().all(|e| e.starts_with("node:")));
+    assert!(first.events[3..].iter().all(|e| e.starts_with("edge:")));
+    assert_eq!(first.events, second.events);
+  }
+}
@@ -0,0 +1,160 @@
+//! CSV format exporter adapter.
+//!
+//! Exports graphs as two CSV tables, a node list and an edge list, for
+//! loading into spreadsheets or data warehouses. The tables are produced by
+//! separate methods so they can be written to separate files. Fields are
+//! quoted per RFC 4180 when they contain commas, quotes, or line breaks.
+//!
+//! Revision History
+//! - 2026-10-18T18:30:00Z @AI: Initial CSV exporter implementation.
+
+/// CSV node and edge list exporter
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::visualization::adapters::csv_exporter::CsvExporter;
+/// use hexser::graph::visualization::domain::visual_graph::VisualGraph;
+/// use hexser::graph::visualization::domain::visual_style::VisualStyle;
+///
+/// let graph = hexser::graph::HexGraph::new();
+/// let visual = VisualGraph::from_hex_graph(&graph, VisualStyle::default());
+///
+/// let exporter = CsvExporter::new();
+/// assert_eq!(exporter.export_nodes(&visual), "id,layer,role,type,module\n");
+/// assert_eq!(exporter.export_edges(&visual), "from,to,relationship\n");
+/// ```
+pub struct CsvExporter;
+
+impl CsvExporter {
+  /// Create new CSV exporter
+  pub fn new() -> Self {
+    Self
+  }
+
+  /// Export nodes as `id,layer,role,type,module` rows with a header line
+  pub fn export_nodes(
+    &self,
+    visual_graph: &crate::graph::visualization::domain::visual_graph::VisualGraph,
+  ) -> String {
+    let mut output = String::from("id,layer,role,type,module\n");
+    for node in &visual_graph.nodes {
+      push_row(
+        &mut output,
+        &[&node.id, &node.layer, &node.role, &node.label, &node.module],
+      );
+    }
+    output
+  }
+
+  /// Export edges as `from,to,relationship` rows with a header line
+  pub fn export_edges(
+    &self,
+    visual_graph: &crate::graph::visualization::domain::visual_graph::VisualGraph,
+  ) -> String {
+    let mut output = String::from("from,to,relationship\n");
+    for edge in &visual_graph.edges {
+      push_row(
+        &mut output,
+        &[&edge.source, &edge.target, &edge.relationship],
+      );
+    }
+    output
+  }
+}
+
+impl Default for CsvExporter {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+fn push_row(output: &mut String, fields: &[&str]) {
+  let row: Vec<String> = fields.iter().map(|field| escape(field)).collect();
+  output.push_str(&row.join(","));
+  output.push('\n');
+}
+
+/// Quote a field if it contains a delimiter, quote, or line break
+fn escape(field: &str) -> String {
+  if field.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", field.replace('"', "\"\""))
+  } else {
+    String::from(field)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn visual_graph() -> crate::graph::visualization::domain::visual_graph::VisualGraph {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("Repo"),
+        crate::graph::layer::Layer::Port,
+        crate::graph::role::Role::Repository,
+        "app::Repo<User, \"v2\">",
+        "app::ports",
+      ))
+      .with_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("User"),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "app::User",
+        "app::domain",
+      ))
+      .with_edge(crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name("Repo"),
+        crate::graph::node_id::NodeId::from_name("User"),
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .build();
+
+    crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph(
+      &graph,
+      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+    )
+  }
+
+  #[test]
+  fn test_export_nodes_quotes_fields() {
+    let csv = CsvExporter::new().export_nodes(&visual_graph());
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines[0], "id,layer,role,type,module");
+    assert_eq!(
+      lines[1],
+      format!(
+        "{},Port,Repository,\"app::Repo<User, \"\"v2\"\">\",app::ports",
+        crate::graph::node_id::NodeId::from_name("Repo")
+      )
+    );
+    assert_eq!(
+      lines[2],
+      format!(
+        "{},Domain,Entity,app::User,app::domain",
+        crate::graph::node_id::NodeId::from_name("User")
+      )
+    );
+  }
+
+  #[test]
+  fn test_export_edges() {
+    let csv = CsvExporter::new().export_edges(&visual_graph());
+    assert_eq!(
+      csv,
+      format!(
+        "from,to,relationship\n{},{},Depends\n",
+        crate::graph::node_id::NodeId::from_name("Repo"),
+        crate::graph::node_id::NodeId::from_name("User")
+      )
+    );
+  }
+
+  #[test]
+  fn test_escape_line_breaks() {
+    assert_eq!(escape("a\nb"), "\"a\nb\"");
+    assert_eq!(escape("plain"), "plain");
+  }
+}
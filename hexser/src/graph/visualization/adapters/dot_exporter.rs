@@ -1,8 +1,21 @@
 //! DOT format exporter adapter.
 //!
-//! Exports graphs to GraphViz DOT format.
+//! Exports graphs to GraphViz DOT format. Fill color follows the node's
+//! layer and shape follows its role, as resolved by the VisualStyle. In
+//! annotated exports, cycle nodes get a red border, orphans an "orphan"
+//! external label, and layer violations a dashed red edge. Clustered
+//! exports group nodes into one `cluster_<context>` subgraph per bounded
+//! context.
 //!
 //! Revision History
+//! - 2026-10-19T10:15:00Z @AI: Opt into build info in the provenance test.
+//! - 2026-10-19T08:35:00Z @AI: Draw bounded context clusters.
+//! - 2026-10-19T07:15:00Z @AI: Style annotated cycles, orphans, and violations.
+//! - 2026-10-19T05:15:00Z @AI: Label the graph with its build info.
+//! - 2026-10-19T01:45:00Z @AI: Render per-role node shapes.
+//! - 2026-10-18T23:30:00Z @AI: Render weighted edges with a proportional penwidth.
+//! - 2026-10-18T21:15:00Z @AI: Stream output via write_to.
+//! - 2026-10-18T20:00:00Z @AI: Render node tags as an extra label line.
 //! - 2025-10-02T16:00:00Z @AI: Initial DOT exporter implementation.
 
 /// DOT format exporter
@@ -20,31 +33,76 @@ impl DotExporter {
 }
 
 impl crate::graph::visualization::ports::format_exporter::FormatExporter for DotExporter {
-  fn export(
+  fn write_to(
     &self,
     visual_graph: &crate::graph::visualization::domain::visual_graph::VisualGraph,
-  ) -> crate::result::hex_result::HexResult<String> {
-    let mut output = format!("digraph hex_architecture {{\n  rankdir={};\n", self.rankdir);
-    output.push_str("  node [shape=box, style=rounded];\n\n");
+    w: &mut dyn std::io::Write,
+  ) -> std::io::Result<()> {
+    writeln!(
+      w,
+      "digraph hex_architecture {{\n  rankdir={};",
+      self.rankdir
+    )?;
+    if let Some(build_info) = &visual_graph.build_info {
+      writeln!(
+        w,
+        "  label=\"{}\";\n  labelloc=b;\n  fontsize=10;",
+        build_info.to_string().replace('"', "\\\"")
+      )?;
+    }
+    writeln!(w, "  node [shape=box, style=rounded];\n")?;
 
     for node in &visual_graph.nodes {
-      output.push_str(&format!(
-        "  \"{}\" [label=\"{}\\n({})\", fillcolor={}, style=filled];\n",
-        node.id, node.label, node.role, node.color
-      ));
+      let tags = if node.tags.is_empty() {
+        String::new()
+      } else {
+        format!("\\n[{}]", node.tags.join(", "))
+      };
+      let mut marks = String::new();
+      if node.in_cycle {
+        marks.push_str(", color=red, penwidth=2");
+      }
+      if node.orphan {
+        marks.push_str(", xlabel=\"orphan\"");
+      }
+      writeln!(
+        w,
+        "  \"{}\" [label=\"{}\\n({}){}\", shape={}, fillcolor={}, style=filled{}];",
+        node.id, node.label, node.role, tags, node.shape, node.color, marks
+      )?;
+    }
+
+    for (context, ids) in visual_graph.clusters() {
+      writeln!(
+        w,
+        "\n  subgraph \"cluster_{}\" {{\n    label=\"{}\";",
+        context, context
+      )?;
+      for id in ids {
+        writeln!(w, "    \"{}\";", id)?;
+      }
+      writeln!(w, "  }}")?;
     }
 
-    output.push_str("\n");
+    writeln!(w)?;
 
     for edge in &visual_graph.edges {
-      output.push_str(&format!(
-        "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
-        edge.source, edge.target, edge.relationship
-      ));
+      let mut attributes = if edge.weight > 1 {
+        format!(", penwidth={}", edge.weight)
+      } else {
+        String::new()
+      };
+      if edge.violation {
+        attributes.push_str(", color=red, style=dashed");
+      }
+      writeln!(
+        w,
+        "  \"{}\" -> \"{}\" [label=\"{}\"{}];",
+        edge.source, edge.target, edge.relationship, attributes
+      )?;
     }
 
-    output.push_str("}\n");
-    Ok(output)
+    writeln!(w, "}}")
   }
 
   fn format_name(&self) -> &str {
@@ -86,4 +144,154 @@ mod tests {
     assert!(dot.contains("digraph hex_architecture"));
     assert!(dot.contains("Test"));
   }
+
+  #[test]
+  fn test_dot_export_renders_tags() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .add_node(
+        crate::graph::hex_node::HexNode::new(
+          crate::graph::node_id::NodeId::from_name("Customer"),
+          crate::graph::layer::Layer::Domain,
+          crate::graph::role::Role::Entity,
+          "Customer",
+          "test",
+        )
+        .with_tags(["pii", "external"]),
+      )
+      .build();
+
+    let visual = crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph(
+      &graph,
+      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+    );
+
+    let dot = DotExporter::new().export(&visual).unwrap();
+    assert!(dot.contains("label=\"Customer\\n(Entity)\\n[pii, external]\""));
+  }
+
+  #[test]
+  fn test_dot_export_thickens_weighted_edges() {
+    let a = crate::graph::node_id::NodeId::from_name("A");
+    let b = crate::graph::node_id::NodeId::from_name("B");
+    let node = |id, name| {
+      crate::graph::hex_node::HexNode::new(
+        id,
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        "test",
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(a, "A"))
+      .with_node(node(b, "B"))
+      .with_edge(
+        crate::graph::hex_edge::HexEdge::new(
+          a,
+          b,
+          crate::graph::relationship::Relationship::Depends,
+        )
+        .with_weight(3),
+      )
+      .with_edge(crate::graph::hex_edge::HexEdge::new(
+        b,
+        a,
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .build();
+    let visual = crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph(
+      &graph,
+      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+    );
+
+    let dot = DotExporter::new().export(&visual).unwrap();
+    assert!(dot.contains(&format!(
+      "\"{}\" -> \"{}\" [label=\"Depends\", penwidth=3];",
+      a, b
+    )));
+    assert!(dot.contains(&format!("\"{}\" -> \"{}\" [label=\"Depends\"];", b, a)));
+  }
+
+  #[test]
+  fn test_dot_export_shapes_follow_role() {
+    let node = |name: &str, layer, role| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        layer,
+        role,
+        name,
+        "test",
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "Order",
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+      ))
+      .with_node(node(
+        "OrderRepository",
+        crate::graph::layer::Layer::Port,
+        crate::graph::role::Role::Repository,
+      ))
+      .with_node(node(
+        "PgOrderRepository",
+        crate::graph::layer::Layer::Adapter,
+        crate::graph::role::Role::Adapter,
+      ))
+      .build();
+    let style = crate::graph::visualization::domain::visual_style::VisualStyle::default()
+      .with_role_shape(crate::graph::role::Role::Entity, "box3d");
+    let visual =
+      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph(&graph, style);
+
+    let dot = DotExporter::new().export(&visual).unwrap();
+    assert!(dot.contains("(Entity)\", shape=box3d, fillcolor=lightblue"));
+    assert!(dot.contains("(Repository)\", shape=ellipse, fillcolor=lightgreen"));
+    assert!(dot.contains("(Adapter)\", shape=component, fillcolor=lightyellow"));
+  }
+
+  #[test]
+  fn test_write_to_streams_same_output_as_export() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .add_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("Test"),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        "Test",
+        "test",
+      ))
+      .build();
+    let visual = crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph(
+      &graph,
+      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+    );
+
+    let exporter = DotExporter::new();
+    let mut streamed = Vec::new();
+    exporter.write_to(&visual, &mut streamed).unwrap();
+
+    assert_eq!(
+      String::from_utf8(streamed).unwrap(),
+      exporter.export(&visual).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_dot_export_labels_build_info() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_app_version("2.3.1")
+      .build();
+    let visual =
+      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph_with_options(
+        &graph,
+        crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+        &crate::graph::visualization::domain::export_options::ExportOptions::new()
+          .include_build_info(true),
+      );
+
+    let dot = DotExporter::new().export(&visual).unwrap();
+    assert!(dot.contains(&format!("  label=\"{}\";", graph.metadata().build_info)));
+    assert!(dot.contains(", app 2.3.1\";"));
+  }
 }
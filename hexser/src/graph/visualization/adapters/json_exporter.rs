@@ -2,9 +2,85 @@
 //!
 //! Exports graphs to JSON format compatible with D3.js.
 //!
+//! The output follows a stable, documented shape described by the JSON
+//! Schema in `JSON_SCHEMA`. Every document names its schema in a `$schema`
+//! field and carries a `schema_version`, so consumers can validate exports
+//! and detect format changes. Adding an optional field keeps the version;
+//! removing, renaming, or retyping a field, or making one required, bumps
+//! `JSON_SCHEMA_VERSION` and the id in `JSON_SCHEMA_ID`.
+//!
 //! Revision History
+//! - 2026-10-19T10:15:00Z @AI: Opt into build info in the provenance test.
+//! - 2026-10-19T08:35:00Z @AI: Add optional node context for clustered exports.
+//! - 2026-10-19T08:15:00Z @AI: Emit $schema and schema_version; embed the JSON Schema.
+//! - 2026-10-19T07:15:00Z @AI: Emit annotations and violation flags when set.
+//! - 2026-10-19T05:15:00Z @AI: Add a metadata object with the graph's build info.
+//! - 2026-10-18T21:15:00Z @AI: Serialize straight into the writer via write_to.
 //! - 2025-10-02T16:00:00Z @AI: Initial JSON exporter implementation.
 
+/// Version of the export shape, written as `schema_version`
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Identifier of the export schema, written as `$schema`
+pub const JSON_SCHEMA_ID: &str = "urn:hexser:graph-export:1";
+
+/// JSON Schema (draft 2020-12) that every `JsonExporter` document satisfies.
+///
+/// - `metadata` (optional): build info as string values, see `BuildInfo`
+/// - `nodes[]`: `id`, `name` (label), `group` (layer), in annotated
+///   exports `annotations` holding `"cycle"` and/or `"orphan"`, and in
+///   clustered exports `context` naming the bounded context
+/// - `links[]`: `source` and `target` node ids, `value` (always 1), and in
+///   annotated exports `violation: true` on layer-violating edges
+pub const JSON_SCHEMA: &str = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$id": "urn:hexser:graph-export:1",
+  "title": "hexser graph export",
+  "type": "object",
+  "required": ["$schema", "schema_version", "nodes", "links"],
+  "additionalProperties": false,
+  "properties": {
+    "$schema": { "const": "urn:hexser:graph-export:1" },
+    "schema_version": { "const": 1 },
+    "metadata": {
+      "type": "object",
+      "additionalProperties": { "type": "string" }
+    },
+    "nodes": {
+      "type": "array",
+      "items": {
+        "type": "object",
+        "required": ["id", "name", "group"],
+        "additionalProperties": false,
+        "properties": {
+          "id": { "type": "string" },
+          "name": { "type": "string" },
+          "group": { "type": "string" },
+          "annotations": {
+            "type": "array",
+            "items": { "enum": ["cycle", "orphan"] }
+          },
+          "context": { "type": "string" }
+        }
+      }
+    },
+    "links": {
+      "type": "array",
+      "items": {
+        "type": "object",
+        "required": ["source", "target", "value"],
+        "additionalProperties": false,
+        "properties": {
+          "source": { "type": "string" },
+          "target": { "type": "string" },
+          "value": { "type": "integer", "minimum": 1 },
+          "violation": { "type": "boolean" }
+        }
+      }
+    }
+  }
+}"##;
+
 /// JSON format exporter
 pub struct JsonExporter;
 
@@ -17,6 +93,11 @@ impl JsonExporter {
 
 #[derive(serde::Serialize)]
 struct D3Graph {
+  #[serde(rename = "$schema")]
+  schema: &'static str,
+  schema_version: u32,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  metadata: Option<std::collections::BTreeMap<&'static str, String>>,
   nodes: Vec<D3Node>,
   links: Vec<D3Link>,
 }
@@ -26,6 +107,12 @@ struct D3Node {
   id: String,
   name: String,
   group: String,
+  /// "cycle" and/or "orphan" in annotated exports
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  annotations: Vec<&'static str>,
+  /// Bounded context in clustered exports
+  #[serde(skip_serializing_if = "Option::is_none")]
+  context: Option<String>,
 }
 
 #[derive(serde::Serialize)]
@@ -33,13 +120,16 @@ struct D3Link {
   source: String,
   target: String,
   value: usize,
+  #[serde(skip_serializing_if = "std::ops::Not::not")]
+  violation: bool,
 }
 
 impl crate::graph::visualization::ports::format_exporter::FormatExporter for JsonExporter {
-  fn export(
+  fn write_to(
     &self,
     visual_graph: &crate::graph::visualization::domain::visual_graph::VisualGraph,
-  ) -> crate::result::hex_result::HexResult<String> {
+    w: &mut dyn std::io::Write,
+  ) -> std::io::Result<()> {
     let d3_nodes = visual_graph
       .nodes
       .iter()
@@ -47,6 +137,11 @@ impl crate::graph::visualization::ports::format_exporter::FormatExporter for Jso
         id: node.id.clone(),
         name: node.label.clone(),
         group: node.layer.clone(),
+        annotations: [(node.in_cycle, "cycle"), (node.orphan, "orphan")]
+          .into_iter()
+          .filter_map(|(set, name)| set.then_some(name))
+          .collect(),
+        context: node.context.clone(),
       })
       .collect();
 
@@ -57,18 +152,23 @@ impl crate::graph::visualization::ports::format_exporter::FormatExporter for Jso
         source: edge.source.clone(),
         target: edge.target.clone(),
         value: 1,
+        violation: edge.violation,
       })
       .collect();
 
     let d3_graph = D3Graph {
+      schema: JSON_SCHEMA_ID,
+      schema_version: JSON_SCHEMA_VERSION,
+      metadata: visual_graph
+        .build_info
+        .as_ref()
+        .map(|info| info.entries().into_iter().collect()),
       nodes: d3_nodes,
       links: d3_links,
     };
 
-    serde_json::to_string_pretty(&d3_graph).map_err(move |e| {
-      let msg = format!("JSON serialization failed: {}", e);
-      crate::error::hex_error::Hexserror::adapter("E_HEX_VIZ_001", msg.as_str())
-    })
+    serde_json::to_writer_pretty(w, &d3_graph)?;
+    Ok(())
   }
 
   fn format_name(&self) -> &str {
@@ -111,4 +211,153 @@ mod tests {
     assert!(json.contains("links"));
     assert!(json.contains("Test"));
   }
+
+  #[test]
+  fn test_json_export_includes_build_info_metadata() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_app_version("2.3.1")
+      .build();
+    let mut visual =
+      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph_with_options(
+        &graph,
+        crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+        &crate::graph::visualization::domain::export_options::ExportOptions::new()
+          .include_build_info(true),
+      );
+
+    let json: serde_json::Value =
+      serde_json::from_str(&JsonExporter::new().export(&visual).unwrap()).unwrap();
+    assert_eq!(json["metadata"]["app_version"], "2.3.1");
+    assert_eq!(
+      json["metadata"]["hexser_version"],
+      env!("CARGO_PKG_VERSION")
+    );
+
+    visual.build_info = None;
+    let json: serde_json::Value =
+      serde_json::from_str(&JsonExporter::new().export(&visual).unwrap()).unwrap();
+    assert!(json.get("metadata").is_none());
+  }
+
+  /// Check `value` against the subset of JSON Schema used by `JSON_SCHEMA`
+  fn schema_errors(
+    schema: &serde_json::Value,
+    value: &serde_json::Value,
+    path: &str,
+  ) -> Vec<String> {
+    let mut errors = Vec::new();
+    if let Some(expected) = schema.get("const")
+      && expected != value
+    {
+      errors.push(format!("{}: expected {}", path, expected));
+    }
+    if let Some(allowed) = schema.get("enum").and_then(|e| e.as_array())
+      && !allowed.contains(value)
+    {
+      errors.push(format!("{}: {} not in enum", path, value));
+    }
+    if let Some(kind) = schema.get("type").and_then(|t| t.as_str()) {
+      let matches = match kind {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_u64() || value.is_i64(),
+        "boolean" => value.is_boolean(),
+        other => panic!("unsupported type {}", other),
+      };
+      if !matches {
+        errors.push(format!("{}: expected {}", path, kind));
+        return errors;
+      }
+    }
+    if let (Some(minimum), Some(number)) = (schema.get("minimum"), value.as_f64())
+      && number < minimum.as_f64().unwrap()
+    {
+      errors.push(format!("{}: below minimum", path));
+    }
+    if let Some(object) = value.as_object() {
+      for key in schema["required"].as_array().into_iter().flatten() {
+        if !object.contains_key(key.as_str().unwrap()) {
+          errors.push(format!("{}: missing {}", path, key));
+        }
+      }
+      for (key, item) in object {
+        let item_path = format!("{}.{}", path, key);
+        match (
+          schema["properties"].get(key),
+          &schema["additionalProperties"],
+        ) {
+          (Some(property), _) => errors.extend(schema_errors(property, item, &item_path)),
+          (None, serde_json::Value::Bool(false)) => {
+            errors.push(format!("{}: not allowed", item_path))
+          }
+          (None, serde_json::Value::Object(_)) => errors.extend(schema_errors(
+            &schema["additionalProperties"],
+            item,
+            &item_path,
+          )),
+          (None, _) => {}
+        }
+      }
+    }
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+      for (index, item) in array.iter().enumerate() {
+        errors.extend(schema_errors(items, item, &format!("{}[{}]", path, index)));
+      }
+    }
+    errors
+  }
+
+  #[test]
+  fn test_json_export_validates_against_embedded_schema() {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let node = |name: &str, layer| {
+      crate::graph::hex_node::HexNode::new(
+        id(name),
+        layer,
+        crate::graph::role::Role::Entity,
+        name,
+        "app::sales",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        id(from),
+        id(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_app_version("1.0.0")
+      .with_node(node("Order", crate::graph::layer::Layer::Domain))
+      .with_node(node("OrderStore", crate::graph::layer::Layer::Adapter))
+      .with_node(node("Unused", crate::graph::layer::Layer::Port))
+      .with_edge(edge("Order", "OrderStore"))
+      .with_edge(edge("OrderStore", "Order"))
+      .build();
+    let visual =
+      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph_with_options(
+        &graph,
+        crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+        &crate::graph::visualization::domain::export_options::ExportOptions::new()
+          .annotate(true)
+          .cluster_by_context(crate::graph::bounded_context::BoundedContexts::new()),
+      );
+
+    let schema: serde_json::Value = serde_json::from_str(JSON_SCHEMA).unwrap();
+    let json: serde_json::Value =
+      serde_json::from_str(&JsonExporter::new().export(&visual).unwrap()).unwrap();
+
+    assert_eq!(schema["$id"], JSON_SCHEMA_ID);
+    assert_eq!(json["$schema"], JSON_SCHEMA_ID);
+    assert_eq!(json["schema_version"], JSON_SCHEMA_VERSION);
+    assert!(json["nodes"][0].get("annotations").is_some());
+    assert_eq!(json["nodes"][0]["context"], "sales");
+    assert_eq!(schema_errors(&schema, &json, "$"), Vec::<String>::new());
+
+    let mut broken = json.clone();
+    broken["links"][0]["value"] = serde_json::Value::from("1");
+    broken["nodes"][0].as_object_mut().unwrap().remove("group");
+    assert_eq!(schema_errors(&schema, &broken, "$").len(), 2);
+  }
 }
@@ -1,8 +1,17 @@
 //! Mermaid format exporter adapter.
 //!
-//! Exports graphs to Mermaid diagram format.
+//! Exports graphs to Mermaid diagram format. In annotated exports, cycle
+//! nodes get a red stroke, orphans a dashed one, and layer violations a
+//! dashed red link. Clustered exports wrap each bounded context's nodes in
+//! a `subgraph`.
 //!
 //! Revision History
+//! - 2026-10-19T10:15:00Z @AI: Opt into build info in the provenance test.
+//! - 2026-10-19T08:35:00Z @AI: Draw bounded context subgraphs.
+//! - 2026-10-19T07:15:00Z @AI: Style annotated cycles, orphans, and violations.
+//! - 2026-10-19T05:15:00Z @AI: Write build info as a comment.
+//! - 2026-10-18T23:30:00Z @AI: Render weighted edges as thick links.
+//! - 2026-10-18T21:15:00Z @AI: Stream output via write_to.
 //! - 2025-10-10T17:33:00Z @AI: Fix node ID sanitization to remove NodeId() wrapper for valid Mermaid syntax.
 //! - 2025-10-02T16:00:00Z @AI: Initial Mermaid exporter implementation.
 
@@ -36,32 +45,68 @@ impl MermaidExporter {
 }
 
 impl crate::graph::visualization::ports::format_exporter::FormatExporter for MermaidExporter {
-  fn export(
+  fn write_to(
     &self,
     visual_graph: &crate::graph::visualization::domain::visual_graph::VisualGraph,
-  ) -> crate::result::hex_result::HexResult<String> {
-    let mut output = format!("graph {}\n", self.direction);
+    w: &mut dyn std::io::Write,
+  ) -> std::io::Result<()> {
+    writeln!(w, "graph {}", self.direction)?;
+    if let Some(build_info) = &visual_graph.build_info {
+      writeln!(w, "  %% {}", build_info)?;
+    }
 
     for node in &visual_graph.nodes {
       let node_id = Self::sanitize_node_id(&node.id);
-      output.push_str(&format!(
-        "  {}[\"{}\\n({})\"]\n",
-        node_id, node.label, node.role
-      ));
+      writeln!(w, "  {}[\"{}\\n({})\"]", node_id, node.label, node.role)?;
+    }
+
+    for (context, ids) in visual_graph.clusters() {
+      writeln!(w, "  subgraph {}", context)?;
+      for id in ids {
+        writeln!(w, "    {}", Self::sanitize_node_id(id))?;
+      }
+      writeln!(w, "  end")?;
     }
 
-    output.push_str("\n");
+    writeln!(w)?;
 
     for edge in &visual_graph.edges {
       let source_id = Self::sanitize_node_id(&edge.source);
       let target_id = Self::sanitize_node_id(&edge.target);
-      output.push_str(&format!(
-        "  {} -->|{}| {}\n",
-        source_id, edge.relationship, target_id
-      ));
+      let arrow = if edge.weight > 1 { "==>" } else { "-->" };
+      writeln!(
+        w,
+        "  {} {}|{}| {}",
+        source_id, arrow, edge.relationship, target_id
+      )?;
+    }
+
+    let in_class =
+      |flag: fn(&crate::graph::visualization::domain::visual_node::VisualNode) -> bool| {
+        visual_graph
+          .nodes
+          .iter()
+          .filter(|node| flag(node))
+          .map(|node| Self::sanitize_node_id(&node.id))
+          .collect::<Vec<_>>()
+      };
+    let cycle_nodes = in_class(|node| node.in_cycle);
+    if !cycle_nodes.is_empty() {
+      writeln!(w, "  classDef cycle stroke:red,stroke-width:2px")?;
+      writeln!(w, "  class {} cycle", cycle_nodes.join(","))?;
+    }
+    let orphans = in_class(|node| node.orphan);
+    if !orphans.is_empty() {
+      writeln!(w, "  classDef orphan stroke-dasharray:4")?;
+      writeln!(w, "  class {} orphan", orphans.join(","))?;
+    }
+    for (index, edge) in visual_graph.edges.iter().enumerate() {
+      if edge.violation {
+        writeln!(w, "  linkStyle {} stroke:red,stroke-dasharray:5", index)?;
+      }
     }
 
-    Ok(output)
+    Ok(())
   }
 
   fn format_name(&self) -> &str {
@@ -103,4 +148,32 @@ mod tests {
     assert!(mermaid.contains("graph TD"));
     assert!(mermaid.contains("Test"));
   }
+
+  #[test]
+  fn test_mermaid_export_comments_build_info() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_app_version("2.3.1")
+      .build();
+    let mut visual =
+      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph_with_options(
+        &graph,
+        crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+        &crate::graph::visualization::domain::export_options::ExportOptions::new()
+          .include_build_info(true),
+      );
+
+    let mermaid = MermaidExporter::new().export(&visual).unwrap();
+    assert_eq!(
+      mermaid.lines().nth(1).unwrap(),
+      format!("  %% {}", graph.metadata().build_info)
+    );
+
+    visual.build_info = None;
+    assert!(
+      !MermaidExporter::new()
+        .export(&visual)
+        .unwrap()
+        .contains("%%")
+    );
+  }
 }
@@ -3,14 +3,24 @@
 //! Concrete implementations of format exporters.
 //!
 //! Revision History
+//! - 2026-10-19T08:15:00Z @AI: Re-export the JSON export schema constants.
+//! - 2026-10-19T03:15:00Z @AI: Add Arrow IPC exporter behind the arrow feature.
+//! - 2026-10-18T18:30:00Z @AI: Add CSV edge list exporter.
 //! - 2025-10-02T16:00:00Z @AI: Initial adapters module.
 
+#[cfg(feature = "arrow")]
+pub mod arrow_exporter;
+pub mod csv_exporter;
 pub mod dot_exporter;
 pub mod mermaid_exporter;
 
 #[cfg(feature = "visualization")]
 pub mod json_exporter;
 
+#[cfg(feature = "arrow")]
+pub use arrow_exporter::{ArrowExporter, ArrowIpcExport};
+pub use csv_exporter::CsvExporter;
+
 #[cfg(not(feature = "visualization"))]
 pub use dot_exporter::DotExporter;
 
@@ -18,4 +28,4 @@ pub use dot_exporter::DotExporter;
 pub use mermaid_exporter::MermaidExporter;
 
 #[cfg(feature = "visualization")]
-pub use json_exporter::JsonExporter;
+pub use json_exporter::{JSON_SCHEMA, JSON_SCHEMA_ID, JSON_SCHEMA_VERSION, JsonExporter};
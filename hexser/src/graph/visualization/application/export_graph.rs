@@ -3,11 +3,16 @@
 //! Orchestrates graph export using format exporters.
 //!
 //! Revision History
+//! - 2026-10-19T08:35:00Z @AI: Test bounded context clusters.
+//! - 2026-10-19T07:15:00Z @AI: Test annotated exports.
+//! - 2026-10-18T21:15:00Z @AI: Add execute_to for streaming exports to a writer.
+//! - 2026-10-18T11:30:00Z @AI: Apply ExportOptions filtering before exporting.
 //! - 2025-10-02T16:00:00Z @AI: Initial ExportGraph use case.
 
 /// Export graph use case
 pub struct ExportGraph<'a> {
   exporter: &'a dyn crate::graph::visualization::ports::format_exporter::FormatExporter,
+  options: crate::graph::visualization::domain::export_options::ExportOptions,
 }
 
 impl<'a> ExportGraph<'a> {
@@ -15,7 +20,19 @@ impl<'a> ExportGraph<'a> {
   pub fn new(
     exporter: &'a dyn crate::graph::visualization::ports::format_exporter::FormatExporter,
   ) -> Self {
-    Self { exporter }
+    Self {
+      exporter,
+      options: crate::graph::visualization::domain::export_options::ExportOptions::default(),
+    }
+  }
+
+  /// Set node exclusion options (builder pattern)
+  pub fn with_options(
+    mut self,
+    options: crate::graph::visualization::domain::export_options::ExportOptions,
+  ) -> Self {
+    self.options = options;
+    self
   }
 
   /// Execute export
@@ -25,9 +42,29 @@ impl<'a> ExportGraph<'a> {
     style: crate::graph::visualization::domain::visual_style::VisualStyle,
   ) -> crate::result::hex_result::HexResult<String> {
     let visual_graph =
-      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph(graph, style);
+      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph_with_options(
+        graph,
+        style,
+        &self.options,
+      );
     self.exporter.export(&visual_graph)
   }
+
+  /// Execute export, streaming the output to `w`
+  pub fn execute_to(
+    &self,
+    graph: &crate::graph::hex_graph::HexGraph,
+    style: crate::graph::visualization::domain::visual_style::VisualStyle,
+    w: &mut dyn std::io::Write,
+  ) -> std::io::Result<()> {
+    let visual_graph =
+      crate::graph::visualization::domain::visual_graph::VisualGraph::from_hex_graph_with_options(
+        graph,
+        style,
+        &self.options,
+      );
+    self.exporter.write_to(&visual_graph, w)
+  }
 }
 
 #[cfg(test)]
@@ -55,4 +92,185 @@ mod tests {
 
     assert!(result.is_ok());
   }
+
+  #[cfg(feature = "visualization")]
+  #[test]
+  fn test_export_options_hide_node_in_every_format() {
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("OrderService"),
+        crate::graph::layer::Layer::Application,
+        crate::graph::role::Role::Directive,
+        "OrderService",
+        "app::orders",
+      ))
+      .with_node(crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name("ConsoleLogger"),
+        crate::graph::layer::Layer::Infrastructure,
+        crate::graph::role::Role::Adapter,
+        "ConsoleLogger",
+        "app::logging",
+      ))
+      .with_edge(crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name("OrderService"),
+        crate::graph::node_id::NodeId::from_name("ConsoleLogger"),
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .build();
+    let logger_id = crate::graph::node_id::NodeId::from_name("ConsoleLogger").to_string();
+    let options = crate::graph::visualization::domain::export_options::ExportOptions::new()
+      .exclude_module("app::logging");
+
+    let dot = crate::graph::visualization::adapters::dot_exporter::DotExporter::new();
+    let mermaid = crate::graph::visualization::adapters::mermaid_exporter::MermaidExporter::new();
+    let json = crate::graph::visualization::adapters::json_exporter::JsonExporter::new();
+    let exporters: [&dyn crate::graph::visualization::ports::format_exporter::FormatExporter; 3] =
+      [&dot, &mermaid, &json];
+
+    for exporter in exporters {
+      let output = ExportGraph::new(exporter)
+        .with_options(options.clone())
+        .execute(
+          &graph,
+          crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+        )
+        .unwrap();
+
+      assert!(
+        output.contains("OrderService"),
+        "{}",
+        exporter.format_name()
+      );
+      assert!(
+        !output.contains("ConsoleLogger"),
+        "{}",
+        exporter.format_name()
+      );
+      assert!(!output.contains(&logger_id), "{}", exporter.format_name());
+    }
+  }
+
+  #[cfg(all(feature = "visualization", feature = "analysis"))]
+  #[test]
+  fn test_annotate_highlights_violations_cycles_and_orphans() {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let node = |name: &str, layer| {
+      crate::graph::hex_node::HexNode::new(
+        id(name),
+        layer,
+        crate::graph::role::Role::Entity,
+        name,
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        id(from),
+        id(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Order", crate::graph::layer::Layer::Domain))
+      .with_node(node("PgStore", crate::graph::layer::Layer::Adapter))
+      .with_node(node("Unused", crate::graph::layer::Layer::Domain))
+      .with_edge(edge("Order", "PgStore"))
+      .with_edge(edge("PgStore", "Order"))
+      .build();
+    let exporter = crate::graph::visualization::adapters::dot_exporter::DotExporter::new();
+    let export = |annotate| {
+      ExportGraph::new(&exporter)
+        .with_options(
+          crate::graph::visualization::domain::export_options::ExportOptions::new()
+            .annotate(annotate),
+        )
+        .execute(
+          &graph,
+          crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+        )
+        .unwrap()
+    };
+
+    let dot = export(true);
+    assert!(dot.contains(&format!(
+      "\"{}\" -> \"{}\" [label=\"Depends\", color=red, style=dashed];",
+      id("Order"),
+      id("PgStore")
+    )));
+    assert!(dot.contains(&format!(
+      "\"{}\" -> \"{}\" [label=\"Depends\"];",
+      id("PgStore"),
+      id("Order")
+    )));
+    assert_eq!(dot.matches("color=red, penwidth=2").count(), 2);
+    assert_eq!(dot.matches("xlabel=\"orphan\"").count(), 1);
+
+    let plain = export(false);
+    assert!(!plain.contains("red") && !plain.contains("orphan"));
+
+    let mermaid = crate::graph::visualization::adapters::mermaid_exporter::MermaidExporter::new();
+    let chart = ExportGraph::new(&mermaid)
+      .with_options(
+        crate::graph::visualization::domain::export_options::ExportOptions::new().annotate(true),
+      )
+      .execute(
+        &graph,
+        crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+      )
+      .unwrap();
+    assert!(chart.contains("  classDef cycle stroke:red,stroke-width:2px"));
+    assert!(chart.contains("  linkStyle "));
+  }
+
+  #[test]
+  fn test_cluster_by_context_groups_nodes_per_context() {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let node = |name: &str, module_path: &str| {
+      crate::graph::hex_node::HexNode::new(
+        id(name),
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+        name,
+        module_path,
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node("Invoice", "shop::billing::invoice"))
+      .with_node(node("Parcel", "shop::shipping"))
+      .build();
+    let export =
+      |exporter: &dyn crate::graph::visualization::ports::format_exporter::FormatExporter,
+       options| {
+        ExportGraph::new(exporter)
+          .with_options(options)
+          .execute(
+            &graph,
+            crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+          )
+          .unwrap()
+      };
+    let clustered = crate::graph::visualization::domain::export_options::ExportOptions::new()
+      .cluster_by_context(crate::graph::bounded_context::BoundedContexts::new());
+
+    let dot = export(
+      &crate::graph::visualization::adapters::dot_exporter::DotExporter::new(),
+      clustered.clone(),
+    );
+    assert!(dot.contains(&format!(
+      "  subgraph \"cluster_billing\" {{\n    label=\"billing\";\n    \"{}\";\n  }}",
+      id("Invoice")
+    )));
+    assert!(dot.contains("subgraph \"cluster_shipping\""));
+
+    let mermaid = crate::graph::visualization::adapters::mermaid_exporter::MermaidExporter::new();
+    let chart = export(&mermaid, clustered);
+    assert!(chart.contains("  subgraph shipping\n"));
+    assert!(chart.contains("  end\n"));
+
+    let plain = export(
+      &mermaid,
+      crate::graph::visualization::domain::export_options::ExportOptions::new(),
+    );
+    assert!(!plain.contains("subgraph"));
+  }
 }
@@ -0,0 +1,147 @@
+//! Export options for filtering graphs before rendering.
+//!
+//! ExportOptions describes which nodes and edges to hide from an export
+//! and whether to annotate problems inline. All of it is applied once while
+//! building the VisualGraph, so every exporter (DOT, Mermaid, JSON)
+//! receives the same reduced and annotated node and edge set.
+//!
+//! Revision History
+//! - 2026-10-19T10:15:00Z @AI: Add include_build_info; provenance is now opt-in.
+//! - 2026-10-19T08:35:00Z @AI: Add cluster_by_context for bounded context clusters.
+//! - 2026-10-19T07:45:00Z @AI: Add cross_layer_only for boundary-only views.
+//! - 2026-10-19T07:15:00Z @AI: Add annotate flag for inline problem highlighting.
+//! - 2026-10-18T11:30:00Z @AI: Initial ExportOptions with role and module exclusions.
+
+/// Node exclusion rules applied before exporting a graph
+///
+/// A node is excluded when its role is listed in `exclude_roles` or its
+/// module path equals, or is nested under, an entry in `exclude_modules`.
+/// Edges touching an excluded node are dropped as well.
+///
+/// With `cross_layer_only` set, edges whose endpoints are in the same layer
+/// are dropped too, leaving only the seams between layers. Nodes are kept
+/// either way, so the node filters above decide what is shown.
+///
+/// With `annotate` set, nodes on a dependency cycle and orphaned nodes are
+/// flagged, and so are layer-violating edges when the `analysis` feature is
+/// enabled; exporters style them so problems show in the diagram itself.
+/// Annotation is off by default.
+///
+/// With `contexts` set, each node is tagged with its bounded context and
+/// exporters draw one cluster per context.
+///
+/// With `include_build_info` set, exporters write the graph's BuildInfo,
+/// including the time the graph was constructed, so repeated exports of the
+/// same graph differ. It is off by default to keep output reproducible for
+/// snapshots and diffs.
+#[derive(Clone, Debug, Default)]
+pub struct ExportOptions {
+  pub exclude_roles: Vec<crate::graph::role::Role>,
+  pub exclude_modules: Vec<String>,
+  pub annotate: bool,
+  pub cross_layer_only: bool,
+  pub contexts: Option<crate::graph::bounded_context::BoundedContexts>,
+  pub include_build_info: bool,
+}
+
+impl ExportOptions {
+  /// Create options that keep every node
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Exclude nodes with the given role (builder pattern)
+  pub fn exclude_role(mut self, role: crate::graph::role::Role) -> Self {
+    self.exclude_roles.push(role);
+    self
+  }
+
+  /// Exclude nodes in the given module or its submodules (builder pattern)
+  pub fn exclude_module(mut self, module_path: impl Into<String>) -> Self {
+    self.exclude_modules.push(module_path.into());
+    self
+  }
+
+  /// Highlight cycles, orphans, and layer violations (builder pattern)
+  pub fn annotate(mut self, annotate: bool) -> Self {
+    self.annotate = annotate;
+    self
+  }
+
+  /// Keep only edges between different layers (builder pattern)
+  pub fn cross_layer_only(mut self, cross_layer_only: bool) -> Self {
+    self.cross_layer_only = cross_layer_only;
+    self
+  }
+
+  /// Write the graph's build info into the export (builder pattern)
+  pub fn include_build_info(mut self, include_build_info: bool) -> Self {
+    self.include_build_info = include_build_info;
+    self
+  }
+
+  /// Cluster nodes by bounded context under `rules` (builder pattern)
+  pub fn cluster_by_context(
+    mut self,
+    rules: crate::graph::bounded_context::BoundedContexts,
+  ) -> Self {
+    self.contexts = Some(rules);
+    self
+  }
+
+  /// Check whether an edge between nodes in these layers is hidden
+  pub fn excludes_edge_between(
+    &self,
+    source: crate::graph::layer::Layer,
+    target: crate::graph::layer::Layer,
+  ) -> bool {
+    self.cross_layer_only && source == target
+  }
+
+  /// Check whether a node is hidden by these options
+  pub fn excludes(&self, node: &crate::graph::hex_node::HexNode) -> bool {
+    self.exclude_roles.contains(&node.role)
+      || self.exclude_modules.iter().any(|module| {
+        node.module_path == *module
+          || node
+            .module_path
+            .strip_prefix(module.as_str())
+            .is_some_and(|rest| rest.starts_with("::"))
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn node(module_path: &str, role: crate::graph::role::Role) -> crate::graph::hex_node::HexNode {
+    crate::graph::hex_node::HexNode::new(
+      crate::graph::node_id::NodeId::from_name(module_path),
+      crate::graph::layer::Layer::Infrastructure,
+      role,
+      "Node",
+      module_path,
+    )
+  }
+
+  #[test]
+  fn test_excludes_by_role() {
+    let options = ExportOptions::new().exclude_role(crate::graph::role::Role::Adapter);
+
+    assert!(options.excludes(&node("app", crate::graph::role::Role::Adapter)));
+    assert!(!options.excludes(&node("app", crate::graph::role::Role::Entity)));
+  }
+
+  #[test]
+  fn test_excludes_module_and_submodules_only() {
+    let options = ExportOptions::new().exclude_module("app::logging");
+
+    assert!(options.excludes(&node("app::logging", crate::graph::role::Role::Adapter)));
+    assert!(options.excludes(&node(
+      "app::logging::file",
+      crate::graph::role::Role::Adapter
+    )));
+    assert!(!options.excludes(&node("app::logging_ext", crate::graph::role::Role::Adapter)));
+  }
+}
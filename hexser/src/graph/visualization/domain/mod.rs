@@ -3,8 +3,10 @@
 //! Core concepts independent of output format.
 //!
 //! Revision History
+//! - 2026-10-18T11:30:00Z @AI: Add export_options for pre-render node filtering.
 //! - 2025-10-02T16:00:00Z @AI: Initial domain module for visualization.
 
+pub mod export_options;
 pub mod visual_edge;
 pub mod visual_graph;
 pub mod visual_node;
@@ -3,6 +3,8 @@
 //! Represents an edge in the visual graph.
 //!
 //! Revision History
+//! - 2026-10-19T07:15:00Z @AI: Add layer violation annotation flag.
+//! - 2026-10-18T23:30:00Z @AI: Carry edge weight for weighted rendering.
 //! - 2025-10-02T16:00:00Z @AI: Initial VisualEdge implementation.
 
 /// Visual edge
@@ -11,6 +13,10 @@ pub struct VisualEdge {
   pub source: String,
   pub target: String,
   pub relationship: String,
+  /// Dependency strength copied from the HexEdge
+  pub weight: u32,
+  /// Set by annotated exports when the edge breaks the layer rules
+  pub violation: bool,
 }
 
 impl VisualEdge {
@@ -20,6 +26,8 @@ impl VisualEdge {
       source: edge.source.to_string(),
       target: edge.target.to_string(),
       relationship: format!("{:?}", edge.relationship),
+      weight: edge.weight,
+      violation: false,
     }
   }
 }
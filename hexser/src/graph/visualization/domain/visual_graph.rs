@@ -4,6 +4,12 @@
 //! independent of output format.
 //!
 //! Revision History
+//! - 2026-10-19T10:15:00Z @AI: Carry build info only with ExportOptions::include_build_info.
+//! - 2026-10-19T08:35:00Z @AI: Tag nodes with their bounded context when clustering.
+//! - 2026-10-19T07:45:00Z @AI: Drop intra-layer edges with ExportOptions::cross_layer_only.
+//! - 2026-10-19T07:15:00Z @AI: Apply ExportOptions::annotate.
+//! - 2026-10-19T05:15:00Z @AI: Carry the source graph's build info for exporters.
+//! - 2026-10-18T11:30:00Z @AI: Add from_hex_graph_with_options filtering pre-pass.
 //! - 2025-10-02T16:00:00Z @AI: Initial VisualGraph implementation.
 
 /// Visual representation of architecture graph
@@ -12,6 +18,9 @@ pub struct VisualGraph {
   pub nodes: Vec<crate::graph::visualization::domain::visual_node::VisualNode>,
   pub edges: Vec<crate::graph::visualization::domain::visual_edge::VisualEdge>,
   pub style: crate::graph::visualization::domain::visual_style::VisualStyle,
+  /// Provenance written into the export's metadata; `None` omits it.
+  /// Set only with `ExportOptions::include_build_info`.
+  pub build_info: Option<crate::graph::metadata::BuildInfo>,
 }
 
 impl VisualGraph {
@@ -20,25 +29,139 @@ impl VisualGraph {
     graph: &crate::graph::hex_graph::HexGraph,
     style: crate::graph::visualization::domain::visual_style::VisualStyle,
   ) -> Self {
+    Self::from_hex_graph_with_options(
+      graph,
+      style,
+      &crate::graph::visualization::domain::export_options::ExportOptions::default(),
+    )
+  }
+
+  /// Create from HexGraph, dropping excluded nodes and edges
+  pub fn from_hex_graph_with_options(
+    graph: &crate::graph::hex_graph::HexGraph,
+    style: crate::graph::visualization::domain::visual_style::VisualStyle,
+    options: &crate::graph::visualization::domain::export_options::ExportOptions,
+  ) -> Self {
+    let kept: std::collections::HashMap<crate::graph::node_id::NodeId, crate::graph::layer::Layer> =
+      graph
+        .nodes()
+        .filter(|node| !options.excludes(node))
+        .map(|node| (node.id, node.layer))
+        .collect();
+
+    let problems = if options.annotate {
+      Problems::find(graph)
+    } else {
+      Problems::default()
+    };
+
     let nodes = graph
       .nodes()
+      .filter(|node| kept.contains_key(&node.id))
       .map(|node| {
-        crate::graph::visualization::domain::visual_node::VisualNode::from_hex_node(node, &style)
+        let mut visual =
+          crate::graph::visualization::domain::visual_node::VisualNode::from_hex_node(node, &style);
+        visual.in_cycle = problems.cycle_nodes.contains(&node.id);
+        visual.orphan = problems.orphans.contains(&node.id);
+        visual.context = options
+          .contexts
+          .as_ref()
+          .and_then(|rules| rules.context_of(&node.module_path));
+        visual
       })
       .collect();
 
     let edges = graph
       .edges()
-      .into_iter()
-      .map(|edge| crate::graph::visualization::domain::visual_edge::VisualEdge::from_hex_edge(edge))
+      .iter()
+      .filter(
+        |edge| match (kept.get(&edge.source), kept.get(&edge.target)) {
+          (Some(source), Some(target)) => !options.excludes_edge_between(*source, *target),
+          _ => false,
+        },
+      )
+      .map(|edge| {
+        let mut visual =
+          crate::graph::visualization::domain::visual_edge::VisualEdge::from_hex_edge(edge);
+        visual.violation = problems.violations.contains(&(edge.source, edge.target));
+        visual
+      })
       .collect();
 
     Self {
       nodes,
       edges,
       style,
+      build_info: options
+        .include_build_info
+        .then(|| graph.metadata().build_info.clone()),
     }
   }
+
+  /// Node ids per bounded context, for exporters drawing clusters
+  ///
+  /// Empty unless the graph was built with
+  /// `ExportOptions::cluster_by_context`.
+  pub fn clusters(&self) -> std::collections::BTreeMap<&str, Vec<&str>> {
+    let mut clusters: std::collections::BTreeMap<&str, Vec<&str>> =
+      std::collections::BTreeMap::new();
+    for node in &self.nodes {
+      if let Some(context) = &node.context {
+        clusters.entry(context).or_default().push(&node.id);
+      }
+    }
+    clusters
+  }
+}
+
+/// Nodes and edges flagged by an annotated export
+#[derive(Default)]
+struct Problems {
+  cycle_nodes: std::collections::HashSet<crate::graph::node_id::NodeId>,
+  orphans: std::collections::HashSet<crate::graph::node_id::NodeId>,
+  violations:
+    std::collections::HashSet<(crate::graph::node_id::NodeId, crate::graph::node_id::NodeId)>,
+}
+
+impl Problems {
+  /// Analyze the full graph, before any export filtering
+  fn find(graph: &crate::graph::hex_graph::HexGraph) -> Self {
+    Self {
+      cycle_nodes: graph
+        .analysis()
+        .detect_cycles()
+        .into_iter()
+        .flatten()
+        .collect(),
+      orphans: graph
+        .nodes()
+        .filter(|n| graph.edges_from(&n.id).is_empty() && graph.edges_to(&n.id).is_empty())
+        .map(|n| n.id)
+        .collect(),
+      violations: Self::layer_violations(graph),
+    }
+  }
+
+  #[cfg(feature = "analysis")]
+  fn layer_violations(
+    graph: &crate::graph::hex_graph::HexGraph,
+  ) -> std::collections::HashSet<(crate::graph::node_id::NodeId, crate::graph::node_id::NodeId)> {
+    graph
+      .validation()
+      .validate_layer_dependencies()
+      .err()
+      .unwrap_or_default()
+      .into_iter()
+      .map(|v| (v.from, v.to))
+      .collect()
+  }
+
+  #[cfg(not(feature = "analysis"))]
+  fn layer_violations(
+    _graph: &crate::graph::hex_graph::HexGraph,
+  ) -> std::collections::HashSet<(crate::graph::node_id::NodeId, crate::graph::node_id::NodeId)> {
+    std::collections::HashSet::new()
+  }
 }
 
 #[cfg(test)]
@@ -64,4 +187,98 @@ mod tests {
 
     assert_eq!(visual.nodes.len(), 1);
   }
+
+  #[test]
+  fn test_build_info_is_opt_in() {
+    let graph = crate::graph::builder::GraphBuilder::new().build();
+    let style = crate::graph::visualization::domain::visual_style::VisualStyle::default();
+
+    assert!(
+      VisualGraph::from_hex_graph(&graph, style.clone())
+        .build_info
+        .is_none()
+    );
+    let with_info = VisualGraph::from_hex_graph_with_options(
+      &graph,
+      style,
+      &crate::graph::visualization::domain::export_options::ExportOptions::new()
+        .include_build_info(true),
+    );
+    assert_eq!(
+      with_info.build_info,
+      Some(graph.metadata().build_info.clone())
+    );
+  }
+
+  #[test]
+  fn test_cross_layer_only_keeps_only_boundary_edges() {
+    let id = crate::graph::node_id::NodeId::from_name;
+    let node = |name: &str, layer, module_path: &str| {
+      crate::graph::hex_node::HexNode::new(
+        id(name),
+        layer,
+        crate::graph::role::Role::Entity,
+        name,
+        module_path,
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      crate::graph::hex_edge::HexEdge::new(
+        id(from),
+        id(to),
+        crate::graph::relationship::Relationship::Depends,
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "Order",
+        crate::graph::layer::Layer::Domain,
+        "app::domain",
+      ))
+      .with_node(node(
+        "Money",
+        crate::graph::layer::Layer::Domain,
+        "app::domain",
+      ))
+      .with_node(node(
+        "OrderRepo",
+        crate::graph::layer::Layer::Port,
+        "app::ports",
+      ))
+      .with_node(node(
+        "PgOrders",
+        crate::graph::layer::Layer::Adapter,
+        "app::pg",
+      ))
+      .with_edge(edge("Order", "Money"))
+      .with_edge(edge("OrderRepo", "Order"))
+      .with_edge(edge("PgOrders", "OrderRepo"))
+      .build();
+    let options = crate::graph::visualization::domain::export_options::ExportOptions::new()
+      .cross_layer_only(true);
+
+    let visual = VisualGraph::from_hex_graph_with_options(
+      &graph,
+      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+      &options,
+    );
+    let edges: Vec<(String, String)> = visual
+      .edges
+      .iter()
+      .map(|e| (e.source.clone(), e.target.clone()))
+      .collect();
+
+    assert_eq!(visual.nodes.len(), 4);
+    assert_eq!(edges.len(), 2);
+    assert!(edges.contains(&(id("OrderRepo").to_string(), id("Order").to_string())));
+    assert!(!edges.contains(&(id("Order").to_string(), id("Money").to_string())));
+
+    let bounded = VisualGraph::from_hex_graph_with_options(
+      &graph,
+      crate::graph::visualization::domain::visual_style::VisualStyle::default(),
+      &options.exclude_module("app::pg"),
+    );
+    assert_eq!(bounded.nodes.len(), 3);
+    assert_eq!(bounded.edges.len(), 1);
+  }
 }
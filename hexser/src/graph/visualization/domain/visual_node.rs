@@ -3,6 +3,11 @@
 //! Represents a node in the visual graph with styling information.
 //!
 //! Revision History
+//! - 2026-10-19T08:35:00Z @AI: Carry the bounded context for clustered exports.
+//! - 2026-10-19T07:15:00Z @AI: Add cycle and orphan annotation flags.
+//! - 2026-10-19T01:45:00Z @AI: Take node shape from the style's role mapping.
+//! - 2026-10-18T20:00:00Z @AI: Carry node tags for exporter markers.
+//! - 2026-10-18T18:30:00Z @AI: Carry module path for tabular exports.
 //! - 2025-10-02T16:00:00Z @AI: Initial VisualNode implementation.
 
 /// Visual node with styling
@@ -12,8 +17,16 @@ pub struct VisualNode {
   pub label: String,
   pub layer: String,
   pub role: String,
+  pub module: String,
+  pub tags: Vec<String>,
   pub color: String,
   pub shape: String,
+  /// Set by annotated exports when the node lies on a dependency cycle
+  pub in_cycle: bool,
+  /// Set by annotated exports when the node has no edges at all
+  pub orphan: bool,
+  /// Set by clustered exports to the node's bounded context
+  pub context: Option<String>,
 }
 
 impl VisualNode {
@@ -23,15 +36,20 @@ impl VisualNode {
     style: &crate::graph::visualization::domain::visual_style::VisualStyle,
   ) -> Self {
     let color = style.color_for_layer(&node.layer);
-    let shape = String::from("box");
+    let shape = style.shape_for_role(&node.role);
 
     Self {
       id: node.id.to_string(),
       label: node.type_name.to_string(),
       layer: format!("{:?}", node.layer),
       role: format!("{:?}", node.role),
+      module: node.module_path.to_string(),
+      tags: node.tags.clone(),
       color,
       shape,
+      in_cycle: false,
+      orphan: false,
+      context: None,
     }
   }
 }
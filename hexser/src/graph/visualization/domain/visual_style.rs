@@ -1,14 +1,20 @@
 //! Visual styling configuration.
 //!
-//! Defines colors, shapes, and other visual properties.
+//! Defines colors, shapes, and other visual properties. Layers pick the
+//! fill color and roles pick the node shape, so diagrams read like
+//! component diagrams: entities as boxes, ports as ellipses, adapters as
+//! components. Shapes are Graphviz shape names.
 //!
 //! Revision History
+//! - 2026-10-19T01:45:00Z @AI: Add per-role node shapes with overridable defaults.
 //! - 2025-10-02T16:00:00Z @AI: Initial VisualStyle implementation.
 
 /// Visual styling configuration
 #[derive(Clone, Debug)]
 pub struct VisualStyle {
   pub color_scheme: ColorScheme,
+  /// Shape overrides by role; roles not present use `default_shape_for_role`
+  pub role_shapes: std::collections::HashMap<crate::graph::role::Role, String>,
 }
 
 /// Color scheme for layers
@@ -29,12 +35,72 @@ impl VisualStyle {
       crate::graph::layer::Layer::Unknown => String::from("red"),
     }
   }
+
+  /// Override the shape used for a role (builder pattern)
+  pub fn with_role_shape(mut self, role: crate::graph::role::Role, shape: &str) -> Self {
+    self.role_shapes.insert(role, String::from(shape));
+    self
+  }
+
+  /// Get shape for role, preferring overrides in `role_shapes`
+  pub fn shape_for_role(&self, role: &crate::graph::role::Role) -> String {
+    self
+      .role_shapes
+      .get(role)
+      .cloned()
+      .unwrap_or_else(|| String::from(default_shape_for_role(role)))
+  }
+}
+
+/// Built-in shape for each role
+pub fn default_shape_for_role(role: &crate::graph::role::Role) -> &'static str {
+  match role {
+    crate::graph::role::Role::Entity => "box",
+    crate::graph::role::Role::Aggregate => "box3d",
+    crate::graph::role::Role::ValueObject => "note",
+    crate::graph::role::Role::DomainEvent => "cds",
+    crate::graph::role::Role::DomainService => "octagon",
+    crate::graph::role::Role::InputPort
+    | crate::graph::role::Role::OutputPort
+    | crate::graph::role::Role::Repository => "ellipse",
+    crate::graph::role::Role::Adapter | crate::graph::role::Role::Mapper => "component",
+    crate::graph::role::Role::UseCase
+    | crate::graph::role::Role::DirectiveHandler
+    | crate::graph::role::Role::QueryHandler => "hexagon",
+    crate::graph::role::Role::Directive | crate::graph::role::Role::Query => "parallelogram",
+    crate::graph::role::Role::Config => "folder",
+    crate::graph::role::Role::Unknown => "box",
+  }
 }
 
 impl Default for VisualStyle {
   fn default() -> Self {
     Self {
       color_scheme: ColorScheme::Default,
+      role_shapes: std::collections::HashMap::new(),
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_shape_for_role_defaults_and_overrides() {
+    let style = VisualStyle::default().with_role_shape(crate::graph::role::Role::Adapter, "tab");
+
+    assert_eq!(
+      style.shape_for_role(&crate::graph::role::Role::Entity),
+      "box"
+    );
+    assert_eq!(
+      style.shape_for_role(&crate::graph::role::Role::Repository),
+      "ellipse"
+    );
+    assert_eq!(
+      style.shape_for_role(&crate::graph::role::Role::Adapter),
+      "tab"
+    );
+  }
+}
@@ -3,15 +3,45 @@
 //! Interface for exporting visual graphs to different formats.
 //!
 //! Revision History
+//! - 2026-10-18T21:15:00Z @AI: Add write_to for streaming exports; export now defaults to it.
 //! - 2025-10-02T16:00:00Z @AI: Initial FormatExporter port.
 
 /// Port trait for format exporters
+///
+/// Implementors write their format with `write_to`, so large graphs can be
+/// streamed straight to a file or socket. `export` is provided on top of it
+/// and buffers the output into a `String`.
 pub trait FormatExporter {
+  /// Write visual graph to `w` without buffering the whole output
+  fn write_to(
+    &self,
+    visual_graph: &crate::graph::visualization::domain::visual_graph::VisualGraph,
+    w: &mut dyn std::io::Write,
+  ) -> std::io::Result<()>;
+
   /// Export visual graph to string
   fn export(
     &self,
     visual_graph: &crate::graph::visualization::domain::visual_graph::VisualGraph,
-  ) -> crate::result::hex_result::HexResult<String>;
+  ) -> crate::result::hex_result::HexResult<String> {
+    let mut buffer = Vec::new();
+    self.write_to(visual_graph, &mut buffer).map_err(|e| {
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::io::IO_FAILURE,
+        &format!("{} export failed: {}", self.format_name(), e),
+      )
+    })?;
+    String::from_utf8(buffer).map_err(|e| {
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::MAPPING_FAILURE,
+        &format!(
+          "{} export produced invalid UTF-8: {}",
+          self.format_name(),
+          e
+        ),
+      )
+    })
+  }
 
   /// Get format name
   fn format_name(&self) -> &str;
@@ -0,0 +1,193 @@
+//! Structured JSON log sink for handler invocations.
+//!
+//! InvocationRecord captures one directive or query execution: which
+//! component ran, how long it took, and how it ended. JsonLogSink renders
+//! each record as a single JSON line, to stderr by default, without pulling
+//! in a logging framework. Pair it with LoggingHandler to log every handled
+//! directive and query.
+//!
+//! Revision History
+//! - 2026-10-18T23:00:00Z @AI: Initial InvocationRecord, LogSink, and JsonLogSink.
+
+/// Kind of handler invocation being logged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvocationKind {
+  /// A directive (write) was handled
+  Directive,
+  /// A query (read) was handled
+  Query,
+}
+
+impl InvocationKind {
+  /// Get the lowercase name used in log output
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Directive => "directive",
+      Self::Query => "query",
+    }
+  }
+}
+
+/// One handled directive or query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvocationRecord {
+  /// Completion time in milliseconds since the Unix epoch
+  pub timestamp: u64,
+  /// Type name of the handled directive or query
+  pub component: String,
+  /// Whether a directive or a query was handled
+  pub kind: InvocationKind,
+  /// Wall-clock handling time in milliseconds
+  pub duration_ms: u64,
+  /// Error code when handling failed, `None` on success
+  pub error_code: Option<String>,
+}
+
+impl InvocationRecord {
+  /// Get the outcome label, `ok` or `error`
+  pub fn outcome(&self) -> &'static str {
+    match self.error_code {
+      Some(_) => "error",
+      None => "ok",
+    }
+  }
+
+  /// Render the record as a single-line JSON object
+  pub fn to_json_line(&self) -> String {
+    let mut line = format!(
+      "{{\"timestamp\":{},\"component\":\"{}\",\"kind\":\"{}\",\"duration_ms\":{},\"outcome\":\"{}\"",
+      self.timestamp,
+      escape_json(&self.component),
+      self.kind.as_str(),
+      self.duration_ms,
+      self.outcome()
+    );
+    if let Some(ref code) = self.error_code {
+      line.push_str(&format!(",\"error_code\":\"{}\"", escape_json(code)));
+    }
+    line.push('}');
+    line
+  }
+}
+
+/// Escape a string for inclusion in a JSON string literal
+fn escape_json(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for c in value.chars() {
+    match c {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+      c => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+/// Destination for invocation records
+pub trait LogSink: Send + Sync {
+  /// Record one handler invocation
+  fn record(&self, record: &InvocationRecord);
+}
+
+/// Log sink writing one JSON line per invocation
+///
+/// Write failures are ignored so logging can never fail a handler.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::infrastructure::{InvocationKind, InvocationRecord, JsonLogSink, LogSink};
+///
+/// let sink = JsonLogSink::new(Vec::new());
+/// sink.record(&InvocationRecord {
+///     timestamp: 0,
+///     component: String::from("CreateUser"),
+///     kind: InvocationKind::Directive,
+///     duration_ms: 3,
+///     error_code: None,
+/// });
+///
+/// let output = String::from_utf8(sink.into_inner()).unwrap();
+/// assert!(output.contains("\"outcome\":\"ok\""));
+/// ```
+pub struct JsonLogSink<W> {
+  writer: std::sync::Mutex<W>,
+}
+
+impl JsonLogSink<std::io::Stderr> {
+  /// Create a sink writing to stderr
+  pub fn stderr() -> Self {
+    Self::new(std::io::stderr())
+  }
+}
+
+impl<W: std::io::Write + Send> JsonLogSink<W> {
+  /// Create a sink writing to `writer`
+  pub fn new(writer: W) -> Self {
+    Self {
+      writer: std::sync::Mutex::new(writer),
+    }
+  }
+
+  /// Unwrap the underlying writer
+  pub fn into_inner(self) -> W {
+    self
+      .writer
+      .into_inner()
+      .unwrap_or_else(std::sync::PoisonError::into_inner)
+  }
+}
+
+impl<W: std::io::Write + Send> LogSink for JsonLogSink<W> {
+  fn record(&self, record: &InvocationRecord) {
+    let mut writer = self
+      .writer
+      .lock()
+      .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let _ = writeln!(writer, "{}", record.to_json_line());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn record(error_code: Option<&str>) -> InvocationRecord {
+    InvocationRecord {
+      timestamp: 1_700_000_000_000,
+      component: String::from("app::CreateUser"),
+      kind: InvocationKind::Directive,
+      duration_ms: 12,
+      error_code: error_code.map(String::from),
+    }
+  }
+
+  #[test]
+  fn test_success_line_omits_error_code() {
+    assert_eq!(
+      record(None).to_json_line(),
+      "{\"timestamp\":1700000000000,\"component\":\"app::CreateUser\",\"kind\":\"directive\",\"duration_ms\":12,\"outcome\":\"ok\"}"
+    );
+  }
+
+  #[test]
+  fn test_failure_line_includes_escaped_error_code() {
+    let line = record(Some("E_\"X\"")).to_json_line();
+    assert!(line.contains("\"outcome\":\"error\""));
+    assert!(line.ends_with(",\"error_code\":\"E_\\\"X\\\"\"}"));
+  }
+
+  #[test]
+  fn test_sink_writes_one_line_per_record() {
+    let sink = JsonLogSink::new(Vec::new());
+    sink.record(&record(None));
+    sink.record(&record(Some("E_HEX_300")));
+
+    let output = String::from_utf8(sink.into_inner()).unwrap();
+    assert_eq!(output.lines().count(), 2);
+  }
+}
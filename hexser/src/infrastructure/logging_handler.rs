@@ -0,0 +1,224 @@
+//! Handler middleware logging every invocation to a LogSink.
+//!
+//! LoggingHandler wraps a directive or query handler, times each call, and
+//! emits one InvocationRecord with the outcome and error code. It leaves the
+//! handler's result untouched, so it can be layered with other wrappers such
+//! as SafeHandler.
+//!
+//! Revision History
+//! - 2026-10-19T04:05:00Z @AI: Forward preview to the inner handler.
+//! - 2026-10-18T23:00:00Z @AI: Initial LoggingHandler middleware.
+
+/// Handler wrapper recording each invocation in a log sink.
+///
+/// The `component` field is the type name of the directive or query.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::application::{Directive, DirectiveHandler};
+/// use hexser::infrastructure::{JsonLogSink, LoggingHandler};
+/// use hexser::HexResult;
+///
+/// struct Ping;
+///
+/// impl Directive for Ping {
+///     fn validate(&self) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// struct PingHandler;
+///
+/// impl DirectiveHandler<Ping> for PingHandler {
+///     fn handle(&self, _directive: Ping) -> HexResult<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// // Emits {"timestamp":...,"component":"...Ping","kind":"directive",...} to stderr
+/// let handler = LoggingHandler::new(PingHandler, JsonLogSink::stderr());
+/// handler.handle(Ping).unwrap();
+/// ```
+pub struct LoggingHandler<H, S> {
+  inner: H,
+  sink: S,
+}
+
+impl<H, S> LoggingHandler<H, S>
+where
+  S: crate::infrastructure::json_log_sink::LogSink,
+{
+  /// Wrap `inner`, recording invocations in `sink`
+  pub fn new(inner: H, sink: S) -> Self {
+    Self { inner, sink }
+  }
+
+  /// Get the wrapped handler
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+
+  /// Get the log sink
+  pub fn sink(&self) -> &S {
+    &self.sink
+  }
+
+  /// Run `f`, timing it and recording the outcome
+  fn observe<C, R>(
+    &self,
+    kind: crate::infrastructure::json_log_sink::InvocationKind,
+    f: impl FnOnce() -> crate::result::hex_result::HexResult<R>,
+  ) -> crate::result::hex_result::HexResult<R> {
+    let started = std::time::Instant::now();
+    let result = f();
+    let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    let timestamp = std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+      .unwrap_or(0);
+
+    self
+      .sink
+      .record(&crate::infrastructure::json_log_sink::InvocationRecord {
+        timestamp,
+        component: String::from(std::any::type_name::<C>()),
+        kind,
+        duration_ms,
+        error_code: result.as_ref().err().map(|e| String::from(e.code())),
+      });
+    result
+  }
+}
+
+impl<D, H, S> crate::application::directive_handler::DirectiveHandler<D> for LoggingHandler<H, S>
+where
+  D: crate::application::directive::Directive,
+  H: crate::application::directive_handler::DirectiveHandler<D>,
+  S: crate::infrastructure::json_log_sink::LogSink,
+{
+  fn handle(&self, directive: D) -> crate::result::hex_result::HexResult<()> {
+    self.observe::<D, ()>(
+      crate::infrastructure::json_log_sink::InvocationKind::Directive,
+      || self.inner.handle(directive),
+    )
+  }
+
+  fn preview(&self, directive: &D) -> crate::result::hex_result::HexResult<String> {
+    self.inner.preview(directive)
+  }
+}
+
+impl<Q, R, H, S> crate::application::query_handler::QueryHandler<Q, R> for LoggingHandler<H, S>
+where
+  H: crate::application::query_handler::QueryHandler<Q, R>,
+  S: crate::infrastructure::json_log_sink::LogSink,
+{
+  fn handle(&self, query: Q) -> crate::result::hex_result::HexResult<R> {
+    self.observe::<Q, R>(
+      crate::infrastructure::json_log_sink::InvocationKind::Query,
+      || self.inner.handle(query),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::application::{DirectiveHandler, QueryHandler};
+
+  #[derive(Default)]
+  struct MemorySink {
+    records: std::sync::Mutex<Vec<crate::infrastructure::json_log_sink::InvocationRecord>>,
+  }
+
+  impl crate::infrastructure::json_log_sink::LogSink for MemorySink {
+    fn record(&self, record: &crate::infrastructure::json_log_sink::InvocationRecord) {
+      self.records.lock().unwrap().push(record.clone());
+    }
+  }
+
+  struct Rename {
+    name: String,
+  }
+
+  impl crate::application::directive::Directive for Rename {
+    fn validate(&self) -> crate::result::hex_result::HexResult<()> {
+      if self.name.is_empty() {
+        return Err(crate::error::hex_error::Hexserror::validation_field(
+          "Name is required",
+          "name",
+        ));
+      }
+      Ok(())
+    }
+  }
+
+  struct RenameHandler;
+
+  impl DirectiveHandler<Rename> for RenameHandler {
+    fn handle(&self, directive: Rename) -> crate::result::hex_result::HexResult<()> {
+      crate::application::directive::Directive::validate(&directive)
+    }
+  }
+
+  struct CountQuery;
+
+  struct CountHandler;
+
+  impl QueryHandler<CountQuery, u64> for CountHandler {
+    fn handle(&self, _query: CountQuery) -> crate::result::hex_result::HexResult<u64> {
+      Ok(3)
+    }
+  }
+
+  #[test]
+  fn test_directive_outcomes_are_recorded() {
+    let handler = LoggingHandler::new(RenameHandler, MemorySink::default());
+
+    assert!(
+      DirectiveHandler::handle(
+        &handler,
+        Rename {
+          name: String::from("Ada")
+        }
+      )
+      .is_ok()
+    );
+    assert!(
+      DirectiveHandler::handle(
+        &handler,
+        Rename {
+          name: String::new()
+        }
+      )
+      .is_err()
+    );
+
+    let records = handler.sink().records.lock().unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records[0].component.ends_with("Rename"));
+    assert_eq!(
+      records[0].kind,
+      crate::infrastructure::json_log_sink::InvocationKind::Directive
+    );
+    assert_eq!(records[0].outcome(), "ok");
+    assert_eq!(
+      records[1].error_code.as_deref(),
+      Some(crate::error::codes::validation::REQUIRED_FIELD)
+    );
+  }
+
+  #[test]
+  fn test_query_result_passes_through() {
+    let handler = LoggingHandler::new(CountHandler, MemorySink::default());
+
+    assert_eq!(QueryHandler::handle(&handler, CountQuery).unwrap(), 3);
+    let records = handler.sink().records.lock().unwrap();
+    assert_eq!(
+      records[0].kind,
+      crate::infrastructure::json_log_sink::InvocationKind::Query
+    );
+    assert!(records[0].component.ends_with("CountQuery"));
+  }
+}
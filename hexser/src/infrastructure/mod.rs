@@ -6,8 +6,16 @@
 //! infrastructure configuration and setup.
 //!
 //! Revision History
+//! - 2026-10-19T02:45:00Z @AI: Add PaginationLinks helper for web adapters.
+//! - 2026-10-18T23:00:00Z @AI: Add JSON log sink and LoggingHandler middleware.
 //! - 2025-10-01T00:00:00Z @AI: Initial Phase 1 infrastructure module structure.
 
 pub mod config;
+pub mod json_log_sink;
+pub mod logging_handler;
+pub mod pagination_links;
 
 pub use config::Config;
+pub use json_log_sink::{InvocationKind, InvocationRecord, JsonLogSink, LogSink};
+pub use logging_handler::LoggingHandler;
+pub use pagination_links::PaginationLinks;
@@ -0,0 +1,167 @@
+//! Pagination link builder for web adapters.
+//!
+//! PaginationLinks turns the total, offset, and limit of a list response
+//! into first/prev/next/last URLs, omitting prev on the first page and next
+//! on the last. It pairs with `QueryRepository::find_page`, whose `Page`
+//! carries the same three numbers.
+//!
+//! Revision History
+//! - 2026-10-19T02:45:00Z @AI: Initial PaginationLinks helper.
+
+/// Navigation links for one page of an offset-paginated list.
+///
+/// Links append `offset` and `limit` query parameters to the base URL,
+/// using `&` when the base already has a query string.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::infrastructure::PaginationLinks;
+///
+/// let links = PaginationLinks::new("/api/articles", 45, 20, 20);
+/// assert_eq!(links.first, "/api/articles?offset=0&limit=20");
+/// assert_eq!(links.prev.as_deref(), Some("/api/articles?offset=0&limit=20"));
+/// assert_eq!(links.next.as_deref(), Some("/api/articles?offset=40&limit=20"));
+/// assert_eq!(links.last, "/api/articles?offset=40&limit=20");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaginationLinks {
+  /// Link to the page starting at offset 0
+  pub first: String,
+  /// Link to the previous page; absent on the first page
+  pub prev: Option<String>,
+  /// Link to the next page; absent on the last page
+  pub next: Option<String>,
+  /// Link to the page holding the final item
+  pub last: String,
+}
+
+impl PaginationLinks {
+  /// Builds links for the page at `offset` of a list with `total` items.
+  ///
+  /// A `limit` of zero is treated as one so the offset math stays defined.
+  /// When `offset` is not a multiple of `limit`, prev steps back by `limit`
+  /// without going below zero.
+  pub fn new(base_url: &str, total: u64, offset: u64, limit: u32) -> Self {
+    let step = u64::from(limit.max(1));
+    let link = |at: u64| Self::link(base_url, at, step);
+    let last_offset = if total == 0 {
+      0
+    } else {
+      ((total - 1) / step) * step
+    };
+
+    Self {
+      first: link(0),
+      prev: (offset > 0).then(|| link(offset.saturating_sub(step))),
+      next: (offset + step < total).then(|| link(offset + step)),
+      last: link(last_offset),
+    }
+  }
+
+  /// Builds links for a page returned by `find_page`.
+  ///
+  /// Returns `None` for unpaginated pages (no limit), which have nothing to
+  /// link to.
+  pub fn from_page<T>(base_url: &str, page: &crate::ports::repository::Page<T>) -> Option<Self> {
+    page
+      .limit
+      .map(|limit| Self::new(base_url, page.total, page.offset, limit))
+  }
+
+  /// Renders the links as an RFC 8288 `Link` header value.
+  pub fn to_link_header(&self) -> String {
+    let mut parts = vec![format!("<{}>; rel=\"first\"", self.first)];
+    if let Some(prev) = &self.prev {
+      parts.push(format!("<{}>; rel=\"prev\"", prev));
+    }
+    if let Some(next) = &self.next {
+      parts.push(format!("<{}>; rel=\"next\"", next));
+    }
+    parts.push(format!("<{}>; rel=\"last\"", self.last));
+    parts.join(", ")
+  }
+
+  fn link(base_url: &str, offset: u64, limit: u64) -> String {
+    let separator = if base_url.contains('?') { '&' } else { '?' };
+    format!("{}{}offset={}&limit={}", base_url, separator, offset, limit)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_first_page_has_no_prev() {
+    let links = PaginationLinks::new("/items", 45, 0, 20);
+    assert_eq!(links.first, "/items?offset=0&limit=20");
+    assert_eq!(links.prev, None);
+    assert_eq!(links.next.as_deref(), Some("/items?offset=20&limit=20"));
+    assert_eq!(links.last, "/items?offset=40&limit=20");
+  }
+
+  #[test]
+  fn test_last_page_has_no_next() {
+    let links = PaginationLinks::new("/items", 45, 40, 20);
+    assert_eq!(links.prev.as_deref(), Some("/items?offset=20&limit=20"));
+    assert_eq!(links.next, None);
+    assert_eq!(links.last, "/items?offset=40&limit=20");
+  }
+
+  #[test]
+  fn test_exact_multiple_total_ends_on_full_page() {
+    let links = PaginationLinks::new("/items", 40, 20, 20);
+    assert_eq!(links.next, None);
+    assert_eq!(links.last, "/items?offset=20&limit=20");
+  }
+
+  #[test]
+  fn test_empty_and_single_page_lists() {
+    let empty = PaginationLinks::new("/items", 0, 0, 20);
+    assert_eq!((empty.prev, empty.next), (None, None));
+    assert_eq!(empty.last, empty.first);
+
+    let single = PaginationLinks::new("/items", 5, 0, 20);
+    assert_eq!((single.prev, single.next), (None, None));
+    assert_eq!(single.last, "/items?offset=0&limit=20");
+  }
+
+  #[test]
+  fn test_unaligned_offset_and_existing_query_string() {
+    let links = PaginationLinks::new("/items?tag=rust", 45, 10, 20);
+    assert_eq!(
+      links.prev.as_deref(),
+      Some("/items?tag=rust&offset=0&limit=20")
+    );
+    assert_eq!(
+      links.next.as_deref(),
+      Some("/items?tag=rust&offset=30&limit=20")
+    );
+  }
+
+  #[test]
+  fn test_from_page_and_link_header() {
+    let page = crate::ports::repository::Page {
+      items: vec![1, 2],
+      total: 4,
+      offset: 2,
+      limit: Some(2),
+    };
+    let links = PaginationLinks::from_page("/items", &page).unwrap();
+    assert_eq!(
+      links.to_link_header(),
+      "</items?offset=0&limit=2>; rel=\"first\", </items?offset=0&limit=2>; rel=\"prev\", \
+       </items?offset=2&limit=2>; rel=\"last\""
+    );
+
+    let unpaginated = crate::ports::repository::Page {
+      items: vec![1],
+      total: 1,
+      offset: 0,
+      limit: None,
+    };
+    assert_eq!(PaginationLinks::from_page("/items", &unpaginated), None);
+  }
+}
@@ -50,6 +50,12 @@
 //! - `analysis`: Architectural analysis and validation (Phase 4+)
 //!
 //! Revision History
+//! - 2026-10-19T10:45:00Z @AI: Re-export Identified.
+//! - 2026-10-19T09:25:00Z @AI: Re-export the DescribeFilters trait and derive.
+//! - 2026-10-19T08:25:00Z @AI: Re-export the Timestamped trait and derive.
+//! - 2026-10-19T02:55:00Z @AI: Re-export hex_crud scaffolding macro.
+//! - 2026-10-19T02:25:00Z @AI: Re-export hex_port attribute macro.
+//! - 2026-10-19T00:35:00Z @AI: Re-export HexContext derive.
 //! - 2025-10-09T14:14:00Z @AI: Remove Entity derive alias, expose HexEntity at crate root for qualified addressing.
 //! - 2025-10-02T13:00:00Z @AI: Re-export inventory and error_codes for proc macros.
 //! - 2025-10-02T12:00:00Z @AI: Add showcase module with Describable and Inspectable traits.
@@ -81,10 +87,12 @@ pub mod container;
 pub use crate::{error::hex_error::Hexserror, result::hex_result::HexResult};
 
 // Re-export all domain traits
-pub use crate::domain::{Aggregate, DomainEvent, DomainService, HexEntity, HexValueItem};
+pub use crate::domain::{
+  Aggregate, DomainEvent, DomainService, HexEntity, HexValueItem, Identified, Timestamped,
+};
 
 // Re-export all port traits
-pub use crate::ports::{InputPort, OutputPort, Query, Repository, UseCase};
+pub use crate::ports::{DescribeFilters, InputPort, OutputPort, Query, Repository, UseCase};
 
 // Re-export all adapter traits
 pub use crate::adapters::{Adapter, Mapper};
@@ -112,8 +120,8 @@ pub use crate::showcase::{ArcGraphExt, Describable, Inspectable, PrettyPrint};
 // Re-export derive macros at crate root for qualified addressing (e.g., hexser::HexEntity)
 #[cfg(feature = "macros")]
 pub use hexser_macros::{
-  HexAdapter, HexAggregate, HexDirective, HexDomain, HexEntity, HexPort, HexQuery, HexRepository,
-  HexValueItem,
+  DescribeFilters, HexAdapter, HexAggregate, HexContext, HexDirective, HexDomain, HexEntity,
+  HexPort, HexQuery, HexRepository, HexValueItem, Timestamped, hex_crud, hex_port,
 };
 
 /// Prelude module for convenient imports.
@@ -126,9 +134,11 @@ pub use hexser_macros::{
 pub mod prelude {
   pub use crate::{HexResult, Hexserror};
 
-  pub use crate::domain::{Aggregate, DomainEvent, DomainService, HexEntity, HexValueItem};
+  pub use crate::domain::{
+    Aggregate, DomainEvent, DomainService, HexEntity, HexValueItem, Identified, Timestamped,
+  };
 
-  pub use crate::ports::{InputPort, OutputPort, Query, Repository, UseCase};
+  pub use crate::ports::{DescribeFilters, InputPort, OutputPort, Query, Repository, UseCase};
 
   pub use crate::adapters::{Adapter, Mapper};
 
@@ -145,8 +155,8 @@ pub mod prelude {
   // Phase 3: Registry and derive macro support
   #[cfg(feature = "macros")]
   pub use hexser_macros::{
-    HexAdapter, HexAggregate, HexDirective, HexDomain, HexEntity, HexPort, HexQuery, HexRepository,
-    HexValueItem,
+    DescribeFilters, HexAdapter, HexAggregate, HexContext, HexDirective, HexDomain, HexEntity,
+    HexPort, HexQuery, HexRepository, HexValueItem, Timestamped, hex_crud, hex_port,
   };
 
   pub use crate::registry::{ComponentEntry, ComponentRegistry, NodeInfo, Registrable};
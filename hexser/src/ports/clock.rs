@@ -0,0 +1,87 @@
+//! Clock port for reading the current time.
+//!
+//! Time-dependent behaviour (event timestamps, expiry checks, audit fields)
+//! should ask a Clock instead of calling `SystemTime::now()` directly, so
+//! tests can substitute a fixed or manually advanced clock. Concrete clocks
+//! live in `crate::adapters::clock`.
+//!
+//! Revision History
+//! - 2026-10-18T23:50:00Z @AI: Initial Clock port with RFC3339 formatting helper.
+
+/// Port trait for obtaining the current wall-clock time.
+///
+/// # Example
+///
+/// ```rust
+/// struct Epoch;
+///
+/// impl hexser::ports::Clock for Epoch {
+///   fn now(&self) -> std::time::SystemTime {
+///     std::time::UNIX_EPOCH
+///   }
+/// }
+///
+/// std::assert_eq!(
+///   hexser::ports::Clock::now_rfc3339(&Epoch),
+///   "1970-01-01T00:00:00Z"
+/// );
+/// ```
+pub trait Clock: Send + Sync {
+  /// Returns the current time according to this clock.
+  fn now(&self) -> std::time::SystemTime;
+
+  /// Returns the current time formatted as an RFC3339 UTC timestamp.
+  fn now_rfc3339(&self) -> std::string::String {
+    format_rfc3339(self.now())
+  }
+}
+
+/// Formats a time as an RFC3339 UTC timestamp with second precision.
+///
+/// Times before the Unix epoch are clamped to `1970-01-01T00:00:00Z`.
+pub fn format_rfc3339(time: std::time::SystemTime) -> std::string::String {
+  let secs = time
+    .duration_since(std::time::UNIX_EPOCH)
+    .map(|d| d.as_secs())
+    .unwrap_or(0);
+  let days = (secs / 86_400) as i64;
+  let rem = secs % 86_400;
+  let (year, month, day) = civil_from_days(days);
+  format!(
+    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+    year,
+    month,
+    day,
+    rem / 3600,
+    (rem % 3600) / 60,
+    rem % 60
+  )
+}
+
+/// Converts days since the Unix epoch to a (year, month, day) civil date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+  let z = days + 719_468;
+  let era = z.div_euclid(146_097);
+  let doe = z.rem_euclid(146_097);
+  let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+  let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let mp = (5 * doy + 2) / 153;
+  let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+  let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+  (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_rfc3339_known_instants() {
+    let at = |secs: u64| std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+
+    std::assert_eq!(format_rfc3339(at(0)), "1970-01-01T00:00:00Z");
+    std::assert_eq!(format_rfc3339(at(951_782_400)), "2000-02-29T00:00:00Z");
+    std::assert_eq!(format_rfc3339(at(1_760_021_460)), "2025-10-09T14:51:00Z");
+  }
+}
@@ -0,0 +1,212 @@
+//! Introspection of the filters a repository understands.
+//!
+//! Generic tooling such as an admin query builder cannot inspect a
+//! repository's `Filter` enum at runtime. DescribeFilters lists its variants
+//! as FilterDescriptors, either by hand or through `#[derive(DescribeFilters)]`
+//! with the `macros` feature, and every QueryRepository whose filter
+//! implements it is a DescribableRepository.
+//!
+//! Revision History
+//! - 2026-10-19T09:25:00Z @AI: Initial DescribeFilters and DescribableRepository.
+
+/// One parameter of a filter variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterParam {
+  /// Field name for struct-like variants; `None` for tuple variants.
+  pub name: Option<String>,
+  /// Parameter type as written in the source, e.g. `String`.
+  pub type_name: String,
+}
+
+/// Name and parameters of one supported filter.
+///
+/// # Example
+///
+/// ```rust
+/// let descriptor = hexser::ports::describable_repository::FilterDescriptor::new("ByEmail")
+///     .with_param("String");
+///
+/// assert_eq!(descriptor.name, "ByEmail");
+/// assert_eq!(descriptor.param_types(), vec!["String"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterDescriptor {
+  /// Filter name, usually the enum variant name.
+  pub name: String,
+  /// Parameters in declaration order; empty for unit variants.
+  pub params: Vec<FilterParam>,
+}
+
+impl FilterDescriptor {
+  /// Describe a filter without parameters
+  pub fn new(name: impl Into<String>) -> Self {
+    Self {
+      name: name.into(),
+      params: Vec::new(),
+    }
+  }
+
+  /// Append a positional parameter of `type_name` (builder pattern)
+  pub fn with_param(mut self, type_name: impl Into<String>) -> Self {
+    self.params.push(FilterParam {
+      name: None,
+      type_name: type_name.into(),
+    });
+    self
+  }
+
+  /// Append a named parameter of `type_name` (builder pattern)
+  pub fn with_named_param(mut self, name: impl Into<String>, type_name: impl Into<String>) -> Self {
+    self.params.push(FilterParam {
+      name: Some(name.into()),
+      type_name: type_name.into(),
+    });
+    self
+  }
+
+  /// Parameter types in declaration order
+  pub fn param_types(&self) -> Vec<&str> {
+    self.params.iter().map(|p| p.type_name.as_str()).collect()
+  }
+}
+
+/// Filter types that can list the filters they express.
+///
+/// Derive it on a filter enum to get one descriptor per variant, in
+/// declaration order.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::ports::describable_repository::{DescribeFilters, FilterDescriptor};
+///
+/// enum ArticleFilter {
+///     All,
+///     ByAuthor(String),
+/// }
+///
+/// impl DescribeFilters for ArticleFilter {
+///     fn filter_descriptors() -> Vec<FilterDescriptor> {
+///         vec![FilterDescriptor::new("All"), FilterDescriptor::new("ByAuthor").with_param("String")]
+///     }
+/// }
+///
+/// assert_eq!(ArticleFilter::filter_descriptors().len(), 2);
+/// ```
+pub trait DescribeFilters {
+  /// Descriptors of every supported filter
+  fn filter_descriptors() -> Vec<FilterDescriptor>;
+}
+
+/// Query repositories that can describe the filters they support.
+///
+/// Implemented for every QueryRepository whose `Filter` implements
+/// DescribeFilters, so repositories get it by describing their filter type.
+pub trait DescribableRepository<T>: crate::ports::repository::QueryRepository<T>
+where
+  T: crate::domain::entity::HexEntity,
+{
+  /// Descriptors of every filter this repository accepts
+  fn supported_filters() -> Vec<FilterDescriptor>;
+}
+
+impl<R, T> DescribableRepository<T> for R
+where
+  R: crate::ports::repository::QueryRepository<T>,
+  R::Filter: DescribeFilters,
+  T: crate::domain::entity::HexEntity,
+{
+  fn supported_filters() -> Vec<FilterDescriptor> {
+    R::Filter::filter_descriptors()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Clone, Debug)]
+  struct Item {
+    id: u64,
+  }
+
+  impl crate::domain::entity::HexEntity for Item {
+    type Id = u64;
+  }
+
+  enum ItemFilter {
+    ById(u64),
+    Between { low: u64, high: u64 },
+  }
+
+  impl DescribeFilters for ItemFilter {
+    fn filter_descriptors() -> Vec<FilterDescriptor> {
+      vec![
+        FilterDescriptor::new("ById").with_param("u64"),
+        FilterDescriptor::new("Between")
+          .with_named_param("low", "u64")
+          .with_named_param("high", "u64"),
+      ]
+    }
+  }
+
+  struct ItemRepository {
+    items: Vec<Item>,
+  }
+
+  impl crate::ports::repository::QueryRepository<Item> for ItemRepository {
+    type Filter = ItemFilter;
+    type SortKey = ();
+
+    fn find_one(&self, filter: &ItemFilter) -> crate::result::hex_result::HexResult<Option<Item>> {
+      Ok(self.find(filter, Default::default())?.into_iter().next())
+    }
+
+    fn find(
+      &self,
+      filter: &ItemFilter,
+      _options: crate::ports::repository::FindOptions<()>,
+    ) -> crate::result::hex_result::HexResult<Vec<Item>> {
+      Ok(
+        self
+          .items
+          .iter()
+          .filter(|item| match filter {
+            ItemFilter::ById(id) => item.id == *id,
+            ItemFilter::Between { low, high } => (*low..=*high).contains(&item.id),
+          })
+          .cloned()
+          .collect(),
+      )
+    }
+
+    fn delete_where(&mut self, _filter: &ItemFilter) -> crate::result::hex_result::HexResult<u64> {
+      Ok(0)
+    }
+  }
+
+  #[test]
+  fn test_repository_describes_its_filter_type() {
+    let filters = <ItemRepository as DescribableRepository<Item>>::supported_filters();
+
+    assert_eq!(filters.len(), 2);
+    assert_eq!(filters[0].param_types(), vec!["u64"]);
+    assert_eq!(filters[0].params[0].name, None);
+    assert_eq!(filters[1].name, "Between");
+    assert_eq!(filters[1].params[1].name.as_deref(), Some("high"));
+
+    let repo = ItemRepository {
+      items: vec![Item { id: 1 }, Item { id: 5 }],
+    };
+    let between = crate::ports::repository::QueryRepository::find(
+      &repo,
+      &ItemFilter::Between { low: 2, high: 9 },
+      Default::default(),
+    )
+    .unwrap();
+    assert_eq!(between.len(), 1);
+    assert!(
+      crate::ports::repository::QueryRepository::exists(&repo, &ItemFilter::ById(1)).unwrap()
+    );
+  }
+}
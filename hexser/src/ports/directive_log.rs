@@ -0,0 +1,30 @@
+//! DirectiveLog port for recording serialized directives.
+//!
+//! An append-only sequence of records, each one serialized directive, that
+//! `RecordingHandler` writes and `replay` reads back in order. Records are
+//! opaque strings (JSON documents in practice), so a log can be backed by
+//! memory, a file, or a table without knowing the directive types.
+//! InMemoryDirectiveLog in `crate::adapters` is the in-process
+//! implementation.
+//!
+//! Revision History
+//! - 2026-10-19T07:25:00Z @AI: Initial DirectiveLog port.
+
+/// Port trait for an append-only log of serialized directives.
+pub trait DirectiveLog: Send + Sync {
+  /// Append one record to the end of the log
+  fn append(&self, record: String) -> crate::result::hex_result::HexResult<()>;
+
+  /// All records, oldest first
+  fn records(&self) -> crate::result::hex_result::HexResult<Vec<String>>;
+}
+
+impl<L: DirectiveLog + ?Sized> DirectiveLog for std::sync::Arc<L> {
+  fn append(&self, record: String) -> crate::result::hex_result::HexResult<()> {
+    (**self).append(record)
+  }
+
+  fn records(&self) -> crate::result::hex_result::HexResult<Vec<String>> {
+    (**self).records()
+  }
+}
@@ -0,0 +1,64 @@
+//! EventStore port trait for event-sourced persistence.
+//!
+//! An event store persists the ordered stream of domain events produced by
+//! each aggregate. Appending extends an aggregate's stream; loading returns
+//! the full stream in append order so the aggregate can be rebuilt.
+//!
+//! Revision History
+//! - 2026-10-18T14:00:00Z @AI: Initial EventStore port trait.
+
+/// Port trait for appending and loading per-aggregate event streams.
+///
+/// Like `EventPublisher<T>`, the store is generic over the event type. Use a
+/// concrete event enum for a single aggregate, or a shared trait object such
+/// as `std::sync::Arc<dyn DomainEvent>` for heterogeneous streams.
+///
+/// # Type Parameter
+///
+/// - `E`: The domain event type stored in each stream
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::EventStore;
+///
+/// #[derive(Clone, Debug)]
+/// struct Deposited {
+///     account_id: std::string::String,
+///     amount: u64,
+/// }
+///
+/// impl hexser::domain::DomainEvent for Deposited {
+///     fn event_type(&self) -> &str { "Deposited" }
+///     fn aggregate_id(&self) -> std::string::String { self.account_id.clone() }
+/// }
+///
+/// let mut store = hexser::adapters::InMemoryEventStore::new();
+/// store
+///     .append("acc-1", vec![Deposited { account_id: "acc-1".into(), amount: 5 }])
+///     .unwrap();
+///
+/// let events = store.load("acc-1").unwrap();
+/// std::assert_eq!(events[0].amount, 5);
+/// ```
+pub trait EventStore<E>
+where
+  E: crate::domain::domain_event::DomainEvent,
+{
+  /// Appends events to the end of an aggregate's stream.
+  ///
+  /// # Errors
+  ///
+  /// Implementations should reject the whole batch, appending nothing, if any
+  /// event's `aggregate_id()` does not match `aggregate_id`.
+  fn append(
+    &mut self,
+    aggregate_id: &str,
+    events: std::vec::Vec<E>,
+  ) -> crate::result::hex_result::HexResult<()>;
+
+  /// Loads all events for an aggregate in append order.
+  ///
+  /// Returns an empty vector for an aggregate with no events.
+  fn load(&self, aggregate_id: &str) -> crate::result::hex_result::HexResult<std::vec::Vec<E>>;
+}
@@ -0,0 +1,145 @@
+//! AsyncEventPublisher port trait for publishing events over async transports.
+//!
+//! Async counterpart of EventPublisher for transports whose clients are
+//! async (Kafka, SNS, HTTP). Application code awaits `publish` directly
+//! instead of wrapping a sync publisher call in `block_on`.
+//!
+//! Revision History
+//! - 2026-10-18T19:30:00Z @AI: Initial AsyncEventPublisher port trait.
+
+/// Port trait for asynchronously publishing CloudEvents-wrapped domain events.
+///
+/// Methods return `impl Future` so implementations can be written with
+/// `async fn`. Whether the returned future is `Send` follows from the
+/// implementation: a Kafka publisher holding thread-safe clients yields
+/// `Send` futures usable with `tokio::spawn`, while the single-threaded
+/// InMemoryEventBus can implement the trait as well. Existing sync publishers
+/// can be used through `SyncPublisherBridge`.
+///
+/// # Type Parameter
+///
+/// - `T`: The domain event type contained in the CloudEvents envelope
+///
+/// # Examples
+///
+/// ```rust
+/// use hexser::ports::events::AsyncEventPublisher;
+///
+/// struct LoggingPublisher;
+///
+/// impl AsyncEventPublisher<std::string::String> for LoggingPublisher {
+///     async fn publish(
+///         &self,
+///         envelope: &hexser::ports::events::CloudEventsEnvelope<std::string::String>,
+///     ) -> hexser::HexResult<()> {
+///         envelope.validate()
+///     }
+/// }
+///
+/// let envelope = hexser::ports::events::CloudEventsEnvelope::new(
+///     std::string::String::from("evt-001"),
+///     std::string::String::from("/services/test"),
+///     std::string::String::from("com.example.test.event"),
+/// );
+///
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// runtime.block_on(LoggingPublisher.publish(&envelope)).unwrap();
+/// ```
+pub trait AsyncEventPublisher<T> {
+  /// Publishes a single CloudEvents envelope to the transport.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error for the same conditions as `EventPublisher::publish`:
+  /// network failures, serialization failures, transport-specific errors, and
+  /// invalid CloudEvents attributes.
+  fn publish(
+    &self,
+    envelope: &super::CloudEventsEnvelope<T>,
+  ) -> impl std::future::Future<Output = crate::HexResult<()>>;
+
+  /// Publishes multiple CloudEvents envelopes.
+  ///
+  /// The default implementation publishes sequentially and stops at the first
+  /// failure. Transports with native batching should override it.
+  fn publish_batch(
+    &self,
+    envelopes: &[super::CloudEventsEnvelope<T>],
+  ) -> impl std::future::Future<Output = crate::HexResult<()>> {
+    async move {
+      for envelope in envelopes {
+        self.publish(envelope).await?;
+      }
+      std::result::Result::Ok(())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct CountingPublisher {
+    published: std::sync::atomic::AtomicUsize,
+  }
+
+  impl AsyncEventPublisher<std::string::String> for CountingPublisher {
+    async fn publish(
+      &self,
+      envelope: &crate::ports::events::CloudEventsEnvelope<std::string::String>,
+    ) -> crate::HexResult<()> {
+      envelope.validate()?;
+      self
+        .published
+        .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+      std::result::Result::Ok(())
+    }
+  }
+
+  fn envelope(id: &str) -> crate::ports::events::CloudEventsEnvelope<std::string::String> {
+    crate::ports::events::CloudEventsEnvelope::new(
+      std::string::String::from(id),
+      std::string::String::from("/test/source"),
+      std::string::String::from("com.test.event"),
+    )
+  }
+
+  #[tokio::test]
+  async fn test_default_publish_batch_publishes_each() {
+    let publisher = CountingPublisher {
+      published: std::sync::atomic::AtomicUsize::new(0),
+    };
+
+    publisher
+      .publish_batch(&[envelope("evt-001"), envelope("evt-002")])
+      .await
+      .unwrap();
+    std::assert_eq!(
+      publisher
+        .published
+        .load(std::sync::atomic::Ordering::SeqCst),
+      2
+    );
+  }
+
+  #[tokio::test]
+  async fn test_publish_future_is_send_for_thread_safe_publishers() {
+    // Test: Futures from thread-safe publishers can be spawned on a runtime.
+    // Justification: Async transports are typically driven from spawned tasks.
+    let publisher = std::sync::Arc::new(CountingPublisher {
+      published: std::sync::atomic::AtomicUsize::new(0),
+    });
+    let spawned = std::sync::Arc::clone(&publisher);
+
+    tokio::spawn(async move { spawned.publish(&envelope("evt-001")).await })
+      .await
+      .unwrap()
+      .unwrap();
+    std::assert_eq!(
+      publisher
+        .published
+        .load(std::sync::atomic::Ordering::SeqCst),
+      1
+    );
+  }
+}
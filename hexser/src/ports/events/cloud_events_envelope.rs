@@ -7,11 +7,38 @@
 //! and support for extension attributes.
 //!
 //! Revision History
+//! - 2026-10-19T03:55:00Z @AI: Add ValidationMode with lenient time repair; check full RFC3339 shape in strict mode.
+//! - 2026-10-19T02:15:00Z @AI: Carry the DomainEvent partition key as the partitionkey extension.
+//! - 2026-10-18T23:50:00Z @AI: Add clock-driven time stamping (from_domain_event_at, stamp_time).
 //! - 2025-10-09T14:51:00Z @AI: Initial CloudEventsEnvelope implementation for CloudEvents v1.0 compliance.
 
 /// CloudEvents v1.0 specification version constant.
 pub const CLOUDEVENTS_SPEC_VERSION: &str = "1.0";
 
+/// Extension attribute holding the partition key (CloudEvents Partitioning extension).
+pub const PARTITION_KEY_EXTENSION: &str = "partitionkey";
+
+/// How strictly `validate_with_mode` treats the `time` attribute.
+///
+/// `Lenient` exists for migrations from producers that emit near-RFC3339
+/// timestamps. It repairs exactly these deviations and nothing else:
+///
+/// - a space between date and time (`2025-10-09 14:51:00Z`) becomes `T`
+/// - a lowercase `t` separator or `z` suffix is uppercased
+/// - a missing UTC offset (`2025-10-09T14:51:00`) is assumed UTC and `Z` is appended
+///
+/// Repairs apply only to values shaped `YYYY-MM-DD?HH:MM:SS[.fraction][offset]`;
+/// fractional seconds and explicit `+HH:MM`/`-HH:MM` offsets are kept as-is.
+/// Anything else fails as in `Strict` mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+  /// Reject any time that is not RFC3339 (the default)
+  #[default]
+  Strict,
+  /// Repair the documented deviations, then validate
+  Lenient,
+}
+
 /// CloudEvents v1.0-compliant envelope wrapping domain events with transport metadata.
 ///
 /// This struct implements the CloudEvents v1.0 specification for standardized
@@ -208,6 +235,7 @@ impl<T> CloudEventsEnvelope<T> {
   /// to CloudEvents attributes:
   /// - `event_type()` → `type` attribute
   /// - `aggregate_id()` → `subject` attribute
+  /// - `partition_key()` → `partitionkey` extension, when present
   ///
   /// # Arguments
   ///
@@ -250,6 +278,10 @@ impl<T> CloudEventsEnvelope<T> {
   {
     let event_type = event.event_type().to_string();
     let subject = event.aggregate_id();
+    let mut extensions = std::collections::HashMap::new();
+    if let std::option::Option::Some(key) = event.partition_key() {
+      extensions.insert(std::string::String::from(PARTITION_KEY_EXTENSION), key);
+    }
 
     Self {
       id,
@@ -261,10 +293,57 @@ impl<T> CloudEventsEnvelope<T> {
       subject: std::option::Option::Some(subject),
       time: std::option::Option::None,
       data: std::option::Option::Some(event),
-      extensions: std::collections::HashMap::new(),
+      extensions,
     }
   }
 
+  /// Creates a CloudEventsEnvelope from a domain event, stamping `time` from a clock.
+  ///
+  /// Behaves like `from_domain_event` and additionally sets the `time`
+  /// attribute to `clock.now_rfc3339()`. Pass `&hexser::adapters::SystemClock`
+  /// in production and a `FixedClock` in tests for deterministic timestamps.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// struct UserCreated;
+  ///
+  /// impl hexser::domain::DomainEvent for UserCreated {
+  ///     fn event_type(&self) -> &str { "com.example.user.created" }
+  ///     fn aggregate_id(&self) -> std::string::String { std::string::String::from("user-123") }
+  /// }
+  ///
+  /// let clock = hexser::adapters::FixedClock::at_unix_secs(1_760_021_460);
+  /// let envelope = hexser::ports::events::CloudEventsEnvelope::from_domain_event_at(
+  ///     std::string::String::from("evt-001"),
+  ///     std::string::String::from("/services/user-service"),
+  ///     UserCreated,
+  ///     &clock,
+  /// );
+  ///
+  /// std::assert_eq!(envelope.time.as_deref(), std::option::Option::Some("2025-10-09T14:51:00Z"));
+  /// ```
+  pub fn from_domain_event_at(
+    id: std::string::String,
+    source: std::string::String,
+    event: T,
+    clock: &dyn crate::ports::clock::Clock,
+  ) -> Self
+  where
+    T: crate::domain::DomainEvent,
+  {
+    let mut envelope = Self::from_domain_event(id, source, event);
+    envelope.stamp_time(clock);
+    envelope
+  }
+
+  /// Sets the `time` attribute to the current time of `clock`.
+  ///
+  /// Overwrites any existing `time` value.
+  pub fn stamp_time(&mut self, clock: &dyn crate::ports::clock::Clock) {
+    self.time = std::option::Option::Some(clock.now_rfc3339());
+  }
+
   /// Validates that required CloudEvents v1.0 attributes are non-empty.
   ///
   /// Checks that all REQUIRED attributes (id, source, specversion, type) are non-empty strings.
@@ -328,7 +407,9 @@ impl<T> CloudEventsEnvelope<T> {
   /// Validates the time attribute format as RFC3339 if present.
   ///
   /// CloudEvents v1.0 specification requires time to be in RFC3339 format.
-  /// This method validates the format if the time attribute is set.
+  /// This method validates the format if the time attribute is set: an
+  /// uppercase `T` separator, optional fractional seconds, and a `Z` or
+  /// `+HH:MM`/`-HH:MM` offset. This is `ValidationMode::Strict`.
   ///
   /// # Returns
   ///
@@ -353,18 +434,16 @@ impl<T> CloudEventsEnvelope<T> {
   /// ```
   pub fn validate_time_format(&self) -> crate::HexResult<()> {
     if let std::option::Option::Some(ref time_str) = self.time {
-      // Basic RFC3339 format validation (simplified)
-      // Format: YYYY-MM-DDTHH:MM:SSZ or YYYY-MM-DDTHH:MM:SS+00:00
-      if time_str.len() < 20 {
+      if !time_str.contains('T') {
         return std::result::Result::Err(crate::Hexserror::validation(
-          "CloudEvents time attribute must be in RFC3339 format (e.g., 2025-10-09T14:51:00Z)",
+          "CloudEvents time attribute must contain 'T' separator (RFC3339 format)",
         ));
       }
 
-      // Check for 'T' separator and 'Z' or '+'/'-' timezone indicator
-      if !time_str.contains('T') {
+      // Already-canonical values are exactly those lenient repair leaves unchanged.
+      if repair_time(time_str).as_deref() != std::option::Option::Some(time_str.as_str()) {
         return std::result::Result::Err(crate::Hexserror::validation(
-          "CloudEvents time attribute must contain 'T' separator (RFC3339 format)",
+          "CloudEvents time attribute must be in RFC3339 format (e.g., 2025-10-09T14:51:00Z)",
         ));
       }
     }
@@ -372,6 +451,39 @@ impl<T> CloudEventsEnvelope<T> {
     std::result::Result::Ok(())
   }
 
+  /// Validates the envelope, treating the time attribute according to `mode`.
+  ///
+  /// Runs `validate` and `validate_time_format`. In `ValidationMode::Lenient`
+  /// the time attribute is first repaired in place as described on
+  /// `ValidationMode`, so the stored value is normalized RFC3339 afterwards.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let mut envelope: hexser::ports::events::CloudEventsEnvelope<std::string::String> =
+  ///     hexser::ports::events::CloudEventsEnvelope::new(
+  ///         std::string::String::from("evt-001"),
+  ///         std::string::String::from("/services/user-service"),
+  ///         std::string::String::from("com.example.user.created"),
+  ///     );
+  /// envelope.time = std::option::Option::Some(std::string::String::from("2025-10-09 14:51:00"));
+  ///
+  /// std::assert!(envelope.validate_with_mode(hexser::ports::events::ValidationMode::Strict).is_err());
+  /// envelope.validate_with_mode(hexser::ports::events::ValidationMode::Lenient).unwrap();
+  /// std::assert_eq!(envelope.time.as_deref(), std::option::Option::Some("2025-10-09T14:51:00Z"));
+  /// ```
+  pub fn validate_with_mode(&mut self, mode: ValidationMode) -> crate::HexResult<()> {
+    self.validate()?;
+    let repaired = match mode {
+      ValidationMode::Strict => std::option::Option::None,
+      ValidationMode::Lenient => self.time.as_deref().and_then(repair_time),
+    };
+    if repaired.is_some() {
+      self.time = repaired;
+    }
+    self.validate_time_format()
+  }
+
   /// Adds an extension attribute to the envelope.
   ///
   /// Extension attributes allow vendor-specific or application-specific metadata.
@@ -456,12 +568,111 @@ impl<T> CloudEventsEnvelope<T> {
   pub fn get_extension(&self, key: &str) -> std::option::Option<&std::string::String> {
     self.extensions.get(key)
   }
+
+  /// Gets the partition key used to keep related events ordered.
+  ///
+  /// Read from the `partitionkey` extension, which `from_domain_event`
+  /// fills from `DomainEvent::partition_key`. Pass it to
+  /// `EventRouter::resolve_partition` to pick a partition.
+  pub fn partition_key(&self) -> std::option::Option<&str> {
+    self
+      .extensions
+      .get(PARTITION_KEY_EXTENSION)
+      .map(std::string::String::as_str)
+  }
+}
+
+/// Repairs a near-RFC3339 timestamp as documented on `ValidationMode::Lenient`.
+///
+/// Valid RFC3339 values come back unchanged. Returns None when the value
+/// does not have the expected shape.
+fn repair_time(time: &str) -> std::option::Option<std::string::String> {
+  let digits = |range: std::ops::Range<usize>| {
+    time
+      .get(range)
+      .is_some_and(|part| part.bytes().all(|b| b.is_ascii_digit()))
+  };
+  let byte_is = |index: usize, expected: &[u8]| {
+    time
+      .as_bytes()
+      .get(index)
+      .is_some_and(|b| expected.contains(b))
+  };
+  let shaped = digits(0..4)
+    && byte_is(4, b"-")
+    && digits(5..7)
+    && byte_is(7, b"-")
+    && digits(8..10)
+    && byte_is(10, b" Tt")
+    && digits(11..13)
+    && byte_is(13, b":")
+    && digits(14..16)
+    && byte_is(16, b":")
+    && digits(17..19);
+  if !shaped {
+    return std::option::Option::None;
+  }
+
+  let rest = &time[19..];
+  let fraction_len = match rest.strip_prefix('.') {
+    std::option::Option::Some(after_dot) => {
+      let len = after_dot.bytes().take_while(u8::is_ascii_digit).count();
+      if len == 0 {
+        return std::option::Option::None;
+      }
+      len + 1
+    }
+    std::option::Option::None => 0,
+  };
+  let (fraction, offset) = rest.split_at(fraction_len);
+  let offset = match offset {
+    "" | "Z" | "z" => "Z",
+    _ if offset.len() == 6
+      && (offset.starts_with('+') || offset.starts_with('-'))
+      && offset.as_bytes()[3] == b':'
+      && offset[1..3]
+        .bytes()
+        .chain(offset[4..].bytes())
+        .all(|b| b.is_ascii_digit()) =>
+    {
+      offset
+    }
+    _ => return std::option::Option::None,
+  };
+
+  std::option::Option::Some(format!(
+    "{}T{}{}{}",
+    &time[..10],
+    &time[11..19],
+    fraction,
+    offset
+  ))
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_from_domain_event_sets_partition_key() {
+    let envelope = CloudEventsEnvelope::from_domain_event(
+      std::string::String::from("evt-001"),
+      std::string::String::from("/test"),
+      TestEvent {
+        id: std::string::String::from("agg-7"),
+        data: std::string::String::from("payload"),
+      },
+    );
+    std::assert_eq!(envelope.partition_key(), std::option::Option::Some("agg-7"));
+
+    let bare: CloudEventsEnvelope<std::string::String> = CloudEventsEnvelope::new(
+      std::string::String::from("evt-002"),
+      std::string::String::from("/test"),
+      std::string::String::from("com.test.event.created"),
+    );
+    std::assert_eq!(bare.partition_key(), std::option::Option::None);
+  }
+
   struct TestEvent {
     id: std::string::String,
     data: std::string::String,
@@ -493,6 +704,32 @@ mod tests {
     std::assert!(envelope.subject.is_none());
   }
 
+  #[test]
+  fn test_stamp_time_uses_clock() {
+    let clock = crate::adapters::clock::FixedClock::at_unix_secs(0);
+    let mut envelope = CloudEventsEnvelope::from_domain_event_at(
+      std::string::String::from("evt-003"),
+      std::string::String::from("/test/source"),
+      TestEvent {
+        id: std::string::String::from("test-123"),
+        data: std::string::String::from("test data"),
+      },
+      &clock,
+    );
+    std::assert_eq!(
+      envelope.time.as_deref(),
+      std::option::Option::Some("1970-01-01T00:00:00Z")
+    );
+    std::assert!(envelope.validate_time_format().is_ok());
+
+    clock.advance(std::time::Duration::from_secs(90));
+    envelope.stamp_time(&clock);
+    std::assert_eq!(
+      envelope.time.as_deref(),
+      std::option::Option::Some("1970-01-01T00:01:30Z")
+    );
+  }
+
   #[test]
   fn test_from_domain_event_maps_attributes() {
     let event = TestEvent {
@@ -587,6 +824,92 @@ mod tests {
     std::assert!(envelope.validate_time_format().is_err());
   }
 
+  fn envelope_at(time: &str) -> CloudEventsEnvelope<std::string::String> {
+    let mut envelope = CloudEventsEnvelope::new(
+      std::string::String::from("evt-001"),
+      std::string::String::from("/test/source"),
+      std::string::String::from("com.example.test"),
+    );
+    envelope.time = std::option::Option::Some(std::string::String::from(time));
+    envelope
+  }
+
+  #[test]
+  fn test_lenient_repairs_space_separator() {
+    let mut envelope = envelope_at("2025-10-09 14:51:00Z");
+    std::assert!(envelope.validate_with_mode(ValidationMode::Strict).is_err());
+    envelope
+      .validate_with_mode(ValidationMode::Lenient)
+      .unwrap();
+    std::assert_eq!(
+      envelope.time.as_deref(),
+      std::option::Option::Some("2025-10-09T14:51:00Z")
+    );
+  }
+
+  #[test]
+  fn test_lenient_assumes_utc_for_missing_offset() {
+    let mut envelope = envelope_at("2025-10-09T14:51:00.250");
+    envelope
+      .validate_with_mode(ValidationMode::Lenient)
+      .unwrap();
+    std::assert_eq!(
+      envelope.time.as_deref(),
+      std::option::Option::Some("2025-10-09T14:51:00.250Z")
+    );
+  }
+
+  #[test]
+  fn test_lenient_uppercases_separator_and_zulu() {
+    let mut envelope = envelope_at("2025-10-09t14:51:00z");
+    envelope
+      .validate_with_mode(ValidationMode::Lenient)
+      .unwrap();
+    std::assert_eq!(
+      envelope.time.as_deref(),
+      std::option::Option::Some("2025-10-09T14:51:00Z")
+    );
+  }
+
+  #[test]
+  fn test_lenient_keeps_explicit_offset() {
+    let mut envelope = envelope_at("2025-10-09 14:51:00+02:00");
+    envelope
+      .validate_with_mode(ValidationMode::Lenient)
+      .unwrap();
+    std::assert_eq!(
+      envelope.time.as_deref(),
+      std::option::Option::Some("2025-10-09T14:51:00+02:00")
+    );
+  }
+
+  #[test]
+  fn test_lenient_rejects_unrepairable_time() {
+    for time in [
+      "09/10/2025 14:51",
+      "2025-10-09 14:51",
+      "2025-10-09 14:51:00 UTC",
+    ] {
+      let mut envelope = envelope_at(time);
+      std::assert!(
+        envelope
+          .validate_with_mode(ValidationMode::Lenient)
+          .is_err(),
+        "{}",
+        time
+      );
+      std::assert_eq!(envelope.time.as_deref(), std::option::Option::Some(time));
+    }
+  }
+
+  #[test]
+  fn test_strict_mode_matches_validate_time_format() {
+    let mut envelope = envelope_at("2025-10-09T14:51:00Z");
+    envelope
+      .validate_with_mode(ValidationMode::default())
+      .unwrap();
+  }
+
   #[test]
   fn test_add_extension_success() {
     let mut envelope: CloudEventsEnvelope<std::string::String> = CloudEventsEnvelope::new(
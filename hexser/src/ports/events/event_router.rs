@@ -6,6 +6,7 @@
 //! without coupling to specific transports.
 //!
 //! Revision History
+//! - 2026-10-19T02:15:00Z @AI: Add resolve_partition with stable hash-based default.
 //! - 2025-10-09T14:51:00Z @AI: Initial EventRouter port trait definition.
 
 /// Port trait for routing CloudEvents to topics and subjects.
@@ -19,6 +20,7 @@
 ///
 /// - `resolve_topic`: Resolves the topic/channel/queue for an event type
 /// - `resolve_subject`: Resolves the subject/partition key for an aggregate ID
+/// - `resolve_partition`: Assigns a partition key to one of N partitions
 ///
 /// # Examples
 ///
@@ -126,6 +128,45 @@ pub trait EventRouter {
   /// // Returns: None
   /// ```
   fn resolve_subject(&self, aggregate_id: &str) -> std::option::Option<std::string::String>;
+
+  /// Assigns a partition key to one of `partition_count` partitions.
+  ///
+  /// Equal keys always map to the same partition, so events sharing a
+  /// `CloudEventsEnvelope::partition_key` stay ordered on transports like
+  /// Kafka. The default hashes the key with FNV-1a, which is stable across
+  /// processes and releases. A `partition_count` of zero yields partition 0.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// struct FlatRouter;
+  ///
+  /// impl hexser::ports::events::EventRouter for FlatRouter {
+  ///     fn resolve_topic(&self, _event_type: &str) -> hexser::HexResult<std::string::String> {
+  ///         std::result::Result::Ok(std::string::String::from("all.events"))
+  ///     }
+  ///
+  ///     fn resolve_subject(&self, _aggregate_id: &str) -> std::option::Option<std::string::String> {
+  ///         std::option::Option::None
+  ///     }
+  /// }
+  ///
+  /// let router = FlatRouter;
+  /// let partition = hexser::ports::events::EventRouter::resolve_partition(&router, "order-1", 12);
+  /// std::assert!(partition < 12);
+  /// std::assert_eq!(partition, hexser::ports::events::EventRouter::resolve_partition(&router, "order-1", 12));
+  /// ```
+  fn resolve_partition(&self, partition_key: &str, partition_count: u32) -> u32 {
+    if partition_count == 0 {
+      return 0;
+    }
+    let hash = partition_key
+      .bytes()
+      .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+      });
+    (hash % u64::from(partition_count)) as u32
+  }
 }
 
 #[cfg(test)]
@@ -267,6 +308,22 @@ mod tests {
     std::assert_eq!(subject, std::option::Option::None);
   }
 
+  #[test]
+  fn test_resolve_partition_is_stable_and_in_range() {
+    let router = FlatRouter;
+
+    std::assert_eq!(
+      router.resolve_partition("", 7),
+      (0xcbf2_9ce4_8422_2325_u64 % 7) as u32
+    );
+    for key in ["order-1", "order-2", "user-123"] {
+      let partition = router.resolve_partition(key, 8);
+      std::assert!(partition < 8);
+      std::assert_eq!(partition, router.resolve_partition(key, 8));
+    }
+    std::assert_eq!(router.resolve_partition("order-1", 0), 0);
+  }
+
   #[test]
   fn test_multiple_event_types_routing() {
     let router = DomainRouter;
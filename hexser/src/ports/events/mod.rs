@@ -12,6 +12,7 @@
 //! - **EventSubscriber<T>**: Port for consuming events from transports
 //! - **EventCodec<T>**: Port for serialization/deserialization
 //! - **EventRouter**: Port for topic/subject resolution
+//! - **PublishReport**: Per-event outcomes of a best-effort batch publish
 //!
 //! # CloudEvents v1.0 Compliance
 //!
@@ -69,17 +70,26 @@
 //! ```
 //!
 //! Revision History
+//! - 2026-10-19T09:15:00Z @AI: Add PublishReport for per-event batch outcomes.
+//! - 2026-10-19T03:55:00Z @AI: Re-export ValidationMode.
+//! - 2026-10-18T19:30:00Z @AI: Add AsyncEventPublisher port behind the async feature.
 //! - 2025-10-09T14:51:00Z @AI: Initial events module with CloudEvents v1.0 ports.
 
+#[cfg(feature = "async")]
+pub mod async_event_publisher;
 pub mod cloud_events_envelope;
 pub mod event_codec;
 pub mod event_publisher;
 pub mod event_router;
 pub mod event_subscriber;
+pub mod publish_report;
 
 // Re-export main types and traits
-pub use cloud_events_envelope::{CLOUDEVENTS_SPEC_VERSION, CloudEventsEnvelope};
+#[cfg(feature = "async")]
+pub use async_event_publisher::AsyncEventPublisher;
+pub use cloud_events_envelope::{CLOUDEVENTS_SPEC_VERSION, CloudEventsEnvelope, ValidationMode};
 pub use event_codec::EventCodec;
 pub use event_publisher::EventPublisher;
 pub use event_router::EventRouter;
 pub use event_subscriber::EventSubscriber;
+pub use publish_report::PublishReport;
@@ -0,0 +1,126 @@
+//! Per-event outcomes of a batch publish.
+//!
+//! A fail-fast `publish_batch` leaves the caller guessing which events made
+//! it when it errors midway. Publishers that keep going past failures return
+//! a PublishReport instead, recording one outcome per input index so the
+//! caller can retry exactly the events that failed.
+//!
+//! Revision History
+//! - 2026-10-19T09:15:00Z @AI: Initial PublishReport.
+
+/// Outcome of each envelope in a batch, in input order.
+///
+/// Index `i` holds the result of publishing `envelopes[i]`.
+///
+/// # Example
+///
+/// ```rust
+/// let mut report = hexser::ports::events::PublishReport::new();
+/// report.push(Ok(()));
+/// report.push(Err(hexser::Hexserror::adapter("E_HEX_201", "Broker unavailable")));
+/// report.push(Ok(()));
+///
+/// assert_eq!(report.delivered(), vec![0, 2]);
+/// assert_eq!(report.failed_indices(), vec![1]);
+/// assert!(report.into_result().is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct PublishReport {
+  outcomes: Vec<crate::result::hex_result::HexResult<()>>,
+}
+
+impl PublishReport {
+  /// Create an empty report
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Record the outcome of the next envelope
+  pub fn push(&mut self, outcome: crate::result::hex_result::HexResult<()>) {
+    self.outcomes.push(outcome);
+  }
+
+  /// Number of envelopes attempted
+  pub fn len(&self) -> usize {
+    self.outcomes.len()
+  }
+
+  /// Whether the batch was empty
+  pub fn is_empty(&self) -> bool {
+    self.outcomes.is_empty()
+  }
+
+  /// Outcome for the envelope at `index`
+  pub fn outcome(&self, index: usize) -> Option<&crate::result::hex_result::HexResult<()>> {
+    self.outcomes.get(index)
+  }
+
+  /// Whether every envelope was published
+  pub fn is_success(&self) -> bool {
+    self.outcomes.iter().all(Result::is_ok)
+  }
+
+  /// Indices of published envelopes, in delivery order
+  pub fn delivered(&self) -> Vec<usize> {
+    self
+      .outcomes
+      .iter()
+      .enumerate()
+      .filter(|(_, outcome)| outcome.is_ok())
+      .map(|(index, _)| index)
+      .collect()
+  }
+
+  /// Indices of envelopes that failed, in input order
+  pub fn failed_indices(&self) -> Vec<usize> {
+    self.failures().map(|(index, _)| index).collect()
+  }
+
+  /// Failed envelopes' indices paired with their errors
+  pub fn failures(&self) -> impl Iterator<Item = (usize, &crate::error::hex_error::Hexserror)> {
+    self
+      .outcomes
+      .iter()
+      .enumerate()
+      .filter_map(|(index, outcome)| outcome.as_ref().err().map(|err| (index, err)))
+  }
+
+  /// Collapse into a single result.
+  ///
+  /// Returns `Ok(())` when every envelope was published, otherwise an
+  /// aggregate error whose sub-errors carry their batch `index` in context.
+  pub fn into_result(self) -> crate::result::hex_result::HexResult<()> {
+    let errors: Vec<crate::error::hex_error::Hexserror> = self
+      .outcomes
+      .into_iter()
+      .enumerate()
+      .filter_map(|(index, outcome)| outcome.err().map(|err| err.with_context("index", index)))
+      .collect();
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(crate::error::hex_error::Hexserror::aggregate(errors))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_into_result_tags_failures_with_index() {
+    let mut report = PublishReport::new();
+    report.push(Ok(()));
+    report.push(Err(crate::error::hex_error::Hexserror::validation("bad")));
+    assert!(!report.is_success());
+    assert_eq!(report.len(), 2);
+
+    let err = report.into_result().unwrap_err();
+    assert!(err.is_aggregate());
+    assert_eq!(err.sub_errors().len(), 1);
+    assert_eq!(err.sub_errors()[0].context()["index"], "1");
+
+    assert!(PublishReport::new().into_result().is_ok());
+  }
+}
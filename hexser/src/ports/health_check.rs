@@ -0,0 +1,121 @@
+//! HealthCheck port for readiness and liveness probes.
+//!
+//! Each adapter that talks to something which can fail (a database, a
+//! broker, a remote API) can report its health through this port, and a
+//! `HealthAggregator` from `crate::adapters::health_check` combines the
+//! results into one status for a Kubernetes-style readiness endpoint. The
+//! trait uses async-trait so checks of different types can be stored
+//! together as `dyn HealthCheck`.
+//!
+//! Revision History
+//! - 2026-10-19T06:45:00Z @AI: Initial HealthCheck port and HealthStatus.
+
+/// Result of a health check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthStatus {
+  /// Fully operational.
+  Healthy,
+  /// Serving, but impaired (slow, running on a fallback, ...).
+  Degraded(String),
+  /// Not able to serve requests.
+  Unhealthy(String),
+}
+
+impl HealthStatus {
+  /// Whether the status is `Healthy`
+  pub fn is_healthy(&self) -> bool {
+    matches!(self, Self::Healthy)
+  }
+
+  /// Whether the component can serve traffic, i.e. is not `Unhealthy`
+  ///
+  /// Degraded components still count as ready, matching how readiness
+  /// probes treat partial service.
+  pub fn is_ready(&self) -> bool {
+    !matches!(self, Self::Unhealthy(_))
+  }
+
+  /// Reason given for a degraded or unhealthy status
+  pub fn reason(&self) -> Option<&str> {
+    match self {
+      Self::Healthy => None,
+      Self::Degraded(reason) | Self::Unhealthy(reason) => Some(reason),
+    }
+  }
+
+  /// Rank used to pick the worst of several statuses
+  pub(crate) fn severity(&self) -> u8 {
+    match self {
+      Self::Healthy => 0,
+      Self::Degraded(_) => 1,
+      Self::Unhealthy(_) => 2,
+    }
+  }
+}
+
+impl std::fmt::Display for HealthStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Healthy => write!(f, "healthy"),
+      Self::Degraded(reason) => write!(f, "degraded: {}", reason),
+      Self::Unhealthy(reason) => write!(f, "unhealthy: {}", reason),
+    }
+  }
+}
+
+/// Port trait for reporting the health of an adapter or service.
+///
+/// Checks should be cheap and bounded in time (a ping, not a full query)
+/// because probes call them frequently.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::ports::{HealthCheck, HealthStatus};
+///
+/// struct Database {
+///     connected: bool,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl HealthCheck for Database {
+///     async fn check(&self) -> HealthStatus {
+///         if self.connected {
+///             HealthStatus::Healthy
+///         } else {
+///             HealthStatus::Unhealthy(String::from("connection lost"))
+///         }
+///     }
+/// }
+///
+/// let runtime = tokio::runtime::Runtime::new().unwrap();
+/// let status = runtime.block_on(Database { connected: false }.check());
+/// assert!(!status.is_ready());
+/// ```
+#[async_trait::async_trait]
+pub trait HealthCheck: Send + Sync {
+  /// Report the current health
+  async fn check(&self) -> HealthStatus;
+}
+
+#[async_trait::async_trait]
+impl<C: HealthCheck + ?Sized> HealthCheck for std::sync::Arc<C> {
+  async fn check(&self) -> HealthStatus {
+    (**self).check().await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_status_readiness_and_reason() {
+    let degraded = HealthStatus::Degraded(String::from("slow"));
+    assert!(degraded.is_ready() && !degraded.is_healthy());
+    assert_eq!(degraded.reason(), Some("slow"));
+    assert_eq!(degraded.to_string(), "degraded: slow");
+    assert!(!HealthStatus::Unhealthy(String::from("down")).is_ready());
+    assert_eq!(HealthStatus::Healthy.reason(), None);
+  }
+}
@@ -0,0 +1,32 @@
+//! IdGenerator port for minting entity identifiers.
+//!
+//! Directive handlers that create entities should depend on an IdGenerator
+//! instead of deriving ids from repository state (e.g. `count + 1`), which
+//! races under concurrency and collides after deletes. Concrete generators
+//! live in `crate::adapters::id_generator`.
+//!
+//! Revision History
+//! - 2026-10-18T23:55:00Z @AI: Initial IdGenerator port.
+
+/// Port trait producing fresh identifiers of type `T`.
+///
+/// Implementations must never return the same id twice for the lifetime
+/// of the generator. Generation is fallible so that adapters backed by an
+/// external sequence (a database, a ticket server) can report failures.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::ports::IdGenerator;
+///
+/// let ids = hexser::adapters::SequentialGenerator::with_prefix("order-");
+/// let first: std::string::String = ids.next_id().unwrap();
+/// let second: std::string::String = ids.next_id().unwrap();
+///
+/// std::assert_eq!(first, "order-1");
+/// std::assert_eq!(second, "order-2");
+/// ```
+pub trait IdGenerator<T>: Send + Sync {
+  /// Returns a new identifier not previously handed out by this generator.
+  fn next_id(&self) -> crate::result::hex_result::HexResult<T>;
+}
@@ -6,29 +6,58 @@
 //! use cases, queries (CQRS pattern), and CloudEvents v1.0-compliant event ports.
 //!
 //! Revision History
+//! - 2026-10-19T09:25:00Z @AI: Add describable_repository port.
+//! - 2026-10-19T07:25:00Z @AI: Add directive_log port.
+//! - 2026-10-19T06:45:00Z @AI: Add health_check port behind the async feature.
+//! - 2026-10-19T00:45:00Z @AI: Add seed module for populating repositories.
+//! - 2026-10-18T23:55:00Z @AI: Add id_generator port.
+//! - 2026-10-18T23:50:00Z @AI: Add clock port.
+//! - 2026-10-18T19:30:00Z @AI: Re-export AsyncEventPublisher behind the async feature.
+//! - 2026-10-18T15:30:00Z @AI: Add soft_delete_repository port.
+//! - 2026-10-18T14:00:00Z @AI: Add event_store port for event-sourced persistence.
 //! - 2025-10-09T14:51:00Z @AI: Add events module with CloudEvents v1.0 ports.
 //! - 2025-10-08T23:35:00Z @AI: Add mcp_server port for Model Context Protocol support.
 //! - 2025-10-08T22:54:00Z @AI: Remove weather_port module (moved to examples).
 //! - 2025-10-01T00:00:00Z @AI: Initial Phase 1 ports module structure.
 
+pub mod clock;
+pub mod describable_repository;
+pub mod directive_log;
+pub mod event_store;
 pub mod events;
+#[cfg(feature = "async")]
+pub mod health_check;
+pub mod id_generator;
 pub mod input_port;
 pub mod output_port;
 pub mod query;
 pub mod repository;
+pub mod seed;
+pub mod soft_delete_repository;
 pub mod use_case;
 
 #[cfg(feature = "mcp")]
 pub mod mcp_server;
 
+pub use clock::Clock;
+pub use describable_repository::{DescribableRepository, DescribeFilters, FilterDescriptor};
+pub use directive_log::DirectiveLog;
+pub use event_store::EventStore;
+#[cfg(feature = "async")]
+pub use health_check::{HealthCheck, HealthStatus};
+pub use id_generator::IdGenerator;
 pub use input_port::InputPort;
 pub use output_port::OutputPort;
 pub use query::Query;
 pub use repository::Repository;
+pub use soft_delete_repository::SoftDeleteRepository;
 pub use use_case::UseCase;
 
 // Re-export CloudEvents v1.0 types and traits
 pub use events::{
   CLOUDEVENTS_SPEC_VERSION, CloudEventsEnvelope, EventCodec, EventPublisher, EventRouter,
-  EventSubscriber,
+  EventSubscriber, PublishReport,
 };
+
+#[cfg(feature = "async")]
+pub use events::AsyncEventPublisher;
@@ -11,13 +11,43 @@
 //! - 2025-10-06T17:22:00Z @AI: Tests: add justifications; remove super import; fully qualify paths per no-use rule.
 //! - 2025-10-07T10:00:00Z @AI: Decouple QueryRepository from ID-centric Repository to enable generic, filter-first repositories.
 //! - 2025-10-07T10:59:00Z @AI: Remove deprecated id-centric methods; focus Repository on save only; update tests for v0.4.
+//! - 2026-10-18T13:30:00Z @AI: Add Page result wrapper and QueryRepository::find_page.
+//! - 2026-10-18T15:30:00Z @AI: Add FindOptions::include_deleted for soft-delete aware adapters.
+//! - 2026-10-18T19:00:00Z @AI: Add QueryRepository::take_where returning removed entities.
+//! - 2026-10-18T19:20:00Z @AI: Add QueryRepository::group_count for per-key occurrence counts.
+//! - 2026-10-18T20:45:00Z @AI: Document multi-key sort semantics; add FindOptions::with_sort.
+//! - 2026-10-18T22:00:00Z @AI: Add QueryRepository::exists_all and count_by batch helpers.
+//! - 2026-10-18T22:45:00Z @AI: Add Repository::save_and_return for enrich-on-write adapters.
+//! - 2026-10-19T01:25:00Z @AI: Add fluent QueryRepository::query builder over FindOptions.
+//! - 2026-10-19T05:05:00Z @AI: Add QueryRepository::clear for explicit resets.
+//! - 2026-10-19T06:15:00Z @AI: Add Repository::upsert reporting Upserted::Inserted or Updated.
+//! - 2026-10-19T07:05:00Z @AI: Add QueryRepository::count_estimate defaulting to count.
+//! - 2026-10-19T07:55:00Z @AI: Add Repository::upsert_many with ConflictStrategy and UpsertReport.
+//! - 2026-10-19T09:05:00Z @AI: Add QueryRepository::find_with_timeout and timeout_error.
+//! - 2026-10-19T09:35:00Z @AI: Add count_with so page totals honour include_deleted.
+//! - 2026-10-19T09:55:00Z @AI: Default clear returns E_HEX_203 instead of a silent no-op.
+//! - 2026-10-19T10:05:00Z @AI: Default take_where fails when delete_where removes a different count.
+//! - 2026-10-19T10:55:00Z @AI: TIMEOUT_ERROR_CODE re-exports codes::timeout::EXCEEDED.
+//! - 2026-10-19T11:55:00Z @AI: Default take_where returns E_HEX_203 without deleting.
+//! - 2026-10-19T12:25:00Z @AI: Add Repository::upsert_many_and_return returning stored entities.
 
 /// Generic query options for fetching collections.
+///
+/// # Sorting
+///
+/// `sort` keys apply in priority order: the first key decides, and each later
+/// key only breaks ties left by the keys before it. Adapters should finish
+/// with an implicit tiebreak on entity id so that results are fully
+/// deterministic even when every key compares equal; the in-memory adapters
+/// in this crate do so. Without `sort`, adapters return their natural order.
 #[derive(Debug, Clone)]
 pub struct FindOptions<K> {
+  /// Sort keys in priority order; see the type-level docs.
   pub sort: Option<Vec<Sort<K>>>,
   pub limit: Option<u32>,
   pub offset: Option<u64>,
+  /// Include soft-deleted entities; ignored by adapters without soft delete.
+  pub include_deleted: bool,
 }
 
 impl<K> Default for FindOptions<K> {
@@ -26,10 +56,22 @@ impl<K> Default for FindOptions<K> {
       sort: None,
       limit: None,
       offset: None,
+      include_deleted: false,
     }
   }
 }
 
+impl<K> FindOptions<K> {
+  /// Append a sort key with lower priority than any already present (builder pattern).
+  pub fn with_sort(mut self, key: K, direction: Direction) -> Self {
+    self
+      .sort
+      .get_or_insert_with(Vec::new)
+      .push(Sort { key, direction });
+    self
+  }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
   Asc,
@@ -42,6 +84,80 @@ pub struct Sort<K> {
   pub direction: Direction,
 }
 
+/// One page of query results together with the total match count.
+///
+/// `offset` and `limit` echo the options used to produce the page, so
+/// pagination UIs can render position and next/previous links directly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+  pub items: Vec<T>,
+  pub total: u64,
+  pub offset: u64,
+  pub limit: Option<u32>,
+}
+
+impl<T> Page<T> {
+  /// Returns true if more matching entities exist after this page.
+  pub fn has_more(&self) -> bool {
+    self.offset + (self.items.len() as u64) < self.total
+  }
+}
+
+/// Outcome of `Repository::upsert`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upserted {
+  /// No entity with the same id existed; it was created.
+  Inserted,
+  /// An entity with the same id existed and was replaced.
+  Updated,
+}
+
+/// How `Repository::upsert_many` resolves an incoming entity whose id exists.
+pub enum ConflictStrategy<T> {
+  /// Replace the stored entity with the incoming one.
+  Overwrite,
+  /// Keep the stored entity and skip the incoming one.
+  KeepExisting,
+  /// Store the entity returned by the function, given `(existing, incoming)`.
+  Merge(fn(&T, T) -> T),
+}
+
+impl<T> Clone for ConflictStrategy<T> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<T> Copy for ConflictStrategy<T> {}
+
+impl<T> std::fmt::Debug for ConflictStrategy<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Overwrite => f.write_str("Overwrite"),
+      Self::KeepExisting => f.write_str("KeepExisting"),
+      Self::Merge(_) => f.write_str("Merge(..)"),
+    }
+  }
+}
+
+/// Counts reported by `Repository::upsert_many`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpsertReport {
+  /// Entities whose id did not exist yet.
+  pub inserted: u64,
+  /// Existing entities that were overwritten or merged.
+  pub updated: u64,
+  /// Incoming entities dropped by `ConflictStrategy::KeepExisting`.
+  pub skipped: u64,
+}
+
+impl UpsertReport {
+  /// Total number of entities processed.
+  pub fn total(&self) -> u64 {
+    self.inserted + self.updated + self.skipped
+  }
+}
+
 /// Trait for repository ports that abstract persistence save operations (v0.4+).
 ///
 /// Starting in v0.4, id-centric methods were removed in favor of the generic,
@@ -58,6 +174,126 @@ where
 {
   /// Save an entity to the repository.
   fn save(&mut self, entity: T) -> crate::result::hex_result::HexResult<()>;
+
+  /// Save an entity and return the persisted version.
+  ///
+  /// Adapters that assign ids, versions, or timestamps on write should
+  /// override this to return the enriched entity, sparing callers a find
+  /// after save. The default calls `save` and returns the input unchanged.
+  fn save_and_return(&mut self, entity: T) -> crate::result::hex_result::HexResult<T>
+  where
+    T: Clone,
+  {
+    self.save(entity.clone())?;
+    Ok(entity)
+  }
+
+  /// Save an entity and report whether it was inserted or updated.
+  ///
+  /// Lets handlers raise the matching domain event, such as `UserCreated`
+  /// versus `UserUpdated`. The existence check and the write must be atomic:
+  /// backends should use a native upsert (`INSERT ... ON CONFLICT` with a
+  /// returned flag, or similar) or a transaction, so concurrent writers
+  /// cannot both observe `Inserted` for one id. The in-memory adapters are
+  /// atomic because they take `&mut self`.
+  ///
+  /// The default returns `E_HEX_203`, since `save` alone cannot tell the
+  /// two cases apart.
+  fn upsert(&mut self, _entity: T) -> crate::result::hex_result::HexResult<Upserted> {
+    Err(
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::UNSUPPORTED_OPERATION,
+        "This repository does not implement upsert",
+      )
+      .with_next_step("Override Repository::upsert in the adapter"),
+    )
+  }
+
+  /// Upsert many entities, resolving id conflicts with `strategy`.
+  ///
+  /// Intended for bulk syncs from an external source. Entities are applied
+  /// in order, so a later entity with the same id as an earlier one in the
+  /// batch conflicts with it like with any stored entity.
+  ///
+  /// The default handles `ConflictStrategy::Overwrite` by calling `upsert`
+  /// for each entity and stops at the first error, leaving earlier entities
+  /// written. The other strategies need to read the stored entity, so the
+  /// default returns `E_HEX_203` for them without writing anything; adapters
+  /// override this to support them, ideally applying the batch atomically.
+  fn upsert_many(
+    &mut self,
+    entities: Vec<T>,
+    strategy: ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<UpsertReport> {
+    if !matches!(strategy, ConflictStrategy::Overwrite) {
+      return Err(
+        crate::error::hex_error::Hexserror::adapter(
+          crate::error::codes::adapter::UNSUPPORTED_OPERATION,
+          &format!(
+            "This repository only supports ConflictStrategy::Overwrite, got {:?}",
+            strategy
+          ),
+        )
+        .with_next_step("Override Repository::upsert_many in the adapter"),
+      );
+    }
+    let mut report = UpsertReport::default();
+    for entity in entities {
+      match self.upsert(entity)? {
+        Upserted::Inserted => report.inserted += 1,
+        Upserted::Updated => report.updated += 1,
+      }
+    }
+    Ok(report)
+  }
+
+  /// Upsert many entities and return the stored version of each written one.
+  ///
+  /// Behaves like `upsert_many` and also returns every inserted or updated
+  /// entity as persisted, in input order: the merged entity under
+  /// `ConflictStrategy::Merge`, including any ids or timestamps the adapter
+  /// assigned. Skipped entities are left out. Adapters that override
+  /// `upsert_many` should override this too.
+  ///
+  /// The default handles `ConflictStrategy::Overwrite` by calling
+  /// `upsert_many` and returning the input unchanged, like `save_and_return`.
+  /// It cannot tell which entities were kept or merged, so it returns
+  /// `E_HEX_203` for the other strategies without writing anything.
+  fn upsert_many_and_return(
+    &mut self,
+    entities: Vec<T>,
+    strategy: ConflictStrategy<T>,
+  ) -> crate::result::hex_result::HexResult<(UpsertReport, Vec<T>)>
+  where
+    T: Clone,
+  {
+    if !matches!(strategy, ConflictStrategy::Overwrite) {
+      return Err(
+        crate::error::hex_error::Hexserror::adapter(
+          crate::error::codes::adapter::UNSUPPORTED_OPERATION,
+          &format!(
+            "This repository cannot return stored entities for {:?}",
+            strategy
+          ),
+        )
+        .with_next_step("Override Repository::upsert_many_and_return in the adapter"),
+      );
+    }
+    let report = self.upsert_many(entities.clone(), strategy)?;
+    Ok((report, entities))
+  }
+}
+
+/// Error code reported when a query exceeds its timeout.
+pub use crate::error::codes::timeout::EXCEEDED as TIMEOUT_ERROR_CODE;
+
+/// Builds the adapter error returned when a query exceeds `timeout`.
+pub fn timeout_error(timeout: std::time::Duration) -> crate::error::hex_error::Hexserror {
+  crate::error::hex_error::Hexserror::adapter(
+    TIMEOUT_ERROR_CODE,
+    &format!("Query exceeded its timeout of {:?}", timeout),
+  )
+  .with_next_step("Narrow the filter, add a limit, or retry with a longer timeout")
 }
 
 /// Generic query-capable repository port for expressive, domain-owned filters.
@@ -81,6 +317,48 @@ where
     options: FindOptions<Self::SortKey>,
   ) -> crate::result::hex_result::HexResult<Vec<T>>;
 
+  /// Fetch entities like `find`, giving up once `timeout` has elapsed.
+  ///
+  /// Enforcement is adapter-dependent. Adapters that can bound a query, for
+  /// example with a driver statement timeout or by running it on a worker
+  /// thread, should override this and fail with `timeout_error` (code
+  /// `E_TIMEOUT`) when the limit is exceeded. The default, used by the
+  /// in-memory adapters, ignores `timeout` and calls `find`.
+  fn find_with_timeout(
+    &self,
+    filter: &Self::Filter,
+    options: FindOptions<Self::SortKey>,
+    timeout: std::time::Duration,
+  ) -> crate::result::hex_result::HexResult<Vec<T>> {
+    let _ = timeout;
+    self.find(filter, options)
+  }
+
+  /// Fetch one page of entities matching `filter` plus the total match count.
+  ///
+  /// The default implementation calls `find` then `count_with`, so `total`
+  /// covers the same entities as `items`, soft-deleted ones included when
+  /// `include_deleted` is set. Adapters backed by
+  /// a transactional store should override it to compute both from one
+  /// consistent snapshot (for example a single query with a window count).
+  fn find_page(
+    &self,
+    filter: &Self::Filter,
+    options: FindOptions<Self::SortKey>,
+  ) -> crate::result::hex_result::HexResult<Page<T>> {
+    let offset = options.offset.unwrap_or(0);
+    let limit = options.limit;
+    let include_deleted = options.include_deleted;
+    let items = self.find(filter, options)?;
+    let total = self.count_with(filter, include_deleted)?;
+    Ok(Page {
+      items,
+      total,
+      offset,
+      limit,
+    })
+  }
+
   /// Check existence of at least one entity matching `filter`.
   fn exists(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<bool> {
     Ok(self.find_one(filter)?.is_some())
@@ -91,11 +369,255 @@ where
     Ok(self.find(filter, FindOptions::default())?.len() as u64)
   }
 
+  /// Count entities matching `filter`, including soft-deleted ones if asked.
+  ///
+  /// Counts the same entities `find` returns for `FindOptions::include_deleted`
+  /// set to `include_deleted`. The default calls `count` when deleted entities
+  /// are excluded and otherwise counts a `find` with the flag set; soft-delete
+  /// aware adapters should override it with a direct count.
+  fn count_with(
+    &self,
+    filter: &Self::Filter,
+    include_deleted: bool,
+  ) -> crate::result::hex_result::HexResult<u64> {
+    if !include_deleted {
+      return self.count(filter);
+    }
+    let options = FindOptions {
+      include_deleted,
+      ..FindOptions::default()
+    };
+    Ok(self.find(filter, options)?.len() as u64)
+  }
+
+  /// Estimate how many entities match `filter`.
+  ///
+  /// Meant for progress reporting and sizing, where an exact total is not
+  /// needed. The result may be approximate; backends where exact counts
+  /// are expensive should override it with something cheap, such as
+  /// table statistics or a query planner estimate. The default returns
+  /// the exact `count`.
+  fn count_estimate(&self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
+    self.count(filter)
+  }
+
+  /// Check that every filter in `filters` matches at least one entity.
+  ///
+  /// Useful for validating foreign-key-like references before a save. An
+  /// empty slice yields `true`. The default calls `exists` per filter and
+  /// stops at the first miss; adapters may override it with a batched query.
+  fn exists_all(&self, filters: &[Self::Filter]) -> crate::result::hex_result::HexResult<bool> {
+    for filter in filters {
+      if !self.exists(filter)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  }
+
+  /// Count entities matching each filter, returned in the order of `filters`.
+  ///
+  /// The default calls `count` per filter; adapters may override it with a
+  /// batched query.
+  fn count_by(&self, filters: &[Self::Filter]) -> crate::result::hex_result::HexResult<Vec<u64>> {
+    filters.iter().map(|filter| self.count(filter)).collect()
+  }
+
   /// Delete by filter; returns number of removed entities.
   fn delete_where(&mut self, _filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64> {
     // Default no-op for backward compatibility in simple adapters.
     Ok(0)
   }
+
+  /// Remove every entity; returns the number removed.
+  ///
+  /// An explicit reset that backends can implement as a fast bulk operation
+  /// such as `TRUNCATE`, instead of matching each row as `delete_where`
+  /// would. Soft-delete aware adapters remove soft-deleted entities too.
+  ///
+  /// The default returns `E_HEX_203` rather than reporting a reset that
+  /// removed nothing.
+  fn clear(&mut self) -> crate::result::hex_result::HexResult<u64> {
+    Err(
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::UNSUPPORTED_OPERATION,
+        "This repository does not implement clear",
+      )
+      .with_next_step("Override QueryRepository::clear in the adapter"),
+    )
+  }
+
+  /// Delete by filter and return the removed entities.
+  ///
+  /// Entities are returned in the repository's natural (insertion) order,
+  /// so callers can emit one deletion event per entity afterwards. Adapters
+  /// must remove and collect in one step so the returned entities are exactly
+  /// the ones removed.
+  ///
+  /// The default returns `E_HEX_203` without deleting anything: combining
+  /// `find` and `delete_where` could remove entities it never returns, and
+  /// their deletion events would be lost.
+  fn take_where(&mut self, _filter: &Self::Filter) -> crate::result::hex_result::HexResult<Vec<T>> {
+    Err(
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::UNSUPPORTED_OPERATION,
+        "This repository does not implement take_where",
+      )
+      .with_next_step("Override QueryRepository::take_where in the adapter"),
+    )
+  }
+
+  /// Start a fluent query over entities matching `filter`.
+  ///
+  /// The returned builder assembles `FindOptions` and dispatches to `find`,
+  /// `find_page`, or `count`, so it works with any adapter unchanged.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use hexser::ports::repository::{Direction, QueryRepository};
+  ///
+  /// #[derive(Clone, Debug)]
+  /// struct Todo { id: u32 }
+  ///
+  /// impl hexser::domain::HexEntity for Todo {
+  ///     type Id = u32;
+  /// }
+  ///
+  /// let mut repo = hexser::adapters::InMemoryRepository::new(
+  ///     |t: &Todo| t.id,
+  ///     |_: &Todo, _: &()| true,
+  ///     |a: &Todo, b: &Todo, _: &()| a.id.cmp(&b.id),
+  /// );
+  /// hexser::ports::seed::seed(&mut repo, (1..=5).map(|id| Todo { id }).collect()).unwrap();
+  ///
+  /// let ids: Vec<u32> = repo
+  ///     .query(&())
+  ///     .sort((), Direction::Desc)
+  ///     .limit(2)
+  ///     .offset(1)
+  ///     .all()
+  ///     .unwrap()
+  ///     .iter()
+  ///     .map(|t| t.id)
+  ///     .collect();
+  /// assert_eq!(ids, vec![4, 3]);
+  /// ```
+  fn query<'r>(&'r self, filter: &'r Self::Filter) -> QueryBuilder<'r, T, Self>
+  where
+    Self: Sized,
+  {
+    QueryBuilder {
+      repo: self,
+      filter,
+      options: FindOptions::default(),
+      entity: std::marker::PhantomData,
+    }
+  }
+
+  /// Count occurrences of each key across entities matching `filter`.
+  ///
+  /// `key` may emit several keys per entity (e.g. tags); every emitted key
+  /// is counted, so an entity emitting the same key twice counts it twice.
+  /// Distinct values are the keys of the returned map. The default computes
+  /// over `find`; adapters backed by a store with native grouping may
+  /// override it.
+  fn group_count<K>(
+    &self,
+    filter: &Self::Filter,
+    key: impl Fn(&T) -> Vec<K>,
+  ) -> crate::result::hex_result::HexResult<std::collections::HashMap<K, u64>>
+  where
+    K: Eq + std::hash::Hash,
+    Self: Sized,
+  {
+    let mut counts = std::collections::HashMap::new();
+    for entity in self.find(filter, FindOptions::default())? {
+      for k in key(&entity) {
+        *counts.entry(k).or_insert(0) += 1;
+      }
+    }
+    Ok(counts)
+  }
+}
+
+/// Fluent query created by `QueryRepository::query`.
+///
+/// `sort` may be called repeatedly; keys apply in call order as described
+/// on `FindOptions`. `count` ignores sort and pagination.
+pub struct QueryBuilder<'r, T, R>
+where
+  T: crate::domain::entity::HexEntity,
+  R: QueryRepository<T>,
+{
+  repo: &'r R,
+  filter: &'r R::Filter,
+  options: FindOptions<R::SortKey>,
+  entity: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<'r, T, R> QueryBuilder<'r, T, R>
+where
+  T: crate::domain::entity::HexEntity,
+  R: QueryRepository<T>,
+{
+  /// Append a sort key with lower priority than any already present.
+  pub fn sort(mut self, key: R::SortKey, direction: Direction) -> Self {
+    self.options = self.options.with_sort(key, direction);
+    self
+  }
+
+  /// Return at most `limit` entities.
+  pub fn limit(mut self, limit: u32) -> Self {
+    self.options.limit = Some(limit);
+    self
+  }
+
+  /// Skip the first `offset` matching entities.
+  pub fn offset(mut self, offset: u64) -> Self {
+    self.options.offset = Some(offset);
+    self
+  }
+
+  /// Include soft-deleted entities where the adapter supports it.
+  pub fn include_deleted(mut self) -> Self {
+    self.options.include_deleted = true;
+    self
+  }
+
+  /// The options assembled so far.
+  pub fn options(&self) -> &FindOptions<R::SortKey> {
+    &self.options
+  }
+
+  /// Fetch every entity the query selects.
+  pub fn all(self) -> crate::result::hex_result::HexResult<Vec<T>> {
+    self.repo.find(self.filter, self.options)
+  }
+
+  /// Fetch the first entity the query selects, honouring sort and offset.
+  pub fn one(mut self) -> crate::result::hex_result::HexResult<Option<T>> {
+    self.options.limit = Some(1);
+    Ok(
+      self
+        .repo
+        .find(self.filter, self.options)?
+        .into_iter()
+        .next(),
+    )
+  }
+
+  /// Fetch the selected page together with the total match count.
+  pub fn page(self) -> crate::result::hex_result::HexResult<Page<T>> {
+    self.repo.find_page(self.filter, self.options)
+  }
+
+  /// Count all entities matching the filter, honouring `include_deleted`.
+  pub fn count(self) -> crate::result::hex_result::HexResult<u64> {
+    self
+      .repo
+      .count_with(self.filter, self.options.include_deleted)
+  }
 }
 
 #[cfg(test)]
@@ -263,6 +785,7 @@ mod tests {
       }]),
       limit: Some(1),
       offset: Some(0),
+      include_deleted: false,
     };
     let page = <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::find(
       &repo,
@@ -288,4 +811,359 @@ mod tests {
     .unwrap();
     assert!(none.is_none());
   }
+
+  #[test]
+  fn test_find_page_returns_items_and_total() {
+    // Test: find_page combines a paginated find with the unpaginated count.
+    // Justification: Pagination UIs need the page items and total from one call.
+    let repo = TestRepository {
+      entities: (1..=5)
+        .map(|id| TestEntity {
+          id,
+          name: format!("N{}", id),
+        })
+        .collect(),
+    };
+    let opts = crate::ports::repository::FindOptions {
+      sort: Some(vec![crate::ports::repository::Sort {
+        key: TestSortKey::Id,
+        direction: crate::ports::repository::Direction::Asc,
+      }]),
+      limit: Some(2),
+      offset: Some(2),
+      include_deleted: false,
+    };
+
+    let page =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::find_page(
+        &repo,
+        &TestFilter::All,
+        opts,
+      )
+      .unwrap();
+
+    assert_eq!(
+      page.items.iter().map(|e| e.id).collect::<Vec<_>>(),
+      vec![3, 4]
+    );
+    assert_eq!(page.total, 5);
+    assert_eq!(page.offset, 2);
+    assert_eq!(page.limit, Some(2));
+    assert!(page.has_more());
+  }
+
+  #[test]
+  fn test_save_and_return_default_returns_input() {
+    // Test: The default save_and_return persists the entity and hands it back.
+    // Justification: Adapters without write-side enrichment must behave like save.
+    let mut repo = TestRepository::default();
+    let saved =
+      <TestRepository as crate::ports::repository::Repository<TestEntity>>::save_and_return(
+        &mut repo,
+        TestEntity {
+          id: 1,
+          name: String::from("A"),
+        },
+      )
+      .unwrap();
+
+    assert_eq!(saved.id, 1);
+    assert_eq!(saved.name, "A");
+    assert_eq!(repo.entities.len(), 1);
+  }
+
+  #[test]
+  fn test_count_estimate_defaults_to_exact_count() {
+    // Test: Without an override, count_estimate returns the exact count.
+    // Justification: Callers may rely on the estimate even for adapters with cheap counts.
+    let repo = TestRepository {
+      entities: (1..=4)
+        .map(|id| TestEntity {
+          id,
+          name: format!("N{}", id),
+        })
+        .collect(),
+    };
+    let estimate =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::count_estimate(
+        &repo,
+        &TestFilter::All,
+      )
+      .unwrap();
+
+    assert_eq!(estimate, 4);
+  }
+
+  #[test]
+  fn test_take_where_default_is_unsupported() {
+    // Test: Adapters that do not override take_where report E_HEX_203 and keep their entities.
+    // Justification: Callers emit deletion events for returned entities, so none may be lost.
+    let mut repo = TestRepository {
+      entities: vec![TestEntity {
+        id: 1,
+        name: String::from("A"),
+      }],
+    };
+    let err =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::take_where(
+        &mut repo,
+        &TestFilter::All,
+      )
+      .unwrap_err();
+
+    assert_eq!(
+      err.code(),
+      crate::error::codes::adapter::UNSUPPORTED_OPERATION
+    );
+    assert_eq!(repo.entities.len(), 1);
+  }
+
+  #[test]
+  fn test_clear_default_is_unsupported() {
+    // Test: Adapters that do not override clear report E_HEX_203 and keep their entities.
+    // Justification: A reset must not report success while every row survives.
+    let mut repo = TestRepository {
+      entities: vec![TestEntity {
+        id: 1,
+        name: String::from("A"),
+      }],
+    };
+    let err =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::clear(&mut repo)
+        .unwrap_err();
+
+    assert_eq!(
+      err.code(),
+      crate::error::codes::adapter::UNSUPPORTED_OPERATION
+    );
+    assert_eq!(repo.entities.len(), 1);
+  }
+
+  #[test]
+  fn test_upsert_default_is_unsupported() {
+    // Test: Adapters that do not override upsert report E_HEX_203 and write nothing.
+    // Justification: save alone cannot distinguish an insert from an update.
+    let mut repo = TestRepository::default();
+    let err = <TestRepository as crate::ports::repository::Repository<TestEntity>>::upsert(
+      &mut repo,
+      TestEntity {
+        id: 1,
+        name: String::from("A"),
+      },
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      err.code(),
+      crate::error::codes::adapter::UNSUPPORTED_OPERATION
+    );
+    assert!(repo.entities.is_empty());
+  }
+
+  #[test]
+  fn test_upsert_many_default_rejects_strategies_needing_reads() {
+    // Test: Without an override, only Overwrite is attempted and it relies on upsert.
+    // Justification: the default cannot read stored entities to keep or merge them.
+    let mut repo = TestRepository::default();
+    let entities = vec![TestEntity {
+      id: 1,
+      name: String::from("A"),
+    }];
+    let keep = <TestRepository as crate::ports::repository::Repository<TestEntity>>::upsert_many(
+      &mut repo,
+      entities.clone(),
+      crate::ports::repository::ConflictStrategy::KeepExisting,
+    )
+    .unwrap_err();
+    let overwrite =
+      <TestRepository as crate::ports::repository::Repository<TestEntity>>::upsert_many(
+        &mut repo,
+        entities,
+        crate::ports::repository::ConflictStrategy::Overwrite,
+      )
+      .unwrap_err();
+
+    assert!(keep.to_string().contains("KeepExisting"));
+    assert_eq!(
+      overwrite.code(),
+      crate::error::codes::adapter::UNSUPPORTED_OPERATION
+    );
+    assert!(repo.entities.is_empty());
+  }
+
+  #[test]
+  fn test_upsert_many_and_return_default_rejects_merge() {
+    // Test: Without an override, Merge is rejected before anything is written.
+    // Justification: the default cannot know the merged entity an adapter stored.
+    let mut repo = TestRepository::default();
+    let merged =
+      <TestRepository as crate::ports::repository::Repository<TestEntity>>::upsert_many_and_return(
+        &mut repo,
+        vec![TestEntity {
+          id: 1,
+          name: String::from("A"),
+        }],
+        crate::ports::repository::ConflictStrategy::Merge(|_, incoming| incoming),
+      )
+      .unwrap_err();
+
+    assert_eq!(
+      merged.code(),
+      crate::error::codes::adapter::UNSUPPORTED_OPERATION
+    );
+    assert!(repo.entities.is_empty());
+  }
+
+  #[test]
+  fn test_with_sort_appends_keys_in_priority_order() {
+    // Test: with_sort builds the sort list in call order.
+    // Justification: Call order defines key priority for multi-key sorting.
+    let opts = crate::ports::repository::FindOptions::default()
+      .with_sort(TestSortKey::Name, crate::ports::repository::Direction::Desc)
+      .with_sort(TestSortKey::Id, crate::ports::repository::Direction::Asc);
+
+    let sorts = opts.sort.unwrap();
+    assert_eq!(sorts.len(), 2);
+    assert_eq!(sorts[0].key, TestSortKey::Name);
+    assert_eq!(
+      sorts[0].direction,
+      crate::ports::repository::Direction::Desc
+    );
+    assert_eq!(sorts[1].key, TestSortKey::Id);
+  }
+
+  #[test]
+  fn test_group_count_counts_every_emitted_key() {
+    // Test: group_count aggregates multiple keys per entity over the filtered set.
+    // Justification: Tag-style aggregation must count each emitted key and respect the filter.
+    let repo = TestRepository {
+      entities: vec![
+        TestEntity {
+          id: 1,
+          name: String::from("a-b"),
+        },
+        TestEntity {
+          id: 2,
+          name: String::from("b"),
+        },
+        TestEntity {
+          id: 3,
+          name: String::from("b-c"),
+        },
+      ],
+    };
+    let split = |e: &TestEntity| e.name.split('-').map(String::from).collect::<Vec<String>>();
+
+    let all =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::group_count(
+        &repo,
+        &TestFilter::All,
+        split,
+      )
+      .unwrap();
+    assert_eq!(all.len(), 3);
+    assert_eq!(all["a"], 1);
+    assert_eq!(all["b"], 3);
+    assert_eq!(all["c"], 1);
+
+    let one =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::group_count(
+        &repo,
+        &TestFilter::ById(3),
+        split,
+      )
+      .unwrap();
+    assert_eq!(one.len(), 2);
+    assert!(!one.contains_key("a"));
+  }
+
+  #[test]
+  fn test_find_with_timeout_defaults_to_find() {
+    // Test: The default find_with_timeout ignores the timeout and delegates to find.
+    // Justification: In-memory adapters are always fast; the API exists for real adapters.
+    let repo = TestRepository {
+      entities: vec![TestEntity {
+        id: 1,
+        name: String::from("a"),
+      }],
+    };
+
+    let found =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::find_with_timeout(
+        &repo,
+        &TestFilter::All,
+        crate::ports::repository::FindOptions::default(),
+        std::time::Duration::ZERO,
+      )
+      .unwrap();
+    assert_eq!(found.len(), 1);
+
+    let err = crate::ports::repository::timeout_error(std::time::Duration::from_millis(250));
+    assert_eq!(err.code(), crate::ports::repository::TIMEOUT_ERROR_CODE);
+    assert!(crate::error::codes::is_registered(err.code()));
+    assert!(err.to_string().contains("250ms"));
+  }
+
+  #[test]
+  fn test_query_builder_realworld_list_articles() {
+    // Test: The RealWorld "list articles" query expressed with the fluent builder.
+    // Justification: Combined filter, newest-first sort and pagination must match find_page.
+    let repo = TestRepository {
+      entities: (1..=6)
+        .map(|id| TestEntity {
+          id,
+          name: String::from(if id % 2 == 0 { "rust" } else { "go" }),
+        })
+        .collect(),
+    };
+    let filter = TestFilter::And(vec![TestFilter::NameEquals(String::from("rust"))]);
+    let (limit, offset) = (Some(2u32), Some(1u64));
+
+    let mut query =
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::query(
+        &repo, &filter,
+      )
+      .sort(TestSortKey::Id, crate::ports::repository::Direction::Desc);
+    if let Some(limit) = limit {
+      query = query.limit(limit);
+    }
+    if let Some(offset) = offset {
+      query = query.offset(offset);
+    }
+    let page = query.page().unwrap();
+
+    assert_eq!(
+      page.items.iter().map(|e| e.id).collect::<Vec<_>>(),
+      vec![4, 2]
+    );
+    assert_eq!(page.total, 3);
+    assert!(!page.has_more());
+
+    let newest = <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::query(
+      &repo, &filter,
+    )
+    .sort(TestSortKey::Id, crate::ports::repository::Direction::Desc)
+    .one()
+    .unwrap();
+    assert_eq!(newest.map(|e| e.id), Some(6));
+    assert_eq!(
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::query(
+        &repo, &filter,
+      )
+      .limit(1)
+      .count()
+      .unwrap(),
+      3
+    );
+    assert_eq!(
+      <TestRepository as crate::ports::repository::QueryRepository<TestEntity>>::query(
+        &repo,
+        &TestFilter::All,
+      )
+      .all()
+      .unwrap()
+      .len(),
+      6
+    );
+  }
 }
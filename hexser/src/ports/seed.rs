@@ -0,0 +1,156 @@
+//! Seeding helpers for populating repositories in demos and tests.
+//!
+//! `seed` saves a list of entities in order through any Repository, and
+//! `seed_from_json` (serde + serde_json features) loads that list from a
+//! JSON array file first. Failures report the zero-based index of the
+//! offending entity as the `seed_index` context key, so a bad fixture row
+//! is easy to locate.
+//!
+//! Revision History
+//! - 2026-10-19T00:45:00Z @AI: Initial seed and seed_from_json helpers.
+
+/// Context key carrying the index of the entity that failed to seed.
+pub const SEED_INDEX_CONTEXT_KEY: &str = "seed_index";
+
+/// Save every entity into `repo`, stopping at the first failure.
+///
+/// Entities before the failing one remain saved; seeding is not transactional.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::ports::repository::QueryRepository;
+///
+/// #[derive(Clone, Debug)]
+/// struct Todo { id: u32 }
+///
+/// impl hexser::domain::HexEntity for Todo {
+///     type Id = u32;
+/// }
+///
+/// let mut repo = hexser::adapters::InMemoryRepository::new(
+///     |t: &Todo| t.id,
+///     |_: &Todo, _: &()| true,
+///     |_: &Todo, _: &Todo, _: &()| std::cmp::Ordering::Equal,
+/// );
+/// hexser::ports::seed::seed(&mut repo, vec![Todo { id: 1 }, Todo { id: 2 }]).unwrap();
+///
+/// assert_eq!(repo.count(&()).unwrap(), 2);
+/// ```
+pub fn seed<T, R>(repo: &mut R, entities: Vec<T>) -> crate::result::hex_result::HexResult<()>
+where
+  T: crate::domain::entity::HexEntity,
+  R: crate::ports::repository::Repository<T>,
+{
+  entities
+    .into_iter()
+    .enumerate()
+    .try_for_each(|(index, entity)| {
+      repo
+        .save(entity)
+        .map_err(|err| err.with_context(SEED_INDEX_CONTEXT_KEY, index))
+    })
+}
+
+/// Deserialize a JSON array of entities from `path` and `seed` them.
+///
+/// Unreadable files fail with an IO error and malformed JSON with a mapping
+/// error; both carry the path as the `seed_path` context key.
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+pub fn seed_from_json<T, R>(
+  repo: &mut R,
+  path: impl AsRef<std::path::Path>,
+) -> crate::result::hex_result::HexResult<()>
+where
+  T: crate::domain::entity::HexEntity + serde::de::DeserializeOwned,
+  R: crate::ports::repository::Repository<T>,
+{
+  let path = path.as_ref();
+  let contents = std::fs::read_to_string(path).map_err(|e| {
+    crate::error::hex_error::Hexserror::adapter(
+      crate::error::codes::io::IO_FAILURE,
+      &format!("Failed to read seed file: {}", e),
+    )
+    .with_context("seed_path", path.display())
+  })?;
+  let entities: Vec<T> = serde_json::from_str(&contents).map_err(|e| {
+    crate::error::hex_error::Hexserror::adapter(
+      crate::error::codes::adapter::MAPPING_FAILURE,
+      &format!("Seed file is not a JSON array of entities: {}", e),
+    )
+    .with_context("seed_path", path.display())
+    .with_next_step("Ensure the file contains a JSON array matching the entity shape")
+  })?;
+  seed(repo, entities)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Clone, Debug, PartialEq)]
+  #[cfg_attr(
+    all(feature = "serde", feature = "serde_json"),
+    derive(serde::Deserialize)
+  )]
+  struct Item {
+    id: u32,
+  }
+
+  impl crate::domain::entity::HexEntity for Item {
+    type Id = u32;
+  }
+
+  /// Rejects ids above a limit to exercise failure reporting.
+  struct CappedRepo {
+    max_id: u32,
+    saved: Vec<Item>,
+  }
+
+  impl crate::ports::repository::Repository<Item> for CappedRepo {
+    fn save(&mut self, entity: Item) -> crate::result::hex_result::HexResult<()> {
+      if entity.id > self.max_id {
+        return Err(crate::error::hex_error::Hexserror::validation_field(
+          "id too large",
+          "id",
+        ));
+      }
+      self.saved.push(entity);
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_seed_reports_failing_index() {
+    let mut repo = CappedRepo {
+      max_id: 2,
+      saved: Vec::new(),
+    };
+    let err = seed(
+      &mut repo,
+      vec![Item { id: 1 }, Item { id: 2 }, Item { id: 9 }],
+    )
+    .unwrap_err();
+
+    assert_eq!(err.context()[SEED_INDEX_CONTEXT_KEY], "2");
+    assert_eq!(repo.saved.len(), 2);
+  }
+
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  #[test]
+  fn test_seed_from_json_file() {
+    let path = std::env::temp_dir().join(format!("hexser_seed_{}.json", std::process::id()));
+    std::fs::write(&path, r#"[{"id": 1}, {"id": 2}]"#).unwrap();
+    let mut repo = CappedRepo {
+      max_id: 10,
+      saved: Vec::new(),
+    };
+
+    let result = seed_from_json(&mut repo, &path);
+    std::fs::remove_file(&path).unwrap();
+
+    result.unwrap();
+    assert_eq!(repo.saved, vec![Item { id: 1 }, Item { id: 2 }]);
+    assert!(seed_from_json::<Item, _>(&mut repo, &path).is_err());
+  }
+}
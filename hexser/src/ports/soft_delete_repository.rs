@@ -0,0 +1,36 @@
+//! SoftDeleteRepository trait for audit-friendly deletion.
+//!
+//! Soft deletion marks entities as deleted instead of removing them, so
+//! history stays available for auditing and mistakes can be undone. Marked
+//! entities are hidden from normal queries and only returned when a caller
+//! opts in with `FindOptions::include_deleted`.
+//!
+//! Revision History
+//! - 2026-10-18T15:30:00Z @AI: Initial SoftDeleteRepository trait.
+
+/// Repository port supporting reversible, marker-based deletion.
+///
+/// # Representing the deleted state
+///
+/// How the marker is stored is an adapter concern and never appears in the
+/// domain entity: SQL adapters typically use a nullable `deleted_at` column,
+/// document stores a boolean field, and in-memory adapters a flag kept next
+/// to each entity. Whatever the representation, adapters must uphold:
+///
+/// - `find_one`, `exists`, and `count` never return or count soft-deleted entities
+/// - `find` skips soft-deleted entities unless `FindOptions::include_deleted` is true
+/// - `delete_where` still removes entities permanently, deleted or not
+///
+/// Because `count` excludes soft-deleted entities, the default
+/// `QueryRepository::find_page` total does too, even when `include_deleted`
+/// is set.
+pub trait SoftDeleteRepository<T>: crate::ports::repository::QueryRepository<T>
+where
+  T: crate::domain::entity::HexEntity,
+{
+  /// Mark entities matching `filter` as deleted; returns the number newly marked.
+  fn soft_delete(&mut self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64>;
+
+  /// Clear the deleted mark on entities matching `filter`; returns the number restored.
+  fn restore(&mut self, filter: &Self::Filter) -> crate::result::hex_result::HexResult<u64>;
+}
@@ -3,6 +3,7 @@
 //! Represents a single registered component in the inventory system.
 //!
 //! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Fill forbidden_layers in test NodeInfo literals.
 //! - 2025-10-02T00:00:00Z @AI: Initial ComponentEntry implementation.
 
 /// Entry for a registered component
@@ -46,6 +47,13 @@ mod tests {
         role: crate::graph::role::Role::Entity,
         type_name: "TestType",
         module_path: "test",
+        returns: None,
+        tags: Vec::new(),
+        source_file: None,
+        source_line: None,
+        required_methods: 0,
+        default_methods: 0,
+        forbidden_layers: Vec::new(),
       }
     }
 
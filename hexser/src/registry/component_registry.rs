@@ -4,6 +4,9 @@
 //! methods to build the architecture graph.
 //!
 //! Revision History
+//! - 2026-10-19T06:25:00Z @AI: Add build_graph_with for preconfigured builders.
+//! - 2026-10-18T23:30:00Z @AI: Weight dependency edges by repeated declarations.
+//! - 2026-10-18T09:00:00Z @AI: Build nodes via node_builder so NodeInfo metadata is preserved.
 //! - 2025-10-02T12:00:00Z @AI: Fix HexNode construction to use with_metadata method.
 //! - 2025-10-02T00:00:00Z @AI: Initial ComponentRegistry implementation.
 
@@ -13,31 +16,30 @@ pub struct ComponentRegistry;
 impl ComponentRegistry {
   /// Build a graph from all registered components
   pub fn build_graph() -> crate::graph::hex_graph::HexGraph {
-    let mut builder = crate::graph::builder::GraphBuilder::new();
+    Self::build_graph_with(crate::graph::builder::GraphBuilder::new())
+  }
 
+  /// Build a graph from all registered components, starting from `builder`
+  ///
+  /// The builder's configuration, such as `exclude_modules`, applies to the
+  /// registered components; nodes and edges already added are kept.
+  pub fn build_graph_with(
+    mut builder: crate::graph::builder::GraphBuilder,
+  ) -> crate::graph::hex_graph::HexGraph {
     for entry in inventory::iter::<crate::registry::component_entry::ComponentEntry> {
       let info = entry.node_info();
-      let node_id = crate::graph::node_id::NodeId::from_type_name(info.type_name);
-
-      let metadata = std::collections::HashMap::new();
-
-      let node = crate::graph::hex_node::HexNode::with_metadata(
-        node_id,
-        info.layer,
-        info.role,
-        info.type_name,
-        info.module_path,
-        metadata,
-      );
+      let node = crate::registry::node_builder::build_node_from_info(info);
+      let node_id = *node.id();
 
       builder = builder.add_node(node);
 
-      for dep_id in entry.dependencies() {
+      for (dep_id, weight) in Self::weigh_dependencies(entry.dependencies()) {
         let edge = crate::graph::hex_edge::HexEdge::new(
           node_id,
           dep_id,
           crate::graph::relationship::Relationship::Depends,
-        );
+        )
+        .with_weight(weight);
         builder = builder.add_edge(edge);
       }
     }
@@ -45,6 +47,23 @@ impl ComponentRegistry {
     builder.build()
   }
 
+  /// Collapse repeated dependency declarations into weighted edges
+  ///
+  /// Each distinct dependency appears once, in first-declaration order,
+  /// weighted by how many times it was declared.
+  fn weigh_dependencies(
+    dependencies: Vec<crate::graph::node_id::NodeId>,
+  ) -> Vec<(crate::graph::node_id::NodeId, u32)> {
+    let mut weighted: Vec<(crate::graph::node_id::NodeId, u32)> = Vec::new();
+    for dep_id in dependencies {
+      match weighted.iter_mut().find(|(id, _)| *id == dep_id) {
+        Some((_, weight)) => *weight = weight.saturating_add(1),
+        None => weighted.push((dep_id, 1)),
+      }
+    }
+    weighted
+  }
+
   /// Count registered components
   pub fn component_count() -> usize {
     inventory::iter::<crate::registry::component_entry::ComponentEntry>().count()
@@ -55,6 +74,15 @@ impl ComponentRegistry {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_repeated_dependencies_accumulate_weight() {
+    let a = crate::graph::node_id::NodeId::from_name("A");
+    let b = crate::graph::node_id::NodeId::from_name("B");
+
+    let weighted = ComponentRegistry::weigh_dependencies(vec![a, b, a, a]);
+    assert_eq!(weighted, vec![(a, 3), (b, 1)]);
+  }
+
   #[test]
   fn test_registry_operations() {
     let count = ComponentRegistry::component_count();
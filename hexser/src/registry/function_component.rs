@@ -0,0 +1,170 @@
+//! Registration support for function-style components.
+//!
+//! Small applications often implement use cases as plain functions rather
+//! than types. FunctionComponent wraps such a function together with the
+//! metadata needed for graph registration, and the `hex_register_fn!` macro
+//! registers a named function as an Application-layer node so it appears in
+//! `HexGraph::current()` alongside derived components.
+//!
+//! Revision History
+//! - 2026-10-18T21:45:00Z @AI: Capture the hex_register_fn! call site as the source location.
+//! - 2026-10-18T21:00:00Z @AI: Initial FunctionComponent wrapper and hex_register_fn! macro.
+
+/// A function registered as an Application-layer component.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::graph::{Layer, Role};
+/// use hexser::registry::FunctionComponent;
+///
+/// fn greet(name: &str) -> String {
+///     format!("Hello, {}", name)
+/// }
+///
+/// let component = FunctionComponent::new("greet", module_path!(), Role::UseCase, greet);
+/// assert_eq!((component.func())("Ada"), "Hello, Ada");
+///
+/// let info = component.node_info();
+/// assert_eq!(info.layer, Layer::Application);
+/// assert_eq!(info.type_name, "greet");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionComponent<F> {
+  name: &'static str,
+  module_path: &'static str,
+  role: crate::graph::role::Role,
+  func: F,
+}
+
+impl<F> FunctionComponent<F> {
+  /// Wrap `func` under `name` with the given role
+  pub const fn new(
+    name: &'static str,
+    module_path: &'static str,
+    role: crate::graph::role::Role,
+    func: F,
+  ) -> Self {
+    Self {
+      name,
+      module_path,
+      role,
+      func,
+    }
+  }
+
+  /// Get the function's registered name
+  pub fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Get the component's role
+  pub fn role(&self) -> crate::graph::role::Role {
+    self.role
+  }
+
+  /// Get the wrapped function
+  pub fn func(&self) -> &F {
+    &self.func
+  }
+
+  /// Unwrap the function
+  pub fn into_inner(self) -> F {
+    self.func
+  }
+
+  /// Build node info placing the function in the Application layer
+  pub fn node_info(&self) -> crate::registry::node_info::NodeInfo {
+    crate::registry::node_info::NodeInfo::new(
+      crate::graph::layer::Layer::Application,
+      self.role,
+      self.name,
+      self.module_path,
+    )
+  }
+}
+
+/// Register a named function as an Application-layer graph node.
+///
+/// The node's type name is the function's name and its module path is the
+/// invoking module. A hidden marker type carries the Registrable impl, so
+/// the function itself is left untouched.
+///
+/// ```rust
+/// use hexser::graph::Role;
+///
+/// fn archive_order(id: u64) -> bool {
+///     id > 0
+/// }
+///
+/// hexser::hex_register_fn!(archive_order, Role::UseCase);
+///
+/// let graph = hexser::graph::HexGraph::current();
+/// assert!(graph.nodes().any(|n| n.type_name() == "archive_order"));
+/// ```
+#[macro_export]
+macro_rules! hex_register_fn {
+  ($f:ident, $role:expr) => {
+    const _: () = {
+      struct HexFunctionComponent;
+
+      impl $crate::registry::Registrable for HexFunctionComponent {
+        fn node_info() -> $crate::registry::NodeInfo {
+          $crate::registry::FunctionComponent::new(
+            ::std::stringify!($f),
+            ::std::module_path!(),
+            $role,
+            $f,
+          )
+          .node_info()
+          .with_source_location(::std::file!(), ::std::line!())
+        }
+
+        fn dependencies() -> ::std::vec::Vec<$crate::graph::NodeId> {
+          ::std::vec![]
+        }
+      }
+
+      $crate::inventory::submit! {
+        $crate::registry::ComponentEntry::new::<HexFunctionComponent>()
+      }
+    };
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  fn place_order(quantity: u32) -> u32 {
+    quantity * 2
+  }
+
+  crate::hex_register_fn!(place_order, crate::graph::role::Role::UseCase);
+
+  #[test]
+  fn test_function_component_wraps_and_describes_function() {
+    let component = crate::registry::function_component::FunctionComponent::new(
+      "place_order",
+      module_path!(),
+      crate::graph::role::Role::DirectiveHandler,
+      place_order,
+    );
+    assert_eq!((component.func())(2), 4);
+
+    let info = component.node_info();
+    assert_eq!(info.layer, crate::graph::layer::Layer::Application);
+    assert_eq!(info.role, crate::graph::role::Role::DirectiveHandler);
+    assert_eq!(info.type_name, "place_order");
+    assert_eq!(info.module_path, module_path!());
+  }
+
+  #[test]
+  fn test_hex_register_fn_adds_node_to_graph() {
+    let graph = crate::registry::component_registry::ComponentRegistry::build_graph();
+    let node = graph
+      .nodes()
+      .find(|n| n.type_name() == "place_order")
+      .expect("registered function node");
+    assert_eq!(node.layer(), crate::graph::layer::Layer::Application);
+    assert_eq!(node.role(), crate::graph::role::Role::UseCase);
+  }
+}
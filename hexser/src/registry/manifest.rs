@@ -0,0 +1,169 @@
+//! Flat component manifest for portals and search indexes.
+//!
+//! Manifest lists every registered component with its layer, role, type,
+//! module, tags, and declared dependencies. Unlike graph exports it is
+//! registry-centric and flat: one record per component, with dependencies
+//! resolved to type names, which suits indexing and developer portals.
+//!
+//! Revision History
+//! - 2026-10-18T23:15:00Z @AI: Initial Manifest and ManifestEntry.
+
+/// Version of the manifest layout, bumped on incompatible changes
+pub const MANIFEST_SCHEMA_VERSION: &str = "1.0.0";
+
+/// Machine-readable listing of registered components
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Manifest {
+  /// Manifest layout version (see MANIFEST_SCHEMA_VERSION)
+  pub schema_version: String,
+  /// Generation time as a Unix timestamp in seconds
+  pub generated_at: u64,
+  /// Components sorted by module path then type name
+  pub components: Vec<ManifestEntry>,
+}
+
+/// One component in a Manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ManifestEntry {
+  pub layer: String,
+  pub role: String,
+  pub type_name: String,
+  pub module_path: String,
+  pub tags: Vec<String>,
+  /// Type names of declared dependencies; unregistered ones keep their NodeId
+  pub dependencies: Vec<String>,
+}
+
+impl Manifest {
+  /// Build a manifest from component info and declared dependencies
+  pub fn from_components(
+    components: Vec<(
+      crate::registry::node_info::NodeInfo,
+      Vec<crate::graph::node_id::NodeId>,
+    )>,
+  ) -> Self {
+    let names: std::collections::HashMap<crate::graph::node_id::NodeId, &'static str> = components
+      .iter()
+      .map(|(info, _)| {
+        (
+          crate::graph::node_id::NodeId::from_type_name(info.type_name),
+          info.type_name,
+        )
+      })
+      .collect();
+
+    let mut entries: Vec<ManifestEntry> = components
+      .iter()
+      .map(|(info, dependencies)| ManifestEntry {
+        layer: info.layer.to_string(),
+        role: info.role.to_string(),
+        type_name: String::from(info.type_name),
+        module_path: String::from(info.module_path),
+        tags: info.tags.iter().map(|t| String::from(*t)).collect(),
+        dependencies: dependencies
+          .iter()
+          .map(|id| match names.get(id) {
+            Some(name) => String::from(*name),
+            None => id.to_string(),
+          })
+          .collect(),
+      })
+      .collect();
+    entries.sort_by(|a, b| (&a.module_path, &a.type_name).cmp(&(&b.module_path, &b.type_name)));
+
+    Self {
+      schema_version: String::from(MANIFEST_SCHEMA_VERSION),
+      generated_at: std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0),
+      components: entries,
+    }
+  }
+
+  /// Serialize the manifest to pretty-printed JSON
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  pub fn to_json(&self) -> crate::result::hex_result::HexResult<String> {
+    serde_json::to_string_pretty(self).map_err(|e| {
+      crate::error::hex_error::Hexserror::adapter(
+        crate::error::codes::adapter::MAPPING_FAILURE,
+        &format!("Manifest serialization failed: {}", e),
+      )
+    })
+  }
+}
+
+impl crate::registry::component_registry::ComponentRegistry {
+  /// Build a flat manifest of all registered components
+  pub fn to_manifest(&self) -> Manifest {
+    Manifest::from_components(
+      inventory::iter::<crate::registry::component_entry::ComponentEntry>
+        .into_iter()
+        .map(|entry| (entry.node_info(), entry.dependencies()))
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn info(type_name: &'static str) -> crate::registry::node_info::NodeInfo {
+    crate::registry::node_info::NodeInfo::new(
+      crate::graph::layer::Layer::Port,
+      crate::graph::role::Role::Repository,
+      type_name,
+      "app::ports",
+    )
+  }
+
+  #[test]
+  fn test_manifest_resolves_dependencies_and_sorts() {
+    let manifest = Manifest::from_components(vec![
+      (
+        info("OrderRepository").with_tags(&["core"]),
+        vec![
+          crate::graph::node_id::NodeId::from_type_name("CustomerRepository"),
+          crate::graph::node_id::NodeId::from_type_name("External"),
+        ],
+      ),
+      (info("CustomerRepository"), vec![]),
+    ]);
+
+    assert_eq!(manifest.schema_version, MANIFEST_SCHEMA_VERSION);
+    assert!(manifest.generated_at > 0);
+    assert_eq!(manifest.components[0].type_name, "CustomerRepository");
+    let order = &manifest.components[1];
+    assert_eq!(order.layer, "Port");
+    assert_eq!(order.role, "Repository");
+    assert_eq!(order.tags, vec!["core"]);
+    assert_eq!(order.dependencies[0], "CustomerRepository");
+    assert_eq!(
+      order.dependencies[1],
+      crate::graph::node_id::NodeId::from_type_name("External").to_string()
+    );
+  }
+
+  #[test]
+  fn test_registry_manifest_lists_registered_components() {
+    let manifest = crate::registry::component_registry::ComponentRegistry.to_manifest();
+    assert_eq!(
+      manifest.components.len(),
+      crate::registry::component_registry::ComponentRegistry::component_count()
+    );
+  }
+
+  #[cfg(all(feature = "serde", feature = "serde_json"))]
+  #[test]
+  fn test_manifest_to_json() {
+    let json = Manifest::from_components(vec![(info("OrderRepository"), vec![])])
+      .to_json()
+      .unwrap();
+    assert!(json.contains("\"schema_version\": \"1.0.0\""));
+    assert!(json.contains("\"generated_at\""));
+    assert!(json.contains("\"module_path\": \"app::ports\""));
+  }
+}
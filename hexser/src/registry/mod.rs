@@ -4,16 +4,25 @@
 //! at compile time using the inventory pattern, enabling automatic graph construction.
 //!
 //! Revision History
+//! - 2026-10-19T04:25:00Z @AI: Add port_registry for runtime adapter lookup.
+//! - 2026-10-18T23:15:00Z @AI: Add manifest for flat component listings.
+//! - 2026-10-18T21:00:00Z @AI: Add function_component for registering plain functions.
 //! - 2025-10-02T00:00:00Z @AI: Initial Phase 3 registry implementation.
 
 pub mod component_entry;
 pub mod component_registry;
+pub mod function_component;
 pub mod inventory_integration;
+pub mod manifest;
 pub mod node_builder;
 pub mod node_info;
+pub mod port_registry;
 pub mod registrable;
 
 pub use component_entry::ComponentEntry;
 pub use component_registry::ComponentRegistry;
+pub use function_component::FunctionComponent;
+pub use manifest::{Manifest, ManifestEntry};
 pub use node_info::NodeInfo;
+pub use port_registry::PortRegistry;
 pub use registrable::Registrable;
@@ -3,6 +3,12 @@
 //! Converts NodeInfo into HexNode for graph construction.
 //!
 //! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Record forbidden dependency layers in node metadata.
+//! - 2026-10-19T02:25:00Z @AI: Record port trait method counts in node metadata.
+//! - 2026-10-19T01:05:00Z @AI: Record inferred intent verb and target in node metadata.
+//! - 2026-10-18T21:45:00Z @AI: Capture declaration file and line in NodeInfo.
+//! - 2026-10-18T20:00:00Z @AI: Copy NodeInfo tags onto the built node.
+//! - 2026-10-18T09:00:00Z @AI: Record declared query result type in node metadata.
 //! - 2025-10-02T12:30:00Z @AI: Fix HexNode construction to use with_metadata.
 //! - 2025-10-02T00:00:00Z @AI: Initial node builder implementation.
 
@@ -11,7 +17,7 @@ pub fn build_node_from_info(
   info: crate::registry::node_info::NodeInfo,
 ) -> crate::graph::hex_node::HexNode {
   let node_id = crate::graph::node_id::NodeId::from_type_name(info.type_name);
-  let metadata = std::collections::HashMap::new();
+  let metadata = build_metadata_from_info(&info);
 
   crate::graph::hex_node::HexNode::with_metadata(
     node_id,
@@ -21,6 +27,83 @@ pub fn build_node_from_info(
     info.module_path,
     metadata,
   )
+  .with_tags(info.tags)
+}
+
+/// Metadata key holding the result type of a query node
+pub const RETURNS_METADATA_KEY: &str = "returns";
+
+/// Metadata key holding the file a component is declared in
+pub const SOURCE_FILE_METADATA_KEY: &str = "source_file";
+
+/// Metadata key holding the line a component is declared on
+pub const SOURCE_LINE_METADATA_KEY: &str = "source_line";
+
+/// Metadata key holding the inferred intent verb of a directive or query
+pub const INTENT_METADATA_KEY: &str = "intent";
+
+/// Metadata key holding the inferred intent target of a directive or query
+pub const INTENT_TARGET_METADATA_KEY: &str = "intent_target";
+
+/// Metadata key holding the number of required methods of a port trait
+pub const REQUIRED_METHODS_METADATA_KEY: &str = "required_methods";
+
+/// Metadata key holding the number of defaulted methods of a port trait
+pub const DEFAULT_METHODS_METADATA_KEY: &str = "default_methods";
+
+/// Metadata key holding the comma-separated layers a node must not depend on
+pub const FORBID_DEPENDS_LAYER_METADATA_KEY: &str = "forbid_depends_layer";
+
+/// Build node metadata from NodeInfo
+///
+/// Queries always carry a `returns` entry; when the result type was not
+/// declared it is recorded as `unknown`. Directives and queries whose name
+/// yields an intent carry `intent` and `intent_target` entries, and port
+/// traits with any methods carry `required_methods` and `default_methods`.
+/// Declared dependency policies are recorded as `forbid_depends_layer`.
+/// Source file and line are recorded when known; like error locations they
+/// stay in memory and are redacted on output according to `error::env_control`.
+pub fn build_metadata_from_info(
+  info: &crate::registry::node_info::NodeInfo,
+) -> std::collections::HashMap<String, String> {
+  let mut metadata = std::collections::HashMap::new();
+  match info.returns {
+    Some(returns) => {
+      metadata.insert(String::from(RETURNS_METADATA_KEY), String::from(returns));
+    }
+    None if info.role == crate::graph::role::Role::Query => {
+      metadata.insert(String::from(RETURNS_METADATA_KEY), String::from("unknown"));
+    }
+    None => {}
+  }
+  if let Some(intent) = info.intent() {
+    metadata.insert(String::from(INTENT_METADATA_KEY), intent.verb);
+    metadata.insert(String::from(INTENT_TARGET_METADATA_KEY), intent.target);
+  }
+  if info.required_methods + info.default_methods > 0 {
+    metadata.insert(
+      String::from(REQUIRED_METHODS_METADATA_KEY),
+      info.required_methods.to_string(),
+    );
+    metadata.insert(
+      String::from(DEFAULT_METHODS_METADATA_KEY),
+      info.default_methods.to_string(),
+    );
+  }
+  if !info.forbidden_layers.is_empty() {
+    let layers: Vec<&str> = info.forbidden_layers.iter().map(|l| l.as_str()).collect();
+    metadata.insert(
+      String::from(FORBID_DEPENDS_LAYER_METADATA_KEY),
+      layers.join(","),
+    );
+  }
+  if let Some(file) = info.source_file {
+    metadata.insert(String::from(SOURCE_FILE_METADATA_KEY), String::from(file));
+  }
+  if let Some(line) = info.source_line {
+    metadata.insert(String::from(SOURCE_LINE_METADATA_KEY), line.to_string());
+  }
+  metadata
 }
 
 #[cfg(test)]
@@ -34,9 +117,102 @@ mod tests {
       role: crate::graph::role::Role::Entity,
       type_name: "TestNode",
       module_path: "test",
+      returns: None,
+      tags: vec!["pii"],
+      source_file: None,
+      source_line: None,
+      required_methods: 0,
+      default_methods: 0,
+      forbidden_layers: Vec::new(),
     };
 
     let node = build_node_from_info(info);
     assert_eq!(node.type_name(), "TestNode");
+    assert_eq!(node.get_metadata(RETURNS_METADATA_KEY), None);
+    assert!(node.has_tag("pii"));
+    assert_eq!(node.get_metadata(SOURCE_FILE_METADATA_KEY), None);
+    assert_eq!(node.get_metadata(REQUIRED_METHODS_METADATA_KEY), None);
+  }
+
+  #[test]
+  fn test_build_node_records_source_location() {
+    let info = crate::registry::node_info::NodeInfo::new(
+      crate::graph::layer::Layer::Domain,
+      crate::graph::role::Role::Entity,
+      "Located",
+      "test",
+    )
+    .with_source_location("src/domain/located.rs", 7);
+
+    let node = build_node_from_info(info);
+    assert_eq!(
+      node
+        .get_metadata(SOURCE_FILE_METADATA_KEY)
+        .map(String::as_str),
+      Some("src/domain/located.rs")
+    );
+    assert_eq!(
+      node
+        .get_metadata(SOURCE_LINE_METADATA_KEY)
+        .map(String::as_str),
+      Some("7")
+    );
+  }
+
+  #[test]
+  fn test_build_node_records_method_counts() {
+    let info = crate::registry::node_info::NodeInfo::new(
+      crate::graph::layer::Layer::Port,
+      crate::graph::role::Role::Repository,
+      "OrderRepository",
+      "test",
+    )
+    .with_method_counts(3, 2);
+
+    let node = build_node_from_info(info);
+    assert_eq!(
+      node.get_metadata(REQUIRED_METHODS_METADATA_KEY),
+      Some(&String::from("3"))
+    );
+    assert_eq!(
+      node.get_metadata(DEFAULT_METHODS_METADATA_KEY),
+      Some(&String::from("2"))
+    );
+  }
+
+  #[test]
+  fn test_build_node_records_query_returns() {
+    let declared = crate::registry::node_info::NodeInfo::new(
+      crate::graph::layer::Layer::Application,
+      crate::graph::role::Role::Query,
+      "ListOrders",
+      "test",
+    )
+    .with_returns("Vec<Order>");
+    let node = build_node_from_info(declared);
+    assert_eq!(
+      node.get_metadata(RETURNS_METADATA_KEY),
+      Some(&String::from("Vec<Order>"))
+    );
+    assert_eq!(
+      node.get_metadata(INTENT_METADATA_KEY),
+      Some(&String::from("List"))
+    );
+    assert_eq!(
+      node.get_metadata(INTENT_TARGET_METADATA_KEY),
+      Some(&String::from("Orders"))
+    );
+
+    let undeclared = crate::registry::node_info::NodeInfo::new(
+      crate::graph::layer::Layer::Application,
+      crate::graph::role::Role::Query,
+      "FindOrder",
+      "test",
+    );
+    let node = build_node_from_info(undeclared);
+    assert_eq!(
+      node.get_metadata(RETURNS_METADATA_KEY),
+      Some(&String::from("unknown"))
+    );
   }
 }
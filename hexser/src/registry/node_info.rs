@@ -3,6 +3,12 @@
 //! Contains metadata about a component for graph node construction.
 //!
 //! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Add forbidden_layers dependency policy.
+//! - 2026-10-19T02:25:00Z @AI: Add required and default method counts for port traits.
+//! - 2026-10-19T01:05:00Z @AI: Add intent inferred from directive and query names.
+//! - 2026-10-18T21:45:00Z @AI: Add source_file and source_line for jump-to-definition tooling.
+//! - 2026-10-18T20:00:00Z @AI: Add tags for custom node classification.
+//! - 2026-10-18T09:00:00Z @AI: Add optional returns field describing a query's result type.
 //! - 2025-10-02T00:00:00Z @AI: Initial NodeInfo implementation.
 
 /// Metadata about a component for registration
@@ -12,6 +18,20 @@ pub struct NodeInfo {
   pub role: crate::graph::role::Role,
   pub type_name: &'static str,
   pub module_path: &'static str,
+  /// Result type produced by a query (e.g. `Vec<Order>`), if declared
+  pub returns: Option<&'static str>,
+  /// Free-form labels such as `pii` or `external`
+  pub tags: Vec<&'static str>,
+  /// File declaring the component, as captured by `file!()`
+  pub source_file: Option<&'static str>,
+  /// Line of the declaration (the derive or registration macro call)
+  pub source_line: Option<u32>,
+  /// Trait methods without a default body; zero for non-trait components
+  pub required_methods: u32,
+  /// Trait methods with a default body; zero for non-trait components
+  pub default_methods: u32,
+  /// Layers this component must not depend on, from `#[hex(forbid_depends_layer)]`
+  pub forbidden_layers: Vec<crate::graph::layer::Layer>,
 }
 
 impl NodeInfo {
@@ -27,8 +47,61 @@ impl NodeInfo {
       role,
       type_name,
       module_path,
+      returns: None,
+      tags: Vec::new(),
+      source_file: None,
+      source_line: None,
+      required_methods: 0,
+      default_methods: 0,
+      forbidden_layers: Vec::new(),
     }
   }
+
+  /// Declare the result type this component produces (builder pattern)
+  pub fn with_returns(mut self, returns: &'static str) -> Self {
+    self.returns = Some(returns);
+    self
+  }
+
+  /// Attach classification tags (builder pattern)
+  pub fn with_tags(mut self, tags: &[&'static str]) -> Self {
+    self.tags.extend_from_slice(tags);
+    self
+  }
+
+  /// Verb and target inferred from the type name of a directive or query
+  ///
+  /// Other roles return None; see `graph::naming` for the parsing rules.
+  pub fn intent(&self) -> Option<crate::graph::naming::InferredIntent> {
+    match self.role {
+      crate::graph::role::Role::Directive | crate::graph::role::Role::Query => {
+        crate::graph::naming::infer_intent(self.type_name)
+      }
+      _ => None,
+    }
+  }
+
+  /// Record how many methods a port trait declares (builder pattern)
+  pub fn with_method_counts(mut self, required: u32, defaulted: u32) -> Self {
+    self.required_methods = required;
+    self.default_methods = defaulted;
+    self
+  }
+
+  /// Forbid dependencies on the given layers (builder pattern)
+  ///
+  /// Checked against the built graph by `HexGraph::check_node_policies`.
+  pub fn with_forbidden_layers(mut self, layers: &[crate::graph::layer::Layer]) -> Self {
+    self.forbidden_layers.extend_from_slice(layers);
+    self
+  }
+
+  /// Record where the component is declared (builder pattern)
+  pub fn with_source_location(mut self, file: &'static str, line: u32) -> Self {
+    self.source_file = Some(file);
+    self.source_line = Some(line);
+    self
+  }
 }
 
 #[cfg(test)]
@@ -46,5 +119,34 @@ mod tests {
 
     assert_eq!(info.type_name, "TestType");
     assert_eq!(info.module_path, "test::module");
+    assert_eq!(info.returns, None);
+    assert_eq!(info.source_file, None);
+  }
+
+  #[test]
+  fn test_node_info_with_source_location() {
+    let info = NodeInfo::new(
+      crate::graph::layer::Layer::Domain,
+      crate::graph::role::Role::Entity,
+      "TestType",
+      "test::module",
+    )
+    .with_source_location("src/domain/test.rs", 12);
+
+    assert_eq!(info.source_file, Some("src/domain/test.rs"));
+    assert_eq!(info.source_line, Some(12));
+  }
+
+  #[test]
+  fn test_node_info_with_returns() {
+    let info = NodeInfo::new(
+      crate::graph::layer::Layer::Application,
+      crate::graph::role::Role::Query,
+      "ListOrders",
+      "test::module",
+    )
+    .with_returns("Vec<Order>");
+
+    assert_eq!(info.returns, Some("Vec<Order>"));
   }
 }
@@ -0,0 +1,221 @@
+//! Runtime registry of adapters keyed by port name.
+//!
+//! The component graph is built at compile time, which does not cover
+//! adapters loaded dynamically, such as plugins. PortRegistry fills that gap:
+//! adapters register themselves under a port name as boxed trait objects and
+//! are looked up by the same name and trait object type. Several adapters
+//! may share one port name; they are kept in registration order.
+//!
+//! Revision History
+//! - 2026-10-19T04:25:00Z @AI: Initial PortRegistry.
+
+/// Runtime map from port names to adapter trait objects.
+///
+/// # Example
+///
+/// ```rust
+/// use hexser::registry::PortRegistry;
+///
+/// trait Greeter {
+///     fn greet(&self) -> String;
+/// }
+///
+/// struct English;
+///
+/// impl Greeter for English {
+///     fn greet(&self) -> String {
+///         String::from("hello")
+///     }
+/// }
+///
+/// let mut registry = PortRegistry::new();
+/// registry.register::<dyn Greeter>("greeter", Box::new(English));
+///
+/// assert_eq!(registry.get::<dyn Greeter>("greeter").unwrap().greet(), "hello");
+/// assert!(registry.get::<dyn std::fmt::Debug>("greeter").is_err());
+/// ```
+#[derive(Default)]
+pub struct PortRegistry {
+  ports: std::collections::BTreeMap<String, Vec<RegisteredAdapter>>,
+}
+
+struct RegisteredAdapter {
+  type_name: &'static str,
+  adapter: Box<dyn std::any::Any>,
+}
+
+impl PortRegistry {
+  /// Create an empty registry
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Register `adapter` under `name` as a `T`, usually a `dyn Port`.
+  ///
+  /// Adapters registered under a name that is already taken are appended
+  /// after the existing ones.
+  pub fn register<T: ?Sized + 'static>(&mut self, name: impl Into<String>, adapter: Box<T>) {
+    self
+      .ports
+      .entry(name.into())
+      .or_default()
+      .push(RegisteredAdapter {
+        type_name: std::any::type_name::<T>(),
+        adapter: Box::new(adapter),
+      });
+  }
+
+  /// Get the first adapter registered under `name` as a `T`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `E_HEX_101` when nothing is registered under `name`, and
+  /// `E_HEX_103` when the adapter was registered as a different type.
+  pub fn get<T: ?Sized + 'static>(&self, name: &str) -> crate::result::hex_result::HexResult<&T> {
+    let first = self.entries(name)?.first().ok_or_else(|| not_found(name))?;
+    downcast(name, first)
+  }
+
+  /// Get every adapter registered under `name` as a `T`, in registration
+  /// order.
+  ///
+  /// # Errors
+  ///
+  /// Returns `E_HEX_101` when nothing is registered under `name`, and
+  /// `E_HEX_103` when any adapter was registered as a different type.
+  pub fn get_all<T: ?Sized + 'static>(
+    &self,
+    name: &str,
+  ) -> crate::result::hex_result::HexResult<Vec<&T>> {
+    self
+      .entries(name)?
+      .iter()
+      .map(|entry| downcast(name, entry))
+      .collect()
+  }
+
+  /// Check whether any adapter is registered under `name`
+  pub fn contains(&self, name: &str) -> bool {
+    self.ports.contains_key(name)
+  }
+
+  /// Registered port names, in sorted order
+  pub fn names(&self) -> impl Iterator<Item = &str> {
+    self.ports.keys().map(String::as_str)
+  }
+
+  fn entries(&self, name: &str) -> crate::result::hex_result::HexResult<&[RegisteredAdapter]> {
+    self
+      .ports
+      .get(name)
+      .map(Vec::as_slice)
+      .ok_or_else(|| not_found(name))
+  }
+}
+
+impl std::fmt::Debug for PortRegistry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_map()
+      .entries(self.ports.iter().map(|(name, entries)| {
+        let types: Vec<&str> = entries.iter().map(|e| e.type_name).collect();
+        (name, types)
+      }))
+      .finish()
+  }
+}
+
+fn downcast<'r, T: ?Sized + 'static>(
+  name: &str,
+  entry: &'r RegisteredAdapter,
+) -> crate::result::hex_result::HexResult<&'r T> {
+  entry
+    .adapter
+    .downcast_ref::<Box<T>>()
+    .map(|adapter| adapter.as_ref())
+    .ok_or_else(|| {
+      crate::error::hex_error::Hexserror::port(
+        crate::error::codes::port::TYPE_MISMATCH,
+        &format!(
+          "Port '{}' holds {}, not {}",
+          name,
+          entry.type_name,
+          std::any::type_name::<T>()
+        ),
+      )
+      .with_next_step("Request the trait object type the adapter was registered as")
+    })
+}
+
+fn not_found(name: &str) -> crate::error::hex_error::Hexserror {
+  crate::error::hex_error::Hexserror::port(
+    crate::error::codes::port::PORT_NOT_FOUND,
+    &format!("No adapter registered for port '{}'", name),
+  )
+  .with_next_step("Register an adapter under this port name before looking it up")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  trait Storage {
+    fn backend(&self) -> &'static str;
+  }
+
+  struct Disk;
+
+  impl Storage for Disk {
+    fn backend(&self) -> &'static str {
+      "disk"
+    }
+  }
+
+  struct Memory;
+
+  impl Storage for Memory {
+    fn backend(&self) -> &'static str {
+      "memory"
+    }
+  }
+
+  #[test]
+  fn test_multiple_adapters_keep_registration_order() {
+    let mut registry = PortRegistry::new();
+    registry.register::<dyn Storage>("storage", Box::new(Disk));
+    registry.register::<dyn Storage>("storage", Box::new(Memory));
+
+    assert_eq!(
+      registry.get::<dyn Storage>("storage").unwrap().backend(),
+      "disk"
+    );
+    let backends: Vec<&str> = registry
+      .get_all::<dyn Storage>("storage")
+      .unwrap()
+      .iter()
+      .map(|s| s.backend())
+      .collect();
+    assert_eq!(backends, vec!["disk", "memory"]);
+    assert_eq!(registry.names().collect::<Vec<_>>(), vec!["storage"]);
+  }
+
+  #[test]
+  fn test_type_mismatch_names_both_types() {
+    let mut registry = PortRegistry::new();
+    registry.register::<dyn Storage>("storage", Box::new(Disk));
+
+    let err = registry.get::<Disk>("storage").err().unwrap();
+    assert_eq!(err.code(), crate::error::codes::port::TYPE_MISMATCH);
+    let message = err.to_string();
+    assert!(message.contains("dyn"));
+    assert!(message.contains("Storage"));
+    assert!(message.contains("Disk"));
+  }
+
+  #[test]
+  fn test_unknown_port_is_not_found() {
+    let registry = PortRegistry::new();
+    assert!(!registry.contains("storage"));
+    let err = registry.get_all::<dyn Storage>("storage").err().unwrap();
+    assert_eq!(err.code(), crate::error::codes::port::PORT_NOT_FOUND);
+  }
+}
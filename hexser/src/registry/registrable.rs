@@ -4,6 +4,7 @@
 //! metadata for automatic graph construction.
 //!
 //! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Fill forbidden_layers in test NodeInfo literals.
 //! - 2025-10-02T12:30:00Z @AI: Add Sized bound to register_self method.
 //! - 2025-10-02T00:00:00Z @AI: Initial Registrable trait implementation.
 
@@ -37,6 +38,13 @@ mod tests {
         role: crate::graph::role::Role::Entity,
         type_name: "TestComponent",
         module_path: module_path!(),
+        returns: None,
+        tags: Vec::new(),
+        source_file: None,
+        source_line: None,
+        required_methods: 0,
+        default_methods: 0,
+        forbidden_layers: Vec::new(),
       }
     }
 
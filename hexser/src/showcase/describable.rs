@@ -4,6 +4,9 @@
 //! Components can describe their purpose, name, and category.
 //!
 //! Revision History
+//! - 2026-10-19T05:15:00Z @AI: Close describe_architecture with the graph's build info.
+//! - 2026-10-19T01:15:00Z @AI: Group directives and queries by inferred intent target.
+//! - 2026-10-18T20:30:00Z @AI: Add HexGraph::describe_architecture system-level overview.
 //! - 2025-10-02T19:00:00Z @AI: Complete rewrite to fix merge conflicts.
 
 /// Trait for self-describing components
@@ -74,6 +77,181 @@ impl Describable for std::sync::Arc<crate::graph::hex_graph::HexGraph> {
   }
 }
 
+impl crate::graph::hex_graph::HexGraph {
+  /// Describe the whole architecture as a short prose overview
+  ///
+  /// Covers component counts per layer, detected patterns (such as ports
+  /// implemented by adapters), directives and queries grouped by the target
+  /// inferred from their names, the most depended-upon components,
+  /// warnings from the health report, and the build info the graph was
+  /// produced with. Intended for onboarding docs.
+  pub fn describe_architecture(&self) -> String {
+    if self.is_empty() {
+      return String::from("The architecture has no registered components.");
+    }
+
+    let report = self.health_report();
+    let mut sections = vec![format!(
+      "The architecture has {} components connected by {} dependencies.",
+      report.node_count, report.edge_count
+    )];
+
+    let layer_lines: Vec<String> = report
+      .layers
+      .iter()
+      .map(|summary| {
+        let mut roles = std::collections::BTreeMap::new();
+        for node in self.nodes_by_layer(summary.layer) {
+          *roles.entry(node.role.as_str()).or_insert(0usize) += 1;
+        }
+        let roles: Vec<String> = roles
+          .iter()
+          .map(|(role, count)| format!("{} {}", count, role))
+          .collect();
+        format!(
+          "- {} layer: {} ({})",
+          summary.layer,
+          plural(summary.node_count, "component"),
+          roles.join(", ")
+        )
+      })
+      .collect();
+    sections.push(layer_lines.join("\n"));
+
+    let patterns = self.architecture_patterns();
+    if !patterns.is_empty() {
+      sections.push(format!("Patterns:\n{}", patterns.join("\n")));
+    }
+
+    let behavior = self.behavior_by_target();
+    if !behavior.is_empty() {
+      sections.push(format!("Behavior by target:\n{}", behavior.join("\n")));
+    }
+
+    if !report.most_depended_upon.is_empty() {
+      let hotspots: Vec<String> = report
+        .most_depended_upon
+        .iter()
+        .map(|h| format!("{} ({})", h.type_name, plural(h.dependents, "dependent")))
+        .collect();
+      sections.push(format!("Most depended upon: {}.", hotspots.join(", ")));
+    }
+
+    let mut warnings = Vec::new();
+    if report.cycle_count > 0 {
+      warnings.push(format!(
+        "- {} detected",
+        plural(report.cycle_count, "dependency cycle")
+      ));
+    }
+    if let Some(violations) = report.layer_violations.filter(|v| *v > 0) {
+      warnings.push(format!("- {}", plural(violations, "layer violation")));
+    }
+    if report.orphan_count > 0 {
+      warnings.push(format!(
+        "- {} without dependencies",
+        plural(report.orphan_count, "component")
+      ));
+    }
+    sections.push(if warnings.is_empty() {
+      String::from("No warnings.")
+    } else {
+      format!("Warnings:\n{}", warnings.join("\n"))
+    });
+    sections.push(format!("Provenance: {}.", self.metadata().build_info));
+
+    sections.join("\n\n")
+  }
+
+  /// Recognizable hexagonal patterns, one bullet per pattern found
+  fn architecture_patterns(&self) -> Vec<String> {
+    let port_roles = [
+      crate::graph::role::Role::Repository,
+      crate::graph::role::Role::InputPort,
+      crate::graph::role::Role::OutputPort,
+    ];
+    let mut patterns = Vec::new();
+
+    for role in port_roles {
+      let ports = self.nodes_by_role(role);
+      let implemented = ports
+        .iter()
+        .filter(|port| {
+          self
+            .edges_to(&port.id)
+            .iter()
+            .any(|e| e.relationship == crate::graph::relationship::Relationship::Implements)
+        })
+        .count();
+      if implemented > 0 {
+        patterns.push(format!(
+          "- {} of {} {} ports have adapter implementations",
+          implemented,
+          ports.len(),
+          role
+        ));
+      }
+    }
+
+    let application = [
+      (crate::graph::role::Role::Directive, "directive"),
+      (crate::graph::role::Role::Query, "query"),
+      (crate::graph::role::Role::UseCase, "use case"),
+    ];
+    let use_cases: Vec<String> = application
+      .iter()
+      .filter_map(|(role, label)| {
+        let count = self.nodes_by_role(*role).len();
+        (count > 0).then(|| plural(count, label))
+      })
+      .collect();
+    if !use_cases.is_empty() {
+      patterns.push(format!(
+        "- Application behavior is exposed through {}",
+        use_cases.join(", ")
+      ));
+    }
+
+    patterns
+  }
+
+  /// One line per inferred target listing the verbs acting on it
+  fn behavior_by_target(&self) -> Vec<String> {
+    let mut targets: std::collections::BTreeMap<String, Vec<String>> =
+      std::collections::BTreeMap::new();
+    for (role, label) in [
+      (crate::graph::role::Role::Directive, "directive"),
+      (crate::graph::role::Role::Query, "query"),
+    ] {
+      for node in self.nodes_by_role(role) {
+        if let Some(intent) = crate::graph::naming::infer_intent(&node.type_name) {
+          targets
+            .entry(intent.target)
+            .or_default()
+            .push(format!("{} ({})", intent.verb, label));
+        }
+      }
+    }
+    targets
+      .into_iter()
+      .map(|(target, mut verbs)| {
+        verbs.sort();
+        format!("- {}: {}", target, verbs.join(", "))
+      })
+      .collect()
+  }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+  if count == 1 {
+    format!("1 {}", noun)
+  } else if let Some(stem) = noun.strip_suffix('y') {
+    format!("{} {}ies", count, stem)
+  } else {
+    format!("{} {}s", count, noun)
+  }
+}
+
 /// Extension trait for pretty printing
 pub trait PrettyPrint {
   /// Print description
@@ -158,4 +336,107 @@ mod tests {
     let entities = graph.nodes_by_role(crate::graph::role::Role::Entity);
     assert_eq!(entities.len(), 1);
   }
+
+  #[test]
+  fn test_describe_architecture() {
+    let node = |name: &str, layer, role| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        layer,
+        role,
+        name,
+        "app",
+      )
+    };
+    let edge = |from: &str, to: &str, relationship| {
+      crate::graph::hex_edge::HexEdge::new(
+        crate::graph::node_id::NodeId::from_name(from),
+        crate::graph::node_id::NodeId::from_name(to),
+        relationship,
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "User",
+        crate::graph::layer::Layer::Domain,
+        crate::graph::role::Role::Entity,
+      ))
+      .with_node(node(
+        "UserRepository",
+        crate::graph::layer::Layer::Port,
+        crate::graph::role::Role::Repository,
+      ))
+      .with_node(node(
+        "PgUserRepository",
+        crate::graph::layer::Layer::Adapter,
+        crate::graph::role::Role::Adapter,
+      ))
+      .with_node(node(
+        "SignUp",
+        crate::graph::layer::Layer::Application,
+        crate::graph::role::Role::Directive,
+      ))
+      .with_edge(edge(
+        "PgUserRepository",
+        "UserRepository",
+        crate::graph::relationship::Relationship::Implements,
+      ))
+      .with_edge(edge(
+        "UserRepository",
+        "User",
+        crate::graph::relationship::Relationship::Depends,
+      ))
+      .with_app_version("2.3.1")
+      .build();
+
+    let overview = graph.describe_architecture();
+    assert!(overview.starts_with("The architecture has 4 components connected by 2 dependencies."));
+    assert!(overview.contains("- Domain layer: 1 component (1 Entity)"));
+    assert!(overview.contains("- 1 of 1 Repository ports have adapter implementations"));
+    assert!(overview.contains("Application behavior is exposed through 1 directive"));
+    assert!(
+      overview.contains("Most depended upon: User (1 dependent), UserRepository (1 dependent).")
+    );
+    assert!(overview.contains("Warnings:\n- 1 component without dependencies"));
+    assert!(overview.ends_with(&format!("Provenance: {}.", graph.metadata().build_info)));
+    assert!(overview.ends_with(", app 2.3.1."));
+  }
+
+  #[test]
+  fn test_describe_architecture_groups_behavior_by_target() {
+    let node = |name: &str, role| {
+      crate::graph::hex_node::HexNode::new(
+        crate::graph::node_id::NodeId::from_name(name),
+        crate::graph::layer::Layer::Application,
+        role,
+        name,
+        "app",
+      )
+    };
+    let graph = crate::graph::builder::GraphBuilder::new()
+      .with_node(node(
+        "CreateOrderDirective",
+        crate::graph::role::Role::Directive,
+      ))
+      .with_node(node("GetOrderQuery", crate::graph::role::Role::Query))
+      .with_node(node("CancelOrder", crate::graph::role::Role::Directive))
+      .with_node(node("ListUsers", crate::graph::role::Role::Query))
+      .with_node(node("Refresh", crate::graph::role::Role::Directive))
+      .build();
+
+    let overview = graph.describe_architecture();
+    assert!(overview.contains(
+      "Behavior by target:\n- Order: Cancel (directive), Create (directive), Get (query)\n- Users: List (query)"
+    ));
+    assert!(!overview.contains("Refresh"));
+  }
+
+  #[test]
+  fn test_describe_empty_architecture() {
+    let graph = crate::graph::builder::GraphBuilder::new().build();
+    assert_eq!(
+      graph.describe_architecture(),
+      "The architecture has no registered components."
+    );
+  }
 }
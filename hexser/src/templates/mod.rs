@@ -32,6 +32,19 @@
 //! hexser::hex_register_adapter!(PgUserRepo, Role::Adapter);
 //! ```
 //!
+//! ```rust
+//! use hexser::prelude::*;
+//!
+//! struct UserRepository;
+//!
+//! // Layer and role are inferred: Port / Repository
+//! hexser::hex_register_repository!(UserRepository);
+//!
+//! let info = <UserRepository as Registrable>::node_info();
+//! assert_eq!(info.layer, Layer::Port);
+//! assert_eq!(info.role, Role::Repository);
+//! ```
+//!
 //! These helpers are intended as templates: copy, adapt, and extend as needed.
 
 /// Split a fully-qualified Rust type path into (module_path, type_name).
@@ -54,6 +67,7 @@ macro_rules! hex_register_component {
         let full = ::std::any::type_name::<Self>();
         let (module_path, type_name) = $crate::templates::split_type_name(full);
         $crate::registry::NodeInfo::new($layer, $role, type_name, module_path)
+          .with_source_location(::std::file!(), ::std::line!())
       }
       fn dependencies() -> ::std::vec::Vec<$crate::graph::NodeId> {
         ::std::vec![]
@@ -102,16 +116,36 @@ macro_rules! hex_register_infrastructure {
   };
 }
 
+/// Convenience macro for repository ports; infers Port layer and Repository role.
+#[macro_export]
+macro_rules! hex_register_repository {
+  ($t:ty) => {
+    $crate::hex_register_port!($t, $crate::graph::Role::Repository);
+  };
+}
+
+/// Convenience macro for directives; infers Application layer and Directive role.
+#[macro_export]
+macro_rules! hex_register_directive {
+  ($t:ty) => {
+    $crate::hex_register_application!($t, $crate::graph::Role::Directive);
+  };
+}
+
 #[cfg(test)]
 mod tests {
   use crate::prelude::*;
 
   struct TDomain;
   struct TAdapter;
+  struct TRepository;
+  struct TDirective;
 
   // Use the macros to implement Registrable
   hex_register_domain!(TDomain, Role::Entity);
   hex_register_adapter!(TAdapter, Role::Adapter);
+  hex_register_repository!(TRepository);
+  hex_register_directive!(TDirective);
 
   #[test]
   fn test_domain_template_macro() {
@@ -119,6 +153,7 @@ mod tests {
     assert_eq!(info.layer, Layer::Domain);
     assert_eq!(info.role, Role::Entity);
     assert_eq!(info.type_name, "TDomain");
+    assert_eq!(info.source_file, Some(file!()));
   }
 
   #[test]
@@ -129,6 +164,23 @@ mod tests {
     assert_eq!(info.type_name, "TAdapter");
   }
 
+  #[test]
+  fn test_repository_template_infers_port_repository() {
+    let info = <TRepository as Registrable>::node_info();
+    assert_eq!(info.layer, Layer::Port);
+    assert_eq!(info.role, Role::Repository);
+    assert_eq!(info.type_name, "TRepository");
+  }
+
+  #[test]
+  fn test_directive_template_infers_application_directive() {
+    let info = <TDirective as Registrable>::node_info();
+    assert_eq!(info.layer, Layer::Application);
+    assert_eq!(info.role, Role::Directive);
+    assert_eq!(info.type_name, "TDirective");
+    assert!(<TDirective as Registrable>::dependencies().is_empty());
+  }
+
   #[test]
   fn test_split_type_name() {
     let (m, n) = super::split_type_name("foo::bar::Baz");
@@ -351,3 +351,63 @@ mod graph_thread_safety {
     handle.join().unwrap();
   }
 }
+
+#[cfg(all(feature = "analysis", feature = "serde", feature = "serde_json"))]
+mod graph_spec {
+  #[test]
+  fn test_sample_spec_file_rejects_adapter_to_domain_edge() {
+    let spec_path =
+      std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/arch_spec.json");
+    let spec = hexser::graph::spec::ArchSpec::from_file(&spec_path).unwrap();
+
+    let node = |name: &str, layer, role| {
+      hexser::graph::HexNode::new(
+        hexser::graph::NodeId::from_name(name),
+        layer,
+        role,
+        name,
+        "m",
+      )
+    };
+    let edge = |from: &str, to: &str| {
+      hexser::graph::HexEdge::new(
+        hexser::graph::NodeId::from_name(from),
+        hexser::graph::NodeId::from_name(to),
+        hexser::graph::Relationship::Depends,
+      )
+    };
+    let graph = hexser::graph::GraphBuilder::new()
+      .with_node(node(
+        "Order",
+        hexser::graph::Layer::Domain,
+        hexser::graph::Role::Entity,
+      ))
+      .with_node(node(
+        "OrderRepository",
+        hexser::graph::Layer::Port,
+        hexser::graph::Role::Repository,
+      ))
+      .with_node(node(
+        "PgOrderRepository",
+        hexser::graph::Layer::Adapter,
+        hexser::graph::Role::Adapter,
+      ))
+      .with_edge(edge("OrderRepository", "Order"))
+      .with_edge(edge("PgOrderRepository", "OrderRepository"))
+      .with_edge(edge("PgOrderRepository", "Order"))
+      .build();
+
+    let violations = graph.check_spec(&spec).unwrap_err();
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+      violations[0].from,
+      hexser::graph::NodeId::from_name("PgOrderRepository")
+    );
+    assert_eq!(violations[0].to, hexser::graph::NodeId::from_name("Order"));
+    assert_eq!(
+      violations[0].kind,
+      hexser::graph::spec::SpecViolationKind::NotAllowed
+    );
+  }
+}
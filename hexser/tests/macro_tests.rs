@@ -117,6 +117,63 @@ mod macro_tests {
     assert!(email.validate().is_ok());
   }
 
+  #[test]
+  fn test_hex_value_item_derive_validated_newtype() {
+    // Test: hex(validate) validates newtypes on TryFrom and exposes into_inner.
+    // Justification: Validated newtypes must reject invalid input at the boundary.
+    fn validate_email(value: &str) -> hexser::HexResult<()> {
+      if value.contains('@') {
+        Ok(())
+      } else {
+        Err(hexser::Hexserror::validation_field(
+          "Email must contain @",
+          "email",
+        ))
+      }
+    }
+
+    #[derive(HexValueItem, Debug)]
+    #[hex(validate = "validate_email")]
+    struct Email(String);
+
+    let email = Email::try_from(String::from("test@example.com")).unwrap();
+    assert!(email.validate().is_ok());
+    assert_eq!(email.into_inner(), "test@example.com");
+
+    let err = Email::try_from(String::from("invalid")).unwrap_err();
+    assert!(matches!(err, hexser::Hexserror::Validation(_)));
+
+    // Validation also applies to values constructed directly
+    assert!(Email(String::from("invalid")).validate().is_err());
+  }
+
+  #[test]
+  fn test_hex_value_item_derive_uses_validate_trait() {
+    // Test: bare hex(validate) delegates to the Validate trait, including on TryFrom.
+    // Justification: Value objects share the domain::validation rule vocabulary.
+    #[derive(HexValueItem, Debug)]
+    #[hex(validate)]
+    struct Sku(String);
+
+    impl hexser::domain::Validate for Sku {
+      fn validate(&self) -> hexser::HexResult<()> {
+        hexser::domain::validation::check_all([
+          hexser::domain::validation::starts_with("sku", &self.0, "SKU-"),
+          hexser::domain::validation::length("sku", &self.0, 5, 12),
+        ])
+      }
+    }
+
+    let sku = Sku::try_from(String::from("SKU-42")).unwrap();
+    assert!(sku.validate().is_ok());
+    assert_eq!(sku.into_inner(), "SKU-42");
+
+    match Sku::try_from(String::from("X")).unwrap_err() {
+      hexser::Hexserror::Validation(err) => assert_eq!(err.violations.len(), 2),
+      other => panic!("expected validation error, got {:?}", other),
+    }
+  }
+
   #[test]
   fn test_hex_value_item_derive_custom_validation() {
     // Test that HexValueItem can be overridden with custom validation
@@ -167,4 +224,284 @@ mod macro_tests {
 
     assert!(true);
   }
+
+  #[test]
+  fn test_hex_query_derive_captures_returns() {
+    #[derive(HexQuery)]
+    #[hex(returns = "Vec<Order>")]
+    struct ListOrders;
+
+    #[derive(HexQuery)]
+    struct FindOrder;
+
+    let info = ListOrders::node_info();
+    assert_eq!(info.role, hexser::graph::Role::Query);
+    assert_eq!(info.returns, Some("Vec<Order>"));
+
+    let info = FindOrder::node_info();
+    assert_eq!(info.returns, None);
+  }
+
+  #[test]
+  fn test_hex_entity_derive_eq_by_id() {
+    #[derive(HexEntity, Debug)]
+    #[hex(eq_by_id)]
+    struct Account {
+      id: u64,
+      balance: i64,
+    }
+
+    let loaded = Account { id: 1, balance: 10 };
+    let updated = Account { id: 1, balance: 99 };
+    let other = Account { id: 2, balance: 10 };
+
+    assert_eq!(loaded, updated);
+    assert_ne!(loaded, other);
+    assert!(loaded.entity_eq(&updated));
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(loaded);
+    assert!(!set.insert(updated));
+    assert_eq!(set.iter().map(|a| a.balance).sum::<i64>(), 10);
+  }
+
+  #[test]
+  fn test_registering_derives_capture_tags() {
+    #[derive(HexDomain)]
+    #[hex(tags("pii", "external"))]
+    struct Customer;
+
+    #[derive(HexQuery)]
+    #[hex(returns = "Vec<Order>", tags("deprecated"))]
+    struct LegacyOrders;
+
+    assert_eq!(Customer::node_info().tags, vec!["pii", "external"]);
+    assert_eq!(LegacyOrders::node_info().tags, vec!["deprecated"]);
+  }
+
+  #[test]
+  fn test_auto_register_infers_role_from_derive() {
+    #[derive(HexEntity)]
+    #[hex(auto_register, tags("core"))]
+    struct AutoOrder {
+      #[allow(dead_code)]
+      id: u64,
+    }
+
+    #[derive(HexValueItem)]
+    #[hex(auto_register)]
+    struct AutoMoney(#[allow(dead_code)] i64);
+
+    #[derive(HexAggregate, HexEntity)]
+    #[hex(auto_register, role = "Aggregate")]
+    struct AutoCart {
+      #[allow(dead_code)]
+      id: u64,
+    }
+
+    let order = AutoOrder::node_info();
+    assert_eq!(order.layer, Layer::Domain);
+    assert_eq!(order.role, Role::Entity);
+    assert_eq!(order.tags, vec!["core"]);
+    assert_eq!(AutoMoney::node_info().role, Role::ValueObject);
+    assert_eq!(AutoCart::node_info().role, Role::Aggregate);
+
+    let graph = hexser::registry::ComponentRegistry::build_graph();
+    let registered = |name: &str| {
+      graph
+        .nodes_by_layer(Layer::Domain)
+        .iter()
+        .any(|n| n.type_name.ends_with(name))
+    };
+    assert!(registered("AutoOrder"));
+    assert!(registered("AutoMoney"));
+    assert!(registered("AutoCart"));
+  }
+
+  #[test]
+  fn test_registering_derives_capture_source_location() {
+    let line = line!() + 1;
+    #[derive(HexAdapter)]
+    struct LocatedAdapter;
+
+    let info = LocatedAdapter::node_info();
+    assert_eq!(info.source_file, Some(file!()));
+    assert_eq!(info.source_line, Some(line));
+  }
+
+  #[test]
+  fn test_timestamped_derive_touches_updated_at() {
+    #[derive(Timestamped)]
+    struct Note {
+      created_at: std::time::SystemTime,
+      updated_at: std::time::SystemTime,
+    }
+
+    let mut note = Note {
+      created_at: std::time::UNIX_EPOCH,
+      updated_at: std::time::UNIX_EPOCH,
+    };
+    note.touch(&hexser::adapters::FixedClock::at_unix_secs(30));
+
+    assert_eq!(note.created_at(), std::time::UNIX_EPOCH);
+    assert_eq!(
+      note.updated_at(),
+      std::time::UNIX_EPOCH + std::time::Duration::from_secs(30)
+    );
+  }
+
+  #[test]
+  fn test_describe_filters_derive_lists_variants() {
+    #[derive(DescribeFilters)]
+    #[allow(dead_code)]
+    enum OrderFilter {
+      All,
+      ByCustomer(String),
+      PlacedBetween { from: u64, to: u64 },
+      Tagged(Vec<String>, bool),
+    }
+
+    let filters = OrderFilter::filter_descriptors();
+
+    let names: Vec<&str> = filters.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["All", "ByCustomer", "PlacedBetween", "Tagged"]);
+    assert!(filters[0].params.is_empty());
+    assert_eq!(filters[1].param_types(), vec!["String"]);
+    assert_eq!(filters[2].params[0].name.as_deref(), Some("from"));
+    assert_eq!(filters[2].param_types(), vec!["u64", "u64"]);
+    assert_eq!(filters[3].param_types(), vec!["Vec<String>", "bool"]);
+  }
+
+  #[test]
+  fn test_hex_context_derive_redacts_and_skips_fields() {
+    #[derive(HexContext)]
+    struct SignIn {
+      email: String,
+      #[hex(redact)]
+      password: String,
+      #[hex(skip)]
+      #[allow(dead_code)]
+      attempts: Vec<u8>,
+    }
+
+    let cmd = SignIn {
+      email: String::from("a@b.com"),
+      password: String::from("hunter2"),
+      attempts: vec![1, 2],
+    };
+    assert_eq!(
+      hexser::error::ContextFields::context_fields(&cmd),
+      vec![
+        ("email", String::from("a@b.com")),
+        ("password", String::from("***"))
+      ]
+    );
+
+    let err = Hexserror::validation("Bad credentials").with_fields(&cmd);
+    assert!(!err.to_string().contains("hunter2"));
+  }
+
+  #[test]
+  fn test_hex_port_attribute_counts_trait_methods() {
+    #[hex_port(role = "Repository")]
+    #[allow(dead_code)]
+    trait OrderStore {
+      fn load(&self, id: u64) -> Option<String>;
+      fn store(&mut self, id: u64, order: String);
+      fn contains(&self, id: u64) -> bool {
+        self.load(id).is_some()
+      }
+    }
+
+    let info = __HexPortMetaOrderStore::node_info();
+    assert_eq!(info.layer, Layer::Port);
+    assert_eq!(info.role, Role::Repository);
+    assert!(info.type_name.ends_with("::OrderStore"));
+    assert_eq!(info.required_methods, 2);
+    assert_eq!(info.default_methods, 1);
+  }
+
+  mod crud {
+    hexser::hex_crud!(
+      Book,
+      id: u32,
+      fields: [title: String, pages: u32],
+      filters: [LongerThan(u32) => |book, pages| book.pages > *pages],
+    );
+  }
+
+  #[test]
+  fn test_hex_crud_generates_in_memory_repository() {
+    use crud::{Book, BookFilter, BookSortKey, InMemoryBookRepository};
+    use hexser::ports::repository::QueryRepository;
+
+    let mut repo = InMemoryBookRepository::default();
+    for (id, pages) in [(2, 300), (1, 120), (3, 80)] {
+      repo
+        .save(Book {
+          id,
+          title: format!("Book {}", id),
+          pages,
+        })
+        .unwrap();
+    }
+    let outcome = repo
+      .upsert(Book {
+        id: 1,
+        title: String::from("Revised"),
+        pages: 150,
+      })
+      .unwrap();
+
+    assert_eq!(outcome, hexser::ports::repository::Upserted::Updated);
+    assert_eq!(repo.items.len(), 3);
+    assert_eq!(
+      repo.find_one(&BookFilter::ById(1)).unwrap().unwrap().title,
+      "Revised"
+    );
+    let long = repo
+      .find(
+        &BookFilter::LongerThan(100),
+        hexser::ports::repository::FindOptions::default()
+          .with_sort(BookSortKey::Id, hexser::ports::repository::Direction::Desc),
+      )
+      .unwrap();
+    assert_eq!(long.iter().map(|b| b.id).collect::<Vec<_>>(), vec![2, 1]);
+
+    let page = repo
+      .query(&BookFilter::All)
+      .sort(BookSortKey::Id, hexser::ports::repository::Direction::Asc)
+      .limit(1)
+      .offset(1)
+      .page()
+      .unwrap();
+    assert_eq!((page.items[0].id, page.total), (2, 3));
+
+    assert_eq!(repo.delete_where(&BookFilter::ById(3)).unwrap(), 1);
+    assert_eq!(repo.count(&BookFilter::All).unwrap(), 2);
+    assert_eq!(repo.clear().unwrap(), 2);
+    assert!(repo.items.is_empty());
+  }
+
+  #[test]
+  fn test_forbid_depends_layer_is_recorded_as_policy() {
+    #[derive(HexDomain)]
+    #[hex(
+      forbid_depends_layer = "adapter",
+      forbid_depends_layer = "Infrastructure"
+    )]
+    struct Invoice;
+
+    let info = Invoice::node_info();
+    assert_eq!(
+      info.forbidden_layers,
+      vec![Layer::Adapter, Layer::Infrastructure]
+    );
+
+    let node = hexser::registry::node_builder::build_node_from_info(info);
+    assert_eq!(
+      hexser::graph::policy::forbidden_layers(&node),
+      vec![Layer::Adapter, Layer::Infrastructure]
+    );
+  }
 }
@@ -0,0 +1,87 @@
+//! Implementation of the #[hex_port] attribute macro.
+//!
+//! Registers a port trait through a hidden companion struct and records how
+//! many of its methods are required and how many have default bodies. The
+//! node's type name is the trait's path, so it appears in the graph under
+//! the trait name. Accepts the `role`, `tags(...)`, and `forbid_depends_layer`
+//! keys of `#[hex(...)]`;
+//! the role defaults to Repository like `#[derive(HexPort)]`.
+//!
+//! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Carry forbid_depends_layer policies into NodeInfo.
+//! - 2026-10-19T02:25:00Z @AI: Initial hex_port attribute with method counts.
+
+/// Expand #[hex_port] on a trait
+pub fn expand(
+  args: proc_macro::TokenStream,
+  input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+  let attrs = match crate::common::attributes::parse_hex_args(args) {
+    Ok(attrs) => attrs,
+    Err(e) => return e.to_compile_error().into(),
+  };
+  let item = syn::parse_macro_input!(input as syn::ItemTrait);
+
+  let role = attrs.role.as_deref().unwrap_or("Repository");
+  let role = match syn::parse_str::<syn::Ident>(role) {
+    Ok(role) => role,
+    Err(_) => {
+      return syn::Error::new_spanned(&item.ident, "hex_port role must name a Role variant")
+        .to_compile_error()
+        .into();
+    }
+  };
+  let tags = attrs.node_tags();
+  let forbidden_layers = attrs.node_forbidden_layers();
+
+  let (defaulted, required): (Vec<&syn::TraitItemFn>, Vec<&syn::TraitItemFn>) = item
+    .items
+    .iter()
+    .filter_map(|item| match item {
+      syn::TraitItem::Fn(method) => Some(method),
+      _ => None,
+    })
+    .partition(|method| method.default.is_some());
+  let required = required.len() as u32;
+  let defaulted = defaulted.len() as u32;
+
+  let vis = &item.vis;
+  let trait_name = item.ident.to_string();
+  let meta = quote::format_ident!("__HexPortMeta{}", item.ident);
+
+  let expanded = quote::quote! {
+      #item
+
+      #[doc(hidden)]
+      #[allow(dead_code)]
+      #vis struct #meta;
+
+      impl hexser::registry::Registrable for #meta {
+          fn node_info() -> hexser::registry::NodeInfo {
+              hexser::registry::NodeInfo {
+                  layer: hexser::graph::Layer::Port,
+                  role: hexser::graph::Role::#role,
+                  type_name: std::concat!(std::module_path!(), "::", #trait_name),
+                  module_path: std::module_path!(),
+                  returns: std::option::Option::None,
+                  tags: #tags,
+                  source_file: std::option::Option::Some(std::file!()),
+                  source_line: std::option::Option::Some(std::line!()),
+                  required_methods: #required,
+                  default_methods: #defaulted,
+                  forbidden_layers: #forbidden_layers,
+              }
+          }
+
+          fn dependencies() -> std::vec::Vec<hexser::graph::NodeId> {
+              std::vec::Vec::new()
+          }
+      }
+
+      hexser::inventory::submit! {
+          hexser::registry::ComponentEntry::new::<#meta>()
+      }
+  };
+
+  proc_macro::TokenStream::from(expanded)
+}
@@ -0,0 +1,10 @@
+//! Attribute macro implementations.
+//!
+//! Derives cannot be applied to traits, so trait-level registration is
+//! provided by attribute macros that re-emit the item unchanged alongside
+//! the generated registration code.
+//!
+//! Revision History
+//! - 2026-10-19T02:25:00Z @AI: Initial attribute macros module with hex_port.
+
+pub mod hex_port;
@@ -1,20 +1,155 @@
 //! Attribute parsing utilities for hex derive macros.
 //!
 //! Provides functions to parse and validate hex attributes like
-//! `#[hex(layer = "Domain", metadata(version = "1.0"))]`.
+//! `#[hex(layer = "Domain", returns = "Vec<Order>")]` and flags like
+//! `#[hex(eq_by_id)]`, `#[hex(auto_register)]` or a bare `#[hex(validate)]`,
+//! plus the `#[hex(tags("pii", "external"))]` list and repeatable
+//! `#[hex(forbid_depends_layer = "adapter")]` dependency policies.
+//! Field-level flags (`#[hex(redact)]`, `#[hex(skip)]`) are parsed separately.
 //!
 //! Revision History
+//! - 2026-10-19T05:25:00Z @AI: Accept bare validate flag for the Validate trait.
+//! - 2026-10-19T03:25:00Z @AI: Add forbid_depends_layer policy key.
+//! - 2026-10-19T02:25:00Z @AI: Share key parsing with attribute macro arguments.
+//! - 2026-10-19T02:05:00Z @AI: Add auto_register flag.
+//! - 2026-10-19T00:35:00Z @AI: Add field-level redact and skip flags.
+//! - 2026-10-18T20:00:00Z @AI: Add tags list and node_tags token helper.
+//! - 2026-10-18T18:00:00Z @AI: Add validate key for HexValueItem newtypes.
+//! - 2026-10-18T12:00:00Z @AI: Add eq_by_id flag for HexEntity identity equality.
+//! - 2026-10-18T09:00:00Z @AI: Parse hex attributes with syn and add returns key for HexQuery.
 //! - 2025-10-02T00:00:00Z @AI: Initial attribute parsing implementation.
 
 /// Parse hex attributes from a derive input
-pub fn parse_hex_attributes(_attrs: &[syn::Attribute]) -> Result<HexAttributes, syn::Error> {
-  Ok(HexAttributes::default())
+pub fn parse_hex_attributes(attrs: &[syn::Attribute]) -> Result<HexAttributes, syn::Error> {
+  let mut parsed = HexAttributes::default();
+
+  for attr in attrs.iter().filter(|a| a.path().is_ident("hex")) {
+    attr.parse_nested_meta(|meta| parse_hex_meta(&mut parsed, meta))?;
+  }
+
+  Ok(parsed)
 }
 
+/// Parse the same keys from attribute macro arguments, e.g. `#[hex_port(role = "InputPort")]`
+pub fn parse_hex_args(args: proc_macro::TokenStream) -> Result<HexAttributes, syn::Error> {
+  let mut parsed = HexAttributes::default();
+  let parser = syn::meta::parser(|meta| parse_hex_meta(&mut parsed, meta));
+  syn::parse::Parser::parse(parser, args)?;
+  Ok(parsed)
+}
+
+fn parse_hex_meta(
+  parsed: &mut HexAttributes,
+  meta: syn::meta::ParseNestedMeta,
+) -> Result<(), syn::Error> {
+  if meta.path.is_ident("eq_by_id") {
+    parsed.eq_by_id = true;
+    return Ok(());
+  }
+  if meta.path.is_ident("auto_register") {
+    parsed.auto_register = true;
+    return Ok(());
+  }
+  if meta.path.is_ident("validate") && !meta.input.peek(syn::Token![=]) {
+    parsed.validate_trait = true;
+    return Ok(());
+  }
+  if meta.path.is_ident("tags") {
+    let content;
+    syn::parenthesized!(content in meta.input);
+    let tags =
+      syn::punctuated::Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated(&content)?;
+    parsed.tags.extend(tags.iter().map(syn::LitStr::value));
+    return Ok(());
+  }
+  let value: syn::LitStr = meta.value()?.parse()?;
+  if meta.path.is_ident("layer") {
+    parsed.layer = Some(value.value());
+  } else if meta.path.is_ident("role") {
+    parsed.role = Some(value.value());
+  } else if meta.path.is_ident("version") {
+    parsed.version = Some(value.value());
+  } else if meta.path.is_ident("returns") {
+    parsed.returns = Some(value.value());
+  } else if meta.path.is_ident("validate") {
+    parsed.validate = Some(value.parse()?);
+  } else if meta.path.is_ident("forbid_depends_layer") {
+    let layer = LAYER_NAMES
+      .iter()
+      .find(|name| name.eq_ignore_ascii_case(&value.value()))
+      .ok_or_else(|| {
+        syn::Error::new(
+          value.span(),
+          format!("unknown layer; expected one of {}", LAYER_NAMES.join(", ")),
+        )
+      })?;
+    parsed.forbidden_layers.push(String::from(*layer));
+  } else {
+    return Err(meta.error("unsupported hex attribute key"));
+  }
+  Ok(())
+}
+
+/// Variant names of `hexser::graph::Layer` accepted by `forbid_depends_layer`
+const LAYER_NAMES: &[&str] = &["Domain", "Port", "Adapter", "Application", "Infrastructure"];
+
 /// Hex attributes that can be applied to derive macros
 #[derive(Default)]
 pub struct HexAttributes {
   pub layer: Option<String>,
   pub role: Option<String>,
   pub version: Option<String>,
+  pub returns: Option<String>,
+  pub eq_by_id: bool,
+  pub auto_register: bool,
+  pub validate: Option<syn::Path>,
+  /// Bare `validate` flag: delegate to `hexser::domain::validation::Validate`
+  pub validate_trait: bool,
+  pub tags: Vec<String>,
+  /// Layer variant names this component must not depend on
+  pub forbidden_layers: Vec<String>,
+}
+
+impl HexAttributes {
+  /// Tokens for the `NodeInfo::tags` field value
+  pub fn node_tags(&self) -> proc_macro2::TokenStream {
+    let tags = &self.tags;
+    quote::quote! { std::vec![#(#tags),*] }
+  }
+
+  /// Tokens for the `NodeInfo::forbidden_layers` field value
+  pub fn node_forbidden_layers(&self) -> proc_macro2::TokenStream {
+    let layers = self
+      .forbidden_layers
+      .iter()
+      .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()));
+    quote::quote! { std::vec![#(hexser::graph::Layer::#layers),*] }
+  }
+}
+
+/// Parse field-level hex flags such as `#[hex(redact)]` and `#[hex(skip)]`
+pub fn parse_field_flags(attrs: &[syn::Attribute]) -> Result<FieldFlags, syn::Error> {
+  let mut flags = FieldFlags::default();
+
+  for attr in attrs.iter().filter(|a| a.path().is_ident("hex")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("redact") {
+        flags.redact = true;
+      } else if meta.path.is_ident("skip") {
+        flags.skip = true;
+      } else {
+        return Err(meta.error("unsupported hex field attribute; expected `redact` or `skip`"));
+      }
+      Ok(())
+    })?;
+  }
+
+  Ok(flags)
+}
+
+/// Hex flags that can be applied to individual struct fields
+#[derive(Default)]
+pub struct FieldFlags {
+  pub redact: bool,
+  pub skip: bool,
 }
@@ -0,0 +1,66 @@
+//! Implementation of #[derive(HexContext)] macro.
+//!
+//! Implements `hexser::error::ContextFields` for structs with named fields so
+//! they can be attached to errors via `Hexserror::with_fields`. Each field is
+//! rendered with Display; `#[hex(redact)]` replaces the value with `***` and
+//! `#[hex(skip)]` leaves the field out entirely.
+//!
+//! Revision History
+//! - 2026-10-19T00:35:00Z @AI: Initial HexContext derive with redact and skip flags.
+
+/// Derive ContextFields for a struct
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+  let fields = match &input.data {
+    syn::Data::Struct(data) => match &data.fields {
+      syn::Fields::Named(fields) => &fields.named,
+      _ => return named_fields_error(name),
+    },
+    _ => return named_fields_error(name),
+  };
+
+  let mut entries = Vec::new();
+  for field in fields {
+    let flags = match crate::common::attributes::parse_field_flags(&field.attrs) {
+      Ok(flags) => flags,
+      Err(e) => return e.to_compile_error().into(),
+    };
+    if flags.skip {
+      continue;
+    }
+    let ident = field.ident.as_ref().expect("named field");
+    let key = ident.to_string();
+    entries.push(if flags.redact {
+      quote::quote! {
+          {
+              let _ = &self.#ident;
+              (#key, std::string::String::from(hexser::error::REDACTED))
+          }
+      }
+    } else {
+      quote::quote! {
+          (#key, std::string::ToString::to_string(&self.#ident))
+      }
+    });
+  }
+
+  let expanded = quote::quote! {
+      impl #impl_generics hexser::error::ContextFields for #name #ty_generics #where_clause {
+          fn context_fields(&self) -> std::vec::Vec<(&'static str, std::string::String)> {
+              std::vec![#(#entries),*]
+          }
+      }
+  };
+
+  proc_macro::TokenStream::from(expanded)
+}
+
+fn named_fields_error(name: &syn::Ident) -> proc_macro::TokenStream {
+  syn::Error::new_spanned(name, "HexContext requires a struct with named fields")
+    .to_compile_error()
+    .into()
+}
@@ -0,0 +1,69 @@
+//! Implementation of #[derive(DescribeFilters)] macro.
+//!
+//! Implements `hexser::ports::DescribeFilters` for filter enums with one
+//! descriptor per variant: the variant name plus its field types, named for
+//! struct-like variants and positional for tuple variants. Types are
+//! recorded as written in the source. Non-enums are rejected.
+//!
+//! Revision History
+//! - 2026-10-19T09:25:00Z @AI: Initial DescribeFilters derive.
+
+/// Derive DescribeFilters for a filter enum
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+  let variants = match &input.data {
+    syn::Data::Enum(data) => &data.variants,
+    _ => {
+      return syn::Error::new_spanned(name, "DescribeFilters can only be derived for enums")
+        .to_compile_error()
+        .into();
+    }
+  };
+
+  let descriptors = variants.iter().map(|variant| {
+    let variant_name = variant.ident.to_string();
+    let params = variant.fields.iter().map(|field| {
+      let ty = type_name(&field.ty);
+      match &field.ident {
+        Some(ident) => {
+          let field_name = ident.to_string();
+          quote::quote! { .with_named_param(#field_name, #ty) }
+        }
+        None => quote::quote! { .with_param(#ty) },
+      }
+    });
+    quote::quote! {
+        hexser::ports::describable_repository::FilterDescriptor::new(#variant_name)
+            #(#params)*
+    }
+  });
+
+  let expanded = quote::quote! {
+      impl #impl_generics hexser::ports::DescribeFilters for #name #ty_generics #where_clause {
+          fn filter_descriptors()
+              -> std::vec::Vec<hexser::ports::describable_repository::FilterDescriptor>
+          {
+              std::vec![#(#descriptors),*]
+          }
+      }
+  };
+
+  proc_macro::TokenStream::from(expanded)
+}
+
+/// Render a type as written, without the token spacing (`Vec<String>`, not `Vec < String >`)
+fn type_name(ty: &syn::Type) -> String {
+  quote::ToTokens::to_token_stream(ty)
+    .to_string()
+    .replace(" :: ", "::")
+    .replace(" < ", "<")
+    .replace("< ", "<")
+    .replace(" >", ">")
+    .replace(" ,", ",")
+    .replace(" ;", ";")
+    .replace("& ", "&")
+}
@@ -3,6 +3,10 @@
 //! Automatically implements the Directive trait for command/intent types.
 //!
 //! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Carry forbid_depends_layer policies into NodeInfo.
+//! - 2026-10-19T02:25:00Z @AI: Fill zero method counts in NodeInfo.
+//! - 2026-10-18T21:45:00Z @AI: Capture declaration file and line in NodeInfo.
+//! - 2026-10-18T20:00:00Z @AI: Forward hex(tags) to NodeInfo.
 //! - 2025-10-02T12:00:00Z @AI: Fix to implement validate method and add inventory submission.
 //! - 2025-10-02T00:00:00Z @AI: Initial Directive derive implementation.
 
@@ -10,6 +14,13 @@
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
+  let attrs = match crate::common::attributes::parse_hex_attributes(&input.attrs) {
+    Ok(attrs) => attrs,
+    Err(e) => return e.to_compile_error().into(),
+  };
+  let tags = attrs.node_tags();
+  let forbidden_layers = attrs.node_forbidden_layers();
+
   let name = &input.ident;
   let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
@@ -27,6 +38,13 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                   role: hexser::graph::Role::Directive,
                   type_name: std::any::type_name::<Self>(),
                   module_path: std::module_path!(),
+                  returns: std::option::Option::None,
+                  tags: #tags,
+                  source_file: std::option::Option::Some(std::file!()),
+                  source_line: std::option::Option::Some(std::line!()),
+                  required_methods: 0,
+                  default_methods: 0,
+                  forbidden_layers: #forbidden_layers,
               }
           }
 
@@ -1,9 +1,15 @@
-//! Implementation of #[derive(Entity)] macro.
+//! Implementation of #[derive(HexEntity)] macro.
 //!
 //! Automatically implements the HexEntity trait, detecting the Id type
-//! from a field named 'id'.
+//! from a field named 'id', and implements Identified when that field
+//! exists. With `#[hex(eq_by_id)]` it also generates
+//! `PartialEq`, `Eq`, and `Hash` implementations based solely on that field.
+//! With `#[hex(auto_register)]` it also registers the type as a domain Entity.
 //!
 //! Revision History
+//! - 2026-10-19T10:45:00Z @AI: Implement Identified instead of the removed HexEntity::entity_id.
+//! - 2026-10-19T02:05:00Z @AI: Support hex(auto_register).
+//! - 2026-10-18T12:00:00Z @AI: Implement entity_id accessor and hex(eq_by_id) identity equality.
 //! - 2025-10-09T09:43:00Z @AI: Update to implement HexEntity trait.
 //! - 2025-10-02T00:00:00Z @AI: Initial Entity derive implementation.
 
@@ -11,6 +17,11 @@
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
+  let attrs = match crate::common::attributes::parse_hex_attributes(&input.attrs) {
+    Ok(attrs) => attrs,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
   let name = &input.ident;
 
   let id_type = match &input.data {
@@ -23,14 +34,69 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     _ => None,
   };
 
+  if attrs.eq_by_id && id_type.is_none() {
+    return syn::Error::new_spanned(name, "hex(eq_by_id) requires a field named `id`")
+      .to_compile_error()
+      .into();
+  }
+
+
+  let registration = match crate::registration::auto_register::generate_auto_registration(
+    &input,
+    &attrs,
+    "Entity",
+  ) {
+    Ok(tokens) => tokens,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
+  let has_id = id_type.is_some();
   let id_type = id_type.unwrap_or_else(|| syn::parse_quote!(std::string::String));
 
   let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+  let identified = if has_id {
+    quote::quote! {
+        impl #impl_generics hexser::domain::Identified for #name #ty_generics #where_clause {
+            fn entity_id(&self) -> &Self::Id {
+                &self.id
+            }
+        }
+    }
+  } else {
+    quote::quote! {}
+  };
+
+  let identity_impls = if attrs.eq_by_id {
+    quote::quote! {
+        impl #impl_generics std::cmp::PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                self.id == other.id
+            }
+        }
+
+        impl #impl_generics std::cmp::Eq for #name #ty_generics #where_clause {}
+
+        impl #impl_generics std::hash::Hash for #name #ty_generics #where_clause {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                std::hash::Hash::hash(&self.id, state);
+            }
+        }
+    }
+  } else {
+    quote::quote! {}
+  };
+
   let expanded = quote::quote! {
       impl #impl_generics hexser::domain::HexEntity for #name #ty_generics #where_clause {
           type Id = #id_type;
       }
+
+      #identified
+
+      #identity_impls
+
+      #registration
   };
 
   proc_macro::TokenStream::from(expanded)
@@ -1,26 +1,166 @@
 //! Implementation of #[derive(HexValueItem)] macro.
 //!
 //! Automatically implements the HexValueItem trait with a default validation
-//! that returns Ok(()). Override the validate method in your impl block if
-//! custom validation logic is needed.
+//! that returns Ok(()). For single-field tuple structs (newtypes),
+//! `#[hex(validate = "path::to::fn")]` runs the given function on the inner
+//! value instead, and generates `TryFrom<Inner>` plus `into_inner()` so values
+//! are validated at construction. A bare `#[hex(validate)]` delegates to the
+//! type's `hexser::domain::validation::Validate` impl instead; newtypes get
+//! the same `TryFrom` and `into_inner`, validating the constructed value.
+//! `#[hex(auto_register)]` also registers the type as a domain ValueObject.
 //!
 //! Revision History
+//! - 2026-10-19T05:25:00Z @AI: Support bare hex(validate) delegating to the Validate trait.
+//! - 2026-10-19T02:05:00Z @AI: Support hex(auto_register).
+//! - 2026-10-18T18:00:00Z @AI: Add hex(validate) newtype support with TryFrom and into_inner.
 //! - 2025-10-09T11:03:00Z @AI: Initial HexValueItem derive implementation.
 
 /// Derive HexValueItem for a type
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
+  let attrs = match crate::common::attributes::parse_hex_attributes(&input.attrs) {
+    Ok(attrs) => attrs,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
+  let registration = match crate::registration::auto_register::generate_auto_registration(
+    &input,
+    &attrs,
+    "ValueObject",
+  ) {
+    Ok(tokens) => tokens,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+  if attrs.validate_trait {
+    if attrs.validate.is_some() {
+      return syn::Error::new_spanned(
+        name,
+        "use either hex(validate) or hex(validate = \"...\"), not both",
+      )
+      .to_compile_error()
+      .into();
+    }
+    return derive_with_validate_trait(&input, registration);
+  }
+
+  let validator = match attrs.validate {
+    Some(validator) => validator,
+    None => {
+      let expanded = quote::quote! {
+          impl #impl_generics hexser::domain::HexValueItem for #name #ty_generics #where_clause {
+              fn validate(&self) -> hexser::result::hex_result::HexResult<()> {
+                  std::result::Result::Ok(())
+              }
+          }
+
+          #registration
+      };
+      return proc_macro::TokenStream::from(expanded);
+    }
+  };
+
+  let inner_type = match newtype_inner(&input) {
+    Some(inner_type) => inner_type,
+    None => return newtype_error(name),
+  };
+
+  let expanded = quote::quote! {
+      impl #impl_generics hexser::domain::HexValueItem for #name #ty_generics #where_clause {
+          fn validate(&self) -> hexser::result::hex_result::HexResult<()> {
+              #validator(&self.0)
+          }
+      }
+
+      impl #impl_generics std::convert::TryFrom<#inner_type> for #name #ty_generics #where_clause {
+          type Error = hexser::error::hex_error::Hexserror;
+
+          fn try_from(value: #inner_type) -> std::result::Result<Self, Self::Error> {
+              #validator(&value)?;
+              std::result::Result::Ok(Self(value))
+          }
+      }
+
+      impl #impl_generics #name #ty_generics #where_clause {
+          /// Consume the value object and return the wrapped value
+          pub fn into_inner(self) -> #inner_type {
+              self.0
+          }
+      }
+
+      #registration
+  };
+
+  proc_macro::TokenStream::from(expanded)
+}
+
+/// Implement HexValueItem via the Validate trait, adding TryFrom for newtypes
+fn derive_with_validate_trait(
+  input: &syn::DeriveInput,
+  registration: proc_macro2::TokenStream,
+) -> proc_macro::TokenStream {
   let name = &input.ident;
   let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+  let constructor = match newtype_inner(input) {
+    Some(inner_type) => quote::quote! {
+        impl #impl_generics std::convert::TryFrom<#inner_type> for #name #ty_generics
+        #where_clause
+        {
+            type Error = hexser::error::hex_error::Hexserror;
+
+            fn try_from(value: #inner_type) -> std::result::Result<Self, Self::Error> {
+                let value = Self(value);
+                hexser::domain::validation::Validate::validate(&value)?;
+                std::result::Result::Ok(value)
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Consume the value object and return the wrapped value
+            pub fn into_inner(self) -> #inner_type {
+                self.0
+            }
+        }
+    },
+    None => proc_macro2::TokenStream::new(),
+  };
+
   let expanded = quote::quote! {
       impl #impl_generics hexser::domain::HexValueItem for #name #ty_generics #where_clause {
           fn validate(&self) -> hexser::result::hex_result::HexResult<()> {
-              std::result::Result::Ok(())
+              hexser::domain::validation::Validate::validate(self)
           }
       }
+
+      #constructor
+
+      #registration
   };
 
   proc_macro::TokenStream::from(expanded)
 }
+
+/// The wrapped type of a single-field tuple struct
+fn newtype_inner(input: &syn::DeriveInput) -> Option<&syn::Type> {
+  match &input.data {
+    syn::Data::Struct(data) => match &data.fields {
+      syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Some(&fields.unnamed[0].ty),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn newtype_error(name: &syn::Ident) -> proc_macro::TokenStream {
+  syn::Error::new_spanned(
+    name,
+    "hex(validate) requires a single-field tuple struct such as `struct Email(String);`",
+  )
+  .to_compile_error()
+  .into()
+}
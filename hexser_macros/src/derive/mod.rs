@@ -4,9 +4,14 @@
 //! module with the implementation logic.
 //!
 //! Revision History
+//! - 2026-10-19T09:25:00Z @AI: Add describe_filters derive.
+//! - 2026-10-19T08:25:00Z @AI: Add timestamped derive.
+//! - 2026-10-19T00:35:00Z @AI: Add context derive.
 //! - 2025-10-02T00:00:00Z @AI: Initial derive implementations module.
 
 pub mod aggregate;
+pub mod context;
+pub mod describe_filters;
 pub mod directive;
 pub mod entity;
 pub mod hex_adapter;
@@ -15,3 +20,4 @@ pub mod hex_port;
 pub mod hex_value_item;
 pub mod query;
 pub mod repository;
+pub mod timestamped;
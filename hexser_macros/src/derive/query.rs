@@ -1,14 +1,33 @@
 //! Implementation of #[derive(HexQuery)] macro.
 //!
-//! Automatically implements the Query trait for query types.
+//! Automatically implements the Query trait for query types. The expected
+//! result type can be declared with `#[hex(returns = "Vec<Order>")]` and is
+//! recorded in the node info so the graph can describe the read model.
 //!
 //! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Carry forbid_depends_layer policies into NodeInfo.
+//! - 2026-10-19T02:25:00Z @AI: Fill zero method counts in NodeInfo.
+//! - 2026-10-18T21:45:00Z @AI: Capture declaration file and line in NodeInfo.
+//! - 2026-10-18T20:00:00Z @AI: Forward hex(tags) to NodeInfo.
+//! - 2026-10-18T09:00:00Z @AI: Capture result type via hex(returns) attribute and submit to inventory.
 //! - 2025-10-02T00:00:00Z @AI: Initial Query derive implementation.
 
 /// Derive Query for a type
 pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
+  let attrs = match crate::common::attributes::parse_hex_attributes(&input.attrs) {
+    Ok(attrs) => attrs,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
+  let returns = match &attrs.returns {
+    Some(returns) => quote::quote! { std::option::Option::Some(#returns) },
+    None => quote::quote! { std::option::Option::None },
+  };
+
+  let tags = attrs.node_tags();
+  let forbidden_layers = attrs.node_forbidden_layers();
   let name = &input.ident;
   let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
@@ -20,6 +39,13 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                   role: hexser::graph::Role::Query,
                   type_name: std::any::type_name::<Self>(),
                   module_path: std::module_path!(),
+                  returns: #returns,
+                  tags: #tags,
+                  source_file: std::option::Option::Some(std::file!()),
+                  source_line: std::option::Option::Some(std::line!()),
+                  required_methods: 0,
+                  default_methods: 0,
+                  forbidden_layers: #forbidden_layers,
               }
           }
 
@@ -27,6 +53,10 @@ pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
               std::vec::Vec::new()
           }
       }
+
+      hexser::inventory::submit! {
+          hexser::registry::ComponentEntry::new::<#name #ty_generics>()
+      }
   };
 
   proc_macro::TokenStream::from(expanded)
@@ -0,0 +1,53 @@
+//! Implementation of #[derive(Timestamped)] macro.
+//!
+//! Implements `hexser::domain::Timestamped` for structs with `created_at`
+//! and `updated_at` fields of type `std::time::SystemTime`. A missing field
+//! is reported on the struct name.
+//!
+//! Revision History
+//! - 2026-10-19T08:25:00Z @AI: Initial Timestamped derive.
+
+/// Derive Timestamped for a struct
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+  let has_field = |wanted: &str| match &input.data {
+    syn::Data::Struct(data) => data
+      .fields
+      .iter()
+      .any(|f| f.ident.as_ref().map(|i| i == wanted).unwrap_or(false)),
+    _ => false,
+  };
+
+  for field in ["created_at", "updated_at"] {
+    if !has_field(field) {
+      return syn::Error::new_spanned(
+        name,
+        format!("Timestamped requires a field named `{}`", field),
+      )
+      .to_compile_error()
+      .into();
+    }
+  }
+
+  let expanded = quote::quote! {
+      impl #impl_generics hexser::domain::Timestamped for #name #ty_generics #where_clause {
+          fn created_at(&self) -> std::time::SystemTime {
+              self.created_at
+          }
+
+          fn updated_at(&self) -> std::time::SystemTime {
+              self.updated_at
+          }
+
+          fn set_updated_at(&mut self, at: std::time::SystemTime) {
+              self.updated_at = at;
+          }
+      }
+  };
+
+  proc_macro::TokenStream::from(expanded)
+}
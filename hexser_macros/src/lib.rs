@@ -14,6 +14,22 @@
 //! - `#[derive(HexValueItem)]` - Implement HexValueItem trait with default validation
 //! - `#[derive(HexAggregate)]` - Mark aggregate roots
 //! - `#[derive(Repository)]` - Mark repository ports
+//! - `#[derive(HexContext)]` - Attach fields as error context, honouring `#[hex(redact)]`
+//! - `#[derive(Timestamped)]` - Implement Timestamped over `created_at`/`updated_at` fields
+//! - `#[derive(DescribeFilters)]` - Describe a filter enum's variants for DescribableRepository
+//!
+//! # Attribute Macros
+//!
+//! - `#[hex_port]` - Register a port trait, recording required and defaulted method counts
+//!
+//! `#[hex(auto_register)]` on `HexEntity` or `HexValueItem` also registers
+//! the type in the domain layer, so no separate `HexDomain` derive is needed;
+//! `#[hex(role = "Aggregate")]` overrides the inferred role.
+//!
+//! # Scaffolding Macros
+//!
+//! - `hex_crud!(Entity, id: IdType, fields: [...])` - Generate an entity, its filter and
+//!   sort enums, and an in-memory `Repository` + `QueryRepository` adapter
 //!
 //! # Error Macros
 //!
@@ -36,14 +52,25 @@
 //! ```
 //!
 //! Revision History
+//! - 2026-10-19T09:25:00Z @AI: Add DescribeFilters derive.
+//! - 2026-10-19T08:25:00Z @AI: Add Timestamped derive.
+//! - 2026-10-19T02:55:00Z @AI: Add hex_crud! scaffolding macro.
+//! - 2026-10-19T02:25:00Z @AI: Add hex_port attribute macro for port traits.
+//! - 2026-10-19T02:05:00Z @AI: Add hex(auto_register) on HexEntity and HexValueItem.
+//! - 2026-10-19T00:35:00Z @AI: Add HexContext derive with field-level redaction.
+//! - 2026-10-18T20:00:00Z @AI: Accept hex(tags) on registering derives, including HexDirective.
+//! - 2026-10-18T18:00:00Z @AI: Accept hex(validate) attribute on HexValueItem derive.
+//! - 2026-10-18T12:00:00Z @AI: Accept hex(eq_by_id) attribute on HexEntity derive.
 //! - 2025-10-09T14:14:00Z @AI: Remove Entity derive, expose only HexEntity for clarity.
 //! - 2025-10-06T02:00:00Z @AI: Add error construction macros.
 //! - 2025-10-02T00:00:00Z @AI: Initial Phase 3 proc macro crate.
 
+mod attribute;
 mod common;
 mod derive;
 mod error;
 mod registration;
+mod scaffold;
 
 #[proc_macro_derive(HexDomain, attributes(hex))]
 pub fn derive_hex_domain(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -65,12 +92,12 @@ pub fn derive_hex_aggregate(input: proc_macro::TokenStream) -> proc_macro::Token
   crate::derive::aggregate::derive(input)
 }
 
-#[proc_macro_derive(HexEntity)]
+#[proc_macro_derive(HexEntity, attributes(hex))]
 pub fn derive_hex_entity(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   crate::derive::entity::derive(input)
 }
 
-#[proc_macro_derive(HexValueItem)]
+#[proc_macro_derive(HexValueItem, attributes(hex))]
 pub fn derive_hex_value_item(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   crate::derive::hex_value_item::derive(input)
 }
@@ -80,16 +107,44 @@ pub fn derive_repository(input: proc_macro::TokenStream) -> proc_macro::TokenStr
   crate::derive::repository::derive(input)
 }
 
-#[proc_macro_derive(HexDirective)]
+#[proc_macro_derive(HexDirective, attributes(hex))]
 pub fn derive_directive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   crate::derive::directive::derive(input)
 }
 
-#[proc_macro_derive(HexQuery)]
+#[proc_macro_derive(HexQuery, attributes(hex))]
 pub fn derive_query(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   crate::derive::query::derive(input)
 }
 
+#[proc_macro_derive(HexContext, attributes(hex))]
+pub fn derive_context(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  crate::derive::context::derive(input)
+}
+
+#[proc_macro_derive(Timestamped)]
+pub fn derive_timestamped(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  crate::derive::timestamped::derive(input)
+}
+
+#[proc_macro_derive(DescribeFilters)]
+pub fn derive_describe_filters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  crate::derive::describe_filters::derive(input)
+}
+
+#[proc_macro_attribute]
+pub fn hex_port(
+  args: proc_macro::TokenStream,
+  input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+  crate::attribute::hex_port::expand(args, input)
+}
+
+#[proc_macro]
+pub fn hex_crud(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  crate::scaffold::hex_crud::expand(input)
+}
+
 #[proc_macro]
 pub fn hex_domain_error(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   crate::error::hex_error_macro::hex_domain_error_impl(input)
@@ -0,0 +1,57 @@
+//! Opt-in registration for types deriving a core trait.
+//!
+//! `#[hex(auto_register)]` on `HexEntity` or `HexValueItem` makes that derive
+//! also implement Registrable and submit the type to the component inventory
+//! in the domain layer, with the role inferred from the trait being derived.
+//! `#[hex(role = "Aggregate")]` overrides the inferred role. Only these two
+//! derives register, so pairing `HexAggregate` with `HexEntity` yields one
+//! impl; pairing either with `HexDomain` is a conflicting-impl error.
+//!
+//! Revision History
+//! - 2026-10-19T02:05:00Z @AI: Initial auto_register support.
+
+/// Generate Registrable impl and inventory submission when opted in
+///
+/// Returns empty tokens unless `#[hex(auto_register)]` was given. Generic
+/// types are rejected because the inventory needs one concrete type.
+pub fn generate_auto_registration(
+  input: &syn::DeriveInput,
+  attrs: &crate::common::attributes::HexAttributes,
+  inferred_role: &str,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+  if !attrs.auto_register {
+    return Ok(quote::quote! {});
+  }
+  if !input.generics.params.is_empty() {
+    return Err(syn::Error::new_spanned(
+      &input.generics,
+      "hex(auto_register) does not support generic types",
+    ));
+  }
+
+  let role = attrs.role.as_deref().unwrap_or(inferred_role);
+  if syn::parse_str::<syn::Ident>(role).is_err() {
+    return Err(syn::Error::new_spanned(
+      &input.ident,
+      "hex(role) must name a hexser::graph::Role variant",
+    ));
+  }
+
+  let name = &input.ident;
+  let node_info = crate::registration::node_gen::generate_node_info(attrs, "Domain", role);
+  let submission = crate::registration::inventory_gen::generate_inventory_submission(name);
+
+  Ok(quote::quote! {
+      impl hexser::registry::Registrable for #name {
+          fn node_info() -> hexser::registry::NodeInfo {
+              #node_info
+          }
+
+          fn dependencies() -> std::vec::Vec<hexser::graph::NodeId> {
+              std::vec::Vec::new()
+          }
+      }
+
+      #submission
+  })
+}
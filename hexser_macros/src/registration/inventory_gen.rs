@@ -3,11 +3,14 @@
 //! Generates `inventory::submit!` blocks for component registration.
 //!
 //! Revision History
+//! - 2026-10-19T02:05:00Z @AI: Generate ComponentEntry submissions.
 //! - 2025-10-02T00:00:00Z @AI: Initial inventory generation implementation.
 
 /// Generate inventory submission for a component
-pub fn generate_inventory_submission(_type_name: &syn::Ident) -> proc_macro2::TokenStream {
+pub fn generate_inventory_submission(type_name: &syn::Ident) -> proc_macro2::TokenStream {
   quote::quote! {
-      // Inventory submission will be generated here
+      hexser::inventory::submit! {
+          hexser::registry::ComponentEntry::new::<#type_name>()
+      }
   }
 }
@@ -3,7 +3,9 @@
 //! Generates inventory submission code for automatic graph construction.
 //!
 //! Revision History
+//! - 2026-10-19T02:05:00Z @AI: Add auto_register for hex(auto_register) on core derives.
 //! - 2025-10-02T00:00:00Z @AI: Initial registration module.
 
+pub mod auto_register;
 pub mod inventory_gen;
 pub mod node_gen;
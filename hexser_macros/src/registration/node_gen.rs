@@ -3,15 +3,37 @@
 //! Generates NodeInfo and related metadata for registered components.
 //!
 //! Revision History
+//! - 2026-10-19T03:25:00Z @AI: Carry forbid_depends_layer policies into NodeInfo.
+//! - 2026-10-19T02:25:00Z @AI: Fill zero method counts in NodeInfo.
+//! - 2026-10-19T02:05:00Z @AI: Generate NodeInfo literals for auto-registered types.
 //! - 2025-10-02T00:00:00Z @AI: Initial node generation implementation.
 
 /// Generate NodeInfo for a component
+///
+/// `layer` and `role` name variants of `hexser::graph::Layer` and
+/// `hexser::graph::Role`; tags come from `#[hex(tags(...))]`.
 pub fn generate_node_info(
-  _type_name: &syn::Ident,
-  _layer: &str,
-  _role: &str,
+  attrs: &crate::common::attributes::HexAttributes,
+  layer: &str,
+  role: &str,
 ) -> proc_macro2::TokenStream {
+  let layer = syn::Ident::new(layer, proc_macro2::Span::call_site());
+  let role = syn::Ident::new(role, proc_macro2::Span::call_site());
+  let tags = attrs.node_tags();
+  let forbidden_layers = attrs.node_forbidden_layers();
   quote::quote! {
-      // NodeInfo generation will be implemented here
+      hexser::registry::NodeInfo {
+          layer: hexser::graph::Layer::#layer,
+          role: hexser::graph::Role::#role,
+          type_name: std::any::type_name::<Self>(),
+          module_path: std::module_path!(),
+          returns: std::option::Option::None,
+          tags: #tags,
+          source_file: std::option::Option::Some(std::file!()),
+          source_line: std::option::Option::Some(std::line!()),
+          required_methods: 0,
+          default_methods: 0,
+          forbidden_layers: #forbidden_layers,
+      }
   }
 }
@@ -0,0 +1,256 @@
+//! Implementation of the hex_crud! scaffolding macro.
+//!
+//! `hex_crud!(Item, id: u64, fields: [name: String])` expands to the same
+//! pieces the CRUD potion writes by hand:
+//!
+//! - `Item` with public `id` and field members, implementing `HexEntity`
+//! - `ItemFilter` with `All` and `ById(u64)`, plus `ItemFilter::matches`
+//! - `ItemSortKey` with `Id`
+//! - `InMemoryItemRepository` implementing `Repository` (upsert by id) and
+//!   `QueryRepository` (filtering, id sorting, offset and limit)
+//!
+//! Extra filter variants are declared up front with
+//! `filters: [ByName(String) => |item, name| item.name == *name]`; each
+//! predicate must be a non-capturing closure taking the entity and the
+//! variant's value by reference. The id type must implement `Ord + Clone`.
+//!
+//! Revision History
+//! - 2026-10-19T06:15:00Z @AI: Generate upsert; save delegates to it.
+//! - 2026-10-19T05:05:00Z @AI: Generate clear for the in-memory repository.
+//! - 2026-10-19T02:55:00Z @AI: Initial hex_crud implementation.
+
+struct CrudInput {
+  entity: syn::Ident,
+  id_field: syn::Ident,
+  id_type: syn::Type,
+  fields: Vec<(syn::Ident, syn::Type)>,
+  filters: Vec<CustomFilter>,
+}
+
+struct CustomFilter {
+  variant: syn::Ident,
+  value_type: syn::Type,
+  predicate: syn::Expr,
+}
+
+impl syn::parse::Parse for CustomFilter {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let variant = input.parse()?;
+    let content;
+    syn::parenthesized!(content in input);
+    let value_type = content.parse()?;
+    input.parse::<syn::Token![=>]>()?;
+    let predicate = input.parse()?;
+    Ok(Self {
+      variant,
+      value_type,
+      predicate,
+    })
+  }
+}
+
+impl syn::parse::Parse for CrudInput {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let entity = input.parse()?;
+    input.parse::<syn::Token![,]>()?;
+    let id_field = input.parse()?;
+    input.parse::<syn::Token![:]>()?;
+    let id_type = input.parse()?;
+
+    let mut fields = Vec::new();
+    let mut filters = Vec::new();
+    while !input.is_empty() {
+      input.parse::<syn::Token![,]>()?;
+      if input.is_empty() {
+        break;
+      }
+      let key: syn::Ident = input.parse()?;
+      input.parse::<syn::Token![:]>()?;
+      let content;
+      syn::bracketed!(content in input);
+      if key == "fields" {
+        let parsed = content.parse_terminated(
+          |field| {
+            let name: syn::Ident = field.parse()?;
+            field.parse::<syn::Token![:]>()?;
+            let ty: syn::Type = field.parse()?;
+            Ok((name, ty))
+          },
+          syn::Token![,],
+        )?;
+        fields.extend(parsed);
+      } else if key == "filters" {
+        let parsed =
+          content.parse_terminated(<CustomFilter as syn::parse::Parse>::parse, syn::Token![,])?;
+        filters.extend(parsed);
+      } else {
+        return Err(syn::Error::new(
+          key.span(),
+          "expected `fields: [...]` or `filters: [...]`",
+        ));
+      }
+    }
+
+    Ok(Self {
+      entity,
+      id_field,
+      id_type,
+      fields,
+      filters,
+    })
+  }
+}
+
+/// Expand hex_crud!
+pub fn expand(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let CrudInput {
+    entity,
+    id_field,
+    id_type,
+    fields,
+    filters,
+  } = syn::parse_macro_input!(input as CrudInput);
+
+  let filter = quote::format_ident!("{}Filter", entity);
+  let sort_key = quote::format_ident!("{}SortKey", entity);
+  let repository = quote::format_ident!("InMemory{}Repository", entity);
+
+  let field_names = fields.iter().map(|(name, _)| name);
+  let field_types = fields.iter().map(|(_, ty)| ty);
+  let variants = filters.iter().map(|f| &f.variant);
+  let value_types = filters.iter().map(|f| &f.value_type);
+  let match_arms = filters.iter().map(|f| {
+    let variant = &f.variant;
+    let value_type = &f.value_type;
+    let predicate = &f.predicate;
+    quote::quote! {
+        Self::#variant(value) => {
+            let predicate: fn(&#entity, &#value_type) -> bool = #predicate;
+            predicate(entity, value)
+        }
+    }
+  });
+
+  let expanded = quote::quote! {
+      /// Entity generated by `hex_crud!`.
+      #[derive(Clone, Debug, PartialEq)]
+      pub struct #entity {
+          pub #id_field: #id_type,
+          #(pub #field_names: #field_types,)*
+      }
+
+      impl hexser::domain::HexEntity for #entity {
+          type Id = #id_type;
+      }
+
+      /// Filters accepted by the generated repository.
+      #[derive(Clone, Debug, PartialEq)]
+      pub enum #filter {
+          All,
+          ById(#id_type),
+          #(#variants(#value_types),)*
+      }
+
+      impl #filter {
+          /// Returns true if `entity` satisfies this filter.
+          pub fn matches(&self, entity: &#entity) -> bool {
+              match self {
+                  Self::All => true,
+                  Self::ById(id) => entity.#id_field == *id,
+                  #(#match_arms)*
+              }
+          }
+      }
+
+      /// Sort keys accepted by the generated repository.
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      pub enum #sort_key {
+          Id,
+      }
+
+      /// In-memory repository generated by `hex_crud!`, in insertion order.
+      #[derive(Debug, Default)]
+      pub struct #repository {
+          pub items: std::vec::Vec<#entity>,
+      }
+
+      impl hexser::ports::repository::Repository<#entity> for #repository {
+          fn save(&mut self, entity: #entity) -> hexser::HexResult<()> {
+              self.upsert(entity).map(|_| ())
+          }
+
+          fn upsert(
+              &mut self,
+              entity: #entity,
+          ) -> hexser::HexResult<hexser::ports::repository::Upserted> {
+              match self.items.iter_mut().find(|e| e.#id_field == entity.#id_field) {
+                  std::option::Option::Some(existing) => {
+                      *existing = entity;
+                      std::result::Result::Ok(hexser::ports::repository::Upserted::Updated)
+                  }
+                  std::option::Option::None => {
+                      self.items.push(entity);
+                      std::result::Result::Ok(hexser::ports::repository::Upserted::Inserted)
+                  }
+              }
+          }
+      }
+
+      impl hexser::ports::repository::QueryRepository<#entity> for #repository {
+          type Filter = #filter;
+          type SortKey = #sort_key;
+
+          fn find_one(
+              &self,
+              filter: &#filter,
+          ) -> hexser::HexResult<std::option::Option<#entity>> {
+              std::result::Result::Ok(self.items.iter().find(|e| filter.matches(e)).cloned())
+          }
+
+          fn find(
+              &self,
+              filter: &#filter,
+              options: hexser::ports::repository::FindOptions<#sort_key>,
+          ) -> hexser::HexResult<std::vec::Vec<#entity>> {
+              let mut found: std::vec::Vec<#entity> =
+                  self.items.iter().filter(|e| filter.matches(e)).cloned().collect();
+              if let std::option::Option::Some(sort) =
+                  options.sort.as_ref().and_then(|sort| sort.first())
+              {
+                  found.sort_by(|a, b| a.#id_field.cmp(&b.#id_field));
+                  if sort.direction == hexser::ports::repository::Direction::Desc {
+                      found.reverse();
+                  }
+              }
+              let offset = options.offset.unwrap_or(0) as usize;
+              let limit = options.limit.map_or(usize::MAX, |limit| limit as usize);
+              std::result::Result::Ok(found.into_iter().skip(offset).take(limit).collect())
+          }
+
+          fn delete_where(&mut self, filter: &#filter) -> hexser::HexResult<u64> {
+              let before = self.items.len();
+              self.items.retain(|e| !filter.matches(e));
+              std::result::Result::Ok((before - self.items.len()) as u64)
+          }
+
+          fn clear(&mut self) -> hexser::HexResult<u64> {
+              let removed = self.items.len() as u64;
+              self.items.clear();
+              std::result::Result::Ok(removed)
+          }
+
+          fn take_where(
+              &mut self,
+              filter: &#filter,
+          ) -> hexser::HexResult<std::vec::Vec<#entity>> {
+              let (taken, kept) = std::mem::take(&mut self.items)
+                  .into_iter()
+                  .partition(|e| filter.matches(e));
+              self.items = kept;
+              std::result::Result::Ok(taken)
+          }
+      }
+  };
+
+  proc_macro::TokenStream::from(expanded)
+}
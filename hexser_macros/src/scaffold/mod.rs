@@ -0,0 +1,6 @@
+//! Function-like macros that scaffold whole adapter skeletons.
+//!
+//! Revision History
+//! - 2026-10-19T02:55:00Z @AI: Initial scaffold module with hex_crud.
+
+pub mod hex_crud;
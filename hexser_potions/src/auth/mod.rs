@@ -5,11 +5,17 @@
 //! - A repository port (UserRepository)
 //! - An adapter (InMemoryUserRepository)
 //! - A directive (SignUpUser) with validation
+//! - An IdGenerator port supplying new user ids
 //! - A small application function to wire it together
 //!
 //! Copy, paste, and adapt as needed.
 //!
 //! Revision History
+//! - 2026-10-19T09:25:00Z @AI: Derive DescribeFilters on UserFilter.
+//! - 2026-10-19T05:05:00Z @AI: Implement clear via Vec::clear.
+//! - 2026-10-18T23:55:00Z @AI: Mint user ids through the IdGenerator port instead of count + 1.
+//! - 2026-10-18T20:45:00Z @AI: Sort by all keys in one pass with a final id tiebreak.
+//! - 2026-10-18T19:00:00Z @AI: Implement take_where by partitioning users.
 //! - 2025-10-07T11:43:00Z @AI: Migrate to v0.4 QueryRepository API; remove id-centric methods; add filter-based querying; fix ID generation.
 
 use hexser::prelude::*;
@@ -54,7 +60,7 @@ impl UserRepository for InMemoryUserRepository {
   }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, DescribeFilters)]
 pub enum UserFilter {
   All,
   ByEmail(String),
@@ -95,19 +101,24 @@ impl hexser::ports::repository::QueryRepository<User> for InMemoryUserRepository
         .collect(),
       UserFilter::ById(id) => self.users.iter().filter(|u| &u.id == id).cloned().collect(),
     };
-    if let Some(mut sorts) = opts.sort {
-      for s in sorts.drain(..).rev() {
-        items.sort_by(|a, b| {
-          let mut ord = match s.key {
-            UserSortKey::Email => a.email.cmp(&b.email),
-            UserSortKey::Id => a.id.cmp(&b.id),
-          };
-          if let hexser::ports::repository::Direction::Desc = s.direction {
-            ord = ord.reverse();
-          }
-          ord
-        });
-      }
+    if let Some(sorts) = opts.sort {
+      // Keys apply in priority order; equal keys fall back to id so results are deterministic.
+      items.sort_by(|a, b| {
+        sorts
+          .iter()
+          .map(|s| {
+            let ord = match s.key {
+              UserSortKey::Email => a.email.cmp(&b.email),
+              UserSortKey::Id => a.id.cmp(&b.id),
+            };
+            match s.direction {
+              hexser::ports::repository::Direction::Asc => ord,
+              hexser::ports::repository::Direction::Desc => ord.reverse(),
+            }
+          })
+          .find(|ord| *ord != std::cmp::Ordering::Equal)
+          .unwrap_or_else(|| a.id.cmp(&b.id))
+      });
     }
     if let Some(offset) = opts.offset {
       let offset_usize: usize = std::convert::TryInto::try_into(offset).unwrap_or(usize::MAX);
@@ -155,6 +166,24 @@ impl hexser::ports::repository::QueryRepository<User> for InMemoryUserRepository
     let removed = before.saturating_sub(self.users.len());
     Ok(removed as u64)
   }
+
+  fn clear(&mut self) -> HexResult<u64> {
+    let removed = self.users.len() as u64;
+    self.users.clear();
+    Ok(removed)
+  }
+
+  fn take_where(&mut self, filter: &UserFilter) -> HexResult<Vec<User>> {
+    let (taken, kept) = std::mem::take(&mut self.users)
+      .into_iter()
+      .partition(|u| match filter {
+        UserFilter::All => true,
+        UserFilter::ByEmail(e) => &u.email == e,
+        UserFilter::ById(id) => &u.id == id,
+      });
+    self.users = kept;
+    Ok(taken)
+  }
 }
 
 /// Directive representing a signup request.
@@ -178,10 +207,14 @@ impl Directive for SignUpUser {
 /// Application helper that executes the signup flow.
 /// - Validates the directive
 /// - Ensures email is unique
-/// - Creates and persists a new user
-pub fn execute_signup<R>(repo: &mut R, cmd: SignUpUser) -> HexResult<User>
+/// - Mints an id from the IdGenerator port and persists the new user
+pub fn execute_signup<R>(
+  repo: &mut R,
+  ids: &dyn hexser::ports::IdGenerator<String>,
+  cmd: SignUpUser,
+) -> HexResult<User>
 where
-  R: UserRepository + hexser::ports::repository::QueryRepository<User, Filter = UserFilter>,
+  R: UserRepository,
 {
   cmd.validate()?;
 
@@ -192,13 +225,8 @@ where
     ));
   }
 
-  // naive ID generation without dependencies: count existing users via QueryRepository
-  let count =
-    <R as hexser::ports::repository::QueryRepository<User>>::count(&*repo, &UserFilter::All)?;
-  let next_id = std::format!("user-{}", count + 1);
-
   let user = User {
-    id: next_id,
+    id: ids.next_id()?,
     email: cmd.email,
   };
 
@@ -213,22 +241,27 @@ mod tests {
   #[test]
   fn signup_happy_path() {
     let mut repo = InMemoryUserRepository::default();
+    let ids = hexser::adapters::SequentialGenerator::with_prefix("user-");
     let user = execute_signup(
       &mut repo,
+      &ids,
       SignUpUser {
         email: "a@b.com".into(),
       },
     )
     .unwrap();
     assert_eq!(user.email, "a@b.com");
+    assert_eq!(user.id, "user-1");
     assert!(repo.find_by_email("a@b.com").unwrap().is_some());
   }
 
   #[test]
   fn signup_rejects_invalid_email() {
     let mut repo = InMemoryUserRepository::default();
+    let ids = hexser::adapters::SequentialGenerator::with_prefix("user-");
     let res = execute_signup(
       &mut repo,
+      &ids,
       SignUpUser {
         email: "not-an-email".into(),
       },
@@ -239,8 +272,10 @@ mod tests {
   #[test]
   fn signup_rejects_duplicates() {
     let mut repo = InMemoryUserRepository::default();
+    let ids = hexser::adapters::SequentialGenerator::with_prefix("user-");
     execute_signup(
       &mut repo,
+      &ids,
       SignUpUser {
         email: "a@b.com".into(),
       },
@@ -248,10 +283,59 @@ mod tests {
     .unwrap();
     let duplicate = execute_signup(
       &mut repo,
+      &ids,
       SignUpUser {
         email: "a@b.com".into(),
       },
     );
     assert!(duplicate.is_err());
   }
+
+  #[test]
+  fn user_repository_describes_user_filters() {
+    use hexser::ports::DescribableRepository;
+
+    let filters = <InMemoryUserRepository as DescribableRepository<User>>::supported_filters();
+    let names: Vec<&str> = filters.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["All", "ByEmail", "ById"]);
+    assert!(filters[0].params.is_empty());
+    assert_eq!(filters[1].param_types(), vec!["String"]);
+    assert_eq!(filters[2].param_types(), vec!["String"]);
+  }
+
+  #[test]
+  fn signup_ids_survive_deletes() {
+    use hexser::ports::repository::QueryRepository;
+
+    let mut repo = InMemoryUserRepository::default();
+    let ids = hexser::adapters::SequentialGenerator::with_prefix("user-");
+    let first = execute_signup(
+      &mut repo,
+      &ids,
+      SignUpUser {
+        email: "a@b.com".into(),
+      },
+    )
+    .unwrap();
+    execute_signup(
+      &mut repo,
+      &ids,
+      SignUpUser {
+        email: "c@d.com".into(),
+      },
+    )
+    .unwrap();
+    repo.delete_where(&UserFilter::ById(first.id)).unwrap();
+
+    let third = execute_signup(
+      &mut repo,
+      &ids,
+      SignUpUser {
+        email: "e@f.com".into(),
+      },
+    )
+    .unwrap();
+    assert_eq!(third.id, "user-3");
+    assert_eq!(repo.count(&UserFilter::All).unwrap(), 2);
+  }
 }
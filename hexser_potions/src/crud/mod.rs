@@ -1,9 +1,16 @@
 //! CRUD potions: a minimal in-memory repository for a single entity type.
 //!
 //! Demonstrates implementing the `Repository<T>` trait with a simple adapter
-//! and using it from application code.
+//! and using it from application code. `hexser::hex_crud!(Item, id: u64,
+//! fields: [name: String])` generates the same entity, filter, sort key, and
+//! adapter; this module keeps them hand-written to show what that expands to.
 //!
 //! Revision History
+//! - 2026-10-19T06:15:00Z @AI: Implement upsert; save delegates to it.
+//! - 2026-10-19T05:05:00Z @AI: Implement clear via Vec::clear.
+//! - 2026-10-19T02:55:00Z @AI: Point to hex_crud! for generating this skeleton.
+//! - 2026-10-18T22:00:00Z @AI: Test exists_all/count_by reference checks.
+//! - 2026-10-18T19:00:00Z @AI: Implement take_where by partitioning items.
 //! - 2025-10-07T11:57:00Z @AI: Migrate to v0.4 Repository/QueryRepository; remove id-centric methods; update API usage.
 
 use hexser::prelude::*;
@@ -27,12 +34,17 @@ pub struct InMemoryItemRepository {
 
 impl Repository<Item> for InMemoryItemRepository {
   fn save(&mut self, entity: Item) -> HexResult<()> {
+    self.upsert(entity).map(|_| ())
+  }
+
+  fn upsert(&mut self, entity: Item) -> HexResult<hexser::ports::repository::Upserted> {
     if let Some(existing) = self.items.iter_mut().find(|e| e.id == entity.id) {
       *existing = entity;
+      Ok(hexser::ports::repository::Upserted::Updated)
     } else {
       self.items.push(entity);
+      Ok(hexser::ports::repository::Upserted::Inserted)
     }
-    Ok(())
   }
 }
 
@@ -78,6 +90,23 @@ impl hexser::ports::repository::QueryRepository<Item> for InMemoryItemRepository
     }
     Ok((before.saturating_sub(self.items.len())) as u64)
   }
+
+  fn clear(&mut self) -> HexResult<u64> {
+    let removed = self.items.len() as u64;
+    self.items.clear();
+    Ok(removed)
+  }
+
+  fn take_where(&mut self, filter: &ItemFilter) -> HexResult<Vec<Item>> {
+    let (taken, kept) = std::mem::take(&mut self.items)
+      .into_iter()
+      .partition(|e| match filter {
+        ItemFilter::All => true,
+        ItemFilter::ById(id) => e.id == *id,
+      });
+    self.items = kept;
+    Ok(taken)
+  }
 }
 
 impl ItemRepository for InMemoryItemRepository {}
@@ -134,4 +163,54 @@ mod tests {
     delete(&mut repo, 1).unwrap();
     assert!(get(&repo, 1).is_err());
   }
+
+  #[test]
+  fn take_where_returns_removed_items_in_order() {
+    let mut repo = InMemoryItemRepository::default();
+    create(&mut repo, 1, "A").unwrap();
+    create(&mut repo, 2, "B").unwrap();
+    create(&mut repo, 3, "C").unwrap();
+
+    let taken =
+      <InMemoryItemRepository as hexser::ports::repository::QueryRepository<Item>>::take_where(
+        &mut repo,
+        &ItemFilter::ById(2),
+      )
+      .unwrap();
+    assert_eq!(taken.iter().map(|i| i.id).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(
+      repo.items.iter().map(|i| i.id).collect::<Vec<_>>(),
+      vec![1, 3]
+    );
+  }
+
+  #[test]
+  fn exists_all_and_count_by_check_references() {
+    let mut repo = InMemoryItemRepository::default();
+    create(&mut repo, 1, "A").unwrap();
+    create(&mut repo, 2, "B").unwrap();
+
+    let present = [ItemFilter::ById(1), ItemFilter::ById(2)];
+    let missing = [ItemFilter::ById(1), ItemFilter::ById(9)];
+    assert!(
+      <InMemoryItemRepository as hexser::ports::repository::QueryRepository<Item>>::exists_all(
+        &repo, &present
+      )
+      .unwrap()
+    );
+    assert!(
+      !<InMemoryItemRepository as hexser::ports::repository::QueryRepository<Item>>::exists_all(
+        &repo, &missing
+      )
+      .unwrap()
+    );
+
+    let counts =
+      <InMemoryItemRepository as hexser::ports::repository::QueryRepository<Item>>::count_by(
+        &repo,
+        &[ItemFilter::All, ItemFilter::ById(2), ItemFilter::ById(9)],
+      )
+      .unwrap();
+    assert_eq!(counts, vec![2, 1, 0]);
+  }
 }